@@ -0,0 +1,779 @@
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+    ShrAssign, Sub, SubAssign
+};
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::number::{decimal, kernels, toom, Number, ParseTernaryError, TryFromIntError};
+use crate::trit::Trit;
+use crate::trit_vec::TritVec;
+
+/// Decimal conversions of more than this many digits are split in two, and
+/// with the `rayon` feature the halves are converted in parallel.
+const SPLIT_DIGITS: usize = 256;
+
+/// A balanced ternary integer of unbounded precision.
+///
+/// Where a [`Number`] wraps on overflow, a `BigTernary` grows to hold the full
+/// result of every operation. The trits are stored from the most significant,
+/// as in a [`Number`], with no leading zeros, so that the trit-wise loops used
+/// by the operators of [`Number`] can be shared directly. Zero has no trits.
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct BigTernary {
+    trits: Vec<Trit>
+}
+
+impl BigTernary {
+    /// The value zero, which has no trits.
+    pub const ZERO: Self = BigTernary { trits: Vec::new() };
+
+    /// Builds an integer from its trits, ordered from the most significant trit
+    /// to the least. Any leading zeros are discarded.
+    pub fn from_trits(trits: &[Trit]) -> Self {
+        Self::from_vec(trits.to_vec())
+    }
+
+    /// Views the significant trits, ordered from the most significant trit to
+    /// the least. Zero has no significant trits.
+    pub fn as_trits(&self) -> &[Trit] {
+        &self.trits
+    }
+
+    /// Returns the number of trits needed to represent the integer, which is
+    /// zero for zero itself.
+    pub fn significant_trits(&self) -> usize {
+        self.trits.len()
+    }
+
+    /// Returns the sign of the integer as a trit.
+    pub fn signum(&self) -> Trit {
+        self.trits.first().copied().unwrap_or(Trit::ZERO)
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub fn is_zero(&self) -> bool {
+        self.trits.is_empty()
+    }
+
+    /// Returns `true` if the integer is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.signum() == Trit::POS
+    }
+
+    /// Returns `true` if the integer is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.signum() == Trit::NEG
+    }
+
+    /// Returns the absolute value of the integer.
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {-self} else {self.clone()}
+    }
+
+    /// Raises the integer to the power of `exponent` by repeated squaring.
+    /// * `exponent` - The power to raise the integer to
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut output = BigTernary::from(1_i8);
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                output *= &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        output
+    }
+
+    /// Returns the greatest common divisor of this integer and `other`. As with
+    /// [`Number::gcd`] the result is always non-negative, and the greatest
+    /// common divisor of zero and zero is zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            (a, b) = (b, remainder);
+        }
+        a
+    }
+
+    /// Performs integer division, returning both the quotient and the
+    /// remainder. As with [`Number::div_rem`] the quotient is rounded towards
+    /// zero and the remainder takes the sign of the numerator.
+    /// * `divisor` - The integer to divide by, which must be non-zero
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if divisor.is_zero() {
+            panic!("Attempt to divide by zero")
+        }
+
+        // The same long division on magnitudes as `Number::div_rem`. The shifted
+        // divisor only needs to reach the width of the numerator for the
+        // remainder to start out less than three times the shifted divisor.
+        let mut abs_remainder = self.abs();
+        let abs_divisor = divisor.abs();
+        let max_shift = self.trits.len().saturating_sub(abs_divisor.trits.len()) + 1;
+
+        let mut quotient = Vec::with_capacity(max_shift + 1);
+        let mut digits = Vec::with_capacity(max_shift + 1);
+        for shift in (0..=max_shift).rev() {
+            let shifted_divisor = &abs_divisor << shift;
+
+            let mut digit = 0;
+            while abs_remainder >= shifted_divisor {
+                abs_remainder -= &shifted_divisor;
+                digit += 1;
+            }
+            digits.push(digit);
+        }
+
+        // The unbalanced digits of 0, 1 or 2 are converted to balanced trits from
+        // the least significant upwards
+        let mut carry = 0;
+        for digit in digits.into_iter().rev() {
+            let (trit, next_carry) = match digit + carry {
+                0 => (Trit::ZERO, 0),
+                1 => (Trit::POS, 0),
+                2 => (Trit::NEG, 1),
+                _ => (Trit::ZERO, 1)
+            };
+            quotient.push(trit);
+            carry = next_carry;
+        }
+        quotient.push(if carry == 1 {Trit::POS} else {Trit::ZERO});
+        quotient.reverse();
+
+        let quotient = Self::from_vec(quotient);
+        let quotient = if self.is_negative() != divisor.is_negative() {-quotient} else {quotient};
+        let remainder = if self.is_negative() {-abs_remainder} else {abs_remainder};
+        (quotient, remainder)
+    }
+
+    /// Fallible [`BigTernary::div_rem`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.div_rem(divisor))
+    }
+
+    /// Parses a conventional decimal integer of any length, with an optional
+    /// leading `+`, `-` or Unicode minus sign `−`.
+    /// ```
+    /// # use balanced_ternary::big_ternary::BigTernary;
+    /// let big = BigTernary::from_decimal_str("-123456789012345678901234567890").unwrap();
+    /// assert_eq!(big.to_decimal_string(), "-123456789012345678901234567890");
+    /// ```
+    pub fn from_decimal_str(encoded: &str) -> Result<Self, ParseTernaryError> {
+        let (is_negative, digits) = decimal::split_decimal(encoded)?;
+        let magnitude = Self::from_decimal_digits(digits);
+        Ok(if is_negative {-magnitude} else {magnitude})
+    }
+
+    /// Formats the value as a conventional decimal integer, with a leading `-`
+    /// if it is negative.
+    pub fn to_decimal_string(&self) -> String {
+        // A value of n trits is less than 3^n, so has at most 0.48n + 1 digits
+        let digits = self.trits.len() * 48 / 100 + 1;
+        let mut chunks = self.abs().to_decimal_chunks(digits.div_ceil(decimal::DIGITS_PER_CHUNK));
+        while chunks.last() == Some(&0) {
+            chunks.pop();
+        }
+
+        decimal::write_chunks(self.is_negative(), &chunks)
+    }
+
+    /// Converts validated decimal digits. Long runs of digits are split in
+    /// half, converting the halves separately and then combining them.
+    fn from_decimal_digits(digits: &str) -> Self {
+        if digits.len() <= SPLIT_DIGITS {
+            let mut magnitude = BigTernary::ZERO;
+            for (chunk, scale) in decimal::decimal_chunks(digits) {
+                magnitude = &magnitude * &BigTernary::from(scale) + BigTernary::from(chunk);
+            }
+            return magnitude;
+        }
+
+        let (high, low) = digits.split_at(digits.len() / 2);
+        let (high, low) = join(
+            || Self::from_decimal_digits(high) * BigTernary::from(10).pow(low.len() as u32),
+            || Self::from_decimal_digits(low)
+        );
+        high + low
+    }
+
+    /// Splits a non-negative value into exactly `count` chunks of 18 decimal
+    /// digits, from the least significant. Many chunks are split in half by
+    /// dividing by a power of ten, converting the quotient and remainder
+    /// separately.
+    fn to_decimal_chunks(&self, count: usize) -> Vec<i128> {
+        if count <= SPLIT_DIGITS / decimal::DIGITS_PER_CHUNK {
+            let divisor = BigTernary::from(decimal::CHUNK_DIVISOR);
+            let mut magnitude = self.clone();
+            let mut chunks = Vec::with_capacity(count);
+            while !magnitude.is_zero() {
+                let (quotient, remainder) = magnitude.div_rem(&divisor);
+                chunks.push(i128::try_from(remainder).unwrap_or_default());
+                magnitude = quotient;
+            }
+            chunks.resize(count, 0);
+            return chunks;
+        }
+
+        let low_count = count / 2;
+        let (high, low) = self.div_rem(&BigTernary::from(decimal::CHUNK_DIVISOR).pow(low_count as u32));
+        let (mut chunks, high_chunks) = join(
+            || low.to_decimal_chunks(low_count),
+            || high.to_decimal_chunks(count - low_count)
+        );
+        chunks.extend(high_chunks);
+        chunks
+    }
+
+    fn from_vec(mut trits: Vec<Trit>) -> Self {
+        let leading_zeros = trits.iter().position(|trit| *trit != Trit::ZERO).unwrap_or(trits.len());
+        trits.drain(..leading_zeros);
+        BigTernary { trits }
+    }
+
+    /// Copies the trits into a vector of the given width, padding with leading
+    /// zeros.
+    fn padded(&self, width: usize) -> Vec<Trit> {
+        let mut trits = alloc::vec![Trit::ZERO; width - self.trits.len()];
+        trits.extend_from_slice(&self.trits);
+        trits
+    }
+}
+
+/// Runs two closures, in parallel on the rayon thread pool with the `rayon`
+/// feature.
+fn join<A: Send, B: Send>(a: impl FnOnce() -> A + Send, b: impl FnOnce() -> B + Send) -> (A, B) {
+    #[cfg(feature = "rayon")]
+    return rayon::join(a, b);
+    #[cfg(not(feature = "rayon"))]
+    return (a(), b());
+}
+
+impl From<i128> for BigTernary {
+    fn from(mut value: i128) -> Self {
+        // Dividing before correcting the digit keeps every intermediate value
+        // in range, including for i128::MIN
+        let mut trits = Vec::new();
+        while value != 0 {
+            let quotient = value / 3;
+            let (trit, quotient) = match value % 3 {
+                0 => (Trit::ZERO, quotient),
+                1 => (Trit::POS, quotient),
+                -1 => (Trit::NEG, quotient),
+                2 => (Trit::NEG, quotient + 1),
+                _ => (Trit::POS, quotient - 1)
+            };
+            trits.push(trit);
+            value = quotient;
+        }
+        trits.reverse();
+        BigTernary { trits }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for BigTernary {
+            fn from(value: $int) -> Self {
+                BigTernary::from(i128::from(value))
+            }
+        }
+    )*};
+}
+
+from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl TryFrom<&BigTernary> for i128 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: &BigTernary) -> Result<Self, Self::Error> {
+        // Each step computes `acc * 3 + trit` as `acc * 2 + (acc + trit)`, which
+        // only overflows when the result does, even for i128::MIN
+        value.trits.iter().try_fold(0_i128, |acc, trit| {
+            acc.checked_mul(2)
+                .and_then(|doubled| doubled.checked_add(acc + i128::from(trit.to_i8())))
+                .ok_or(TryFromIntError(()))
+        })
+    }
+}
+
+impl TryFrom<BigTernary> for i128 {
+    type Error = TryFromIntError;
+
+    fn try_from(value: BigTernary) -> Result<Self, Self::Error> {
+        i128::try_from(&value)
+    }
+}
+
+impl <const N: usize> From<Number<N>> for BigTernary {
+    fn from(number: Number<N>) -> Self {
+        BigTernary::from_trits(number.as_trits())
+    }
+}
+
+impl <const N: usize> TryFrom<&BigTernary> for Number<N> {
+    type Error = TryFromIntError;
+
+    /// Converts to a number of N trits, failing if the integer needs more than
+    /// N trits.
+    fn try_from(value: &BigTernary) -> Result<Self, Self::Error> {
+        if value.trits.len() <= N {
+            Ok(Number::<N>::from_trits_be(&value.trits))
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+impl <const N: usize> TryFrom<BigTernary> for Number<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: BigTernary) -> Result<Self, Self::Error> {
+        Number::<N>::try_from(&value)
+    }
+}
+
+impl From<&TritVec> for BigTernary {
+    fn from(trits: &TritVec) -> Self {
+        let mut trits: Vec<Trit> = trits.iter().collect();
+        trits.reverse();
+        Self::from_vec(trits)
+    }
+}
+
+impl From<TritVec> for BigTernary {
+    fn from(trits: TritVec) -> Self {
+        BigTernary::from(&trits)
+    }
+}
+
+/// Converts to a trit vector exactly as wide as the significant trits.
+impl From<BigTernary> for TritVec {
+    fn from(value: BigTernary) -> Self {
+        value.trits.iter().rev().copied().collect()
+    }
+}
+
+impl Ord for BigTernary {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // With no leading zeros, the sign and then the width decide most
+        // comparisons before any trits need to be compared
+        match self.signum().cmp(&other.signum()) {
+            Ordering::Equal => (),
+            ordering => return ordering
+        }
+
+        match self.trits.len().cmp(&other.trits.len()) {
+            Ordering::Equal => kernels::compare(&self.trits, &other.trits),
+            ordering if self.is_negative() => ordering.reverse(),
+            ordering => ordering
+        }
+    }
+}
+
+impl PartialOrd for BigTernary {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Sum for BigTernary {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(BigTernary::ZERO, Add::add)
+    }
+}
+
+impl Neg for BigTernary {
+    type Output = BigTernary;
+
+    fn neg(mut self) -> Self::Output {
+        kernels::negate(&mut self.trits);
+        self
+    }
+}
+
+impl Neg for &BigTernary {
+    type Output = BigTernary;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add for &BigTernary {
+    type Output = BigTernary;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // One extra trit always holds the carry out of the sum
+        let width = self.trits.len().max(rhs.trits.len()) + 1;
+        let mut sum = self.padded(width);
+        kernels::add_assign(&mut sum, &rhs.padded(width), Trit::ZERO);
+        BigTernary::from_vec(sum)
+    }
+}
+
+impl Sub for &BigTernary {
+    type Output = BigTernary;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigTernary {
+    type Output = BigTernary;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigTernary::ZERO;
+        }
+
+        // Multiply the trits as polynomials, as for wide numbers, keeping every
+        // trit of the product
+        let lhs_coefficients: Vec<i64> = self.trits.iter().rev().map(|trit| i64::from(trit.to_i8())).collect();
+        let rhs_coefficients: Vec<i64> = rhs.trits.iter().rev().map(|trit| i64::from(trit.to_i8())).collect();
+        let product = toom::convolve(&lhs_coefficients, &rhs_coefficients);
+
+        let mut trits: Vec<Trit> = toom::normalise(&product).collect();
+        trits.reverse();
+        BigTernary::from_vec(trits)
+    }
+}
+
+impl Div for &BigTernary {
+    type Output = BigTernary;
+
+    fn div(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).0
+    }
+}
+
+impl Rem for &BigTernary {
+    type Output = BigTernary;
+
+    fn rem(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).1
+    }
+}
+
+// Forwards the operators on owned integers and mixed operands to the
+// implementations on references
+macro_rules! forward_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl $op_trait for BigTernary {
+            type Output = BigTernary;
+
+            fn $op_fn(self, rhs: BigTernary) -> Self::Output {
+                $op_trait::$op_fn(&self, &rhs)
+            }
+        }
+
+        impl $op_trait<&BigTernary> for BigTernary {
+            type Output = BigTernary;
+
+            fn $op_fn(self, rhs: &BigTernary) -> Self::Output {
+                $op_trait::$op_fn(&self, rhs)
+            }
+        }
+
+        impl $op_trait<BigTernary> for &BigTernary {
+            type Output = BigTernary;
+
+            fn $op_fn(self, rhs: BigTernary) -> Self::Output {
+                $op_trait::$op_fn(self, &rhs)
+            }
+        }
+
+        impl $assign_trait<&BigTernary> for BigTernary {
+            fn $assign_fn(&mut self, rhs: &BigTernary) {
+                *self = $op_trait::$op_fn(&*self, rhs);
+            }
+        }
+
+        impl $assign_trait for BigTernary {
+            fn $assign_fn(&mut self, rhs: BigTernary) {
+                *self = $op_trait::$op_fn(&*self, &rhs);
+            }
+        }
+    };
+}
+
+forward_binary_op!(Add, add, AddAssign, add_assign);
+forward_binary_op!(Sub, sub, SubAssign, sub_assign);
+forward_binary_op!(Mul, mul, MulAssign, mul_assign);
+forward_binary_op!(Div, div, DivAssign, div_assign);
+forward_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+/// Multiplies by 3^positions. No trits are ever lost.
+impl Shl<usize> for &BigTernary {
+    type Output = BigTernary;
+
+    fn shl(self, positions: usize) -> Self::Output {
+        let mut output = self.clone();
+        output <<= positions;
+        output
+    }
+}
+
+impl Shl<usize> for BigTernary {
+    type Output = BigTernary;
+
+    fn shl(mut self, positions: usize) -> Self::Output {
+        self <<= positions;
+        self
+    }
+}
+
+impl ShlAssign<usize> for BigTernary {
+    fn shl_assign(&mut self, positions: usize) {
+        if !self.is_zero() {
+            self.trits.resize(self.trits.len() + positions, Trit::ZERO);
+        }
+    }
+}
+
+/// Divides by 3^positions, rounding to the nearest integer in the same way as
+/// a right shift of a [`Number`].
+impl Shr<usize> for &BigTernary {
+    type Output = BigTernary;
+
+    fn shr(self, positions: usize) -> Self::Output {
+        let mut output = self.clone();
+        output >>= positions;
+        output
+    }
+}
+
+impl Shr<usize> for BigTernary {
+    type Output = BigTernary;
+
+    fn shr(mut self, positions: usize) -> Self::Output {
+        self >>= positions;
+        self
+    }
+}
+
+impl ShrAssign<usize> for BigTernary {
+    fn shr_assign(&mut self, positions: usize) {
+        // The leading trit is non-zero, so no leading zeros are exposed unless
+        // every trit is shifted out
+        let kept = self.trits.len().saturating_sub(positions);
+        self.trits.truncate(kept);
+    }
+}
+
+impl FromStr for BigTernary {
+    type Err = ParseTernaryError;
+
+    /// Parses the `-`/`0`/`+` notation, most significant trit first. Leading
+    /// zeros are accepted and discarded.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        if encoded.is_empty() {
+            return Err(ParseTernaryError::Empty);
+        }
+
+        let trits = encoded.chars()
+            .enumerate()
+            .map(|(position, character)| Trit::from_char(character)
+                .ok_or(ParseTernaryError::InvalidTrit { character, position }))
+            .collect::<Result<Vec<Trit>, _>>()?;
+        Ok(BigTernary::from_vec(trits))
+    }
+}
+
+/// Writes the significant trits from the most significant, or a single `0`
+/// for zero.
+impl fmt::Display for BigTernary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", Trit::ZERO);
+        }
+        for trit in self.trits.iter() {
+            write!(f, "{}", trit)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BigTernary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BigTernary({})", self)
+    }
+}
+
+/// Overwrites the trits, along with any spare capacity, leaving zero. Integers
+/// are also zeroized when dropped.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for BigTernary {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.trits);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BigTernary {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for BigTernary {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(encoded: &str) -> BigTernary {
+        encoded.parse().unwrap()
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(big("000+-0--"), big("+-0--"));
+        assert_eq!(big("000"), BigTernary::ZERO);
+        assert_eq!(big("+-0--").significant_trits(), 5);
+        assert_eq!(BigTernary::ZERO.to_string(), "0");
+        assert_eq!(format!("{:?}", big("-+0++")), "BigTernary(-+0++)");
+        assert_eq!("+x".parse::<BigTernary>(), Err(ParseTernaryError::InvalidTrit { character: 'x', position: 1 }));
+    }
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(BigTernary::from(50), big("+-0--"));
+        assert_eq!(BigTernary::from(-50_i64), big("-+0++"));
+        assert_eq!(BigTernary::from(0_u8), BigTernary::ZERO);
+
+        for value in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX - 1, i128::MAX] {
+            assert_eq!(i128::try_from(BigTernary::from(value)), Ok(value));
+        }
+        let too_big = BigTernary::from(i128::MAX) + BigTernary::from(1);
+        assert_eq!(i128::try_from(too_big), Err(TryFromIntError(())));
+    }
+
+    #[test]
+    fn decimal_strings() {
+        let power = BigTernary::from(3).pow(100);
+        let decimal = "515377520732011331036461129765621272702107522001";
+        assert_eq!(power.to_decimal_string(), decimal);
+        assert_eq!(BigTernary::from_decimal_str(decimal), Ok(power.clone()));
+        assert_eq!(BigTernary::from_decimal_str(&format!("\u{2212}{}", decimal)), Ok(-power));
+
+        assert_eq!(BigTernary::ZERO.to_decimal_string(), "0");
+        assert_eq!(BigTernary::from(-50).to_decimal_string(), "-50");
+        assert_eq!(BigTernary::from_decimal_str("1000000000000000000"), Ok(BigTernary::from(1_000_000_000_000_000_000_i64)));
+        assert_eq!(BigTernary::from_decimal_str("+"), Err(ParseTernaryError::Empty));
+        assert_eq!(BigTernary::from_decimal_str("12a"), Err(ParseTernaryError::InvalidDigit { character: 'a', position: 2 }));
+    }
+
+    #[test]
+    fn long_decimal_strings() {
+        // Long enough for the digits to be split in half more than once, with
+        // runs of zero chunks either side of each split
+        let nines = "9".repeat(700);
+        let power = BigTernary::from(10).pow(700);
+        assert_eq!(BigTernary::from_decimal_str(&nines), Ok(&power - &BigTernary::from(1)));
+        assert_eq!(power.to_decimal_string(), format!("1{}", "0".repeat(700)));
+        assert_eq!((-&power + BigTernary::from(1)).to_decimal_string(), format!("-{}", nines));
+
+        let power = BigTernary::from(3).pow(1500);
+        assert_eq!(BigTernary::from_decimal_str(&power.to_decimal_string()), Ok(power));
+    }
+
+    #[test]
+    fn number_conversions() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(BigTernary::from(num_50), big("+-0--"));
+        assert_eq!(Number::<5>::try_from(big("+-0--")), Ok(Number::<5>::from("+-0--")));
+        assert_eq!(Number::<4>::try_from(big("+-0--")), Err(TryFromIntError(())));
+
+        let trits = TritVec::from(big("+-0--"));
+        assert_eq!(trits.len(), 5);
+        assert_eq!(BigTernary::from(trits), big("+-0--"));
+    }
+
+    #[test]
+    fn arithmetic_matches_i128() {
+        let values = [-3281_i128, -1000, -50, -13, -4, -1, 0, 1, 2, 3, 14, 50, 121, 9841, 88573];
+        for lhs in values {
+            for rhs in values {
+                let (big_lhs, big_rhs) = (BigTernary::from(lhs), BigTernary::from(rhs));
+
+                assert_eq!(&big_lhs + &big_rhs, BigTernary::from(lhs + rhs));
+                assert_eq!(&big_lhs - &big_rhs, BigTernary::from(lhs - rhs));
+                assert_eq!(&big_lhs * &big_rhs, BigTernary::from(lhs * rhs));
+                assert_eq!(big_lhs.cmp(&big_rhs), lhs.cmp(&rhs));
+                if rhs != 0 {
+                    assert_eq!(&big_lhs / &big_rhs, BigTernary::from(lhs / rhs));
+                    assert_eq!(&big_lhs % &big_rhs, BigTernary::from(lhs % rhs));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unbounded_growth() {
+        // 3^100 - 1 overflows every primitive integer type
+        let power = BigTernary::from(3).pow(100);
+        assert_eq!(power.significant_trits(), 101);
+        assert_eq!(power, BigTernary::from(1) << 100);
+
+        let squared = &power * &power;
+        assert_eq!(squared, BigTernary::from(1) << 200);
+        assert_eq!(&squared / &power, power);
+        assert_eq!(&(&squared + &BigTernary::from(7)) % &power, BigTernary::from(7));
+        assert_eq!(squared.gcd(&(&power * &BigTernary::from(-4))), power);
+        assert_eq!(BigTernary::ZERO.gcd(&-&power), power);
+
+        let mut accumulator = power.clone();
+        accumulator -= BigTernary::from(1);
+        assert_eq!(accumulator.to_string(), format!("+{}-", "0".repeat(99)));
+    }
+
+    #[test]
+    fn shifts() {
+        let num_50 = big("+-0--");
+
+        assert_eq!(&num_50 << 2, big("+-0--00"));
+        assert_eq!(&num_50 >> 2, big("+-0"));
+        assert_eq!(&num_50 >> 5, BigTernary::ZERO);
+        assert_eq!(big("+000-") >> 1, big("+000"));
+        assert_eq!(BigTernary::ZERO << 3, BigTernary::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = big("+-0--");
+        secret.zeroize();
+        assert!(secret.is_zero());
+        assert_eq!(secret, BigTernary::ZERO);
+    }
+
+    #[test]
+    fn fallible_division() {
+        let (num_50, num_neg_7) = (big("+-0--"), big("-+-"));
+        assert_eq!(num_50.try_div_rem(&BigTernary::ZERO), Err(Error::DivisionByZero));
+        assert_eq!(num_50.try_div_rem(&num_neg_7), Ok(num_50.div_rem(&num_neg_7)));
+    }
+}
@@ -0,0 +1,323 @@
+//! `tern`, a command-line tool for converting and calculating with balanced
+//! ternary numbers. It is built with the `cli` feature:
+//! ```text
+//! $ cargo run --features cli -- convert 42
+//! trits:   +---0
+//! decimal: 42
+//! base27:  2F
+//! ```
+//! Run `tern --help` for the full list of commands and options.
+
+use std::env;
+use std::io;
+use std::process::ExitCode;
+
+use balanced_ternary::eval::{Context, EvalError};
+use balanced_ternary::number::{FormatOptions, Number};
+use balanced_ternary::Error;
+
+/// The widths that numbers can be given, in trits.
+const WIDTHS: [usize; 4] = [9, 27, 81, 243];
+
+/// Calls a function that is generic over the width of its numbers with a
+/// width chosen at runtime, which must be one of [`WIDTHS`].
+macro_rules! with_width {
+    ($width:expr, $function:ident($($arg:expr),*)) => {
+        match $width {
+            9 => $function::<9>($($arg),*),
+            27 => $function::<27>($($arg),*),
+            81 => $function::<81>($($arg),*),
+            243 => $function::<243>($($arg),*),
+            width => unreachable!("unsupported width {}", width)
+        }
+    };
+}
+
+mod repl;
+
+const USAGE: &str = "\
+Usage: tern [OPTIONS] <COMMAND> <ARGS>...
+
+Commands:
+  convert <VALUE>...   Writes each value as trits, in decimal and in base 27
+  eval <EXPRESSION>    Evaluates an arithmetic expression of + - * / % ^ and
+                       parentheses, on decimal integers and quoted trit
+                       strings such as \"+0--\"
+  info <VALUE>...      Writes the width, significant trits and trit counts
+                       of each value
+  repl                 Starts an interactive session with variables, where
+                       :help lists the commands
+
+Options:
+  -w, --width <TRITS>  The width of the numbers: 9, 27 (default), 81 or 243
+  -f, --from <NOTATION>
+                       How values are written: decimal (default), trits or
+                       base27
+  -t, --to <NOTATION>  Writes results only in the given notation
+  -h, --help           Prints this help";
+
+/// The notations that values can be read and written in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Notation {
+    Decimal,
+    Trits,
+    Base27
+}
+
+impl Notation {
+    const ALL: [Notation; 3] = [Notation::Trits, Notation::Decimal, Notation::Base27];
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "decimal" | "dec" | "10" => Some(Notation::Decimal),
+            "trits" | "ternary" | "3" => Some(Notation::Trits),
+            "base27" | "27" => Some(Notation::Base27),
+            _ => None
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Notation::Decimal => "decimal",
+            Notation::Trits => "trits",
+            Notation::Base27 => "base27"
+        }
+    }
+
+    fn read<const N: usize>(self, encoded: &str) -> Result<Number<N>, Error> {
+        Ok(match self {
+            Notation::Decimal => Number::from_decimal_str(encoded)?,
+            Notation::Trits => encoded.parse()?,
+            Notation::Base27 => Number::from_base27_str(encoded)?
+        })
+    }
+
+    fn write<const N: usize>(self, number: Number<N>) -> String {
+        match self {
+            Notation::Decimal => number.to_decimal_string(),
+            Notation::Trits => number.format_with(&FormatOptions::new().with_leading_zeros(false)).to_string(),
+            Notation::Base27 => {
+                let digits = number.to_base27_string();
+                let significant = digits.trim_start_matches('0');
+                if significant.is_empty() {"0".to_string()} else {significant.to_string()}
+            }
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    Convert(Vec<String>),
+    Eval(String),
+    Info(Vec<String>),
+    Repl
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Options {
+    width: usize,
+    from: Notation,
+    to: Option<Notation>,
+    command: Command
+}
+
+/// Reads the command line, or returns `Ok(None)` if help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let (mut width, mut from, mut to) = (27, Notation::Decimal, None);
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| args.next().ok_or_else(|| format!("{} needs a value", option));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--" => positional.extend(args.by_ref()),
+            "-w" | "--width" => {
+                let trits = value(&arg)?;
+                width = trits.parse().map_err(|_| format!("invalid width {:?}", trits))?;
+            },
+            "-f" | "--from" => {
+                let name = value(&arg)?;
+                from = Notation::from_name(&name).ok_or_else(|| format!("unknown notation {:?}", name))?;
+            },
+            "-t" | "--to" => {
+                let name = value(&arg)?;
+                to = Some(Notation::from_name(&name).ok_or_else(|| format!("unknown notation {:?}", name))?);
+            },
+            // Anything else is a value, including negative decimals such as -5
+            _ => positional.push(arg)
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let command = match positional.next().as_deref() {
+        Some("convert") => Command::Convert(positional.collect()),
+        Some("info") => Command::Info(positional.collect()),
+        // The expression may be split across arguments by the shell
+        Some("eval") => Command::Eval(positional.collect::<Vec<_>>().join(" ")),
+        Some("repl") => match positional.next() {
+            None => Command::Repl,
+            Some(_) => return Err("repl takes no values".to_string())
+        },
+        Some(other) => return Err(format!("unknown command {:?}", other)),
+        None => return Err("no command given".to_string())
+    };
+    match &command {
+        Command::Convert(values) | Command::Info(values) if values.is_empty() =>
+            return Err("no values given".to_string()),
+        Command::Eval(expression) if expression.trim().is_empty() =>
+            return Err("no expression given".to_string()),
+        _ => ()
+    }
+
+    if !WIDTHS.contains(&width) {
+        return Err(format!("unsupported width {}, expected 9, 27, 81 or 243", width));
+    }
+
+    Ok(Some(Options { width, from, to, command }))
+}
+
+/// Runs the command on numbers of N trits, returning the lines to print.
+fn run<const N: usize>(options: &Options) -> Result<Vec<String>, String> {
+    let labelled = |number| labelled::<N>(number, options.to);
+    let mut lines = Vec::new();
+    match &options.command {
+        Command::Convert(values) => for value in values {
+            lines.extend(labelled(options.from.read(value).map_err(|error| error.to_string())?));
+        },
+        Command::Eval(expression) => {
+            let value = Context::new().evaluate(expression)
+                .map_err(|error| describe_eval_error(expression, &error))?;
+            lines.extend(labelled(value));
+        },
+        Command::Info(values) => for value in values {
+            let number: Number<N> = options.from.read(value).map_err(|error| error.to_string())?;
+            let [neg, zero, pos] = number.trit_counts();
+            lines.extend(labelled(number));
+            lines.push(format!("width:   {} trits", N));
+            lines.push(format!("significant trits: {}", number.significant_trits()));
+            lines.push(format!("trit counts: {} positive, {} zero, {} negative", pos, zero, neg));
+        },
+        Command::Repl => unreachable!("the REPL is run separately")
+    }
+    Ok(lines)
+}
+
+/// Writes a number in the notation `to`, or on a labelled line for each
+/// notation if it is `None`.
+fn labelled<const N: usize>(number: Number<N>, to: Option<Notation>) -> Vec<String> {
+    match to {
+        Some(notation) => vec![notation.write(number)],
+        None => Notation::ALL.iter()
+            .map(|notation| format!("{:<8} {}", format!("{}:", notation.name()), notation.write(number)))
+            .collect()
+    }
+}
+
+/// Describes why an expression couldn't be evaluated, pointing out where a
+/// parse error lies.
+fn describe_eval_error(expression: &str, error: &EvalError) -> String {
+    match error {
+        EvalError::Parse(parse_error) =>
+            format!("{}\n  {}\n  {:>3$}", parse_error, expression, "^", parse_error.position() + 1),
+        _ => error.to_string()
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        },
+        Err(message) => {
+            eprintln!("tern: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if options.command == Command::Repl {
+        let repl = repl::Repl::new(options.width, options.to);
+        return match repl::run(repl, io::stdin().lock(), io::stdout()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("tern: {}", error);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    match with_width!(options.width, run(&options)) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+            ExitCode::SUCCESS
+        },
+        Err(error) => {
+            eprintln!("tern: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse_args(args("-w 81 --from trits convert +0-- -+")).unwrap().unwrap();
+        assert_eq!(options, Options {
+            width: 81,
+            from: Notation::Trits,
+            to: None,
+            command: Command::Convert(vec!["+0--".to_string(), "-+".to_string()])
+        });
+        assert_eq!(parse_args(args("eval 1 + -2")).unwrap().unwrap().command, Command::Eval("1 + -2".to_string()));
+        assert_eq!(parse_args(args("info -h")), Ok(None));
+        assert!(parse_args(args("convert")).is_err());
+        assert!(parse_args(args("frobnicate 1")).is_err());
+        assert!(parse_args(args("--to octal convert 1")).is_err());
+        assert!(parse_args(args("-w 10 convert 1")).is_err());
+        assert_eq!(parse_args(args("repl")).unwrap().unwrap().command, Command::Repl);
+        assert!(parse_args(args("repl 1")).is_err());
+    }
+
+    #[test]
+    fn converts_between_notations() {
+        let options = parse_args(args("convert -42")).unwrap().unwrap();
+        assert_eq!(run::<27>(&options).unwrap(), ["trits:   -+++0", "decimal: -42", "base27:  PC"]);
+
+        let options = parse_args(args("--from base27 --to trits convert 2F 0")).unwrap().unwrap();
+        assert_eq!(run::<9>(&options).unwrap(), ["+---0", "0"]);
+        let options = parse_args(args("convert 99999")).unwrap().unwrap();
+        assert!(run::<9>(&options).is_err());
+    }
+
+    #[test]
+    fn evaluates_expressions() {
+        let options = parse_args(args("--to decimal eval \"+0--\" * 3 - 7 % 4")).unwrap().unwrap();
+        assert_eq!(run::<9>(&options).unwrap(), ["66"]);
+
+        let options = parse_args(args("eval 1 + * 2")).unwrap().unwrap();
+        assert_eq!(run::<9>(&options).unwrap_err(), "unexpected character '*' at position 4\n  1 + * 2\n      ^");
+        let options = parse_args(args("eval 9841 + 1")).unwrap().unwrap();
+        assert_eq!(run::<9>(&options).unwrap_err(), "value is out of range of the target type");
+    }
+
+    #[test]
+    fn reports_properties() {
+        let options = parse_args(args("--to decimal info 23")).unwrap().unwrap();
+        assert_eq!(run::<9>(&options).unwrap(), [
+            "23",
+            "width:   9 trits",
+            "significant trits: 4",
+            "trit counts: 1 positive, 6 zero, 2 negative"
+        ]);
+    }
+}
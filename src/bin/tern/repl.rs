@@ -0,0 +1,249 @@
+//! The interactive mode of `tern`, started with `tern repl`, which evaluates
+//! each line as an expression or assignment and keeps the variables between
+//! lines.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use balanced_ternary::big_ternary::BigTernary;
+use balanced_ternary::eval::Context;
+use balanced_ternary::number::Number;
+
+use super::{describe_eval_error, labelled, Notation, WIDTHS};
+
+const HELP: &str = "\
+Enter an expression to evaluate it, or `name = expression` to assign it to a
+variable. The last result is kept in the variable `_`.
+
+Commands:
+  :help            Prints this help
+  :vars            Lists the variables
+  :history         Lists the lines entered so far
+  !N               Runs line N of the history again
+  :width <TRITS>   Changes the width to 9, 27, 81 or 243 trits
+  :to <NOTATION>   Writes results in decimal, trits, base27 or all of them
+  :quit            Leaves the REPL, as does the end of input";
+
+/// The state of an interactive session.
+///
+/// Variables are kept as [`BigTernary`] values so that they outlive a change
+/// of width, and are only narrowed to the current width for evaluation.
+#[derive(Debug)]
+pub struct Repl {
+    width: usize,
+    to: Option<Notation>,
+    variables: BTreeMap<String, BigTernary>,
+    history: Vec<String>
+}
+
+impl Repl {
+    /// Starts a session with numbers of `width` trits, which must be one of
+    /// [`WIDTHS`], writing results as `to` or in every notation if `None`.
+    pub fn new(width: usize, to: Option<Notation>) -> Self {
+        Repl { width, to, variables: BTreeMap::new(), history: Vec::new() }
+    }
+
+    /// Handles one line of input, returning the lines to print or a
+    /// description of what went wrong.
+    pub fn handle_line(&mut self, line: &str) -> Result<Vec<String>, String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(Vec::new());
+        }
+        if let Some(index) = line.strip_prefix('!') {
+            let entry = index.parse::<usize>().ok()
+                .and_then(|index| self.history.get(index.wrapping_sub(1)))
+                .cloned()
+                .ok_or_else(|| format!("no line {} in the history", index))?;
+            // Echo the line being run, as a shell does
+            let mut lines = vec![entry.clone()];
+            lines.extend(self.handle_line(&entry)?);
+            return Ok(lines);
+        }
+        if let Some(command) = line.strip_prefix(':') {
+            return self.command(command);
+        }
+
+        self.history.push(line.to_string());
+        let (name, expression) = match line.split_once('=') {
+            Some((name, expression)) if is_variable_name(name.trim()) => (Some(name.trim()), expression.trim()),
+            _ => (None, line)
+        };
+        with_width!(self.width, evaluate(self, name, expression))
+    }
+
+    fn command(&mut self, command: &str) -> Result<Vec<String>, String> {
+        let (command, argument) = command.split_once(char::is_whitespace).unwrap_or((command, ""));
+        let argument = argument.trim();
+        match command {
+            "help" | "h" => Ok(HELP.lines().map(String::from).collect()),
+            "vars" => Ok(with_width!(self.width, list_variables(self))),
+            "history" => Ok(self.history.iter()
+                .enumerate()
+                .map(|(index, line)| format!("{:>4}  {}", index + 1, line))
+                .collect()),
+            "width" => {
+                let width = argument.parse().ok()
+                    .filter(|width| WIDTHS.contains(width))
+                    .ok_or_else(|| format!("unsupported width {:?}, expected 9, 27, 81 or 243", argument))?;
+                self.width = width;
+
+                // Variables too large for the new width are forgotten
+                let mut lines = vec![format!("width is now {} trits", width)];
+                let too_wide = self.variables.iter()
+                    .filter(|(_, value)| value.significant_trits() > width)
+                    .map(|(name, _)| name.clone())
+                    .collect::<Vec<_>>();
+                for name in too_wide {
+                    self.variables.remove(&name);
+                    lines.push(format!("removed {} as it doesn't fit", name));
+                }
+                Ok(lines)
+            },
+            "to" => {
+                self.to = match argument {
+                    "all" => None,
+                    name => Some(Notation::from_name(name).ok_or_else(|| format!("unknown notation {:?}", name))?)
+                };
+                Ok(Vec::new())
+            },
+            _ => Err(format!("unknown command :{}, try :help", command))
+        }
+    }
+}
+
+/// Evaluates an expression with numbers of N trits, assigning the result to
+/// `name` if there is one, and to `_`.
+fn evaluate<const N: usize>(repl: &mut Repl, name: Option<&str>, expression: &str) -> Result<Vec<String>, String> {
+    let mut context = Context::<N>::new();
+    for (name, value) in &repl.variables {
+        if let Ok(value) = Number::try_from(value) {
+            context.set(name.as_str(), value);
+        }
+    }
+
+    let value = context.evaluate(expression).map_err(|error| describe_eval_error(expression, &error))?;
+    if let Some(name) = name {
+        repl.variables.insert(name.to_string(), BigTernary::from(value));
+    }
+    repl.variables.insert("_".to_string(), BigTernary::from(value));
+    Ok(labelled(value, repl.to))
+}
+
+/// Lists the variables in the output notation, or in decimal if every
+/// notation is being written.
+fn list_variables<const N: usize>(repl: &Repl) -> Vec<String> {
+    let notation = repl.to.unwrap_or(Notation::Decimal);
+    repl.variables.iter()
+        .filter_map(|(name, value)| Some(format!("{} = {}", name, notation.write(Number::<N>::try_from(value).ok()?))))
+        .collect()
+}
+
+/// Returns whether `name` can be assigned to and used in an expression.
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|first| first.is_alphabetic() || first == '_')
+        && chars.all(|character| character.is_alphanumeric() || character == '_')
+}
+
+/// Runs a session, reading lines from `input` until it ends or `:quit` is
+/// entered, and writing prompts, results and errors to `output`.
+pub fn run(mut repl: Repl, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    writeln!(output, "tern REPL with {} trit numbers, enter :help for help", repl.width)?;
+    let mut lines = input.lines();
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            // Leave the terminal on a fresh line
+            return writeln!(output);
+        };
+
+        let line = line?;
+        if matches!(line.trim(), ":quit" | ":q") {
+            return Ok(());
+        }
+        match repl.handle_line(&line) {
+            Ok(results) => for result in results {
+                writeln!(output, "{}", result)?;
+            },
+            Err(error) => writeln!(output, "error: {}", error)?
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variables_and_assignment() {
+        let mut repl = Repl::new(9, Some(Notation::Decimal));
+        assert_eq!(repl.handle_line("x = 6 * 7"), Ok(vec!["42".to_string()]));
+        assert_eq!(repl.handle_line("  x - 2  "), Ok(vec!["40".to_string()]));
+        assert_eq!(repl.handle_line("_ / 4"), Ok(vec!["10".to_string()]));
+        assert_eq!(repl.handle_line("y"), Err("unknown variable \"y\"".to_string()));
+        assert_eq!(repl.handle_line(":vars"), Ok(vec!["_ = 10".to_string(), "x = 42".to_string()]));
+
+        // Not a variable name, so the `=` is a parse error
+        assert!(repl.handle_line("2x = 1").is_err());
+        assert_eq!(repl.handle_line(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn history() {
+        let mut repl = Repl::new(9, Some(Notation::Trits));
+        repl.handle_line("x = 4").unwrap();
+        repl.handle_line("x * 3").unwrap();
+        repl.handle_line("x = x + 1").unwrap();
+        assert_eq!(repl.handle_line("!3"), Ok(vec!["x = x + 1".to_string(), "+-0".to_string()]));
+        assert_eq!(repl.handle_line("!2"), Ok(vec!["x * 3".to_string(), "+-00".to_string()]));
+        assert_eq!(repl.handle_line(":history"), Ok(vec![
+            "   1  x = 4".to_string(),
+            "   2  x * 3".to_string(),
+            "   3  x = x + 1".to_string(),
+            "   4  x = x + 1".to_string(),
+            "   5  x * 3".to_string()
+        ]));
+        assert!(repl.handle_line("!0").is_err());
+        assert!(repl.handle_line("!6").is_err());
+    }
+
+    #[test]
+    fn width_and_notation() {
+        let mut repl = Repl::new(27, None);
+        repl.handle_line("big = 3 ^ 20").unwrap();
+        repl.handle_line("small = -13").unwrap();
+        assert_eq!(repl.handle_line("small"), Ok(vec![
+            "trits:   ---".to_string(),
+            "decimal: -13".to_string(),
+            "base27:  E".to_string()
+        ]));
+
+        assert_eq!(repl.handle_line(":width 9"), Ok(vec![
+            "width is now 9 trits".to_string(),
+            "removed big as it doesn't fit".to_string()
+        ]));
+        assert_eq!(repl.handle_line(":to base27"), Ok(vec![]));
+        assert_eq!(repl.handle_line("small * 2"), Ok(vec!["Q1".to_string()]));
+        assert_eq!(repl.handle_line("9841 + 1"), Err("value is out of range of the target type".to_string()));
+        assert!(repl.handle_line(":width 10").is_err());
+        assert!(repl.handle_line(":to octal").is_err());
+        assert!(repl.handle_line(":frobnicate").is_err());
+    }
+
+    #[test]
+    fn session() {
+        let input = "a = 5\n:to decimal\na * (2 +\na * a\n:quit\n1 + 1\n";
+        let mut output = Vec::new();
+        run(Repl::new(9, Some(Notation::Trits)), input.as_bytes(), &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "\
+tern REPL with 9 trit numbers, enter :help for help
+> +--
+> > error: expected an operand at position 8
+  a * (2 +
+          ^
+> 25
+> ");
+    }
+}
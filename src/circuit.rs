@@ -0,0 +1,338 @@
+//! Gate-level ternary logic circuits.
+//!
+//! A [`Circuit`] is a netlist of gates built up one at a time, each returning
+//! the [`Wire`] carrying its output. A gate can only take wires that already
+//! exist as its inputs, so every circuit is acyclic and is simulated in a
+//! single pass over its gates.
+//! ```
+//! # use balanced_ternary::circuit::Circuit;
+//! # use balanced_ternary::trit::Trit;
+//! // The consensus of two trits is the carry of their sum
+//! let mut circuit = Circuit::new();
+//! let lhs = circuit.input();
+//! let rhs = circuit.input();
+//! let carry = circuit.consensus(lhs, rhs);
+//! circuit.output(carry);
+//!
+//! assert_eq!(circuit.simulate(&[Trit::POS, Trit::POS]), [Trit::POS]);
+//! assert_eq!(circuit.simulate(&[Trit::POS, Trit::ZERO]), [Trit::ZERO]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::trit::{logic, Trit};
+use crate::truth_table::TruthTable;
+
+/// A handle to the output of a gate in a [`Circuit`]. Wires are only
+/// meaningful in the circuit that created them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Wire(usize);
+
+impl Wire {
+    /// Returns the position of the gate driving this wire, which indexes the
+    /// values returned by [`Circuit::values`].
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A gate of a circuit, along with the wires it reads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Gate {
+    /// The next of the circuit's inputs.
+    Input,
+    /// A fixed trit.
+    Constant(Trit),
+    /// The lesser of two trits, the ternary AND.
+    Min(Wire, Wire),
+    /// The greater of two trits, the ternary OR.
+    Max(Wire, Wire),
+    /// The negation of a trit.
+    Not(Wire),
+    /// The common value of two trits if they agree, and zero otherwise.
+    Consensus(Wire, Wire),
+    /// A trit plus one modulo three, cycling from negative to zero to positive
+    /// and back to negative.
+    Increment(Wire)
+}
+
+impl Gate {
+    fn evaluate(self, values: &[Trit]) -> Trit {
+        match self {
+            Gate::Input => unreachable!(),
+            Gate::Constant(trit) => trit,
+            Gate::Min(lhs, rhs) => logic::and(values[lhs.0], values[rhs.0]),
+            Gate::Max(lhs, rhs) => logic::or(values[lhs.0], values[rhs.0]),
+            Gate::Not(wire) => logic::not(values[wire.0]),
+            Gate::Consensus(lhs, rhs) => logic::consensus(values[lhs.0], values[rhs.0]),
+            Gate::Increment(wire) => match values[wire.0] {
+                Trit::NEG => Trit::ZERO,
+                Trit::ZERO => Trit::POS,
+                Trit::POS => Trit::NEG
+            }
+        }
+    }
+
+    fn inputs(self) -> [Option<Wire>; 2] {
+        match self {
+            Gate::Input | Gate::Constant(_) => [None, None],
+            Gate::Not(wire) | Gate::Increment(wire) => [Some(wire), None],
+            Gate::Min(lhs, rhs) | Gate::Max(lhs, rhs) | Gate::Consensus(lhs, rhs) => [Some(lhs), Some(rhs)]
+        }
+    }
+}
+
+/// A netlist of ternary gates with a list of output wires.
+///
+/// The gate-building methods panic if passed a wire that doesn't belong to the
+/// circuit.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Circuit {
+    gates: Vec<Gate>,
+    inputs: usize,
+    outputs: Vec<Wire>
+}
+
+impl Circuit {
+    /// Builds an empty circuit.
+    pub fn new() -> Self {
+        Circuit::default()
+    }
+
+    /// Adds an input, which takes the next of the trits passed to
+    /// [`simulate`](Self::simulate).
+    pub fn input(&mut self) -> Wire {
+        self.inputs += 1;
+        self.push(Gate::Input)
+    }
+
+    /// Adds a constant trit.
+    pub fn constant(&mut self, trit: Trit) -> Wire {
+        self.push(Gate::Constant(trit))
+    }
+
+    /// Adds a gate giving the lesser of two trits.
+    pub fn min(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.push(Gate::Min(lhs, rhs))
+    }
+
+    /// Adds a gate giving the greater of two trits.
+    pub fn max(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.push(Gate::Max(lhs, rhs))
+    }
+
+    /// Adds a gate negating a trit.
+    pub fn not(&mut self, wire: Wire) -> Wire {
+        self.push(Gate::Not(wire))
+    }
+
+    /// Adds a gate giving the consensus of two trits.
+    pub fn consensus(&mut self, lhs: Wire, rhs: Wire) -> Wire {
+        self.push(Gate::Consensus(lhs, rhs))
+    }
+
+    /// Adds a gate incrementing a trit modulo three.
+    pub fn increment(&mut self, wire: Wire) -> Wire {
+        self.push(Gate::Increment(wire))
+    }
+
+    /// Marks a wire as an output of the circuit. Outputs are reported in the
+    /// order they are marked, and a wire can be marked more than once.
+    pub fn output(&mut self, wire: Wire) {
+        self.check(wire);
+        self.outputs.push(wire);
+    }
+
+    /// Returns the gates of the circuit in the order they were added, which
+    /// is also the order of the wires.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Returns the number of inputs.
+    pub fn input_count(&self) -> usize {
+        self.inputs
+    }
+
+    /// Returns the output wires.
+    pub fn outputs(&self) -> &[Wire] {
+        &self.outputs
+    }
+
+    /// Returns the number of logic gates, not counting inputs and constants.
+    pub fn gate_count(&self) -> usize {
+        self.gates.iter().filter(|gate| !matches!(gate, Gate::Input | Gate::Constant(_))).count()
+    }
+
+    /// Returns the largest number of logic gates on any path from an input or
+    /// constant to an output.
+    pub fn depth(&self) -> usize {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let depth = gate.inputs().into_iter().flatten().map(|wire| depths[wire.0] + 1).max().unwrap_or(0);
+            depths.push(depth);
+        }
+        self.outputs.iter().map(|wire| depths[wire.0]).max().unwrap_or(0)
+    }
+
+    /// Simulates the circuit, returning the value of every output.
+    ///
+    /// # Panics
+    /// Panics if the number of trits supplied doesn't match the number of
+    /// inputs.
+    pub fn simulate(&self, inputs: &[Trit]) -> Vec<Trit> {
+        let values = self.values(inputs);
+        self.outputs.iter().map(|wire| values[wire.0]).collect()
+    }
+
+    /// Simulates the circuit on every combination of inputs, returning the
+    /// truth table of each output.
+    pub fn truth_tables(&self) -> Vec<TruthTable> {
+        let rows: Vec<Vec<Trit>> = TruthTable::from_fn(self.inputs, |_| Trit::ZERO).rows().map(|(inputs, _)| self.simulate(&inputs)).collect();
+        (0..self.outputs.len()).map(|output| TruthTable::new(self.inputs, rows.iter().map(|row| row[output]).collect())).collect()
+    }
+
+    /// Simulates the circuit, returning the value of every wire, indexed by
+    /// [`Wire::index`].
+    ///
+    /// # Panics
+    /// Panics if the number of trits supplied doesn't match the number of
+    /// inputs.
+    pub fn values(&self, inputs: &[Trit]) -> Vec<Trit> {
+        assert_eq!(inputs.len(), self.inputs, "Circuit expected {} inputs but {} were supplied", self.inputs, inputs.len());
+
+        let mut inputs = inputs.iter();
+        let mut values = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let value = match gate {
+                Gate::Input => *inputs.next().unwrap(),
+                gate => gate.evaluate(&values)
+            };
+            values.push(value);
+        }
+        values
+    }
+
+    fn push(&mut self, gate: Gate) -> Wire {
+        for wire in gate.inputs().into_iter().flatten() {
+            self.check(wire);
+        }
+        self.gates.push(gate);
+        Wire(self.gates.len() - 1)
+    }
+
+    fn check(&self, wire: Wire) {
+        assert!(wire.0 < self.gates.len(), "Wire does not belong to this circuit");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trit::adders;
+
+    const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+    /// Builds a wire that is positive when `wire` carries `trit` and negative
+    /// otherwise.
+    fn equals(circuit: &mut Circuit, wire: Wire, trit: Trit) -> Wire {
+        // Rotate the wire so that the trit of interest is positive, then keep
+        // it only if the next rotation isn't also positive
+        let mut rotated = wire;
+        let steps = match trit {
+            Trit::NEG => 2,
+            Trit::ZERO => 1,
+            Trit::POS => 0
+        };
+        for _ in 0..steps {
+            rotated = circuit.increment(rotated);
+        }
+        let next = circuit.increment(rotated);
+        let not_next = circuit.not(next);
+        circuit.min(rotated, not_next)
+    }
+
+    /// Builds the sum of two trits modulo three by selecting a rotation of
+    /// `lhs` for each value of `rhs`.
+    fn sum(circuit: &mut Circuit, lhs: Wire, rhs: Wire) -> Wire {
+        let up = circuit.increment(lhs);
+        let down = circuit.increment(up);
+
+        let mut output = circuit.constant(Trit::NEG);
+        for (trit, rotation) in [(Trit::NEG, down), (Trit::ZERO, lhs), (Trit::POS, up)] {
+            let selected = equals(circuit, rhs, trit);
+            let term = circuit.min(selected, rotation);
+            output = circuit.max(output, term);
+        }
+        output
+    }
+
+    #[test]
+    fn gates() {
+        let mut circuit = Circuit::new();
+        let lhs = circuit.input();
+        let rhs = circuit.input();
+        for wire in [circuit.min(lhs, rhs), circuit.max(lhs, rhs), circuit.not(lhs), circuit.consensus(lhs, rhs), circuit.increment(lhs)] {
+            circuit.output(wire);
+        }
+        assert_eq!(circuit.gate_count(), 5);
+        assert_eq!(circuit.depth(), 1);
+
+        for lhs in TRITS {
+            for rhs in TRITS {
+                let increment = Trit::try_from((i8::from(lhs) + 2) % 3 - 1).unwrap();
+                assert_eq!(circuit.simulate(&[lhs, rhs]), [lhs.min(rhs), lhs.max(rhs), -lhs, logic::consensus(lhs, rhs), increment]);
+            }
+        }
+    }
+
+    #[test]
+    fn full_adder_matches_trit_addition() {
+        // The carry of each half adder is the consensus of its inputs, and the
+        // two carries can never both be non-zero with the same sign
+        let mut circuit = Circuit::new();
+        let [lhs, rhs, carry_in] = [circuit.input(), circuit.input(), circuit.input()];
+        let partial = sum(&mut circuit, lhs, rhs);
+        let result = sum(&mut circuit, partial, carry_in);
+        let first_carry = circuit.consensus(lhs, rhs);
+        let second_carry = circuit.consensus(partial, carry_in);
+        let carry = sum(&mut circuit, first_carry, second_carry);
+        circuit.output(result);
+        circuit.output(carry);
+
+        for lhs in TRITS {
+            for rhs in TRITS {
+                for carry in TRITS {
+                    let expected = adders::full_adder(lhs, rhs, carry);
+                    assert_eq!(circuit.simulate(&[lhs, rhs, carry]), [expected.result, expected.carry]);
+                }
+            }
+        }
+        assert_eq!(circuit.input_count(), 3);
+        assert_eq!(circuit.depth(), 19);
+
+        let tables = circuit.truth_tables();
+        assert_eq!(tables[1], TruthTable::from_fn(3, |inputs| adders::full_adder(inputs[0], inputs[1], inputs[2]).carry));
+        assert_eq!(tables[1].minimize().to_truth_table(), tables[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Circuit expected 1 inputs but 2 were supplied")]
+    fn wrong_input_count() {
+        let mut circuit = Circuit::new();
+        let input = circuit.input();
+        circuit.output(input);
+        let _ = circuit.simulate(&[Trit::POS, Trit::POS]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Wire does not belong to this circuit")]
+    fn foreign_wire() {
+        let mut other = Circuit::new();
+        let _ = other.input();
+        let wire = other.input();
+
+        let mut circuit = Circuit::new();
+        let _ = circuit.not(wire);
+    }
+}
@@ -0,0 +1,5 @@
+//! Error correcting codes over trits and GF(3^n) symbols.
+
+pub mod golay;
+#[cfg(feature = "alloc")]
+pub mod reed_solomon;
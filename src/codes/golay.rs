@@ -0,0 +1,192 @@
+//! The perfect ternary Golay code, which encodes 6 trits in 11 and corrects
+//! any 2 trit errors.
+//!
+//! The code is the cyclic code generated by x^5 + x^4 - x^3 + x^2 - 1 over
+//! GF(3), and has a minimum distance of 5. It is perfect: each of the 3^5
+//! syndromes belongs to exactly one error pattern of at most two trits, so
+//! every word of 11 trits decodes to the nearest codeword.
+//! ```
+//! # use balanced_ternary::codes::golay;
+//! # use balanced_ternary::trit::Trit;
+//! let message = [Trit::POS, Trit::NEG, Trit::ZERO, Trit::ZERO, Trit::POS, Trit::POS];
+//! let mut received = golay::encode(message);
+//! received[2] = -received[2];
+//! received[9] = Trit::POS;
+//! assert_eq!(golay::decode(received), message);
+//! ```
+
+use crate::trit::Trit;
+
+/// The number of trits in a codeword.
+pub const LENGTH: usize = 11;
+
+/// The number of message trits in a codeword.
+pub const MESSAGE_LENGTH: usize = 6;
+
+/// The number of parity trits in a codeword, which is also the length of a
+/// syndrome.
+pub const PARITY_LENGTH: usize = LENGTH - MESSAGE_LENGTH;
+
+/// The coefficients of the generator polynomial from x^5 down.
+const GENERATOR: [Trit; PARITY_LENGTH + 1] = [Trit::POS, Trit::POS, Trit::NEG, Trit::POS, Trit::ZERO, Trit::NEG];
+
+/// Encodes 6 message trits into a codeword of 11 trits, the message followed
+/// by 5 parity trits.
+pub fn encode(message: [Trit; MESSAGE_LENGTH]) -> [Trit; LENGTH] {
+    let mut codeword = [Trit::ZERO; LENGTH];
+    codeword[..MESSAGE_LENGTH].copy_from_slice(&message);
+
+    // Subtracting the remainder of the shifted message leaves a multiple of
+    // the generator
+    let remainder = syndrome(codeword);
+    for (parity, remainder) in codeword[MESSAGE_LENGTH..].iter_mut().zip(remainder) {
+        *parity = -remainder;
+    }
+    codeword
+}
+
+/// Returns the syndrome of a word, the remainder of dividing it by the
+/// generator polynomial, which is zero exactly for codewords. The word is read
+/// as the coefficients of a polynomial from x^10 down.
+pub fn syndrome(word: [Trit; LENGTH]) -> [Trit; PARITY_LENGTH] {
+    let mut remainder = word;
+    for idx in 0..MESSAGE_LENGTH {
+        let coeff = remainder[idx];
+        if coeff == Trit::ZERO {
+            continue;
+        }
+        for (offset, generator) in GENERATOR.iter().enumerate().skip(1) {
+            remainder[idx + offset] = subtract(remainder[idx + offset], coeff * *generator);
+        }
+    }
+
+    let mut syndrome = [Trit::ZERO; PARITY_LENGTH];
+    syndrome.copy_from_slice(&remainder[MESSAGE_LENGTH..]);
+    syndrome
+}
+
+/// Corrects a word in place to the nearest codeword, returning the number of
+/// trits that were changed, which is at most two.
+pub fn correct(word: &mut [Trit; LENGTH]) -> usize {
+    let target = syndrome(*word);
+    if target == [Trit::ZERO; PARITY_LENGTH] {
+        return 0;
+    }
+
+    // The syndrome of an error is the sum of the syndromes of its trits, so
+    // each error pattern of one or two trits is built from the syndromes of
+    // single positive trits
+    let mut columns = [[Trit::ZERO; PARITY_LENGTH]; LENGTH];
+    for (position, column) in columns.iter_mut().enumerate() {
+        let mut unit = [Trit::ZERO; LENGTH];
+        unit[position] = Trit::POS;
+        *column = syndrome(unit);
+    }
+
+    for (first, first_column) in columns.iter().enumerate() {
+        for first_error in [Trit::POS, Trit::NEG] {
+            let single = scale(*first_column, first_error);
+            if single == target {
+                word[first] = subtract(word[first], first_error);
+                return 1;
+            }
+
+            for (second, second_column) in columns.iter().enumerate().skip(first + 1) {
+                for second_error in [Trit::POS, Trit::NEG] {
+                    let double = add(single, scale(*second_column, second_error));
+                    if double == target {
+                        word[first] = subtract(word[first], first_error);
+                        word[second] = subtract(word[second], second_error);
+                        return 2;
+                    }
+                }
+            }
+        }
+    }
+    unreachable!("Every syndrome of the perfect Golay code has an error pattern")
+}
+
+/// Decodes a received word to the message of the nearest codeword.
+pub fn decode(received: [Trit; LENGTH]) -> [Trit; MESSAGE_LENGTH] {
+    let mut codeword = received;
+    correct(&mut codeword);
+
+    let mut message = [Trit::ZERO; MESSAGE_LENGTH];
+    message.copy_from_slice(&codeword[..MESSAGE_LENGTH]);
+    message
+}
+
+/// Subtracts two trits as elements of GF(3).
+fn subtract(lhs: Trit, rhs: Trit) -> Trit {
+    lhs.add(&-rhs).result
+}
+
+fn add(lhs: [Trit; PARITY_LENGTH], rhs: [Trit; PARITY_LENGTH]) -> [Trit; PARITY_LENGTH] {
+    core::array::from_fn(|idx| lhs[idx].add(&rhs[idx]).result)
+}
+
+fn scale(syndrome: [Trit; PARITY_LENGTH], factor: Trit) -> [Trit; PARITY_LENGTH] {
+    syndrome.map(|trit| trit * factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+    /// Every message of 6 trits.
+    fn messages() -> impl Iterator<Item = [Trit; MESSAGE_LENGTH]> {
+        (0..729_usize).map(|index| core::array::from_fn(|idx| TRITS[index / 3_usize.pow(idx as u32) % 3]))
+    }
+
+    #[test]
+    fn codewords() {
+        let mut weight_counts = [0; LENGTH + 1];
+        for message in messages() {
+            let codeword = encode(message);
+            assert_eq!(&codeword[..MESSAGE_LENGTH], &message);
+            assert_eq!(syndrome(codeword), [Trit::ZERO; PARITY_LENGTH]);
+            assert_eq!(decode(codeword), message);
+            weight_counts[codeword.iter().filter(|trit| **trit != Trit::ZERO).count()] += 1;
+        }
+
+        // The weight distribution of the ternary Golay code, which has a
+        // minimum distance of 5
+        assert_eq!(weight_counts, [1, 0, 0, 0, 0, 132, 132, 0, 330, 110, 0, 24]);
+    }
+
+    #[test]
+    fn corrects_two_errors() {
+        for message in messages().step_by(37) {
+            let codeword = encode(message);
+            for first in 0..LENGTH {
+                for second in first..LENGTH {
+                    for (first_error, second_error) in [(Trit::POS, Trit::POS), (Trit::NEG, Trit::POS), (Trit::POS, Trit::NEG)] {
+                        let mut received = codeword;
+                        received[first] = received[first].add(&first_error).result;
+                        received[second] = received[second].add(&second_error).result;
+
+                        let changed = received.iter().zip(&codeword).filter(|(lhs, rhs)| lhs != rhs).count();
+                        assert_eq!(correct(&mut received), changed);
+                        assert_eq!(received, codeword);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn syndromes_are_perfect() {
+        // All 243 syndromes are reached by the 243 error patterns of at most
+        // two trits, so three errors always decode to a different codeword
+        let codeword = encode([Trit::ZERO; MESSAGE_LENGTH]);
+        let mut received = codeword;
+        received[0] = Trit::POS;
+        received[5] = Trit::NEG;
+        received[10] = Trit::POS;
+        assert_eq!(correct(&mut received), 2);
+        assert_ne!(received, codeword);
+        assert_eq!(syndrome(received), [Trit::ZERO; PARITY_LENGTH]);
+    }
+}
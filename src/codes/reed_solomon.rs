@@ -0,0 +1,390 @@
+//! Reed–Solomon codes over GF(3^M), whose symbols are numbers of M trits.
+//!
+//! A code of length n and dimension k appends n - k parity symbols to a
+//! message of k symbols, and can then correct any combination of e errors at
+//! unknown positions and f erasures at known positions with 2e + f <= n - k.
+
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::gf3::{Gf3n, LogTables};
+use crate::number::Number;
+
+/// Reasons that a Reed–Solomon code could not be built or a word could not be
+/// encoded or decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReedSolomonError {
+    /// The length and dimension don't describe a code over the field, which
+    /// needs 0 < k < n < 3^M and a field with logarithm tables.
+    InvalidParameters,
+    /// The number of symbols supplied doesn't match the code.
+    WrongLength { length: usize, expected: usize },
+    /// An erasure position lies beyond the end of the codeword.
+    InvalidErasure { position: usize },
+    /// The word has more errors and erasures than the code can correct.
+    TooManyErrors
+}
+
+impl fmt::Display for ReedSolomonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReedSolomonError::InvalidParameters =>
+                write!(f, "invalid length or dimension for the field"),
+            ReedSolomonError::WrongLength { length, expected } =>
+                write!(f, "{} symbols supplied but expected {}", length, expected),
+            ReedSolomonError::InvalidErasure { position } =>
+                write!(f, "erasure position {} is outside the codeword", position),
+            ReedSolomonError::TooManyErrors =>
+                write!(f, "too many errors to correct")
+        }
+    }
+}
+
+impl Error for ReedSolomonError {}
+
+/// A systematic Reed–Solomon code of length n and dimension k over GF(3^M).
+///
+/// A codeword is the message followed by n - k parity symbols. Read from the
+/// first symbol, as the coefficients of a polynomial from the highest power
+/// down, every codeword is a multiple of the generator polynomial
+/// (x - a)(x - a^2)...(x - a^(n-k)), where a is the generator of the field's
+/// logarithm tables. The field must therefore be small enough to have
+/// logarithm tables.
+/// ```
+/// # use balanced_ternary::codes::reed_solomon::ReedSolomon;
+/// # use balanced_ternary::gf3::Gf3n;
+/// # use balanced_ternary::number::Number;
+/// let code = ReedSolomon::new(Gf3n::<2>::default(), 8, 4).unwrap();
+/// let message = ["+-", "0+", "--", "00"].map(Number::<2>::from);
+/// let mut received = code.encode(&message).unwrap();
+/// received[1] = Number::<2>::from("++");
+/// received[6] = Number::<2>::from("-0");
+/// assert_eq!(code.decode(&received, &[]).unwrap(), message);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReedSolomon<const M: usize> {
+    field: Gf3n<M>,
+    length: usize,
+    message_length: usize,
+    // Ordered from the highest power, which is one
+    generator: Vec<Number<M>>
+}
+
+impl <const M: usize> ReedSolomon<M> {
+    /// Builds the code of the given length and dimension over a field.
+    /// * `field` - The field of the symbols, which must have logarithm tables
+    /// * `length` - The number of symbols n in a codeword, less than 3^M
+    /// * `message_length` - The number of message symbols k, less than n
+    pub fn new(field: Gf3n<M>, length: usize, message_length: usize) -> Result<Self, ReedSolomonError> {
+        let tables = field.log_tables().ok_or(ReedSolomonError::InvalidParameters)?;
+        if message_length == 0 || length <= message_length || length > tables.order() {
+            return Err(ReedSolomonError::InvalidParameters);
+        }
+
+        let mut generator = alloc::vec![Number::<M>::ONE];
+        for power in 1..=length - message_length {
+            // Multiply by (x - a^power)
+            let root = -tables.antilog(power);
+            let mut next = generator.clone();
+            next.push(Number::<M>::ZERO);
+            for (idx, coeff) in generator.iter().enumerate() {
+                next[idx + 1] = field.add(next[idx + 1], field.mul(*coeff, root));
+            }
+            generator = next;
+        }
+
+        Ok(ReedSolomon { field, length, message_length, generator })
+    }
+
+    /// Returns the number of symbols n in a codeword.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the number of message symbols k in a codeword.
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    /// Returns the number of parity symbols, n - k.
+    pub fn parity_length(&self) -> usize {
+        self.length - self.message_length
+    }
+
+    /// Encodes a message of k symbols into a codeword of n symbols, the message
+    /// followed by its parity symbols.
+    pub fn encode(&self, message: &[Number<M>]) -> Result<Vec<Number<M>>, ReedSolomonError> {
+        if message.len() != self.message_length {
+            return Err(ReedSolomonError::WrongLength { length: message.len(), expected: self.message_length });
+        }
+
+        // Long division of the message shifted up by n - k places leaves the
+        // remainder in the parity positions, which is then subtracted to give
+        // a multiple of the generator
+        let mut codeword = message.to_vec();
+        codeword.resize(self.length, Number::<M>::ZERO);
+        for idx in 0..self.message_length {
+            let coeff = codeword[idx];
+            if coeff.is_zero() {
+                continue;
+            }
+            for (offset, generator) in self.generator.iter().enumerate().skip(1) {
+                codeword[idx + offset] = self.field.sub(codeword[idx + offset], self.field.mul(*generator, coeff));
+            }
+        }
+        codeword[..self.message_length].copy_from_slice(message);
+        for parity in &mut codeword[self.message_length..] {
+            *parity = -*parity;
+        }
+        Ok(codeword)
+    }
+
+    /// Decodes a received word of n symbols, correcting any errors and the
+    /// erasures at the given positions, and returns the message.
+    /// * `received` - The symbols of the received word
+    /// * `erasures` - The positions of symbols known to be unreliable
+    pub fn decode(&self, received: &[Number<M>], erasures: &[usize]) -> Result<Vec<Number<M>>, ReedSolomonError> {
+        let mut codeword = received.to_vec();
+        self.correct(&mut codeword, erasures)?;
+        codeword.truncate(self.message_length);
+        Ok(codeword)
+    }
+
+    /// Corrects a received word of n symbols in place, returning the number of
+    /// errors and erasures that were located.
+    /// * `codeword` - The symbols of the received word
+    /// * `erasures` - The positions of symbols known to be unreliable
+    pub fn correct(&self, codeword: &mut [Number<M>], erasures: &[usize]) -> Result<usize, ReedSolomonError> {
+        if codeword.len() != self.length {
+            return Err(ReedSolomonError::WrongLength { length: codeword.len(), expected: self.length });
+        }
+        if let Some(position) = erasures.iter().find(|position| **position >= self.length) {
+            return Err(ReedSolomonError::InvalidErasure { position: *position });
+        }
+        let parity_length = self.parity_length();
+        if erasures.len() > parity_length {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        let syndromes = self.syndromes(codeword);
+        if syndromes.iter().all(|syndrome| syndrome.is_zero()) {
+            return Ok(0);
+        }
+
+        let locator = self.error_locator(&syndromes, erasures)?;
+
+        // Chien search, trying every position for a root of the locator at
+        // the inverse of its location
+        let tables = self.tables();
+        let positions: Vec<usize> = (0..self.length)
+            .filter(|position| {
+                let inverse = tables.antilog(tables.order() - self.power(*position));
+                evaluate(&self.field, &locator, inverse).is_zero()
+            })
+            .collect();
+        if positions.len() + 1 != locator.len() {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+
+        // The Forney algorithm gives the value of each error from the error
+        // evaluator, S(x)L(x) mod x^(n-k), and the formal derivative of the
+        // locator
+        let mut evaluator = multiply(&self.field, &syndromes, &locator);
+        evaluator.truncate(parity_length);
+        let derivative: Vec<Number<M>> = locator.iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, coeff)| match power % 3 {
+                0 => Number::<M>::ZERO,
+                1 => *coeff,
+                _ => -*coeff
+            })
+            .collect();
+        for position in &positions {
+            let inverse = tables.antilog(tables.order() - self.power(*position));
+            let numerator = evaluate(&self.field, &evaluator, inverse);
+            let denominator = evaluate(&self.field, &derivative, inverse);
+            let magnitude = self.field.div(numerator, denominator).ok_or(ReedSolomonError::TooManyErrors)?;
+            codeword[*position] = self.field.add(codeword[*position], magnitude);
+        }
+
+        if self.syndromes(codeword).iter().any(|syndrome| !syndrome.is_zero()) {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+        Ok(positions.len())
+    }
+
+    /// Evaluates the received word at a, a^2, ... a^(n-k), all of which are
+    /// zero for a codeword. The syndromes are returned as the coefficients of
+    /// S(x) from the constant term up.
+    fn syndromes(&self, codeword: &[Number<M>]) -> Vec<Number<M>> {
+        let tables = self.tables();
+        (1..=self.parity_length())
+            .map(|power| {
+                let x = tables.antilog(power);
+                codeword.iter().fold(Number::<M>::ZERO, |acc, symbol| self.field.add(self.field.mul(acc, x), *symbol))
+            })
+            .collect()
+    }
+
+    /// Finds the error locator, the polynomial with a root at the inverse of
+    /// the location of each error and erasure, by the Berlekamp–Massey
+    /// algorithm started from the locator of the erasures. The coefficients
+    /// are returned from the constant term up.
+    fn error_locator(&self, syndromes: &[Number<M>], erasures: &[usize]) -> Result<Vec<Number<M>>, ReedSolomonError> {
+        let tables = self.tables();
+        let field = &self.field;
+
+        let mut locator = alloc::vec![Number::<M>::ONE];
+        for position in erasures {
+            // Multiply by (1 - X x) for the erasure location X
+            locator = multiply(field, &locator, &[Number::<M>::ONE, -tables.antilog(self.power(*position))]);
+        }
+        let mut previous = locator.clone();
+        let erasure_count = erasures.len();
+        let mut order = erasure_count;
+
+        for step in erasure_count..syndromes.len() {
+            let discrepancy = locator.iter()
+                .take(step + 1)
+                .enumerate()
+                .fold(Number::<M>::ZERO, |acc, (idx, coeff)| field.add(acc, field.mul(*coeff, syndromes[step - idx])));
+
+            previous.insert(0, Number::<M>::ZERO);
+            if discrepancy.is_zero() {
+                continue;
+            }
+
+            let mut next = locator.clone();
+            next.resize(next.len().max(previous.len()), Number::<M>::ZERO);
+            for (coeff, shifted) in next.iter_mut().zip(&previous) {
+                *coeff = field.sub(*coeff, field.mul(discrepancy, *shifted));
+            }
+            if 2 * order <= step + erasure_count {
+                order = step + 1 + erasure_count - order;
+                let scale = field.inv(discrepancy).ok_or(ReedSolomonError::TooManyErrors)?;
+                previous = locator.iter().map(|coeff| field.mul(*coeff, scale)).collect();
+            }
+            locator = next;
+        }
+
+        while locator.last().is_some_and(|coeff| coeff.is_zero()) {
+            locator.pop();
+        }
+        if locator.len() != order + 1 || 2 * order - erasure_count > syndromes.len() {
+            return Err(ReedSolomonError::TooManyErrors);
+        }
+        Ok(locator)
+    }
+
+    /// The power of x whose coefficient is held at a position of a codeword.
+    fn power(&self, position: usize) -> usize {
+        self.length - 1 - position
+    }
+
+    fn tables(&self) -> &LogTables<M> {
+        // Codes are only built over fields with logarithm tables
+        self.field.log_tables().expect("Reed-Solomon field has logarithm tables")
+    }
+}
+
+/// Evaluates a polynomial over GF(3^M), with coefficients from the constant
+/// term up, by Horner's method.
+fn evaluate<const M: usize>(field: &Gf3n<M>, poly: &[Number<M>], x: Number<M>) -> Number<M> {
+    poly.iter().rev().fold(Number::<M>::ZERO, |acc, coeff| field.add(field.mul(acc, x), *coeff))
+}
+
+/// Multiplies two polynomials over GF(3^M).
+fn multiply<const M: usize>(field: &Gf3n<M>, lhs: &[Number<M>], rhs: &[Number<M>]) -> Vec<Number<M>> {
+    let mut product = alloc::vec![Number::<M>::ZERO; lhs.len() + rhs.len() - 1];
+    for (lhs_power, lhs_coeff) in lhs.iter().enumerate() {
+        for (rhs_power, rhs_coeff) in rhs.iter().enumerate() {
+            let term = &mut product[lhs_power + rhs_power];
+            *term = field.add(*term, field.mul(*lhs_coeff, *rhs_coeff));
+        }
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trit::Trit;
+
+    /// A deterministic symbol for a position and seed.
+    fn symbol<const M: usize>(position: usize, seed: usize) -> Number<M> {
+        Number::<M>::from_rev_iter((0..M).map(|i| [Trit::NEG, Trit::ZERO, Trit::POS][(position * 7 + i * i * seed + seed / 3) % 3]))
+    }
+
+    #[test]
+    fn parameters() {
+        assert!(ReedSolomon::new(Gf3n::<2>::default(), 8, 8).is_err());
+        assert!(ReedSolomon::new(Gf3n::<2>::default(), 9, 4).is_err());
+        assert!(ReedSolomon::new(Gf3n::<2>::default(), 8, 0).is_err());
+        assert!(ReedSolomon::new(Gf3n::<10>::default(), 20, 10).is_err());
+
+        let code = ReedSolomon::new(Gf3n::<2>::default(), 8, 5).unwrap();
+        assert_eq!((code.length(), code.message_length(), code.parity_length()), (8, 5, 3));
+        assert_eq!(code.encode(&[Number::<2>::ONE; 4]), Err(ReedSolomonError::WrongLength { length: 4, expected: 5 }));
+        assert_eq!(code.decode(&[Number::<2>::ONE; 8], &[8]), Err(ReedSolomonError::InvalidErasure { position: 8 }));
+    }
+
+    #[test]
+    fn codewords_are_systematic() {
+        let code = ReedSolomon::new(Gf3n::<3>::default(), 26, 20).unwrap();
+        for seed in 0..10 {
+            let message: Vec<Number<3>> = (0..20).map(|position| symbol(position, seed)).collect();
+            let codeword = code.encode(&message).unwrap();
+            assert_eq!(&codeword[..20], &message[..]);
+            assert_eq!(code.decode(&codeword, &[]).unwrap(), message);
+        }
+        assert_eq!(code.encode(&[Number::<3>::ZERO; 20]).unwrap(), [Number::<3>::ZERO; 26]);
+    }
+
+    #[test]
+    fn errors_and_erasures() {
+        // Ten parity symbols correct 2e + f <= 10
+        let code = ReedSolomon::new(Gf3n::<3>::default(), 26, 16).unwrap();
+        for seed in 1..30 {
+            let message: Vec<Number<3>> = (0..16).map(|position| symbol(position, seed)).collect();
+            let codeword = code.encode(&message).unwrap();
+
+            for (error_count, erasure_count) in [(1, 0), (5, 0), (0, 10), (3, 4), (4, 2), (2, 6)] {
+                let mut received = codeword.clone();
+                let positions: Vec<usize> = (0..error_count + erasure_count).map(|idx| (idx * 5 + seed) % 26).collect();
+                for position in &positions[..error_count] {
+                    // Adding a non-zero symbol always changes the symbol
+                    received[*position] = code.field.add(received[*position], Number::<3>::from(seed as i32 % 13 + 1));
+                }
+                for position in &positions[error_count..] {
+                    received[*position] = symbol(*position, seed + 1);
+                }
+
+                let mut corrected = received.clone();
+                let changed = code.correct(&mut corrected, &positions[error_count..]).unwrap();
+                assert_eq!(corrected, codeword, "{} errors and {} erasures", error_count, erasure_count);
+                assert!(changed >= error_count);
+            }
+        }
+    }
+
+    #[test]
+    fn too_many_errors() {
+        let code = ReedSolomon::new(Gf3n::<2>::default(), 8, 4).unwrap();
+        let codeword = code.encode(&[Number::<2>::ONE, Number::<2>::ZERO, Number::<2>::NEG_ONE, Number::<2>::ONE]).unwrap();
+
+        assert_eq!(code.decode(&codeword, &[0, 1, 2, 3, 4]), Err(ReedSolomonError::TooManyErrors));
+
+        // Three errors exceed the two that four parity symbols can correct, and
+        // the decoder either detects this or finds a different codeword
+        let mut received = codeword.clone();
+        for position in [0, 3, 5] {
+            received[position] = code.field.add(received[position], Number::<2>::ONE);
+        }
+        match code.decode(&received, &[]) {
+            Ok(message) => assert_ne!(message, &codeword[..4]),
+            Err(error) => assert_eq!(error, ReedSolomonError::TooManyErrors)
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! A versioned binary container for persisting numbers.
+//!
+//! Each container holds a single integer, a [`Number`] or a [`BigTernary`],
+//! and can be checked for damage and for being the kind of data expected
+//! before it is decoded. All multi-byte fields are little-endian:
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 4 | The magic bytes `BTRN` |
+//! | 4 | 1 | The format version, currently 1 |
+//! | 5 | 1 | The [`Codec`] of the payload: 0 for packed, 1 for two-bit |
+//! | 6 | 8 | The width of the integer in trits |
+//! | 14 | ceil(width / trits per byte) | The payload of trits, packed with the codec from the least significant |
+//! | | 4 | The CRC-32 of every preceding byte |
+//!
+//! The payload has the layout of [`Number::to_packed_bytes`] or
+//! [`Number::encode_2bit`]. A number is stored at its full width N, and a
+//! [`BigTernary`] at its number of significant trits. A container can be read
+//! back at a different width so long as the trits that don't fit are all zero.
+//!
+//! Reading fails with an error of the kind
+//! [`InvalidData`](io::ErrorKind::InvalidData) if the container is malformed,
+//! which wraps a [`ContainerError`] or an
+//! [`UnpackBytesError`](crate::number::UnpackBytesError) saying why.
+//! ```
+//! # use balanced_ternary::io::Codec;
+//! # use balanced_ternary::number::Number;
+//! let number = Number::<12>::from(-9841);
+//! let mut bytes = Vec::new();
+//! number.write_to(&mut bytes, Codec::Packed).unwrap();
+//! assert_eq!(&bytes[..4], b"BTRN");
+//! assert_eq!(Number::<12>::read_from(&mut bytes.as_slice()).unwrap(), number);
+//!
+//! // The value fits in fewer trits, but not in too few
+//! assert_eq!(Number::<9>::read_from(&mut bytes.as_slice()).unwrap(), Number::from(-9841));
+//! assert!(Number::<8>::read_from(&mut bytes.as_slice()).is_err());
+//! ```
+//!
+//! [`Number`]: crate::number::Number
+//! [`Number::to_packed_bytes`]: crate::number::Number::to_packed_bytes
+//! [`Number::encode_2bit`]: crate::number::Number::encode_2bit
+//! [`BigTernary`]: crate::big_ternary::BigTernary
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::big_ternary::BigTernary;
+use crate::io::{Codec, TritReader, TritWriter};
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// The bytes that start every container.
+pub const MAGIC: [u8; 4] = *b"BTRN";
+
+/// The version of the format written by this crate.
+pub const VERSION: u8 = 1;
+
+/// Reasons that a container could not be read, beyond errors from the reader
+/// itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContainerError {
+    /// The data doesn't start with the magic bytes, so isn't a container.
+    BadMagic,
+    /// The container is of a version this crate can't read.
+    UnsupportedVersion(u8),
+    /// The codec id doesn't name a known codec.
+    UnknownCodec(u8),
+    /// The checksum doesn't match the contents, which have been damaged.
+    ChecksumMismatch,
+    /// One of the padding trits in the final byte of the payload was non-zero.
+    NonZeroPadding,
+    /// The stored value needs more trits than the number being read can hold.
+    TooWide { width: u64, capacity: usize }
+}
+
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContainerError::BadMagic => write!(f, "data is not a balanced ternary container"),
+            ContainerError::UnsupportedVersion(version) => write!(f, "unsupported container version {}", version),
+            ContainerError::UnknownCodec(id) => write!(f, "unknown codec id {}", id),
+            ContainerError::ChecksumMismatch => write!(f, "container checksum does not match its contents"),
+            ContainerError::NonZeroPadding => write!(f, "non-zero padding trits in container payload"),
+            ContainerError::TooWide { width, capacity } =>
+                write!(f, "stored value of {} trits does not fit in {} trits", width, capacity)
+        }
+    }
+}
+
+impl Error for ContainerError {}
+
+impl <const N: usize> Number<N> {
+    /// Writes the number to a container, packing its trits with `codec`.
+    pub fn write_to(&self, writer: &mut impl Write, codec: Codec) -> io::Result<()> {
+        write_container(writer, codec, N, self.iter())
+    }
+
+    /// Reads a number from a container written by [`Number::write_to`] or
+    /// [`BigTernary::write_to`].
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        let trits = read_container(reader)?;
+        if trits[N.min(trits.len())..].iter().any(|trit| *trit != Trit::ZERO) {
+            return Err(invalid(ContainerError::TooWide { width: trits.len() as u64, capacity: N }));
+        }
+        Ok(Number::<N>::from_rev_iter(trits.into_iter()))
+    }
+}
+
+impl BigTernary {
+    /// Writes the integer to a container, packing its significant trits with
+    /// `codec`.
+    pub fn write_to(&self, writer: &mut impl Write, codec: Codec) -> io::Result<()> {
+        let trits = self.as_trits();
+        write_container(writer, codec, trits.len(), trits.iter().rev().copied())
+    }
+
+    /// Reads an integer from a container written by [`BigTernary::write_to`] or
+    /// [`Number::write_to`].
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        let mut trits = read_container(reader)?;
+        trits.reverse();
+        Ok(BigTernary::from_trits(&trits))
+    }
+}
+
+fn write_container(writer: &mut impl Write, codec: Codec, width: usize, mut trits: impl Iterator<Item = Trit>) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(18 + width.div_ceil(codec.trits_per_byte()));
+    bytes.extend_from_slice(&MAGIC);
+    bytes.push(VERSION);
+    bytes.push(codec_id(codec));
+    bytes.extend_from_slice(&(width as u64).to_le_bytes());
+
+    let mut trit_writer = TritWriter::new(bytes, codec);
+    trits.try_for_each(|trit| trit_writer.write_trit(trit))?;
+    let mut bytes = trit_writer.into_inner()?;
+
+    let checksum = crc32(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    writer.write_all(&bytes)
+}
+
+/// Reads and checks a container, returning its trits from the least
+/// significant.
+fn read_container(reader: &mut impl Read) -> io::Result<Vec<Trit>> {
+    let mut header = [0; 14];
+    reader.read_exact(&mut header)?;
+    if header[..4] != MAGIC {
+        return Err(invalid(ContainerError::BadMagic));
+    }
+    if header[4] != VERSION {
+        return Err(invalid(ContainerError::UnsupportedVersion(header[4])));
+    }
+    let codec = match header[5] {
+        0 => Codec::Packed,
+        1 => Codec::TwoBit,
+        id => return Err(invalid(ContainerError::UnknownCodec(id)))
+    };
+    let width = u64::from_le_bytes(header[6..].try_into().expect("Header has eight bytes of width"));
+
+    // The payload is read through `take` rather than into a buffer of the
+    // stated size, so that a corrupt width can't force a huge allocation
+    let payload_len = width.div_ceil(codec.trits_per_byte() as u64);
+    let mut payload = Vec::new();
+    reader.take(payload_len).read_to_end(&mut payload)?;
+    if (payload.len() as u64) < payload_len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "container ended part way through its payload"));
+    }
+    let mut checksum = [0; 4];
+    reader.read_exact(&mut checksum)?;
+
+    let mut contents = header.to_vec();
+    contents.extend_from_slice(&payload);
+    if crc32(&contents) != u32::from_le_bytes(checksum) {
+        return Err(invalid(ContainerError::ChecksumMismatch));
+    }
+
+    let width = usize::try_from(width).map_err(|_| invalid(ContainerError::TooWide { width, capacity: usize::MAX }))?;
+    let mut trit_reader = TritReader::new(payload.as_slice(), codec);
+    let mut trits = vec![Trit::ZERO; payload.len() * codec.trits_per_byte()];
+    trit_reader.read_trits(&mut trits)?;
+    if trits[width..].iter().any(|trit| *trit != Trit::ZERO) {
+        return Err(invalid(ContainerError::NonZeroPadding));
+    }
+    trits.truncate(width);
+    Ok(trits)
+}
+
+fn codec_id(codec: Codec) -> u8 {
+    match codec {
+        Codec::Packed => 0,
+        Codec::TwoBit => 1
+    }
+}
+
+fn invalid(error: ContainerError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// The CRC-32 used by zlib and PNG, with the reflected polynomial 0xEDB88320.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        (0..8).fold(crc ^ u32::from(*byte), |crc, _| (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container_error(error: io::Error) -> ContainerError {
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        *error.into_inner().unwrap().downcast::<ContainerError>().unwrap()
+    }
+
+    #[test]
+    fn checksum() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn round_trips() {
+        for codec in [Codec::Packed, Codec::TwoBit] {
+            for value in [-29524, -1, 0, 1, 12345, 29524] {
+                let number = Number::<10>::from(value);
+                let mut bytes = Vec::new();
+                number.write_to(&mut bytes, codec).unwrap();
+                assert_eq!(bytes.len(), 18 + 10_usize.div_ceil(codec.trits_per_byte()));
+                assert_eq!(Number::<10>::read_from(&mut bytes.as_slice()).unwrap(), number);
+                assert_eq!(Number::<40>::read_from(&mut bytes.as_slice()).unwrap(), Number::<40>::from(value));
+                assert_eq!(BigTernary::read_from(&mut bytes.as_slice()).unwrap(), BigTernary::from(value));
+            }
+
+            let big = BigTernary::from(-7).pow(200);
+            let mut bytes = Vec::new();
+            big.write_to(&mut bytes, codec).unwrap();
+            assert_eq!(BigTernary::read_from(&mut bytes.as_slice()).unwrap(), big);
+            assert_eq!(container_error(Number::<100>::read_from(&mut bytes.as_slice()).unwrap_err()),
+                ContainerError::TooWide { width: big.significant_trits() as u64, capacity: 100 });
+        }
+
+        let mut bytes = Vec::new();
+        BigTernary::ZERO.write_to(&mut bytes, Codec::Packed).unwrap();
+        assert_eq!(bytes.len(), 18);
+        assert_eq!(Number::<5>::read_from(&mut bytes.as_slice()).unwrap(), Number::<5>::ZERO);
+    }
+
+    #[test]
+    fn consecutive_containers() {
+        let mut bytes = Vec::new();
+        Number::<4>::from(10).write_to(&mut bytes, Codec::TwoBit).unwrap();
+        Number::<7>::from(-200).write_to(&mut bytes, Codec::Packed).unwrap();
+
+        let mut reader = bytes.as_slice();
+        assert_eq!(Number::<4>::read_from(&mut reader).unwrap(), Number::<4>::from(10));
+        assert_eq!(Number::<7>::read_from(&mut reader).unwrap(), Number::<7>::from(-200));
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn malformed_containers() {
+        let mut bytes = Vec::new();
+        Number::<8>::from(1000).write_to(&mut bytes, Codec::Packed).unwrap();
+        let read = |bytes: &[u8]| Number::<8>::read_from(&mut &bytes[..]);
+
+        let mut damaged = bytes.clone();
+        damaged[0] = b'X';
+        assert_eq!(container_error(read(&damaged).unwrap_err()), ContainerError::BadMagic);
+
+        let mut damaged = bytes.clone();
+        damaged[4] = 2;
+        assert_eq!(container_error(read(&damaged).unwrap_err()), ContainerError::UnsupportedVersion(2));
+
+        let mut damaged = bytes.clone();
+        damaged[5] = 7;
+        assert_eq!(container_error(read(&damaged).unwrap_err()), ContainerError::UnknownCodec(7));
+
+        let mut damaged = bytes.clone();
+        damaged[15] ^= 1;
+        assert_eq!(container_error(read(&damaged).unwrap_err()), ContainerError::ChecksumMismatch);
+
+        assert_eq!(read(&bytes[..bytes.len() - 1]).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(read(&bytes[..10]).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        // A huge width with a short payload fails without allocating for it
+        let mut damaged = bytes.clone();
+        damaged[6..14].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(read(&damaged).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn non_canonical_payloads() {
+        // A width of 3 trits leaves two padding trits in the packed byte
+        let mut contents = MAGIC.to_vec();
+        contents.extend_from_slice(&[VERSION, 0]);
+        contents.extend_from_slice(&3_u64.to_le_bytes());
+        contents.push(121 + 27);
+        let checksum = crc32(&contents);
+        contents.extend_from_slice(&checksum.to_le_bytes());
+        assert_eq!(container_error(Number::<8>::read_from(&mut contents.as_slice()).unwrap_err()), ContainerError::NonZeroPadding);
+
+        // A byte outside the range of the codec
+        let len = contents.len();
+        contents[14] = 250;
+        let checksum = crc32(&contents[..len - 4]);
+        contents[len - 4..].copy_from_slice(&checksum.to_le_bytes());
+        assert_eq!(Number::<8>::read_from(&mut contents.as_slice()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
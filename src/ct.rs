@@ -0,0 +1,239 @@
+//! Constant-time operations on numbers, for arithmetic on secret values.
+//!
+//! The comparison operators of [`Number`] stop at the first differing trit,
+//! so the time they take depends on the values compared. The functions here
+//! instead always look at every trit, combining them with masks rather than
+//! branches, and report their results as a [`Choice`] rather than a `bool`
+//! so that callers aren't tempted to branch on them either.
+//!
+//! With the `subtle` feature, `Number` also implements the traits of the
+//! [subtle](https://crates.io/crates/subtle) crate, and [`Choice`] converts
+//! into `subtle::Choice`.
+//! ```
+//! # use balanced_ternary::ct;
+//! # use balanced_ternary::number::Number;
+//! let secret = Number::<8>::from(-50);
+//! let guess = Number::<8>::from(50);
+//! assert!(!bool::from(ct::eq(&secret, &guess)));
+//! assert!(bool::from(ct::less(&secret, &guess)));
+//!
+//! let mut value = guess;
+//! ct::conditional_negate(&mut value, ct::less(&secret, &guess));
+//! assert_eq!(ct::select(&guess, &value, ct::eq(&value, &secret)), secret);
+//! ```
+
+use core::ops::{BitAnd, BitOr, Not};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// The result of a constant-time test, either 0 for false or 1 for true.
+///
+/// The value is passed through [`core::hint::black_box`] when a choice is
+/// made, which discourages the compiler from turning later uses of it back
+/// into branches. Converting to a `bool` gives up that protection.
+#[derive(Clone, Copy, Debug)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Returns 1 for true and 0 for false.
+    pub const fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Makes a choice from the lowest bit of a byte, which must be 0 or 1.
+    fn from_bit(bit: u8) -> Self {
+        debug_assert!(bit <= 1);
+        Choice(core::hint::black_box(bit))
+    }
+
+    /// A byte with every bit set if the choice is true, and none otherwise.
+    fn mask(self) -> u8 {
+        self.0.wrapping_neg()
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 != 0
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Choice::from_bit(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Choice::from_bit(self.0 | rhs.0)
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Self::Output {
+        Choice::from_bit(self.0 ^ 1)
+    }
+}
+
+/// Tests whether two numbers are equal, in constant time.
+pub fn eq<const N: usize>(lhs: &Number<N>, rhs: &Number<N>) -> Choice {
+    let difference = lhs.as_trits().iter().zip(rhs.as_trits())
+        .fold(0, |difference, (lhs, rhs)| difference | (*lhs as u8 ^ *rhs as u8));
+
+    is_zero(difference)
+}
+
+/// Compares two numbers in constant time, returning the sign of `lhs - rhs`:
+/// positive if `lhs` is greater, negative if it is less and zero if they are
+/// equal.
+pub fn compare<const N: usize>(lhs: &Number<N>, rhs: &Number<N>) -> Trit {
+    // Working up from the least significant trit, every differing trit
+    // replaces the result with its own ordering, so that the most significant
+    // difference decides it
+    let mut result = 0_i8;
+    for (lhs, rhs) in lhs.as_trits().iter().zip(rhs.as_trits()).rev() {
+        let difference = *lhs as i8 - *rhs as i8;
+        let sign = (difference >> 7) | ((difference.wrapping_neg() as u8) >> 7) as i8;
+        let differs = (sign & 1).wrapping_neg();
+        result ^= (result ^ sign) & differs;
+    }
+    trit_from_byte((result + 1) as u8)
+}
+
+/// Tests whether `lhs` is less than `rhs`, in constant time.
+pub fn less<const N: usize>(lhs: &Number<N>, rhs: &Number<N>) -> Choice {
+    // The negative trit is stored as zero
+    is_zero(compare(lhs, rhs) as u8)
+}
+
+/// Tests whether `lhs` is greater than `rhs`, in constant time.
+pub fn greater<const N: usize>(lhs: &Number<N>, rhs: &Number<N>) -> Choice {
+    less(rhs, lhs)
+}
+
+/// Returns `rhs` if the choice is true and `lhs` otherwise, in constant time.
+pub fn select<const N: usize>(lhs: &Number<N>, rhs: &Number<N>, choice: Choice) -> Number<N> {
+    let mask = choice.mask();
+    let (lhs, rhs) = (lhs.as_trits(), rhs.as_trits());
+    Number::<N>::from_trits(core::array::from_fn(|idx| {
+        let (lhs, rhs) = (lhs[idx] as u8, rhs[idx] as u8);
+        trit_from_byte(lhs ^ (mask & (lhs ^ rhs)))
+    }))
+}
+
+/// Negates a number in place if the choice is true, in constant time.
+pub fn conditional_negate<const N: usize>(number: &mut Number<N>, choice: Choice) {
+    // Negation maps the stored byte b to 2 - b
+    let mask = choice.mask();
+    let trits = number.as_trits();
+    *number = Number::<N>::from_trits(core::array::from_fn(|idx| {
+        let byte = trits[idx] as u8;
+        trit_from_byte(byte ^ (mask & (byte ^ (2 - byte))))
+    }));
+}
+
+/// Swaps two numbers if the choice is true, in constant time.
+pub fn conditional_swap<const N: usize>(lhs: &mut Number<N>, rhs: &mut Number<N>, choice: Choice) {
+    let (new_lhs, new_rhs) = (select(lhs, rhs, choice), select(rhs, lhs, choice));
+    (*lhs, *rhs) = (new_lhs, new_rhs);
+}
+
+fn is_zero(byte: u8) -> Choice {
+    // The top bit of x | -x is set exactly when x is non-zero
+    Choice::from_bit(((byte | byte.wrapping_neg()) >> 7) ^ 1)
+}
+
+/// Converts the stored byte of a trit back into the trit, without the branches
+/// of a `match`.
+fn trit_from_byte(byte: u8) -> Trit {
+    debug_assert!(byte <= 2);
+    // SAFETY: `Trit` is `repr(u8)` with the values 0, 1 and 2 for the negative,
+    // zero and positive trits, and every byte passed here is one of those
+    unsafe { core::mem::transmute::<u8, Trit>(byte) }
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_impls {
+    use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    use super::*;
+
+    impl From<Choice> for subtle::Choice {
+        fn from(choice: Choice) -> Self {
+            subtle::Choice::from(choice.unwrap_u8())
+        }
+    }
+
+    impl <const N: usize> ConstantTimeEq for Number<N> {
+        fn ct_eq(&self, other: &Self) -> subtle::Choice {
+            eq(self, other).into()
+        }
+    }
+
+    impl <const N: usize> ConstantTimeGreater for Number<N> {
+        fn ct_gt(&self, other: &Self) -> subtle::Choice {
+            greater(self, other).into()
+        }
+    }
+
+    impl <const N: usize> ConstantTimeLess for Number<N> {}
+
+    impl <const N: usize> ConditionallySelectable for Number<N> {
+        fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+            select(a, b, Choice::from_bit(choice.unwrap_u8()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_comparison_operators() {
+        let numbers: Vec<Number<4>> = (-40..=40).map(Number::<4>::from).collect();
+        for lhs in &numbers {
+            for rhs in &numbers {
+                assert_eq!(bool::from(eq(lhs, rhs)), lhs == rhs);
+                assert_eq!(bool::from(less(lhs, rhs)), lhs < rhs);
+                assert_eq!(bool::from(greater(lhs, rhs)), lhs > rhs);
+                let expected = match lhs.cmp(rhs) {
+                    core::cmp::Ordering::Less => Trit::NEG,
+                    core::cmp::Ordering::Equal => Trit::ZERO,
+                    core::cmp::Ordering::Greater => Trit::POS
+                };
+                assert_eq!(compare(lhs, rhs), expected);
+            }
+        }
+        assert_eq!(compare(&Number::<40>::MAX, &Number::<40>::MIN), Trit::POS);
+    }
+
+    #[test]
+    fn selection_and_negation() {
+        let (lhs, rhs) = (Number::<6>::from(100), Number::<6>::from(-7));
+        let (yes, no) = (Choice::from_bit(1), Choice::from_bit(0));
+        assert_eq!(select(&lhs, &rhs, yes), rhs);
+        assert_eq!(select(&lhs, &rhs, no), lhs);
+        assert_eq!((yes & no).unwrap_u8(), 0);
+        assert_eq!((yes | no).unwrap_u8(), 1);
+        assert_eq!((!no).unwrap_u8(), 1);
+
+        let mut value = lhs;
+        conditional_negate(&mut value, no);
+        assert_eq!(value, lhs);
+        conditional_negate(&mut value, yes);
+        assert_eq!(value, -lhs);
+
+        let (mut first, mut second) = (lhs, rhs);
+        conditional_swap(&mut first, &mut second, yes);
+        assert_eq!((first, second), (rhs, lhs));
+    }
+}
@@ -0,0 +1,153 @@
+//! The crate-wide error type, returned by the `try_*` methods.
+//!
+//! Most operations that can fail on bad input have a `try_*` form, which
+//! returns an [`Error`] where the plain method would panic:
+//! * Division, remainders and modular reduction by zero, on [`Number`],
+//!   [`BigTernary`], [`TritVec`], [`Poly`], [`Fixed`] and [`Ratio`].
+//! * Operations outside their domain, such as the square root of a negative
+//!   number or the logarithm of zero.
+//! * Intervals and clamps whose lower bound exceeds the upper bound.
+//! * Numbers built from more trits than they can hold.
+//!
+//! The errors of the parsing and conversion functions convert into [`Error`]
+//! as well, so that `?` can mix them in a function returning `Error`.
+//! ```
+//! # use balanced_ternary::Error;
+//! # use balanced_ternary::number::Number;
+//! fn average(total: &str, count: &str) -> Result<Number<16>, Error> {
+//!     let (total, count): (Number<16>, Number<16>) = (total.parse()?, count.parse()?);
+//!     total.try_div(count)
+//! }
+//!
+//! assert_eq!(average("+0-+0", "+-"), Ok(Number::from(37)));
+//! assert_eq!(average("+0-+0", "0"), Err(Error::DivisionByZero));
+//! assert!(matches!(average("+0x", "+"), Err(Error::Parse(_))));
+//! ```
+//!
+//! [`Number`]: crate::number::Number
+//! [`BigTernary`]: crate::big_ternary::BigTernary
+//! [`TritVec`]: crate::trit_vec::TritVec
+//! [`Poly`]: crate::gf3::Poly
+//! [`Fixed`]: crate::fixed::Fixed
+//! [`Ratio`]: crate::ratio::Ratio
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::number::UnpackBytesError;
+use crate::number::{FloatConversionError, ParseTernaryError, TryFromIntError};
+use crate::trit::ParseTritError;
+
+/// Reasons that an operation of this crate failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A division, remainder or modular reduction was by zero.
+    DivisionByZero,
+    /// The operation is undefined for a negative operand, such as the square
+    /// root of a negative number.
+    NegativeOperand,
+    /// The operation is undefined for a zero operand, such as the logarithm of
+    /// zero.
+    ZeroOperand,
+    /// A lower bound was greater than the upper bound.
+    InvalidBounds,
+    /// More trits were supplied than can be stored in the number.
+    TooManyTrits { length: usize, capacity: usize },
+    /// The result or a converted value is too large in magnitude to be stored
+    /// in the target type.
+    OutOfRange,
+    /// A balanced ternary string couldn't be parsed.
+    Parse(ParseTernaryError),
+    /// A character couldn't be parsed as a trit.
+    ParseTrit(ParseTritError),
+    /// A floating point value couldn't be converted.
+    FloatConversion(FloatConversionError),
+    /// Packed bytes couldn't be unpacked into trits.
+    #[cfg(feature = "alloc")]
+    UnpackBytes(UnpackBytesError)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::DivisionByZero => write!(f, "attempt to divide by zero"),
+            Error::NegativeOperand => write!(f, "operation is undefined for a negative operand"),
+            Error::ZeroOperand => write!(f, "operation is undefined for a zero operand"),
+            Error::InvalidBounds => write!(f, "lower bound is greater than the upper bound"),
+            Error::TooManyTrits { length, capacity } =>
+                write!(f, "{} trits supplied but number can only hold {}", length, capacity),
+            Error::OutOfRange => write!(f, "value is out of range of the target type"),
+            Error::Parse(error) => fmt::Display::fmt(error, f),
+            Error::ParseTrit(error) => fmt::Display::fmt(error, f),
+            Error::FloatConversion(error) => fmt::Display::fmt(error, f),
+            #[cfg(feature = "alloc")]
+            Error::UnpackBytes(error) => fmt::Display::fmt(error, f)
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            Error::ParseTrit(error) => Some(error),
+            Error::FloatConversion(error) => Some(error),
+            #[cfg(feature = "alloc")]
+            Error::UnpackBytes(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<ParseTernaryError> for Error {
+    fn from(error: ParseTernaryError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl From<ParseTritError> for Error {
+    fn from(error: ParseTritError) -> Self {
+        Error::ParseTrit(error)
+    }
+}
+
+impl From<FloatConversionError> for Error {
+    fn from(error: FloatConversionError) -> Self {
+        Error::FloatConversion(error)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(_: TryFromIntError) -> Self {
+        Error::OutOfRange
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<UnpackBytesError> for Error {
+    fn from(error: UnpackBytesError) -> Self {
+        Error::UnpackBytes(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::error::Error as _;
+
+    use super::*;
+    use crate::number::Number;
+
+    #[test]
+    fn conversions_and_sources() {
+        let parse_error = "+x".parse::<Number<4>>().unwrap_err();
+        let error = Error::from(parse_error.clone());
+        assert_eq!(error, Error::Parse(parse_error.clone()));
+        assert_eq!(error.to_string(), parse_error.to_string());
+        assert!(error.source().is_some());
+
+        let int_error = Number::<2>::try_from(100_i64).unwrap_err();
+        assert_eq!(Error::from(int_error), Error::OutOfRange);
+        assert!(Error::DivisionByZero.source().is_none());
+        assert_eq!(Error::DivisionByZero.to_string(), "attempt to divide by zero");
+    }
+}
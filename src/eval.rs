@@ -0,0 +1,587 @@
+//! Parsing and evaluation of arithmetic expressions over [`Number`].
+//!
+//! An expression is made of decimal integers, trit strings in double quotes,
+//! variables, parentheses and the operators below, listed from the loosest
+//! binding to the tightest:
+//! * `+` and `-`, for addition and subtraction
+//! * `*`, `/` and `%`, for multiplication, division and remainder, where
+//!   division rounds towards zero as with `Div`
+//! * unary `-` and `+`
+//! * `^`, for exponentiation, which groups from the right so `2^3^2` is 2^9
+//!
+//! Variables are names of letters, digits and underscores that don't start
+//! with a digit, and are looked up in a [`Context`] when the expression is
+//! evaluated. Unlike the operators of [`Number`], evaluation never wraps: a
+//! result too large for N trits is reported as [`Error::OutOfRange`].
+//! ```
+//! # use balanced_ternary::eval::Context;
+//! # use balanced_ternary::number::Number;
+//! let mut context = Context::<16>::new();
+//! context.set("x", Number::from(4));
+//! assert_eq!(context.evaluate(r#"("+0--" * 3) + x"#), Ok(Number::from(73)));
+//! assert_eq!(context.evaluate("-x^2 % 7"), Ok(Number::from(-2)));
+//! ```
+//!
+//! Parse errors give the character position of the problem, so that it can
+//! be pointed out to whoever wrote the expression:
+//! ```
+//! # use balanced_ternary::eval::{Context, EvalError, ParseError};
+//! let error = Context::<16>::new().evaluate("(1 + 2 * ").unwrap_err();
+//! assert_eq!(error, EvalError::Parse(ParseError::UnexpectedEnd { position: 9 }));
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::fmt;
+use core::iter::Peekable;
+use core::str::{Chars, FromStr};
+
+use crate::error::Error;
+use crate::number::{FormatOptions, Number, ParseTernaryError};
+use crate::trit::Trit;
+
+/// The deepest that an expression can nest, counting each parenthesis,
+/// operator and unary sign that an operand is inside. Parsing, evaluating,
+/// formatting and dropping an expression all recurse through its nesting, so
+/// it is limited to keep them from overflowing the stack.
+pub const MAX_DEPTH: usize = 256;
+
+/// Reasons that an expression couldn't be parsed. Positions are counted in
+/// characters from the start of the expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A character was found where it can't appear, such as an operator with
+    /// no operand after it or a symbol that isn't part of the syntax.
+    UnexpectedCharacter { character: char, position: usize },
+    /// The expression ended where an operand was expected, including when it
+    /// is empty.
+    UnexpectedEnd { position: usize },
+    /// A parenthesis opened at the given position is never closed.
+    UnclosedParenthesis { position: usize },
+    /// A trit string opened at the given position has no closing quote.
+    UnterminatedString { position: usize },
+    /// A decimal integer or trit string starting at the given position isn't
+    /// valid, or doesn't fit in N trits.
+    InvalidLiteral { error: ParseTernaryError, position: usize },
+    /// The expression nests deeper than [`MAX_DEPTH`] at the given position.
+    TooDeep { position: usize }
+}
+
+impl ParseError {
+    /// Returns the character position that the error refers to.
+    pub fn position(&self) -> usize {
+        match *self {
+            ParseError::UnexpectedCharacter { position, .. }
+            | ParseError::UnexpectedEnd { position }
+            | ParseError::UnclosedParenthesis { position }
+            | ParseError::UnterminatedString { position }
+            | ParseError::InvalidLiteral { position, .. }
+            | ParseError::TooDeep { position } => position
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter { character, position } =>
+                write!(f, "unexpected character {:?} at position {}", character, position),
+            ParseError::UnexpectedEnd { position } =>
+                write!(f, "expected an operand at position {}", position),
+            ParseError::UnclosedParenthesis { position } =>
+                write!(f, "parenthesis at position {} is never closed", position),
+            ParseError::UnterminatedString { position } =>
+                write!(f, "trit string at position {} is never closed", position),
+            ParseError::InvalidLiteral { error, position } =>
+                write!(f, "invalid literal at position {}: {}", position, error),
+            ParseError::TooDeep { position } =>
+                write!(f, "expression nests too deeply at position {}", position)
+        }
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidLiteral { error, .. } => Some(error),
+            _ => None
+        }
+    }
+}
+
+/// Reasons that an expression couldn't be evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EvalError {
+    /// The expression couldn't be parsed.
+    Parse(ParseError),
+    /// A variable has no value in the context.
+    UnknownVariable(String),
+    /// An operation failed, by dividing by zero, raising to a negative power
+    /// or overflowing N trits.
+    Arithmetic(Error)
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::Parse(error) => fmt::Display::fmt(error, f),
+            EvalError::UnknownVariable(name) => write!(f, "unknown variable {:?}", name),
+            EvalError::Arithmetic(error) => fmt::Display::fmt(error, f)
+        }
+    }
+}
+
+impl core::error::Error for EvalError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            EvalError::Parse(error) => Some(error),
+            EvalError::UnknownVariable(_) => None,
+            EvalError::Arithmetic(error) => Some(error)
+        }
+    }
+}
+
+impl From<ParseError> for EvalError {
+    fn from(error: ParseError) -> Self {
+        EvalError::Parse(error)
+    }
+}
+
+impl From<Error> for EvalError {
+    fn from(error: Error) -> Self {
+        EvalError::Arithmetic(error)
+    }
+}
+
+/// The binary operators of an expression.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow
+}
+
+impl BinaryOp {
+    /// Returns the symbol of the operator.
+    pub fn symbol(self) -> char {
+        match self {
+            BinaryOp::Add => '+',
+            BinaryOp::Sub => '-',
+            BinaryOp::Mul => '*',
+            BinaryOp::Div => '/',
+            BinaryOp::Rem => '%',
+            BinaryOp::Pow => '^'
+        }
+    }
+
+    /// Applies the operator, without wrapping on overflow.
+    fn apply<const N: usize>(self, lhs: Number<N>, rhs: Number<N>) -> Result<Number<N>, Error> {
+        match self {
+            BinaryOp::Add => lhs.checked_add(rhs).ok_or(Error::OutOfRange),
+            BinaryOp::Sub => lhs.checked_sub(rhs).ok_or(Error::OutOfRange),
+            BinaryOp::Mul => lhs.checked_mul(rhs).ok_or(Error::OutOfRange),
+            BinaryOp::Div => lhs.try_div(rhs),
+            BinaryOp::Rem => lhs.try_rem(rhs),
+            BinaryOp::Pow => {
+                if rhs.is_negative() {
+                    return Err(Error::NegativeOperand);
+                }
+                // Powers of -1, 0 and 1 fit however large the exponent is
+                if lhs.abs() <= Number::<N>::ONE && !rhs.is_zero() {
+                    // Every power of three is odd, so the exponent is odd
+                    // exactly when it has an odd number of non-zero trits
+                    let odd = rhs.iter().filter(|trit| *trit != Trit::ZERO).count() % 2 == 1;
+                    return Ok(if lhs.is_negative() && !odd {-lhs} else {lhs});
+                }
+                let exponent = i64::try_from(rhs).ok().and_then(|exponent| u32::try_from(exponent).ok());
+                exponent.and_then(|exponent| lhs.checked_pow(exponent)).ok_or(Error::OutOfRange)
+            }
+        }
+    }
+}
+
+/// A parsed expression, which can be evaluated any number of times with
+/// different variables.
+///
+/// Expressions are parsed with [`FromStr`], and displayed with every operation
+/// in parentheses and every literal as a trit string, which parses back to the
+/// same expression.
+/// ```
+/// # use balanced_ternary::eval::Expression;
+/// # use balanced_ternary::number::Number;
+/// let expression: Expression<8> = "2 * n + 1".parse().unwrap();
+/// assert_eq!(expression.to_string(), r#"(("+-" * n) + "+")"#);
+/// assert_eq!(expression.evaluate_with(|_| Some(Number::from(5))), Ok(Number::from(11)));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression<const N: usize> {
+    Literal(Number<N>),
+    Variable(String),
+    Neg(Box<Expression<N>>),
+    Binary(BinaryOp, Box<Expression<N>>, Box<Expression<N>>)
+}
+
+impl <const N: usize> Expression<N> {
+    /// Evaluates the expression with the variables of `context`.
+    pub fn evaluate(&self, context: &Context<N>) -> Result<Number<N>, EvalError> {
+        self.evaluate_with(|name| context.get(name))
+    }
+
+    /// Evaluates the expression, looking up each variable with `lookup`, which
+    /// returns `None` for an unknown variable.
+    pub fn evaluate_with(&self, lookup: impl Fn(&str) -> Option<Number<N>>) -> Result<Number<N>, EvalError> {
+        self.evaluate_dyn(&lookup)
+    }
+
+    fn evaluate_dyn(&self, lookup: &dyn Fn(&str) -> Option<Number<N>>) -> Result<Number<N>, EvalError> {
+        match self {
+            Expression::Literal(value) => Ok(*value),
+            Expression::Variable(name) => lookup(name).ok_or_else(|| EvalError::UnknownVariable(name.clone())),
+            Expression::Neg(operand) => Ok(-operand.evaluate_dyn(lookup)?),
+            Expression::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate_dyn(lookup)?, rhs.evaluate_dyn(lookup)?);
+                Ok(op.apply(lhs, rhs)?)
+            }
+        }
+    }
+}
+
+impl <const N: usize> FromStr for Expression<N> {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { chars: source.chars().peekable(), position: 0, nesting: 0 };
+        let (expression, _) = parser.sum()?;
+        match parser.peek() {
+            Some(character) => Err(ParseError::UnexpectedCharacter { character, position: parser.position }),
+            None => Ok(expression)
+        }
+    }
+}
+
+impl <const N: usize> fmt::Display for Expression<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expression::Literal(value) => write!(f, "\"{}\"", value.format_with(&FormatOptions::new().with_leading_zeros(false))),
+            Expression::Variable(name) => f.write_str(name),
+            Expression::Neg(operand) => write!(f, "(-{})", operand),
+            Expression::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op.symbol(), rhs)
+        }
+    }
+}
+
+/// A parsed expression along with the depth of its tree.
+type Parsed<const N: usize> = (Expression<N>, usize);
+
+/// A recursive descent parser over the characters of an expression, with one
+/// method for each level of precedence.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    position: usize,
+    // The number of calls to `unary` being parsed, which every recursion of
+    // the parser passes through
+    nesting: usize
+}
+
+impl Parser<'_> {
+    fn sum<const N: usize>(&mut self) -> Result<Parsed<N>, ParseError> {
+        let mut expression = self.product()?;
+        while let Some(op) = self.next_operator(&[BinaryOp::Add, BinaryOp::Sub]) {
+            let position = self.position - 1;
+            expression = Self::binary(op, expression, self.product()?, position)?;
+        }
+        Ok(expression)
+    }
+
+    fn product<const N: usize>(&mut self) -> Result<Parsed<N>, ParseError> {
+        let mut expression = self.unary()?;
+        while let Some(op) = self.next_operator(&[BinaryOp::Mul, BinaryOp::Div, BinaryOp::Rem]) {
+            let position = self.position - 1;
+            expression = Self::binary(op, expression, self.unary()?, position)?;
+        }
+        Ok(expression)
+    }
+
+    fn unary<const N: usize>(&mut self) -> Result<Parsed<N>, ParseError> {
+        // Limiting the nesting here bounds the stack used by the parser, as
+        // the trees it builds are only checked once they are complete
+        if self.nesting == MAX_DEPTH {
+            return Err(ParseError::TooDeep { position: self.position });
+        }
+        self.nesting += 1;
+
+        let position = self.position;
+        let parsed = match self.next_operator(&[BinaryOp::Add, BinaryOp::Sub]) {
+            Some(BinaryOp::Sub) => self.unary().and_then(|(operand, depth)| {
+                Self::node(Expression::Neg(Box::new(operand)), depth + 1, position)
+            }),
+            Some(_) => self.unary(),
+            None => self.power()
+        };
+        self.nesting -= 1;
+        parsed
+    }
+
+    fn power<const N: usize>(&mut self) -> Result<Parsed<N>, ParseError> {
+        let base = self.operand()?;
+        if self.next_operator(&[BinaryOp::Pow]).is_none() {
+            return Ok(base);
+        }
+        // The exponent may itself be negated or raised to a power
+        let position = self.position - 1;
+        Self::binary(BinaryOp::Pow, base, self.unary()?, position)
+    }
+
+    fn operand<const N: usize>(&mut self) -> Result<Parsed<N>, ParseError> {
+        let start = self.position;
+        match self.peek() {
+            None => Err(ParseError::UnexpectedEnd { position: start }),
+            Some('(') => {
+                self.bump();
+                let expression = self.sum()?;
+                match self.peek() {
+                    Some(')') => {
+                        self.bump();
+                        Ok(expression)
+                    },
+                    Some(character) => Err(ParseError::UnexpectedCharacter { character, position: self.position }),
+                    None => Err(ParseError::UnclosedParenthesis { position: start })
+                }
+            },
+            Some('"') => {
+                self.bump();
+                let mut trits = String::new();
+                loop {
+                    match self.bump() {
+                        Some('"') => break,
+                        Some(character) => trits.push(character),
+                        None => return Err(ParseError::UnterminatedString { position: start })
+                    }
+                }
+                trits.parse()
+                    .map(|value| (Expression::Literal(value), 1))
+                    .map_err(|error| ParseError::InvalidLiteral { error, position: start })
+            },
+            Some(character) if character.is_ascii_digit() => {
+                let digits = self.take_while(|character| character.is_ascii_alphanumeric() || character == '_');
+                Number::from_decimal_str(&digits)
+                    .map(|value| (Expression::Literal(value), 1))
+                    .map_err(|error| ParseError::InvalidLiteral { error, position: start })
+            },
+            Some(character) if character.is_alphabetic() || character == '_' => {
+                let name = self.take_while(|character| character.is_alphanumeric() || character == '_');
+                Ok((Expression::Variable(name), 1))
+            },
+            Some(character) => Err(ParseError::UnexpectedCharacter { character, position: start })
+        }
+    }
+
+    /// Joins two operands with the operator at `position`.
+    fn binary<const N: usize>(op: BinaryOp, (lhs, lhs_depth): Parsed<N>, (rhs, rhs_depth): Parsed<N>, position: usize) -> Result<Parsed<N>, ParseError> {
+        Self::node(Expression::Binary(op, Box::new(lhs), Box::new(rhs)), lhs_depth.max(rhs_depth) + 1, position)
+    }
+
+    /// Checks the depth of a new node, which long chains of operators can
+    /// take past the limit without any nesting in the parser.
+    fn node<const N: usize>(expression: Expression<N>, depth: usize, position: usize) -> Result<Parsed<N>, ParseError> {
+        if depth > MAX_DEPTH {
+            return Err(ParseError::TooDeep { position });
+        }
+        Ok((expression, depth))
+    }
+
+    /// Consumes the next operator if it is one of `ops`.
+    fn next_operator(&mut self, ops: &[BinaryOp]) -> Option<BinaryOp> {
+        let character = self.peek()?;
+        let op = ops.iter().copied().find(|op| op.symbol() == character)?;
+        self.bump();
+        Some(op)
+    }
+
+    /// Skips whitespace and returns the next character without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.next_if(|character| character.is_whitespace()).is_some() {
+            self.position += 1;
+        }
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let character = self.chars.next()?;
+        self.position += 1;
+        Some(character)
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+        while let Some(character) = self.chars.next_if(|&character| predicate(character)) {
+            self.position += 1;
+            taken.push(character);
+        }
+        taken
+    }
+}
+
+/// A set of named variables that expressions are evaluated with.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Context<const N: usize> {
+    variables: BTreeMap<String, Number<N>>
+}
+
+impl <const N: usize> Context<N> {
+    /// Creates a context with no variables.
+    pub fn new() -> Self {
+        Context { variables: BTreeMap::new() }
+    }
+
+    /// Sets a variable, returning its previous value if it had one. Names
+    /// that aren't valid variable names can be set, but can't be referred to
+    /// by an expression.
+    pub fn set(&mut self, name: impl Into<String>, value: Number<N>) -> Option<Number<N>> {
+        self.variables.insert(name.into(), value)
+    }
+
+    /// Returns the value of a variable, if it is set.
+    pub fn get(&self, name: &str) -> Option<Number<N>> {
+        self.variables.get(name).copied()
+    }
+
+    /// Removes a variable, returning its value if it was set.
+    pub fn remove(&mut self, name: &str) -> Option<Number<N>> {
+        self.variables.remove(name)
+    }
+
+    /// Iterates over the variables in order of their names.
+    pub fn variables(&self) -> impl Iterator<Item = (&str, Number<N>)> {
+        self.variables.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// Parses and evaluates an expression with the variables of this context.
+    pub fn evaluate(&self, source: &str) -> Result<Number<N>, EvalError> {
+        source.parse::<Expression<N>>()?.evaluate(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str) -> Result<Number<9>, EvalError> {
+        let mut context = Context::new();
+        context.set("x", Number::from(4));
+        context.set("big_1", Number::from(9000));
+        context.evaluate(source)
+    }
+
+    #[test]
+    fn precedence_and_associativity() {
+        assert_eq!(eval("1 + 2 * 3"), Ok(Number::from(7)));
+        assert_eq!(eval("(1 + 2) * 3"), Ok(Number::from(9)));
+        assert_eq!(eval("10 - 4 - 3"), Ok(Number::from(3)));
+        assert_eq!(eval("100 / 10 / 5"), Ok(Number::from(2)));
+        assert_eq!(eval("2 ^ 3 ^ 2"), Ok(Number::from(512)));
+        assert_eq!(eval("-3 ^ 2"), Ok(Number::from(-9)));
+        assert_eq!(eval("--x + +x"), Ok(Number::from(8)));
+        assert_eq!(eval("-7 / 2"), Ok(Number::from(-3)));
+        assert_eq!(eval("-7 % 2"), Ok(Number::from(-1)));
+        assert_eq!(eval("\"+0--\"*3+x"), Ok(Number::from(73)));
+        assert_eq!(eval("big_1 - \"\""), Err(EvalError::Parse(ParseError::InvalidLiteral {
+            error: ParseTernaryError::Empty,
+            position: 8
+        })));
+    }
+
+    #[test]
+    fn arithmetic_errors() {
+        assert_eq!(eval("1 / (x - 4)"), Err(EvalError::Arithmetic(Error::DivisionByZero)));
+        assert_eq!(eval("x % 0"), Err(EvalError::Arithmetic(Error::DivisionByZero)));
+        assert_eq!(eval("big_1 + 900"), Err(EvalError::Arithmetic(Error::OutOfRange)));
+        assert_eq!(eval("big_1 * -2"), Err(EvalError::Arithmetic(Error::OutOfRange)));
+        assert_eq!(eval("3 ^ 9"), Err(EvalError::Arithmetic(Error::OutOfRange)));
+        assert_eq!(eval("3 ^ -1"), Err(EvalError::Arithmetic(Error::NegativeOperand)));
+        assert_eq!(eval("y + 1"), Err(EvalError::UnknownVariable("y".into())));
+    }
+
+    #[test]
+    fn powers_of_small_bases() {
+        let eval = |source| Context::<27>::new().evaluate(source);
+        assert_eq!(eval("1 ^ 10000000000"), Ok(Number::ONE));
+        assert_eq!(eval("0 ^ 10000000000"), Ok(Number::ZERO));
+        assert_eq!(eval("(-1) ^ 10000000001"), Ok(Number::NEG_ONE));
+        assert_eq!(eval("(-1) ^ 10000000000"), Ok(Number::ONE));
+        assert_eq!(eval("0 ^ 0"), Ok(Number::ONE));
+        assert_eq!(eval("2 ^ 10000000000"), Err(EvalError::Arithmetic(Error::OutOfRange)));
+        assert_eq!(eval("0 ^ -10000000000"), Err(EvalError::Arithmetic(Error::NegativeOperand)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |source| match eval(source) {
+            Err(EvalError::Parse(error)) => error,
+            other => panic!("{:?} parsed as {:?}", source, other)
+        };
+        assert_eq!(error(""), ParseError::UnexpectedEnd { position: 0 });
+        assert_eq!(error("1 +"), ParseError::UnexpectedEnd { position: 3 });
+        assert_eq!(error("1 + * 2"), ParseError::UnexpectedCharacter { character: '*', position: 4 });
+        assert_eq!(error("1 2"), ParseError::UnexpectedCharacter { character: '2', position: 2 });
+        assert_eq!(error("x (1)"), ParseError::UnexpectedCharacter { character: '(', position: 2 });
+        assert_eq!(error("(1 + (2)"), ParseError::UnclosedParenthesis { position: 0 });
+        assert_eq!(error("(1 + 2]"), ParseError::UnexpectedCharacter { character: ']', position: 6 });
+        assert_eq!(error("x * \"+0-"), ParseError::UnterminatedString { position: 4 });
+        assert_eq!(error("\u{3c0} + \"+x\""), ParseError::InvalidLiteral {
+            error: ParseTernaryError::InvalidTrit { character: 'x', position: 1 },
+            position: 4
+        });
+        assert_eq!(error("12ab"), ParseError::InvalidLiteral {
+            error: ParseTernaryError::InvalidDigit { character: 'a', position: 2 },
+            position: 0
+        });
+        assert_eq!(error("99999"), ParseError::InvalidLiteral { error: ParseTernaryError::OutOfRange, position: 0 });
+        assert_eq!(error("1 +").to_string(), "expected an operand at position 3");
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let too_deep = |position| Err(EvalError::Parse(ParseError::TooDeep { position }));
+        let nested = |depth| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(eval(&nested(MAX_DEPTH - 1)), Ok(Number::ONE));
+        assert_eq!(eval(&nested(MAX_DEPTH)), too_deep(MAX_DEPTH));
+        assert_eq!(eval(&nested(100_000)), too_deep(MAX_DEPTH));
+
+        let negated = |depth| format!("{}1", "-".repeat(depth));
+        assert_eq!(eval(&negated(MAX_DEPTH - 1)), Ok(Number::NEG_ONE));
+        assert_eq!(eval(&negated(MAX_DEPTH)), too_deep(MAX_DEPTH));
+        assert_eq!(eval(&negated(100_000)), too_deep(MAX_DEPTH));
+
+        // Long chains of operators build deep trees without nesting
+        let chained = |length| format!("1{}", "+1".repeat(length));
+        assert_eq!(eval(&chained(MAX_DEPTH - 1)), Ok(Number::from(256)));
+        assert_eq!(eval(&chained(MAX_DEPTH)), too_deep(2 * MAX_DEPTH - 1));
+        assert_eq!(eval(&chained(100_000)), too_deep(2 * MAX_DEPTH - 1));
+        assert_eq!(eval(&"2^".repeat(100_000)), too_deep(2 * MAX_DEPTH));
+        assert_eq!(ParseError::TooDeep { position: 3 }.to_string(), "expression nests too deeply at position 3");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for source in ["1 + 2 * -x", "(a - b) % 3 ^ -c ^ 2", "(-2) ^ 2", "0", "-\"-+\""] {
+            let expression: Expression<9> = source.parse().unwrap();
+            assert_eq!(expression.to_string().parse::<Expression<9>>(), Ok(expression));
+        }
+    }
+
+    #[test]
+    fn context_variables() {
+        let mut context = Context::<9>::new();
+        assert_eq!(context.set("b", Number::from(2)), None);
+        assert_eq!(context.set("a", Number::from(1)), None);
+        assert_eq!(context.set("b", Number::from(3)), Some(Number::from(2)));
+        assert_eq!(context.variables().collect::<Vec<_>>(), [("a", Number::from(1)), ("b", Number::from(3))]);
+        assert_eq!(context.remove("a"), Some(Number::from(1)));
+        assert_eq!(context.get("a"), None);
+    }
+}
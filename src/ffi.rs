@@ -0,0 +1,303 @@
+//! A C interface to the arithmetic of [`BigTernary`].
+//!
+//! Numbers are handed to C as pointers to an opaque `BtNumber`, which are
+//! created by the constructors and arithmetic functions below and released
+//! with [`bt_number_free`]. Every function returning a number returns a new
+//! one that the caller owns, leaving its arguments untouched. Strings returned
+//! to C are owned by the caller too, and are released with [`bt_string_free`].
+//!
+//! The functions are unmangled and only use C types, so a header can be
+//! generated with cbindgen, and the crate linked by building it as a static or
+//! dynamic library:
+//! ```text
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+//! ```c
+//! BtNumber *a = bt_number_parse("+0--");
+//! BtNumber *b = bt_number_from_i64(4);
+//! BtNumber *sum = bt_number_add(a, b);
+//! char *text = bt_number_to_decimal_string(sum);  /* "27" */
+//! bt_string_free(text);
+//! bt_number_free(sum);
+//! bt_number_free(b);
+//! bt_number_free(a);
+//! ```
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+use crate::big_ternary::BigTernary;
+
+/// An opaque balanced ternary integer of unbounded precision.
+pub struct BtNumber(BigTernary);
+
+/// Moves a number to the heap, giving ownership of it to the caller.
+fn into_handle(number: BigTernary) -> *mut BtNumber {
+    Box::into_raw(Box::new(BtNumber(number)))
+}
+
+/// Borrows the number behind a handle.
+///
+/// # Safety
+/// `handle` must be a live pointer returned by this module.
+unsafe fn number<'a>(handle: *const BtNumber) -> &'a BigTernary {
+    // SAFETY: upheld by the caller
+    unsafe { &(*handle).0 }
+}
+
+/// Reads a C string, or returns `None` if it is null or isn't UTF-8.
+///
+/// # Safety
+/// `text` must be null or point to a nul-terminated string.
+unsafe fn read_str<'a>(text: *const c_char) -> Option<&'a str> {
+    if text.is_null() {
+        return None;
+    }
+    // SAFETY: upheld by the caller
+    unsafe { CStr::from_ptr(text) }.to_str().ok()
+}
+
+/// Gives ownership of a string to the caller. None of the strings written by
+/// this crate contain a nul, but null is returned if one does.
+fn into_c_string(text: String) -> *mut c_char {
+    CString::new(text).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Creates a number from a 64-bit integer.
+#[no_mangle]
+pub extern "C" fn bt_number_from_i64(value: i64) -> *mut BtNumber {
+    into_handle(BigTernary::from(i128::from(value)))
+}
+
+/// Parses a number from a string of `-`, `0` and `+` trits, most significant
+/// first. Returns null if `text` is null or isn't a valid trit string.
+///
+/// # Safety
+/// `text` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_parse(text: *const c_char) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    match unsafe { read_str(text) }.map(str::parse) {
+        Some(Ok(number)) => into_handle(number),
+        _ => ptr::null_mut()
+    }
+}
+
+/// Parses a number from a decimal integer, with an optional sign. Returns
+/// null if `text` is null or isn't a valid decimal integer.
+///
+/// # Safety
+/// `text` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_parse_decimal(text: *const c_char) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    match unsafe { read_str(text) }.map(BigTernary::from_decimal_str) {
+        Some(Ok(number)) => into_handle(number),
+        _ => ptr::null_mut()
+    }
+}
+
+/// Creates a copy of a number.
+///
+/// # Safety
+/// `number` must be a live number returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_clone(number: *const BtNumber) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    into_handle(unsafe { self::number(number) }.clone())
+}
+
+/// Releases a number. Passing null does nothing.
+///
+/// # Safety
+/// `number` must be null or a live number returned by this module, which
+/// mustn't be used again.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_free(number: *mut BtNumber) {
+    if !number.is_null() {
+        // SAFETY: the number was boxed by `into_handle`, and ownership is
+        // returned by the caller
+        drop(unsafe { Box::from_raw(number) });
+    }
+}
+
+/// Writes a number as a string of `-`, `0` and `+` trits without leading
+/// zeros, or `0` for zero. The string must be released with
+/// [`bt_string_free`].
+///
+/// # Safety
+/// `number` must be a live number returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_to_string(number: *const BtNumber) -> *mut c_char {
+    // SAFETY: upheld by the caller
+    into_c_string(unsafe { self::number(number) }.to_string())
+}
+
+/// Writes a number as a decimal integer. The string must be released with
+/// [`bt_string_free`].
+///
+/// # Safety
+/// `number` must be a live number returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_to_decimal_string(number: *const BtNumber) -> *mut c_char {
+    // SAFETY: upheld by the caller
+    into_c_string(unsafe { self::number(number) }.to_decimal_string())
+}
+
+/// Releases a string returned by this module. Passing null does nothing.
+///
+/// # Safety
+/// `text` must be null or a live string returned by this module, which
+/// mustn't be used again.
+#[no_mangle]
+pub unsafe extern "C" fn bt_string_free(text: *mut c_char) {
+    if !text.is_null() {
+        // SAFETY: the string was created by `into_c_string`, and ownership is
+        // returned by the caller
+        drop(unsafe { CString::from_raw(text) });
+    }
+}
+
+/// Converts a number to a 64-bit integer, storing it in `out`. Returns false,
+/// leaving `out` untouched, if the number is out of range.
+///
+/// # Safety
+/// `number` must be a live number returned by this module, and `out` must be
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_to_i64(number: *const BtNumber, out: *mut i64) -> bool {
+    // SAFETY: upheld by the caller
+    let number = unsafe { self::number(number) };
+    match i128::try_from(number).ok().and_then(|value| i64::try_from(value).ok()) {
+        Some(value) => {
+            // SAFETY: upheld by the caller
+            unsafe { out.write(value) };
+            true
+        },
+        None => false
+    }
+}
+
+/// Adds two numbers.
+///
+/// # Safety
+/// `lhs` and `rhs` must be live numbers returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_add(lhs: *const BtNumber, rhs: *const BtNumber) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    into_handle(unsafe { number(lhs) + number(rhs) })
+}
+
+/// Subtracts `rhs` from `lhs`.
+///
+/// # Safety
+/// `lhs` and `rhs` must be live numbers returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_sub(lhs: *const BtNumber, rhs: *const BtNumber) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    into_handle(unsafe { number(lhs) - number(rhs) })
+}
+
+/// Multiplies two numbers.
+///
+/// # Safety
+/// `lhs` and `rhs` must be live numbers returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_mul(lhs: *const BtNumber, rhs: *const BtNumber) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    into_handle(unsafe { number(lhs) * number(rhs) })
+}
+
+/// Negates a number.
+///
+/// # Safety
+/// `number` must be a live number returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_neg(number: *const BtNumber) -> *mut BtNumber {
+    // SAFETY: upheld by the caller
+    into_handle(unsafe { -self::number(number) })
+}
+
+/// Compares two numbers, returning -1, 0 or 1 if `lhs` is less than, equal to
+/// or greater than `rhs`.
+///
+/// # Safety
+/// `lhs` and `rhs` must be live numbers returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn bt_number_compare(lhs: *const BtNumber, rhs: *const BtNumber) -> c_int {
+    // SAFETY: upheld by the caller
+    unsafe { number(lhs).cmp(number(rhs)) as c_int }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Takes ownership of a returned string.
+    unsafe fn take_string(text: *mut c_char) -> String {
+        assert!(!text.is_null());
+        let string = unsafe { CStr::from_ptr(text) }.to_str().unwrap().to_string();
+        unsafe { bt_string_free(text) };
+        string
+    }
+
+    #[test]
+    fn arithmetic_round_trip() {
+        unsafe {
+            let a = bt_number_parse(c"+0--".as_ptr());
+            let b = bt_number_from_i64(4);
+            let c = bt_number_parse_decimal(c"-123456789012345678901234567890".as_ptr());
+
+            let sum = bt_number_add(a, b);
+            let product = bt_number_mul(sum, c);
+            let difference = bt_number_sub(a, b);
+            let negated = bt_number_neg(difference);
+            assert_eq!(take_string(bt_number_to_decimal_string(sum)), "27");
+            assert_eq!(take_string(bt_number_to_string(sum)), "+000");
+            assert_eq!(take_string(bt_number_to_decimal_string(product)), "-3333333303333333330333333333030");
+            assert_eq!(take_string(bt_number_to_string(negated)), "-+0-");
+
+            let mut value = 0;
+            assert!(bt_number_to_i64(negated, &mut value));
+            assert_eq!(value, -19);
+            assert!(!bt_number_to_i64(product, &mut value));
+            assert_eq!(value, -19);
+
+            for number in [a, b, c, sum, product, difference, negated] {
+                bt_number_free(number);
+            }
+        }
+    }
+
+    #[test]
+    fn comparison_and_copies() {
+        unsafe {
+            let a = bt_number_from_i64(-5);
+            let b = bt_number_clone(a);
+            let c = bt_number_from_i64(i64::MAX);
+            assert_eq!(bt_number_compare(a, b), 0);
+            assert_eq!(bt_number_compare(a, c), -1);
+            assert_eq!(bt_number_compare(c, a), 1);
+
+            let mut value = 0;
+            assert!(bt_number_to_i64(c, &mut value));
+            assert_eq!(value, i64::MAX);
+            for number in [a, b, c] {
+                bt_number_free(number);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        unsafe {
+            assert!(bt_number_parse(ptr::null()).is_null());
+            assert!(bt_number_parse(c"+0x".as_ptr()).is_null());
+            assert!(bt_number_parse(c"".as_ptr()).is_null());
+            assert!(bt_number_parse_decimal(c"12a".as_ptr()).is_null());
+            assert!(bt_number_parse_decimal(c"\xff".as_ptr()).is_null());
+            bt_number_free(ptr::null_mut());
+            bt_string_free(ptr::null_mut());
+        }
+    }
+}
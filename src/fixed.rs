@@ -0,0 +1,417 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "alloc")]
+use core::ops::{Div, DivAssign, Mul, MulAssign};
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use crate::big_ternary::BigTernary;
+#[cfg(feature = "alloc")]
+use crate::error::Error;
+use crate::number::{Number, ParseTernaryError};
+#[cfg(feature = "alloc")]
+use crate::ratio::{Ratio, RatioInteger};
+use crate::trit::Trit;
+
+/// A balanced ternary fixed point number with I integer trits and F
+/// fractional trits, whose value is its I + F trits read as an integer and
+/// divided by 3^F.
+///
+/// Since the fractional trits of a balanced ternary number always lie
+/// strictly between -1/2 and 1/2, the integer trits alone are the value
+/// rounded to the nearest integer. Rounding to fewer fractional trits is
+/// likewise a matter of truncating the trits, which is how products are
+/// rounded back to F fractional trits. Arithmetic wraps on overflow of the
+/// integer trits, in the same way as [`Number`].
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Fixed<const I: usize, const F: usize> {
+    // Ordering the integer trits first lets the derived comparisons compare
+    // values, as each part compares by value and the fractional part never
+    // reaches a half
+    int: Number<I>,
+    frac: Number<F>
+}
+
+impl <const I: usize, const F: usize> Fixed<I, F> {
+    /// The value zero.
+    pub const ZERO: Self = Fixed { int: Number::<I>::ZERO, frac: Number::<F>::ZERO };
+
+    /// The value one. Using this constant with no integer trits is a
+    /// compile-time error.
+    pub const ONE: Self = Fixed { int: Number::<I>::ONE, frac: Number::<F>::ZERO };
+
+    /// The smallest positive value, 3^-F. Using this constant with no
+    /// fractional trits is a compile-time error.
+    pub const EPSILON: Self = Fixed { int: Number::<I>::ZERO, frac: Number::<F>::ONE };
+
+    /// The largest representable value, with every trit set to positive.
+    pub const MAX: Self = Fixed { int: Number::<I>::MAX, frac: Number::<F>::MAX };
+
+    /// The smallest representable value, with every trit set to negative.
+    pub const MIN: Self = Fixed { int: Number::<I>::MIN, frac: Number::<F>::MIN };
+
+    /// Builds a fixed point number from its integer trits and its fractional
+    /// trits. The fractional trits are read as a number of 3^-F units.
+    /// * `int` - The integer trits
+    /// * `frac` - The fractional trits
+    pub const fn from_parts(int: Number<I>, frac: Number<F>) -> Self {
+        Fixed { int, frac }
+    }
+
+    /// Returns the integer trits, which are the value rounded to the nearest
+    /// integer. Ties can't occur, as the fractional trits never reach a half.
+    /// ```
+    /// # use balanced_ternary::fixed::Fixed;
+    /// # use balanced_ternary::number::Number;
+    /// // 1 2/9 rounds down to 1 and 1 2/3 rounds up to 2, just by dropping the
+    /// // fractional trits
+    /// assert_eq!("+.+-".parse::<Fixed<2, 2>>().unwrap().round(), Number::<2>::from("+"));
+    /// assert_eq!("+-.-".parse::<Fixed<2, 2>>().unwrap().round(), Number::<2>::from("+-"));
+    /// ```
+    pub const fn round(self) -> Number<I> {
+        self.int
+    }
+
+    /// Returns the fractional trits, as a number of 3^-F units between -1/2
+    /// and 1/2 exclusive.
+    pub const fn fract(self) -> Number<F> {
+        self.frac
+    }
+
+    /// Returns the sign of the value as a trit.
+    pub const fn signum(self) -> Trit {
+        match self.int.signum() {
+            Trit::ZERO => self.frac.signum(),
+            sign => sign
+        }
+    }
+
+    /// Returns `true` if the value is less than zero.
+    pub const fn is_negative(self) -> bool {
+        self.signum() as u8 == Trit::NEG as u8
+    }
+
+    /// Returns the absolute value. This never overflows.
+    pub fn abs(self) -> Self {
+        if self.is_negative() {-self} else {self}
+    }
+
+    /// Rounds a ratio to the nearest multiple of 3^-F, with ties rounded away
+    /// from zero as in division, wrapping if the integer part overflows.
+    /// ```
+    /// # use balanced_ternary::fixed::Fixed;
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::ratio::Ratio;
+    /// let third = Ratio::new(Number::<4>::from(1), Number::<4>::from(3));
+    /// assert_eq!(Fixed::<4, 3>::from_ratio(third).to_string(), "0000.+00");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_ratio<T: RatioInteger + Into<BigTernary>>(ratio: Ratio<T>) -> Self {
+        let (numer, denom) = ratio.into_parts();
+        Fixed::from_quotient(&numer.into(), &denom.into())
+    }
+
+    /// Converts the value to an exact ratio.
+    #[cfg(feature = "alloc")]
+    pub fn to_ratio(self) -> Ratio<BigTernary> {
+        Ratio::new(self.to_units(), BigTernary::from(1) << F)
+    }
+
+    /// Fallible division, rounding as `Div` does. Returns
+    /// [`Error::DivisionByZero`] if `divisor` is zero.
+    #[cfg(feature = "alloc")]
+    pub fn try_div(self, divisor: Self) -> Result<Self, Error> {
+        let divisor = divisor.to_units();
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Fixed::from_quotient(&self.to_units(), &divisor))
+    }
+
+    /// Reads every trit as a single integer of 3^-F units.
+    #[cfg(feature = "alloc")]
+    fn to_units(self) -> BigTernary {
+        let mut units = BigTernary::from(self.int);
+        units <<= F;
+        units + BigTernary::from(self.frac)
+    }
+
+    /// Divides two integers, rounding to the nearest multiple of 3^-F with ties
+    /// away from zero and wrapping like [`Fixed::from_units`].
+    #[cfg(feature = "alloc")]
+    fn from_quotient(numerator: &BigTernary, divisor: &BigTernary) -> Self {
+        let (mut quotient, remainder) = (numerator << F).div_rem(divisor);
+
+        // Unlike a product, the exact quotient can lie half way between two
+        // multiples of 3^-F, such as when dividing one unit by two
+        if &remainder.abs() + &remainder.abs() >= divisor.abs() {
+            let step = if remainder.is_negative() == divisor.is_negative() {1} else {-1};
+            quotient += BigTernary::from(step);
+        }
+        Fixed::from_units(&quotient)
+    }
+
+    /// Keeps the I + F least significant trits of an integer of 3^-F units,
+    /// wrapping in the same way as the arithmetic of [`Number`].
+    #[cfg(feature = "alloc")]
+    fn from_units(units: &BigTernary) -> Self {
+        let trits = units.as_trits();
+        let (high, frac) = trits.split_at(trits.len().saturating_sub(F));
+        let int = &high[high.len().saturating_sub(I)..];
+        Fixed { int: Number::<I>::from_trits_be(int), frac: Number::<F>::from_trits_be(frac) }
+    }
+}
+
+impl <const I: usize, const F: usize> From<Number<I>> for Fixed<I, F> {
+    fn from(int: Number<I>) -> Self {
+        Fixed { int, frac: Number::<F>::ZERO }
+    }
+}
+
+impl <const I: usize, const F: usize> Neg for Fixed<I, F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Fixed { int: -self.int, frac: -self.frac }
+    }
+}
+
+impl <const I: usize, const F: usize> Add for Fixed<I, F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // The fractional trits are the low word of a two word addition
+        let (frac, carry) = self.frac.carrying_add(rhs.frac, Trit::ZERO);
+        let (int, _) = self.int.carrying_add(rhs.int, carry);
+        Fixed { int, frac }
+    }
+}
+
+impl <const I: usize, const F: usize> AddAssign for Fixed<I, F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl <const I: usize, const F: usize> Sub for Fixed<I, F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Add::add(self, -rhs)
+    }
+}
+
+impl <const I: usize, const F: usize> SubAssign for Fixed<I, F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiplies exactly and then rounds to the nearest multiple of 3^-F, which
+/// in balanced ternary only requires truncating the extra fractional trits.
+#[cfg(feature = "alloc")]
+impl <const I: usize, const F: usize> Mul for Fixed<I, F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = self.to_units() * rhs.to_units();
+        Fixed::from_units(&(product >> F))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl <const I: usize, const F: usize> MulAssign for Fixed<I, F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Divides and rounds to the nearest multiple of 3^-F, with ties rounded away
+/// from zero.
+///
+/// # Panics
+/// Panics if `divisor` is zero.
+#[cfg(feature = "alloc")]
+impl <const I: usize, const F: usize> Div for Fixed<I, F> {
+    type Output = Self;
+
+    fn div(self, divisor: Self) -> Self::Output {
+        Fixed::from_quotient(&self.to_units(), &divisor.to_units())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl <const I: usize, const F: usize> DivAssign for Fixed<I, F> {
+    fn div_assign(&mut self, divisor: Self) {
+        *self = *self / divisor;
+    }
+}
+
+impl <const I: usize, const F: usize> FromStr for Fixed<I, F> {
+    type Err = ParseTernaryError;
+
+    /// Parses integer trits and fractional trits separated by a `.`, such as
+    /// `+-.0+-`. Either side of the point may have fewer trits than the type,
+    /// and the point may be left out when there are no fractional trits.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        let (int_str, frac_str) = encoded.split_once('.').unwrap_or((encoded, ""));
+        if int_str.is_empty() && frac_str.is_empty() {
+            return Err(ParseTernaryError::Empty);
+        }
+
+        // Validate every character first, so that the reported position of an
+        // invalid trit is always the leftmost one
+        for (position, character) in encoded.chars().enumerate() {
+            if Trit::from_char(character).is_none() && (character != '.' || position != int_str.chars().count()) {
+                return Err(ParseTernaryError::InvalidTrit { character, position });
+            }
+        }
+
+        let int_length = int_str.chars().count();
+        if int_length > I {
+            return Err(ParseTernaryError::TooLong { length: int_length, capacity: I });
+        }
+        let frac_length = frac_str.chars().count();
+        if frac_length > F {
+            return Err(ParseTernaryError::TooLong { length: frac_length, capacity: F });
+        }
+
+        // The fractional trits are aligned from the point, so any missing low
+        // trits are zero
+        let int = Number::<I>::from_rev_iter(int_str.chars().rev().filter_map(Trit::from_char));
+        let frac = Number::<F>::from_rev_iter(
+            core::iter::repeat_n(Trit::ZERO, F - frac_length).chain(frac_str.chars().rev().filter_map(Trit::from_char))
+        );
+        Ok(Fixed { int, frac })
+    }
+}
+
+/// Writes every integer trit, a `.`, and every fractional trit.
+impl <const I: usize, const F: usize> fmt::Display for Fixed<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for trit in self.int.as_trits() {
+            write!(f, "{}", trit)?;
+        }
+        write!(f, ".")?;
+        for trit in self.frac.as_trits() {
+            write!(f, "{}", trit)?;
+        }
+        Ok(())
+    }
+}
+
+impl <const I: usize, const F: usize> fmt::Debug for Fixed<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Fixed<{}, {}>({})", I, F, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed(encoded: &str) -> Fixed<4, 3> {
+        encoded.parse().unwrap()
+    }
+
+    #[test]
+    fn parsing_and_formatting() {
+        let value = fixed("+-.0+-");
+        assert_eq!(value, Fixed::from_parts(Number::<4>::from("+-"), Number::<3>::from("0+-")));
+        assert_eq!(value.to_string(), "00+-.0+-");
+        assert_eq!(format!("{:?}", value), "Fixed<4, 3>(00+-.0+-)");
+
+        assert_eq!(fixed("+"), Fixed::ONE);
+        assert_eq!(fixed(".+"), Fixed::from_parts(Number::<4>::ZERO, Number::<3>::from("+00")));
+        assert_eq!(fixed(".00+"), Fixed::EPSILON);
+
+        assert_eq!("".parse::<Fixed<4, 3>>(), Err(ParseTernaryError::Empty));
+        assert_eq!(".".parse::<Fixed<4, 3>>(), Err(ParseTernaryError::Empty));
+        assert_eq!("+.-.".parse::<Fixed<4, 3>>(), Err(ParseTernaryError::InvalidTrit { character: '.', position: 3 }));
+        assert_eq!("+-0+-".parse::<Fixed<4, 3>>(), Err(ParseTernaryError::TooLong { length: 5, capacity: 4 }));
+        assert_eq!(".+-0+".parse::<Fixed<4, 3>>(), Err(ParseTernaryError::TooLong { length: 4, capacity: 3 }));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut values = [fixed("+.-"), fixed("-.+"), fixed("0.+"), fixed("+.0"), fixed(".-"), fixed("-+.-")];
+        values.sort();
+        // -2 1/3, -2/3, -1/3, 1/3, 2/3, 1
+        assert_eq!(values, [fixed("-+.-"), fixed("-.+"), fixed(".-"), fixed("0.+"), fixed("+.-"), fixed("+.0")]);
+        assert!(fixed("-.+").is_negative());
+        assert_eq!(fixed("-.+").abs(), fixed("+.-"));
+    }
+
+    #[test]
+    fn addition_carries_between_parts() {
+        // 4/9 + 4/9 = 8/9, which carries into the integer trits as 1 - 1/9
+        assert_eq!(fixed(".++") + fixed(".++"), fixed("+.0-"));
+        assert_eq!(fixed("+.0-") - fixed(".++"), fixed(".++"));
+
+        let mut accumulator = Fixed::<4, 3>::ZERO;
+        for _ in 0..27 {
+            accumulator += Fixed::EPSILON;
+        }
+        assert_eq!(accumulator, Fixed::ONE);
+        accumulator -= Fixed::ONE;
+        assert_eq!(accumulator, Fixed::ZERO);
+
+        // Overflow wraps at the integer trits
+        assert_eq!(Fixed::<4, 3>::MAX + Fixed::EPSILON, Fixed::<4, 3>::MIN);
+    }
+
+    #[test]
+    fn rounding_by_truncation() {
+        // 13/27 rounds down and 14/27 rounds up
+        assert_eq!(fixed(".+++").round(), Number::<4>::ZERO);
+        assert_eq!(fixed("+.---").round(), Number::<4>::ONE);
+        assert_eq!(fixed("+.---").fract(), Number::<3>::from("---"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn multiplication_and_division() {
+        assert_eq!(fixed("+-") * fixed("+0"), fixed("+-0")); // 2 * 3
+        assert_eq!(fixed(".+") * fixed("+-"), fixed("+.-")); // 1/3 * 2
+        assert_eq!(fixed("-.+") * fixed("-.+"), fixed(".++")); // 4/9
+
+        // Products are rounded to the nearest 27th: 1/81 rounds down to zero,
+        // and 4/81 rounds down to 1/27
+        assert_eq!(fixed(".0+") * fixed(".0+"), Fixed::ZERO);
+        assert_eq!(fixed(".+-") * fixed(".+-"), Fixed::EPSILON);
+
+        assert_eq!(fixed("+-0") / fixed("+-"), fixed("+0")); // 6 / 2
+        assert_eq!(Fixed::ONE / fixed("+0"), fixed(".+")); // 1/3
+        // 1/2 is 13.5/27, a tie rounded away from zero to 14/27
+        assert_eq!(Fixed::ONE / fixed("+-"), fixed("+.---"));
+        assert_eq!(-Fixed::ONE / fixed("+-"), fixed("-.+++"));
+
+        let mut value = fixed("+-");
+        value *= fixed("+-");
+        value /= fixed("++");
+        assert_eq!(value, Fixed::ONE);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ratio_conversions() {
+        let ratio = |numer: i32, denom: i32| Ratio::new(Number::<4>::from(numer), Number::<4>::from(denom));
+        assert_eq!(Fixed::from_ratio(ratio(-7, 3)), fixed("-+.-"));
+        // 1/2 is a tie, rounded away from zero as in division
+        assert_eq!(Fixed::from_ratio(ratio(1, 2)), fixed("+.---"));
+        assert_eq!(Fixed::from_ratio(ratio(-1, 2)), fixed("-.+++"));
+
+        // 2 + 2/27
+        let value = fixed("+-.0+-");
+        assert_eq!(value.to_ratio(), Ratio::new(BigTernary::from(56), BigTernary::from(27)));
+        assert_eq!(Fixed::from_ratio(value.to_ratio()), value);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fallible_division() {
+        let (value, divisor) = (fixed("+-.0+-"), fixed("-.+"));
+        assert_eq!(value.try_div(Fixed::ZERO), Err(Error::DivisionByZero));
+        assert_eq!(value.try_div(divisor), Ok(value / divisor));
+    }
+}
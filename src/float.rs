@@ -0,0 +1,748 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::big_ternary::BigTernary;
+use crate::number::{Number, RoundingMode};
+use crate::ratio::{Ratio, RatioInteger};
+use crate::trit::Trit;
+
+/// A balanced ternary floating point number, with a mantissa of M trits and an
+/// exponent of E trits, whose value is `mantissa * 3^exponent`.
+///
+/// Finite values are normalised so that the leading trit of the mantissa is
+/// non-zero, except for the smallest exponent where the mantissa may have
+/// leading zeros to give gradual underflow, as with the subnormal numbers of
+/// IEEE 754. Every finite value therefore has exactly one representation.
+/// Balanced ternary needs no sign bit, so there is no negative zero, but
+/// there are positive and negative infinities and a not-a-number value.
+///
+/// The arithmetic operators compute the exact result and round it to M
+/// trits to the nearest representable value, with ties to even. Dropping
+/// balanced ternary trits already rounds to nearest, so ties only arise in
+/// division and just above the largest mantissa of an exponent, where the
+/// spacing of representable values triples. The `*_round` methods take an
+/// explicit [`RoundingMode`] instead. The exponent is limited to 39 trits,
+/// which is checked at compile time.
+#[derive(Clone, Copy)]
+pub struct Float<const M: usize, const E: usize> {
+    repr: Repr<M, E>
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Repr<const M: usize, const E: usize> {
+    Finite { mantissa: Number<M>, exponent: Number<E> },
+    Infinite { sign: Trit },
+    NaN
+}
+
+/// A value that is exactly `numerator / denominator * 3^exponent`, with a
+/// positive denominator, from which a result is rounded.
+struct Exact {
+    numerator: BigTernary,
+    denominator: BigTernary,
+    exponent: i64
+}
+
+impl <const M: usize, const E: usize> Float<M, E> {
+    const VALID_WIDTHS: () = {
+        assert!(M > 0, "Mantissa must have at least one trit");
+        assert!(E <= 39, "Exponent must fit in an i64");
+    };
+
+    /// Zero.
+    pub const ZERO: Self = Float { repr: Repr::Finite { mantissa: Number::<M>::ZERO, exponent: Number::<E>::ZERO } };
+
+    /// The largest finite value, with every trit of the mantissa and exponent
+    /// set to positive.
+    pub const MAX: Self = Float { repr: Repr::Finite { mantissa: Number::<M>::MAX, exponent: Number::<E>::MAX } };
+
+    /// The smallest finite value, which is the negation of [`Float::MAX`].
+    pub const MIN: Self = Float { repr: Repr::Finite { mantissa: Number::<M>::MIN, exponent: Number::<E>::MAX } };
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Float { repr: Repr::Infinite { sign: Trit::POS } };
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Float { repr: Repr::Infinite { sign: Trit::NEG } };
+
+    /// Not a number.
+    pub const NAN: Self = Float { repr: Repr::NaN };
+
+    /// Builds the value `mantissa * 3^exponent`, normalising it. This is exact
+    /// unless the mantissa has leading zeros that can't all be shifted out
+    /// without going below the smallest exponent.
+    /// * `mantissa` - The trits of the mantissa, read as an integer
+    /// * `exponent` - The power of three to scale the mantissa by
+    pub fn from_parts(mantissa: Number<M>, exponent: Number<E>) -> Self {
+        Self::round_exact(Exact::from_integer(BigTernary::from(mantissa), Self::exponent_value(exponent)), RoundingMode::NearestTiesEven)
+    }
+
+    /// Returns the mantissa and exponent of a finite value, or `None` for an
+    /// infinity or not-a-number.
+    pub fn to_parts(self) -> Option<(Number<M>, Number<E>)> {
+        match self.repr {
+            Repr::Finite { mantissa, exponent } => Some((mantissa, exponent)),
+            _ => None
+        }
+    }
+
+    /// Returns `true` if the value is not a number.
+    pub fn is_nan(self) -> bool {
+        self.repr == Repr::NaN
+    }
+
+    /// Returns `true` if the value is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        matches!(self.repr, Repr::Infinite { .. })
+    }
+
+    /// Returns `true` if the value is neither infinite nor not-a-number.
+    pub fn is_finite(self) -> bool {
+        matches!(self.repr, Repr::Finite { .. })
+    }
+
+    /// Returns `true` if the value is zero.
+    pub fn is_zero(self) -> bool {
+        matches!(self.repr, Repr::Finite { mantissa, .. } if mantissa.is_zero())
+    }
+
+    /// Returns the sign of the value as a trit, or `None` for not-a-number.
+    pub fn signum(self) -> Option<Trit> {
+        match self.repr {
+            Repr::Finite { mantissa, .. } => Some(mantissa.signum()),
+            Repr::Infinite { sign } => Some(sign),
+            Repr::NaN => None
+        }
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(self) -> Self {
+        if self.signum() == Some(Trit::NEG) {-self} else {self}
+    }
+
+    /// Adds with the given rounding mode.
+    pub fn add_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        let (lhs_mantissa, lhs_exponent, rhs_mantissa, rhs_exponent) = match (self.repr, rhs.repr) {
+            (Repr::NaN, _) | (_, Repr::NaN) => return Self::NAN,
+            (Repr::Infinite { sign: lhs_sign }, Repr::Infinite { sign: rhs_sign }) if lhs_sign != rhs_sign => return Self::NAN,
+            (Repr::Infinite { .. }, _) => return self,
+            (_, Repr::Infinite { .. }) => return rhs,
+            (Repr::Finite { mantissa: lhs_mantissa, exponent: lhs_exponent }, Repr::Finite { mantissa: rhs_mantissa, exponent: rhs_exponent }) =>
+                (lhs_mantissa, Self::exponent_value(lhs_exponent), rhs_mantissa, Self::exponent_value(rhs_exponent))
+        };
+        if rhs_mantissa.is_zero() {
+            return self;
+        }
+        if lhs_mantissa.is_zero() {
+            return rhs;
+        }
+
+        // Align the operands to the smaller exponent. When the exponents are
+        // far apart the smaller operand lies below half of the last trit of
+        // the result, so only its sign affects rounding and it can be replaced
+        // by a single trit just below the larger operand's mantissa.
+        let ((high_mantissa, high_exponent), (low_mantissa, low_exponent)) = if lhs_exponent >= rhs_exponent {
+            ((lhs_mantissa, lhs_exponent), (rhs_mantissa, rhs_exponent))
+        } else {
+            ((rhs_mantissa, rhs_exponent), (lhs_mantissa, lhs_exponent))
+        };
+        let max_gap = M as i64 + 3;
+        let (low, low_exponent) = if high_exponent - low_exponent > max_gap {
+            (BigTernary::from(i8::from(low_mantissa.signum())), high_exponent - max_gap)
+        } else {
+            (BigTernary::from(low_mantissa), low_exponent)
+        };
+
+        let sum = (BigTernary::from(high_mantissa) << (high_exponent - low_exponent) as usize) + low;
+        Self::round_exact(Exact::from_integer(sum, low_exponent), mode)
+    }
+
+    /// Subtracts with the given rounding mode.
+    pub fn sub_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        self.add_round(-rhs, mode)
+    }
+
+    /// Multiplies with the given rounding mode.
+    pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        match (self.repr, rhs.repr) {
+            (Repr::NaN, _) | (_, Repr::NaN) => Self::NAN,
+            (Repr::Finite { mantissa: lhs_mantissa, exponent: lhs_exponent }, Repr::Finite { mantissa: rhs_mantissa, exponent: rhs_exponent }) => {
+                let product = BigTernary::from(lhs_mantissa) * BigTernary::from(rhs_mantissa);
+                let exponent = Self::exponent_value(lhs_exponent) + Self::exponent_value(rhs_exponent);
+                Self::round_exact(Exact::from_integer(product, exponent), mode)
+            },
+            // Infinity times zero has no meaningful value
+            _ if self.is_zero() || rhs.is_zero() => Self::NAN,
+            _ => Self::infinity(self.signum().unwrap_or_default() * rhs.signum().unwrap_or_default())
+        }
+    }
+
+    /// Divides with the given rounding mode. A non-zero value divided by zero
+    /// is an infinity with the sign of the numerator.
+    pub fn div_round(self, divisor: Self, mode: RoundingMode) -> Self {
+        match (self.repr, divisor.repr) {
+            (Repr::NaN, _) | (_, Repr::NaN) | (Repr::Infinite { .. }, Repr::Infinite { .. }) => Self::NAN,
+            (Repr::Infinite { sign }, _) => Self::infinity(sign * divisor.signum().filter(|sign| *sign != Trit::ZERO).unwrap_or(Trit::POS)),
+            (_, Repr::Infinite { .. }) => Self::ZERO,
+            _ if divisor.is_zero() && self.is_zero() => Self::NAN,
+            _ if divisor.is_zero() => Self::infinity(self.signum().unwrap_or_default()),
+            (Repr::Finite { mantissa, exponent }, Repr::Finite { mantissa: divisor_mantissa, exponent: divisor_exponent }) => {
+                // Move the sign of the divisor onto the numerator
+                let numerator = BigTernary::from(mantissa);
+                let numerator = if divisor_mantissa.is_negative() {-numerator} else {numerator};
+                let exact = Exact {
+                    numerator,
+                    denominator: BigTernary::from(divisor_mantissa.abs()),
+                    exponent: Self::exponent_value(exponent) - Self::exponent_value(divisor_exponent)
+                };
+                Self::round_exact(exact, mode)
+            }
+        }
+    }
+
+    /// Converts from an `f64`, rounding to the nearest value with ties to even.
+    /// Infinities and not-a-number convert to their equivalents.
+    pub fn from_f64(value: f64) -> Self {
+        Self::from_f64_round(value, RoundingMode::NearestTiesEven)
+    }
+
+    /// Converts from an `f64` with the given rounding mode.
+    pub fn from_f64_round(value: f64, mode: RoundingMode) -> Self {
+        if value.is_nan() {
+            return Self::NAN;
+        }
+        if value.is_infinite() {
+            return Self::infinity(if value > 0.0 {Trit::POS} else {Trit::NEG});
+        }
+
+        // Every finite f64 is an integer mantissa times a power of two
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, binary_exponent) = match biased_exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | 1 << 52, biased_exponent - 1075)
+        };
+
+        let numerator = BigTernary::from(mantissa);
+        let numerator = if bits >> 63 == 1 {-numerator} else {numerator};
+        let power = BigTernary::from(2).pow(binary_exponent.unsigned_abs());
+        let exact = if binary_exponent >= 0 {
+            Exact::from_integer(numerator * power, 0)
+        } else {
+            Exact { numerator, denominator: power, exponent: 0 }
+        };
+        Self::round_exact(exact, mode)
+    }
+
+    /// Converts to the nearest `f64`, with ties to even. Values beyond the
+    /// range of an `f64` become infinite, and values too small become zero.
+    pub fn to_f64(self) -> f64 {
+        let (mantissa, exponent) = match self.repr {
+            Repr::NaN => return f64::NAN,
+            Repr::Infinite { sign: Trit::NEG } => return f64::NEG_INFINITY,
+            Repr::Infinite { .. } => return f64::INFINITY,
+            Repr::Finite { mantissa, exponent } => (BigTernary::from(mantissa), Self::exponent_value(exponent))
+        };
+        if mantissa.is_zero() {
+            return 0.0;
+        }
+
+        // 3^647 exceeds the largest f64, and 3^-680 is below half of the
+        // smallest, so there's no need to build any larger powers of three
+        let magnitude_order = exponent + mantissa.significant_trits() as i64;
+        if magnitude_order > 650 {
+            return if mantissa.is_negative() {f64::NEG_INFINITY} else {f64::INFINITY};
+        }
+        if magnitude_order < -680 {
+            return 0.0;
+        }
+
+        let power = BigTernary::from(3).pow(exponent.unsigned_abs() as u32);
+        if exponent >= 0 {
+            rational_to_f64(&mantissa * &power, BigTernary::from(1))
+        } else {
+            rational_to_f64(mantissa, power)
+        }
+    }
+
+    /// Converts a ratio to the nearest value, with ties to even.
+    pub fn from_ratio<T: RatioInteger + Into<BigTernary>>(ratio: Ratio<T>) -> Self {
+        Self::from_ratio_round(ratio, RoundingMode::NearestTiesEven)
+    }
+
+    /// Converts a ratio, rounding it in the given direction.
+    /// * `ratio` - The value to convert
+    /// * `mode` - How to round a value that can't be represented exactly
+    pub fn from_ratio_round<T: RatioInteger + Into<BigTernary>>(ratio: Ratio<T>, mode: RoundingMode) -> Self {
+        let (numerator, denominator) = ratio.into_parts();
+        Self::round_exact(Exact { numerator: numerator.into(), denominator: denominator.into(), exponent: 0 }, mode)
+    }
+
+    /// Converts a finite value to an exact ratio, or returns `None` for an
+    /// infinity or not-a-number.
+    pub fn to_ratio(self) -> Option<Ratio<BigTernary>> {
+        let (mantissa, exponent) = self.to_parts()?;
+        let (mantissa, exponent) = (BigTernary::from(mantissa), Self::exponent_value(exponent));
+        Some(if exponent >= 0 {
+            Ratio::from(mantissa << exponent as usize)
+        } else {
+            Ratio::new(mantissa, BigTernary::from(1) << exponent.unsigned_abs() as usize)
+        })
+    }
+
+    fn infinity(sign: Trit) -> Self {
+        Float { repr: Repr::Infinite { sign } }
+    }
+
+    fn exponent_value(exponent: Number<E>) -> i64 {
+        let () = Self::VALID_WIDTHS;
+        i128::try_from(exponent).unwrap_or_default() as i64
+    }
+
+    /// Rounds an exact value to M trits at an exponent within range.
+    fn round_exact(exact: Exact, mode: RoundingMode) -> Self {
+        let () = Self::VALID_WIDTHS;
+        if exact.numerator.is_zero() {
+            return Self::ZERO;
+        }
+
+        let max_exponent = Self::exponent_value(Number::<E>::MAX);
+        let min_exponent = -max_exponent;
+
+        // The result is `round(numerator * 3^shift / denominator)` at an
+        // exponent of `exponent - shift`. Start from an estimate of the shift
+        // that gives M trits, then correct it, without letting the exponent go
+        // below the minimum.
+        let max_shift = exact.exponent - min_exponent;
+        let estimate = M as i64 + 1 + exact.denominator.significant_trits() as i64
+            - exact.numerator.significant_trits() as i64;
+        if estimate - max_shift > M as i64 + 2 {
+            // So far below the smallest value that only the sign affects rounding
+            return Self::round_tiny(exact.numerator.signum(), mode, min_exponent);
+        }
+
+        // Find the finest shift, the largest, at which the truncated value
+        // still fits in M trits
+        let mut shift = estimate.min(max_shift);
+        while exact.truncate_at(shift).0.significant_trits() > M {
+            shift -= 1;
+        }
+        while shift < max_shift && exact.truncate_at(shift + 1).0.significant_trits() <= M {
+            shift += 1;
+        }
+
+        let (quotient, remainder) = exact.truncate_at(shift);
+        let sign = exact.numerator.signum();
+        let (magnitude, shift) = if remainder.is_zero() {
+            (quotient.abs(), shift)
+        } else {
+            // The representable magnitudes either side of the exact one. Below
+            // it, the largest mantissa at the next finer shift is closer than
+            // the truncated quotient when that has only M - 1 trits. Above it,
+            // the next mantissa may only fit at the next coarser shift.
+            let one = BigTernary::from(1);
+            let max = BigTernary::from(Number::<M>::MAX);
+            let truncated = quotient.abs();
+            let lower = if shift < max_shift && max > &truncated << 1 {
+                (max.clone(), shift + 1)
+            } else {
+                (truncated.clone(), shift)
+            };
+            let next = &truncated + &one;
+            let upper = if next.significant_trits() <= M {
+                (next, shift)
+            } else {
+                (&(&max >> 1) + &one, shift - 1)
+            };
+
+            let round_up = match mode {
+                RoundingMode::TowardZero => false,
+                RoundingMode::Floor => sign == Trit::NEG,
+                RoundingMode::Ceil => sign == Trit::POS,
+                RoundingMode::NearestTiesAway | RoundingMode::NearestTiesEven => {
+                    // Compare twice the exact magnitude with the sum of the two
+                    // candidates, at the finer of their shifts
+                    let sum = &lower.0 + &(&upper.0 << (lower.1 - upper.1) as usize);
+                    match exact.compare_doubled(lower.1, &sum) {
+                        Ordering::Less => false,
+                        Ordering::Greater => true,
+                        // Candidates at different shifts can both be even,
+                        // in which case the smaller is kept
+                        Ordering::Equal => mode == RoundingMode::NearestTiesAway
+                            || (is_odd(&lower.0) && !is_odd(&upper.0))
+                    }
+                }
+            };
+            if round_up {upper} else {lower}
+        };
+
+        // A mantissa from the coarser shift can have fewer than M trits, and
+        // scaling it back up is exact
+        let (mut mantissa, mut shift) = (magnitude, shift);
+        while !mantissa.is_zero() && mantissa.significant_trits() < M && shift < max_shift {
+            mantissa = &mantissa << 1;
+            shift += 1;
+        }
+        if sign == Trit::NEG {
+            mantissa = -mantissa;
+        }
+
+        let exponent = exact.exponent - shift;
+        if mantissa.is_zero() {
+            return Self::ZERO;
+        }
+        if exponent > max_exponent {
+            return Self::overflow(sign, mode);
+        }
+        Float {
+            repr: Repr::Finite {
+                mantissa: Number::<M>::try_from(mantissa).unwrap_or(Number::<M>::ZERO),
+                exponent: Number::<E>::try_from(exponent).unwrap_or(Number::<E>::ZERO)
+            }
+        }
+    }
+
+    /// The result of rounding a value much smaller in magnitude than the
+    /// smallest non-zero value.
+    fn round_tiny(sign: Trit, mode: RoundingMode, min_exponent: i64) -> Self {
+        let away_from_zero = match mode {
+            RoundingMode::Floor => sign == Trit::NEG,
+            RoundingMode::Ceil => sign == Trit::POS,
+            _ => false
+        };
+        if away_from_zero {
+            let mut smallest = Number::<M>::ZERO;
+            smallest.set_trit(0, sign);
+            Float { repr: Repr::Finite { mantissa: smallest, exponent: Number::<E>::try_from(min_exponent).unwrap_or(Number::<E>::ZERO) } }
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// The result of rounding a value too large in magnitude to represent,
+    /// which is infinite unless the rounding mode is towards zero.
+    fn overflow(sign: Trit, mode: RoundingMode) -> Self {
+        let to_max = match mode {
+            RoundingMode::TowardZero => true,
+            RoundingMode::Floor => sign == Trit::POS,
+            RoundingMode::Ceil => sign == Trit::NEG,
+            _ => false
+        };
+        match (to_max, sign) {
+            (true, Trit::NEG) => Self::MIN,
+            (true, _) => Self::MAX,
+            (false, sign) => Self::infinity(sign)
+        }
+    }
+}
+
+impl Exact {
+    fn from_integer(numerator: BigTernary, exponent: i64) -> Self {
+        Exact { numerator, denominator: BigTernary::from(1), exponent }
+    }
+
+    /// Divides `numerator * 3^shift` by the denominator, truncating towards
+    /// zero, and returns the quotient and remainder.
+    fn truncate_at(&self, shift: i64) -> (BigTernary, BigTernary) {
+        if shift >= 0 {
+            (&self.numerator << shift as usize).div_rem(&self.denominator)
+        } else {
+            self.numerator.div_rem(&(&self.denominator << shift.unsigned_abs() as usize))
+        }
+    }
+
+    /// Compares `2 * |numerator| * 3^shift / denominator` with `value`.
+    fn compare_doubled(&self, shift: i64, value: &BigTernary) -> Ordering {
+        let doubled = &self.numerator.abs() + &self.numerator.abs();
+        if shift >= 0 {
+            (&doubled << shift as usize).cmp(&(value * &self.denominator))
+        } else {
+            doubled.cmp(&(&(value * &self.denominator) << shift.unsigned_abs() as usize))
+        }
+    }
+}
+
+/// Returns `true` if an integer is odd, which in balanced ternary is when it
+/// has an odd number of non-zero trits.
+fn is_odd(value: &BigTernary) -> bool {
+    value.as_trits().iter().filter(|trit| **trit != Trit::ZERO).count() % 2 == 1
+}
+
+/// Converts `numerator / denominator`, with a positive denominator, to the
+/// nearest `f64` with ties to even.
+fn rational_to_f64(numerator: BigTernary, denominator: BigTernary) -> f64 {
+    let is_negative = numerator.is_negative();
+    let numerator = numerator.abs();
+
+    // Find the scale that gives a quotient of exactly 64 bits. Each trit is
+    // about 1.585 bits, so the estimate is out by only a few bits and a
+    // second attempt with the exact correction always succeeds.
+    let trits = numerator.significant_trits() as i64 - denominator.significant_trits() as i64;
+    let mut scale = 63 - trits * 1585 / 1000;
+    let (quotient, sticky) = loop {
+        let power = BigTernary::from(2).pow(scale.unsigned_abs() as u32);
+        let (quotient, remainder) = if scale >= 0 {
+            (&numerator * &power).div_rem(&denominator)
+        } else {
+            numerator.div_rem(&(&denominator * &power))
+        };
+        let quotient = i128::try_from(quotient).unwrap_or_default() as u128;
+        let bits = 128 - i64::from(quotient.leading_zeros());
+        if bits == 64 {
+            break (quotient as u64, !remainder.is_zero());
+        }
+        scale += 64 - bits;
+    };
+
+    // The value is `quotient * 2^-scale`, with the quotient in [2^63, 2^64)
+    let binary_exponent = 63 - scale;
+    let magnitude = if binary_exponent > 1023 {
+        f64::INFINITY
+    } else if binary_exponent >= -1022 {
+        // The sticky bit is far below the rounding position, so the cast
+        // rounds correctly, and scaling by powers of two is then exact
+        let rounded = (quotient | u64::from(sticky)) as f64;
+        rounded * f64::from_bits((1023 - 63) << 52) * f64::from_bits(((binary_exponent + 1023) as u64) << 52)
+    } else {
+        // Subnormal, so round to a multiple of 2^-1074 directly. A shift of
+        // more than 64 leaves less than half of the smallest subnormal.
+        let shift = scale - 1074;
+        if shift > 64 {
+            0.0
+        } else {
+            let quotient = u128::from(quotient);
+            let units = quotient >> shift;
+            let dropped = quotient & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            let round_up = dropped > half || (dropped == half && (sticky || units % 2 == 1));
+            f64::from_bits((units + u128::from(round_up)) as u64)
+        }
+    };
+    if is_negative {-magnitude} else {magnitude}
+}
+
+impl <const M: usize, const E: usize> PartialEq for Float<M, E> {
+    /// Compares by value, so that not-a-number is unequal to everything.
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl <const M: usize, const E: usize> PartialOrd for Float<M, E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (lhs_sign, rhs_sign) = (self.signum()?, other.signum()?);
+        if lhs_sign != rhs_sign {
+            return Some(lhs_sign.cmp(&rhs_sign));
+        }
+
+        let magnitude_cmp = match (self.repr, other.repr) {
+            (Repr::Infinite { .. }, Repr::Infinite { .. }) => Ordering::Equal,
+            (Repr::Infinite { .. }, _) => Ordering::Greater,
+            (_, Repr::Infinite { .. }) => Ordering::Less,
+            (Repr::Finite { mantissa: lhs_mantissa, exponent: lhs_exponent }, Repr::Finite { mantissa: rhs_mantissa, exponent: rhs_exponent }) => {
+                // Normalised mantissas have no leading zeros, so the position of
+                // the leading trit orders the magnitudes unless it is the same,
+                // in which case so are the exponents
+                let lhs_order = Self::exponent_value(lhs_exponent) + i64::from(lhs_mantissa.significant_trits());
+                let rhs_order = Self::exponent_value(rhs_exponent) + i64::from(rhs_mantissa.significant_trits());
+                lhs_order.cmp(&rhs_order).then_with(|| lhs_mantissa.abs().cmp(&rhs_mantissa.abs()))
+            },
+            _ => return None
+        };
+        Some(if lhs_sign == Trit::NEG {magnitude_cmp.reverse()} else {magnitude_cmp})
+    }
+}
+
+impl <const M: usize, const E: usize> Neg for Float<M, E> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self.repr {
+            Repr::Finite { mantissa, exponent } => Float { repr: Repr::Finite { mantissa: -mantissa, exponent } },
+            Repr::Infinite { sign } => Self::infinity(-sign),
+            Repr::NaN => self
+        }
+    }
+}
+
+// Each operator rounds to nearest with ties to even, and the assigning
+// variants forward to it
+macro_rules! rounded_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $round_fn:ident) => {
+        impl <const M: usize, const E: usize> $op_trait for Float<M, E> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                self.$round_fn(rhs, RoundingMode::NearestTiesEven)
+            }
+        }
+
+        impl <const M: usize, const E: usize> $assign_trait for Float<M, E> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = self.$round_fn(rhs, RoundingMode::NearestTiesEven);
+            }
+        }
+    };
+}
+
+rounded_binary_op!(Add, add, AddAssign, add_assign, add_round);
+rounded_binary_op!(Sub, sub, SubAssign, sub_assign, sub_round);
+rounded_binary_op!(Mul, mul, MulAssign, mul_assign, mul_round);
+rounded_binary_op!(Div, div, DivAssign, div_assign, div_round);
+
+/// Writes finite values as the mantissa trits and exponent trits separated by
+/// `e`, such as `+-0--e-+` for 50 * 3^-2.
+impl <const M: usize, const E: usize> fmt::Display for Float<M, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repr {
+            Repr::Finite { mantissa, exponent } => {
+                for trit in mantissa.as_trits() {
+                    write!(f, "{}", trit)?;
+                }
+                write!(f, "e")?;
+                for trit in exponent.as_trits() {
+                    write!(f, "{}", trit)?;
+                }
+                Ok(())
+            },
+            Repr::Infinite { sign: Trit::NEG } => write!(f, "-inf"),
+            Repr::Infinite { .. } => write!(f, "inf"),
+            Repr::NaN => write!(f, "NaN")
+        }
+    }
+}
+
+impl <const M: usize, const E: usize> fmt::Debug for Float<M, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Float<{}, {}>({})", M, E, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F8 = Float<8, 4>;
+
+    fn float(value: f64) -> F8 {
+        F8::from_f64(value)
+    }
+
+    #[test]
+    fn normalisation() {
+        let num_50 = F8::from_parts(Number::<8>::from("+-0--"), Number::<4>::ZERO);
+        assert_eq!(num_50.to_parts(), Some((Number::<8>::from("+-0--000"), Number::<4>::from("-0"))));
+        assert_eq!(num_50.to_string(), "+-0--000e00-0");
+        assert_eq!(num_50.to_f64(), 50.0);
+        assert_eq!(F8::ZERO.to_parts(), Some((Number::<8>::ZERO, Number::<4>::ZERO)));
+
+        // The smallest exponent keeps leading zeros for gradual underflow
+        let tiny = F8::from_parts(Number::<8>::ONE, Number::<4>::MIN);
+        assert_eq!(tiny.to_parts(), Some((Number::<8>::ONE, Number::<4>::MIN)));
+    }
+
+    #[test]
+    fn exact_arithmetic() {
+        assert_eq!(float(50.0) + float(-8.0), float(42.0));
+        assert_eq!(float(50.0) - float(58.0), float(-8.0));
+        assert_eq!(float(-12.0) * float(0.5), float(-6.0));
+        assert_eq!(float(1.0) / float(27.0) * float(27.0), float(1.0));
+        assert_eq!((float(7.0) / float(3.0)).to_parts(), Some((Number::<8>::from("+-+00000"), Number::<4>::from("-+0"))));
+
+        let mut value = float(2.0);
+        value *= float(3.0);
+        value += float(1.0);
+        value -= float(4.0);
+        value /= float(-3.0);
+        assert_eq!(value, float(-1.0));
+    }
+
+    #[test]
+    fn rounding() {
+        // 3280 is the largest 8 trit mantissa and above it only multiples of
+        // three fit, so 3281 lies halfway between 3280 and 3282. Both are even,
+        // so the tie keeps the smaller.
+        let max_mantissa = float(3280.0);
+        assert_eq!(max_mantissa + float(1.0), float(3280.0));
+        assert_eq!(max_mantissa + float(1.5), float(3282.0));
+        assert_eq!(max_mantissa.add_round(float(1.0), RoundingMode::TowardZero), float(3280.0));
+        assert_eq!(max_mantissa.add_round(float(1.0), RoundingMode::Ceil), float(3282.0));
+        assert_eq!((-max_mantissa).add_round(float(-1.0), RoundingMode::Floor), float(-3282.0));
+
+        assert_eq!(max_mantissa.add_round(float(1.0), RoundingMode::NearestTiesAway), float(3282.0));
+
+        // 1/2 is 3280.5 * 3^-8, but 3281 needs 9 trits, so the neighbours are
+        // 3280 * 3^-8 and 1094 * 3^-7, and the lower is nearer
+        let half = float(1.0) / float(2.0);
+        let half_up = float(1.0).div_round(float(2.0), RoundingMode::Ceil);
+        assert_eq!(half.to_parts(), Some((Number::<8>::MAX, Number::<4>::from("-0+"))));
+        assert_eq!(half_up.to_parts(), Some((Number::<8>::from(1094), Number::<4>::from("-+-"))));
+        assert_eq!(float(1.0).div_round(float(2.0), RoundingMode::Floor), half);
+        assert_eq!(float(-1.0).div_round(float(2.0), RoundingMode::Floor), -half_up);
+        assert!(half.to_f64() < 0.5 && half_up.to_f64() > 0.5);
+        assert_eq!(float(1.0).div_round(float(3.0), RoundingMode::Floor), float(1.0) / float(3.0));
+    }
+
+    #[test]
+    fn special_values() {
+        assert!((F8::INFINITY + F8::NEG_INFINITY).is_nan());
+        assert!((F8::INFINITY * F8::ZERO).is_nan());
+        assert!((F8::ZERO / F8::ZERO).is_nan());
+        assert_eq!(float(-2.0) / F8::ZERO, F8::NEG_INFINITY);
+        assert_eq!(float(2.0) / F8::INFINITY, F8::ZERO);
+        assert_eq!(F8::NEG_INFINITY * float(-1.0), F8::INFINITY);
+        assert_ne!(F8::NAN, F8::NAN);
+        assert_eq!(F8::NAN.partial_cmp(&F8::ZERO), None);
+
+        // Overflow rounds to infinity, or to the largest value towards zero
+        assert_eq!(F8::MAX * float(3.0), F8::INFINITY);
+        assert_eq!(F8::MAX.mul_round(float(3.0), RoundingMode::TowardZero), F8::MAX);
+        assert_eq!(F8::MIN.mul_round(float(3.0), RoundingMode::Ceil), F8::MIN);
+        assert!(F8::from_f64(f64::NAN).is_nan());
+        assert_eq!(F8::from_f64(f64::NEG_INFINITY), F8::NEG_INFINITY);
+    }
+
+    #[test]
+    fn underflow() {
+        let smallest = F8::from_parts(Number::<8>::ONE, Number::<4>::MIN);
+        assert_eq!(smallest * float(0.1), F8::ZERO);
+        assert_eq!((-smallest).mul_round(smallest, RoundingMode::Floor), -smallest);
+        assert_eq!(smallest.mul_round(smallest, RoundingMode::Ceil), smallest);
+        assert_eq!(smallest / float(3.0), F8::ZERO);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut values = [float(3.0), F8::NEG_INFINITY, float(-0.25), F8::ZERO, F8::MAX, float(1e-10), float(-700.0), F8::INFINITY];
+        values.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+        let expected = [F8::NEG_INFINITY, float(-700.0), float(-0.25), F8::ZERO, float(1e-10), float(3.0), F8::MAX, F8::INFINITY];
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn f64_round_trips() {
+        // 40 trits is enough precision that every f64 survives a round trip
+        for value in [0.0, 1.0, -1.0, 50.0, 2.5, -0.125, 1e300, -1e-300, 5e-324, f64::MAX, f64::MIN_POSITIVE] {
+            assert_eq!(Float::<40, 7>::from_f64(value).to_f64(), value);
+        }
+        assert_eq!(Float::<40, 7>::from_f64(0.1).to_f64(), 0.1);
+        assert_eq!(Float::<40, 7>::MAX.to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn ratio_conversions() {
+        let ratio = |numer: i32, denom: i32| Ratio::new(Number::<12>::from(numer), Number::<12>::from(denom));
+        assert_eq!(F8::from_ratio(ratio(7, 3)), float(7.0) / float(3.0));
+        assert_eq!(F8::from_ratio(ratio(-1, 2)), float(-0.5));
+        assert_eq!(F8::from_ratio_round(ratio(1, 2), RoundingMode::Ceil), float(1.0).div_round(float(2.0), RoundingMode::Ceil));
+
+        let third = F8::from_ratio(ratio(1, 3)).to_ratio().unwrap();
+        assert_eq!(third, Ratio::new(BigTernary::from(1), BigTernary::from(3)));
+        let large = float(-3282.0).to_ratio().unwrap();
+        assert_eq!(large, Ratio::from(BigTernary::from(-3282)));
+        assert_eq!(F8::from_ratio(large), float(-3282.0));
+        assert_eq!(F8::NAN.to_ratio(), None);
+    }
+}
@@ -0,0 +1,846 @@
+//! Arithmetic over GF(3), the field of integers modulo three, whose elements
+//! are exactly the three trits. [`Trit::NEG`] stands for both -1 and 2, which
+//! are congruent modulo three, so trits add without carrying and multiply as
+//! they already do.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::number::{Number, ParseTernaryError};
+use crate::trit::Trit;
+
+/// Adds two elements of GF(3), which is the sum trit without its carry.
+pub fn add(lhs: Trit, rhs: Trit) -> Trit {
+    lhs.add(&rhs).result
+}
+
+/// Subtracts two elements of GF(3).
+pub fn sub(lhs: Trit, rhs: Trit) -> Trit {
+    add(lhs, -rhs)
+}
+
+/// Returns the multiplicative inverse of an element of GF(3), or `None` for
+/// zero. Both non-zero elements are their own inverses.
+pub fn inverse(value: Trit) -> Option<Trit> {
+    if value == Trit::ZERO {None} else {Some(value)}
+}
+
+/// A polynomial with coefficients in GF(3).
+///
+/// The coefficients are stored from the constant term upwards, so that index
+/// i holds the coefficient of x^i, with no zero coefficients above the
+/// leading term. The zero polynomial has no coefficients.
+/// ```
+/// # use balanced_ternary::gf3::Poly;
+/// # use balanced_ternary::trit::Trit;
+/// // x^2 + 1 is irreducible over GF(3), so it has no roots
+/// let poly: Poly = "+0+".parse().unwrap();
+/// assert_eq!(poly.to_string(), "x^2 + 1");
+/// assert!([Trit::NEG, Trit::ZERO, Trit::POS].iter().all(|x| poly.eval(*x) != Trit::ZERO));
+/// ```
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Poly {
+    coeffs: Vec<Trit>
+}
+
+impl Poly {
+    /// The zero polynomial.
+    pub const ZERO: Self = Poly { coeffs: Vec::new() };
+
+    /// Builds a polynomial from its coefficients, ordered from the constant
+    /// term upwards. Any zero coefficients above the leading term are
+    /// discarded.
+    pub fn from_coeffs(coeffs: &[Trit]) -> Self {
+        Self::from_vec(coeffs.to_vec())
+    }
+
+    /// Builds the polynomial `coeff * x^degree`.
+    pub fn monomial(degree: usize, coeff: Trit) -> Self {
+        let mut coeffs = alloc::vec![Trit::ZERO; degree + 1];
+        coeffs[degree] = coeff;
+        Self::from_vec(coeffs)
+    }
+
+    /// Builds the constant polynomial one.
+    pub fn one() -> Self {
+        Poly { coeffs: alloc::vec![Trit::POS] }
+    }
+
+    /// Views the coefficients, ordered from the constant term upwards.
+    pub fn coeffs(&self) -> &[Trit] {
+        &self.coeffs
+    }
+
+    /// Returns the coefficient of x^`power`, which is zero above the degree.
+    pub fn coeff(&self, power: usize) -> Trit {
+        self.coeffs.get(power).copied().unwrap_or(Trit::ZERO)
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero
+    /// polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// Returns `true` if this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the coefficient of the highest power, which is zero only for
+    /// the zero polynomial.
+    pub fn leading_coeff(&self) -> Trit {
+        self.coeffs.last().copied().unwrap_or(Trit::ZERO)
+    }
+
+    /// Returns the polynomial scaled to have a leading coefficient of one, or
+    /// the zero polynomial unchanged.
+    pub fn monic(&self) -> Self {
+        match self.leading_coeff() {
+            Trit::NEG => -self,
+            _ => self.clone()
+        }
+    }
+
+    /// Evaluates the polynomial at `x` by Horner's method.
+    pub fn eval(&self, x: Trit) -> Trit {
+        self.coeffs.iter().rev().fold(Trit::ZERO, |acc, coeff| add(acc * x, *coeff))
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder, which
+    /// has a lower degree than the divisor.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("Attempt to divide by the zero polynomial");
+        // Dividing by the leading coefficient is multiplying by it, as it's its
+        // own inverse
+        let lead = divisor.leading_coeff();
+
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = alloc::vec![Trit::ZERO; remainder.len().saturating_sub(divisor_degree)];
+        for power in (0..quotient.len()).rev() {
+            let factor = remainder[power + divisor_degree] * lead;
+            if factor == Trit::ZERO {
+                continue;
+            }
+            quotient[power] = factor;
+            for (idx, coeff) in divisor.coeffs.iter().enumerate() {
+                remainder[power + idx] = sub(remainder[power + idx], factor * *coeff);
+            }
+        }
+        (Self::from_vec(quotient), Self::from_vec(remainder))
+    }
+
+    /// Fallible [`Poly::div_rem`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is the zero polynomial.
+    pub fn try_div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.div_rem(divisor))
+    }
+
+    /// Returns the monic greatest common divisor of this polynomial and
+    /// `other`, which is the zero polynomial only if both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            (a, b) = (b, remainder);
+        }
+        a.monic()
+    }
+
+    /// Raises the polynomial to the power of `exponent` by repeated squaring.
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut output = Poly::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                output *= &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        output
+    }
+
+    fn from_vec(mut coeffs: Vec<Trit>) -> Self {
+        while coeffs.last() == Some(&Trit::ZERO) {
+            coeffs.pop();
+        }
+        Poly { coeffs }
+    }
+}
+
+impl Neg for &Poly {
+    type Output = Poly;
+
+    fn neg(self) -> Self::Output {
+        Poly { coeffs: self.coeffs.iter().map(|coeff| -*coeff).collect() }
+    }
+}
+
+impl Neg for Poly {
+    type Output = Poly;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl Add for &Poly {
+    type Output = Poly;
+
+    fn add(self, rhs: &Poly) -> Self::Output {
+        let length = self.coeffs.len().max(rhs.coeffs.len());
+        Poly::from_vec((0..length).map(|power| add(self.coeff(power), rhs.coeff(power))).collect())
+    }
+}
+
+impl Sub for &Poly {
+    type Output = Poly;
+
+    fn sub(self, rhs: &Poly) -> Self::Output {
+        Add::add(self, &-rhs)
+    }
+}
+
+impl Mul for &Poly {
+    type Output = Poly;
+
+    fn mul(self, rhs: &Poly) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Poly::ZERO;
+        }
+
+        let mut product = alloc::vec![Trit::ZERO; self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (lhs_power, lhs_coeff) in self.coeffs.iter().enumerate() {
+            for (rhs_power, rhs_coeff) in rhs.coeffs.iter().enumerate() {
+                let term = &mut product[lhs_power + rhs_power];
+                *term = add(*term, *lhs_coeff * *rhs_coeff);
+            }
+        }
+        // The product of two leading coefficients in a field is non-zero
+        Poly { coeffs: product }
+    }
+}
+
+/// # Panics
+/// Panics if `rhs` is the zero polynomial.
+impl Div for &Poly {
+    type Output = Poly;
+
+    fn div(self, rhs: &Poly) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+/// # Panics
+/// Panics if `rhs` is the zero polynomial.
+impl Rem for &Poly {
+    type Output = Poly;
+
+    fn rem(self, rhs: &Poly) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+macro_rules! forward_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl $op_trait for Poly {
+            type Output = Poly;
+
+            fn $op_fn(self, rhs: Poly) -> Self::Output {
+                $op_trait::$op_fn(&self, &rhs)
+            }
+        }
+
+        impl $op_trait<&Poly> for Poly {
+            type Output = Poly;
+
+            fn $op_fn(self, rhs: &Poly) -> Self::Output {
+                $op_trait::$op_fn(&self, rhs)
+            }
+        }
+
+        impl $op_trait<Poly> for &Poly {
+            type Output = Poly;
+
+            fn $op_fn(self, rhs: Poly) -> Self::Output {
+                $op_trait::$op_fn(self, &rhs)
+            }
+        }
+
+        impl $assign_trait<&Poly> for Poly {
+            fn $assign_fn(&mut self, rhs: &Poly) {
+                *self = $op_trait::$op_fn(&*self, rhs);
+            }
+        }
+
+        impl $assign_trait for Poly {
+            fn $assign_fn(&mut self, rhs: Poly) {
+                *self = $op_trait::$op_fn(&*self, &rhs);
+            }
+        }
+    };
+}
+
+forward_binary_op!(Add, add, AddAssign, add_assign);
+forward_binary_op!(Sub, sub, SubAssign, sub_assign);
+forward_binary_op!(Mul, mul, MulAssign, mul_assign);
+forward_binary_op!(Div, div, DivAssign, div_assign);
+forward_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+impl FromStr for Poly {
+    type Err = ParseTernaryError;
+
+    /// Parses the coefficients in `-`/`0`/`+` notation, from the highest
+    /// power down to the constant term, as in the notation of a number.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        if encoded.is_empty() {
+            return Err(ParseTernaryError::Empty);
+        }
+
+        let mut coeffs = encoded.chars()
+            .enumerate()
+            .map(|(position, character)| Trit::from_char(character)
+                .ok_or(ParseTernaryError::InvalidTrit { character, position }))
+            .collect::<Result<Vec<Trit>, _>>()?;
+        coeffs.reverse();
+        Ok(Self::from_vec(coeffs))
+    }
+}
+
+/// Writes the polynomial in conventional notation, such as `x^2 - x + 1`,
+/// where a negative coefficient stands for -1.
+impl fmt::Display for Poly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (power, coeff) in self.coeffs.iter().enumerate().rev() {
+            let sign = match (coeff, first) {
+                (Trit::ZERO, _) => continue,
+                (Trit::NEG, true) => "-",
+                (Trit::POS, true) => "",
+                (Trit::NEG, false) => " - ",
+                (Trit::POS, false) => " + "
+            };
+            first = false;
+            match power {
+                0 => write!(f, "{}1", sign)?,
+                1 => write!(f, "{}x", sign)?,
+                _ => write!(f, "{}x^{}", sign, power)?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Poly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Poly(")?;
+        for coeff in self.coeffs.iter().rev() {
+            write!(f, "{}", coeff)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// The finite field GF(3^N), whose elements are the polynomials over GF(3) of
+/// degree less than N, multiplied modulo an irreducible polynomial of degree
+/// N.
+///
+/// An element is held as a [`Number`], reading the trit of weight 3^i as the
+/// coefficient of x^i, so that the 3^N elements correspond one to one with
+/// the numbers of N trits. Addition is then trit-wise addition without carries.
+/// The field holds the reduction polynomial, and for fields of up to
+/// [`Gf3n::MAX_TABLE_DEGREE`] trits also logarithm and antilogarithm tables,
+/// which turn multiplication and inversion into table lookups.
+/// ```
+/// # use balanced_ternary::gf3::{Gf3n, Poly};
+/// # use balanced_ternary::number::Number;
+/// // GF(9) as polynomials modulo x^2 + 1
+/// let field = Gf3n::<2>::new(&"+0+".parse().unwrap()).unwrap();
+/// let x = Number::<2>::from("+0");
+/// assert_eq!(field.mul(x, x), Number::<2>::from("0-"));
+/// assert_eq!(field.mul(x, field.inv(x).unwrap()), Number::<2>::ONE);
+/// ```
+#[derive(Clone)]
+pub struct Gf3n<const N: usize> {
+    // The coefficients of x^0 to x^(N-1) of the monic reduction polynomial
+    reduction: [Trit; N],
+    tables: Option<LogTables<N>>
+}
+
+impl <const N: usize> Gf3n<N> {
+    const VALID_DEGREE: () = assert!(N > 0, "Field extension must have a degree of at least one");
+
+    /// The largest degree for which logarithm tables are built, giving tables
+    /// of 3^8 = 6561 entries.
+    pub const MAX_TABLE_DEGREE: usize = 8;
+
+    /// Builds the field from a reduction polynomial, which is scaled to be
+    /// monic. Returns `None` if the polynomial doesn't have degree N or isn't
+    /// irreducible.
+    pub fn new(modulus: &Poly) -> Option<Self> {
+        let () = Self::VALID_DEGREE;
+        let modulus = modulus.monic();
+        if modulus.degree() != Some(N) || !is_irreducible(&modulus) {
+            return None;
+        }
+
+        let mut reduction = [Trit::ZERO; N];
+        reduction.copy_from_slice(&modulus.coeffs()[..N]);
+        let mut field = Gf3n { reduction, tables: None };
+        if N <= Self::MAX_TABLE_DEGREE {
+            field.tables = Some(LogTables::build(&field));
+        }
+        Some(field)
+    }
+
+    /// Returns the monic reduction polynomial of degree N.
+    pub fn modulus(&self) -> Poly {
+        let mut coeffs = self.reduction.to_vec();
+        coeffs.push(Trit::POS);
+        Poly::from_vec(coeffs)
+    }
+
+    /// Returns the logarithm tables, which are built for fields of degree up to
+    /// [`Gf3n::MAX_TABLE_DEGREE`].
+    pub fn log_tables(&self) -> Option<&LogTables<N>> {
+        self.tables.as_ref()
+    }
+
+    /// Reads an element as a polynomial.
+    pub fn to_poly(element: Number<N>) -> Poly {
+        Poly::from_vec(element.iter().collect())
+    }
+
+    /// Reduces a polynomial modulo the reduction polynomial to give an element.
+    pub fn reduce(&self, poly: &Poly) -> Number<N> {
+        let remainder = poly % &self.modulus();
+        Number::<N>::from_trits_le(remainder.coeffs())
+    }
+
+    /// Adds two elements, trit by trit without carrying.
+    pub fn add(&self, lhs: Number<N>, rhs: Number<N>) -> Number<N> {
+        Number::<N>::from_rev_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| add(lhs, rhs)))
+    }
+
+    /// Subtracts two elements.
+    pub fn sub(&self, lhs: Number<N>, rhs: Number<N>) -> Number<N> {
+        self.add(lhs, -rhs)
+    }
+
+    /// Multiplies two elements, using the logarithm tables if the field has
+    /// them.
+    pub fn mul(&self, lhs: Number<N>, rhs: Number<N>) -> Number<N> {
+        if let Some(tables) = &self.tables {
+            return match (tables.log(lhs), tables.log(rhs)) {
+                (Some(lhs), Some(rhs)) => tables.antilog(lhs + rhs),
+                _ => Number::<N>::ZERO
+            };
+        }
+
+        // Shift and add from the highest coefficient of `rhs`, replacing any
+        // x^N that appears by the negated lower terms of the reduction
+        // polynomial
+        let lhs: Vec<Trit> = lhs.iter().collect();
+        let mut product = [Trit::ZERO; N];
+        for coeff in rhs.as_trits() {
+            let overflow = product[N-1];
+            product.copy_within(0..N-1, 1);
+            product[0] = Trit::ZERO;
+            for (term, reduction) in product.iter_mut().zip(self.reduction) {
+                *term = sub(*term, overflow * reduction);
+            }
+            for (term, lhs) in product.iter_mut().zip(&lhs) {
+                *term = add(*term, *coeff * *lhs);
+            }
+        }
+        Number::<N>::from_trits_le(&product)
+    }
+
+    /// Raises an element to the power of `exponent`. Zero to the power of zero
+    /// is one.
+    pub fn pow(&self, base: Number<N>, mut exponent: u64) -> Number<N> {
+        let mut base = base;
+        let mut output = Number::<N>::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                output = self.mul(output, base);
+            }
+            exponent >>= 1;
+            base = self.mul(base, base);
+        }
+        output
+    }
+
+    /// Returns the multiplicative inverse of an element, or `None` for zero.
+    pub fn inv(&self, element: Number<N>) -> Option<Number<N>> {
+        if element.is_zero() {
+            return None;
+        }
+        if let Some(tables) = &self.tables {
+            let log = tables.log(element)?;
+            return Some(tables.antilog(tables.exp.len() - log));
+        }
+
+        inverse_modulo(&Self::to_poly(element), &self.modulus()).map(|inverse| self.reduce(&inverse))
+    }
+
+    /// Divides one element by another, returning `None` if the divisor is
+    /// zero.
+    pub fn div(&self, lhs: Number<N>, rhs: Number<N>) -> Option<Number<N>> {
+        Some(self.mul(lhs, self.inv(rhs)?))
+    }
+}
+
+impl <const N: usize> Default for Gf3n<N> {
+    /// Builds the field from the first monic irreducible polynomial of degree
+    /// N, counting through the lower coefficients as the trits of a number
+    /// from zero upwards.
+    fn default() -> Self {
+        let mut candidate = Number::<N>::ZERO;
+        loop {
+            let mut coeffs: Vec<Trit> = candidate.iter().collect();
+            coeffs.push(Trit::POS);
+            if let Some(field) = Gf3n::new(&Poly::from_vec(coeffs)) {
+                return field;
+            }
+            // There is always an irreducible polynomial of every degree, so
+            // this never wraps back round
+            candidate.inc();
+        }
+    }
+}
+
+impl <const N: usize> fmt::Debug for Gf3n<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Gf3n<{}>({})", N, self.modulus())
+    }
+}
+
+/// Logarithm and antilogarithm tables for a small field GF(3^N), relative to a
+/// generator of its multiplicative group.
+#[derive(Clone)]
+pub struct LogTables<const N: usize> {
+    // Indexed by the element read as an unsigned base three number, with zero
+    // mapped to `usize::MAX`
+    log: Vec<usize>,
+    exp: Vec<Number<N>>
+}
+
+impl <const N: usize> LogTables<N> {
+    fn build(field: &Gf3n<N>) -> Self {
+        let size = 3_usize.pow(N as u32);
+
+        // Search for a generator, an element whose powers run through every
+        // non-zero element before returning to one
+        let mut candidate = Number::<N>::ZERO;
+        let exp = loop {
+            candidate.inc();
+            let mut powers = alloc::vec![Number::<N>::ONE];
+            let mut power = candidate;
+            while power != Number::<N>::ONE {
+                powers.push(power);
+                power = field.mul(power, candidate);
+            }
+            if powers.len() == size - 1 {
+                break powers;
+            }
+        };
+
+        let mut log = alloc::vec![usize::MAX; size];
+        for (power, element) in exp.iter().enumerate() {
+            log[Self::index(*element)] = power;
+        }
+        LogTables { log, exp }
+    }
+
+    /// The generator whose powers the tables hold.
+    pub fn generator(&self) -> Number<N> {
+        self.exp[1 % self.exp.len()]
+    }
+
+    /// Returns the order of the multiplicative group, 3^N - 1, after which the
+    /// powers of the generator repeat.
+    pub fn order(&self) -> usize {
+        self.exp.len()
+    }
+
+    /// Returns the power of the generator equal to `element`, in the range
+    /// `0..3^N - 1`, or `None` for zero.
+    pub fn log(&self, element: Number<N>) -> Option<usize> {
+        let log = self.log[Self::index(element)];
+        if log == usize::MAX {None} else {Some(log)}
+    }
+
+    /// Returns the generator raised to the power of `power`.
+    pub fn antilog(&self, power: usize) -> Number<N> {
+        self.exp[power % self.exp.len()]
+    }
+
+    /// Reads the trits of an element as the unsigned digits of a base three
+    /// number, offset by one.
+    fn index(element: Number<N>) -> usize {
+        element.as_trits().iter().fold(0, |acc, trit| acc * 3 + (i8::from(*trit) + 1) as usize)
+    }
+}
+
+/// Finds the inverse of a polynomial modulo another by the extended Euclidean
+/// algorithm, or `None` if they share a factor.
+fn inverse_modulo(value: &Poly, modulus: &Poly) -> Option<Poly> {
+    // Each remainder r is tracked along with the s for which s * value = r
+    let (mut old_r, mut r) = (modulus.clone(), value % modulus);
+    let (mut old_s, mut s) = (Poly::ZERO, Poly::one());
+    while !r.is_zero() {
+        let (quotient, remainder) = old_r.div_rem(&r);
+        let next_s = &old_s - &(&quotient * &s);
+        (old_r, r) = (r, remainder);
+        (old_s, s) = (s, next_s);
+    }
+
+    // The last non-zero remainder is a constant exactly when there's no common
+    // factor, and that constant is its own inverse
+    if old_r.degree() == Some(0) {
+        Some(&old_s * &old_r)
+    } else {
+        None
+    }
+}
+
+/// Tests a monic polynomial for irreducibility by Ben-Or's algorithm: a
+/// polynomial f of degree n is irreducible if and only if it shares no factor
+/// with x^(3^i) - x for every i up to n / 2, as the latter is the product of
+/// every monic irreducible polynomial whose degree divides i.
+fn is_irreducible(modulus: &Poly) -> bool {
+    let degree = modulus.degree().unwrap_or(0);
+    let x = Poly::monomial(1, Trit::POS);
+    let mut power = x.clone();
+    for _ in 0..degree / 2 {
+        power = &power.pow(3) % modulus;
+        if modulus.gcd(&(&power - &x)) != Poly::one() {
+            return false;
+        }
+    }
+    degree > 0
+}
+
+/// Overwrites the coefficients, along with any spare capacity, leaving the
+/// zero polynomial. Polynomials are also zeroized when dropped.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Poly {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.coeffs);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Poly {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Poly {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::number_pattern;
+
+    fn poly(encoded: &str) -> Poly {
+        encoded.parse().unwrap()
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let elements = [Trit::NEG, Trit::ZERO, Trit::POS];
+        for lhs in elements {
+            for rhs in elements {
+                let expected = (i8::from(lhs) + i8::from(rhs)).rem_euclid(3);
+                assert_eq!(i8::from(add(lhs, rhs)).rem_euclid(3), expected);
+                assert_eq!(add(sub(lhs, rhs), rhs), lhs);
+            }
+            if let Some(inverse) = inverse(lhs) {
+                assert_eq!(lhs * inverse, Trit::POS);
+            }
+        }
+        assert_eq!(inverse(Trit::ZERO), None);
+    }
+
+    #[test]
+    fn construction_and_formatting() {
+        assert_eq!(poly("00+-"), poly("+-"));
+        assert_eq!(poly("000"), Poly::ZERO);
+        assert_eq!(poly("+0-").degree(), Some(2));
+        assert_eq!(Poly::ZERO.degree(), None);
+        assert_eq!(Poly::monomial(3, Trit::NEG), poly("-000"));
+        assert_eq!(Poly::from_coeffs(&[Trit::POS, Trit::NEG, Trit::ZERO]), poly("-+"));
+
+        assert_eq!(poly("+0-+").to_string(), "x^3 - x + 1");
+        assert_eq!(poly("--").to_string(), "-x - 1");
+        assert_eq!(Poly::ZERO.to_string(), "0");
+        assert_eq!(format!("{:?}", poly("+0-")), "Poly(+0-)");
+    }
+
+    #[test]
+    fn ring_arithmetic() {
+        // (x + 1) + (x - 1) = 2x = -x
+        assert_eq!(poly("++") + poly("+-"), poly("-0"));
+        assert_eq!(poly("++") - poly("++"), Poly::ZERO);
+        // (x + 1)(x - 1) = x^2 - 1
+        assert_eq!(poly("++") * poly("+-"), poly("+0-"));
+        // (x + 1)^3 = x^3 + 1 in characteristic three
+        assert_eq!(poly("++").pow(3), poly("+00+"));
+        assert_eq!(poly("++") * Poly::ZERO, Poly::ZERO);
+        assert_eq!(-poly("+-0"), poly("-+0"));
+    }
+
+    #[test]
+    fn division() {
+        let dividend = poly("+-0+-+");
+        for divisor in [poly("+"), poly("-"), poly("++"), poly("-0+"), poly("+0-+")] {
+            let (quotient, remainder) = dividend.div_rem(&divisor);
+            assert_eq!(&quotient * &divisor + &remainder, dividend);
+            assert!(remainder.degree() < divisor.degree());
+        }
+
+        // x^2 - 1 = (x + 1)(x - 1)
+        assert_eq!(poly("+0-") / poly("++"), poly("+-"));
+        assert_eq!(poly("+0-") % poly("++"), Poly::ZERO);
+        assert_eq!(poly("+") / poly("+0"), Poly::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = poly("+") / Poly::ZERO;
+    }
+
+    #[test]
+    fn gcd_and_evaluation() {
+        // (x + 1)(x - 1) and (x + 1)(x^2 + 1)
+        let lhs = poly("+0-");
+        let rhs = poly("++") * poly("+0+");
+        assert_eq!(lhs.gcd(&rhs), poly("++"));
+        assert_eq!((-&lhs).gcd(&Poly::ZERO), lhs);
+        assert_eq!(poly("+0+").gcd(&poly("+-")), Poly::one());
+        assert_eq!(Poly::ZERO.gcd(&Poly::ZERO), Poly::ZERO);
+
+        // x^2 - 1 has roots at both 1 and -1
+        assert_eq!(lhs.eval(Trit::POS), Trit::ZERO);
+        assert_eq!(lhs.eval(Trit::NEG), Trit::ZERO);
+        assert_eq!(lhs.eval(Trit::ZERO), Trit::NEG);
+        assert_eq!(Poly::ZERO.eval(Trit::POS), Trit::ZERO);
+    }
+
+    /// Every element of a small field, in the order of the numbers.
+    fn elements<const N: usize>() -> impl Iterator<Item = Number<N>> {
+        let limit = (3_i64.pow(N as u32) - 1) / 2;
+        (-limit..=limit).map(|value| Number::<N>::try_from(value).unwrap())
+    }
+
+    #[test]
+    fn field_construction() {
+        // x^2 - 1 = (x + 1)(x - 1) is reducible
+        assert!(Gf3n::<2>::new(&poly("+0-")).is_none());
+        assert!(Gf3n::<3>::new(&poly("+0+")).is_none());
+        assert!(Gf3n::<1>::new(&poly("+0")).is_some());
+
+        let field = Gf3n::<2>::new(&poly("-0-")).unwrap();
+        assert_eq!(field.modulus(), poly("+0+"));
+        assert_eq!(Gf3n::<2>::default().modulus(), poly("+0+"));
+        assert_eq!(format!("{:?}", field), "Gf3n<2>(x^2 + 1)");
+        assert!(Gf3n::<12>::default().log_tables().is_none());
+    }
+
+    #[test]
+    fn field_axioms() {
+        let field = Gf3n::<3>::default();
+        let reference = |lhs: Number<3>, rhs: Number<3>| {
+            field.reduce(&(Gf3n::<3>::to_poly(lhs) * Gf3n::<3>::to_poly(rhs)))
+        };
+
+        for lhs in elements::<3>() {
+            for rhs in elements::<3>() {
+                let product = field.mul(lhs, rhs);
+                assert_eq!(product, reference(lhs, rhs));
+                assert_eq!(product, field.mul(rhs, lhs));
+                assert_eq!(field.sub(field.add(lhs, rhs), rhs), lhs);
+                if !rhs.is_zero() {
+                    assert_eq!(field.mul(field.div(lhs, rhs).unwrap(), rhs), lhs);
+                }
+            }
+            match field.inv(lhs) {
+                Some(inverse) => assert_eq!(field.mul(lhs, inverse), Number::<3>::ONE),
+                None => assert!(lhs.is_zero())
+            }
+        }
+        assert_eq!(field.pow(Number::<3>::from(5), 26), Number::<3>::ONE);
+    }
+
+    #[test]
+    fn log_tables() {
+        let field = Gf3n::<4>::default();
+        let tables = field.log_tables().unwrap();
+        let mut seen = [false; 80];
+        for element in elements::<4>() {
+            match tables.log(element) {
+                Some(log) => {
+                    assert!(!seen[log]);
+                    seen[log] = true;
+                    assert_eq!(tables.antilog(log), element);
+                    assert_eq!(field.pow(tables.generator(), log as u64), element);
+                },
+                None => assert!(element.is_zero())
+            }
+        }
+        assert_eq!(tables.antilog(80), Number::<4>::ONE);
+    }
+
+    #[test]
+    fn large_fields() {
+        // Without tables multiplication reduces as it goes and inversion uses
+        // the extended Euclidean algorithm
+        let field = Gf3n::<12>::default();
+        for seed in 1..20_usize {
+            let (lhs, rhs) = (number_pattern::<12>(seed), number_pattern::<12>(seed + 7));
+            let expected = field.reduce(&(Gf3n::<12>::to_poly(lhs) * Gf3n::<12>::to_poly(rhs)));
+            assert_eq!(field.mul(lhs, rhs), expected);
+            assert_eq!(field.mul(lhs, field.inv(lhs).unwrap()), Number::<12>::ONE);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = poly("+0-+");
+        secret.zeroize();
+        assert!(secret.is_zero());
+        assert_eq!(secret, Poly::default());
+    }
+
+    #[test]
+    fn fallible_division() {
+        let (dividend, divisor) = (poly("+0-+"), poly("+-"));
+        assert_eq!(dividend.try_div_rem(&Poly::default()), Err(Error::DivisionByZero));
+        assert_eq!(dividend.try_div_rem(&divisor), Ok(dividend.div_rem(&divisor)));
+    }
+}
@@ -0,0 +1,295 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::error::Error;
+use crate::number::Number;
+
+/// A closed interval of balanced ternary integers, holding every value from
+/// its lower bound to its upper bound inclusive.
+///
+/// The arithmetic is that of interval arithmetic, where the result of an
+/// operation contains every result of applying it to values taken from the
+/// operands. Division rounds the lower bound of its result down and the upper
+/// bound up, so that the interval always contains the exact quotient. To keep
+/// that guarantee, an operation whose bounds would overflow N trits panics
+/// rather than wrapping, and the `checked_*` methods return `None` instead.
+/// ```
+/// # use balanced_ternary::interval::Interval;
+/// # use balanced_ternary::number::Number;
+/// let x = Interval::new(Number::<8>::from(-2), Number::<8>::from(3));
+/// let y = Interval::from(Number::<8>::from(4));
+/// assert_eq!(x * y, Interval::new(Number::<8>::from(-8), Number::<8>::from(12)));
+/// assert_eq!(x / y, Interval::new(Number::<8>::from(-1), Number::<8>::from(1)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Interval<const N: usize> {
+    lower: Number<N>,
+    upper: Number<N>
+}
+
+impl <const N: usize> Interval<N> {
+    /// The interval holding every number of N trits.
+    pub const FULL: Self = Interval { lower: Number::<N>::MIN, upper: Number::<N>::MAX };
+
+    /// Builds the interval from `lower` to `upper` inclusive.
+    /// * `lower` - The smallest value in the interval
+    /// * `upper` - The largest value in the interval
+    ///
+    /// # Panics
+    /// Panics if `lower` is greater than `upper`.
+    pub fn new(lower: Number<N>, upper: Number<N>) -> Self {
+        if lower > upper {
+            panic!("Lower bound of an interval must not exceed the upper bound")
+        }
+        Interval { lower, upper }
+    }
+
+    /// Fallible [`Interval::new`]. Returns [`Error::InvalidBounds`] if `lower`
+    /// is greater than `upper`.
+    pub fn try_new(lower: Number<N>, upper: Number<N>) -> Result<Self, Error> {
+        if lower > upper {
+            return Err(Error::InvalidBounds);
+        }
+        Ok(Interval { lower, upper })
+    }
+
+    /// Returns the smallest value in the interval.
+    pub const fn lower(self) -> Number<N> {
+        self.lower
+    }
+
+    /// Returns the largest value in the interval.
+    pub const fn upper(self) -> Number<N> {
+        self.upper
+    }
+
+    /// Returns `true` if the interval holds a single value.
+    pub fn is_point(self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Returns `true` if `value` lies within the interval.
+    pub fn contains(self, value: Number<N>) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Returns `true` if every value of `other` lies within the interval.
+    pub fn contains_interval(self, other: Self) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    /// Returns the values common to both intervals, or `None` if they are
+    /// disjoint.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let lower = self.lower.max(other.lower);
+        let upper = self.upper.min(other.upper);
+        if lower <= upper {Some(Interval { lower, upper })} else {None}
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(self, other: Self) -> Self {
+        Interval { lower: self.lower.min(other.lower), upper: self.upper.max(other.upper) }
+    }
+
+    /// Returns the interval of absolute values. This never overflows.
+    pub fn abs(self) -> Self {
+        if self.lower.is_negative() && self.upper.is_positive() {
+            Interval { lower: Number::<N>::ZERO, upper: self.upper.max(-self.lower) }
+        } else if self.upper.is_negative() || self.upper.is_zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Adds two intervals, returning `None` if either bound overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Interval { lower: self.lower.checked_add(rhs.lower)?, upper: self.upper.checked_add(rhs.upper)? })
+    }
+
+    /// Subtracts one interval from another, returning `None` if either bound
+    /// overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(-rhs)
+    }
+
+    /// Multiplies two intervals, returning `None` if any product of the bounds
+    /// overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let products = [
+            self.lower.checked_mul(rhs.lower)?,
+            self.lower.checked_mul(rhs.upper)?,
+            self.upper.checked_mul(rhs.lower)?,
+            self.upper.checked_mul(rhs.upper)?
+        ];
+        Some(Self::spanning(products, products))
+    }
+
+    /// Divides one interval by another, rounding outwards so that the result
+    /// contains every exact quotient. Returns `None` if the divisor contains
+    /// zero, since the quotient is then unbounded.
+    pub fn checked_div(self, divisor: Self) -> Option<Self> {
+        if divisor.contains(Number::<N>::ZERO) {
+            return None;
+        }
+
+        // Away from zero the quotient is monotonic in both operands, so its
+        // extremes lie at the corners. Balanced ternary ranges are symmetric,
+        // so no quotient can overflow.
+        let corners = [
+            (self.lower, divisor.lower),
+            (self.lower, divisor.upper),
+            (self.upper, divisor.lower),
+            (self.upper, divisor.upper)
+        ];
+        Some(Self::spanning(
+            corners.map(|(numerator, divisor)| numerator.div_floor(divisor)),
+            corners.map(|(numerator, divisor)| numerator.div_ceil(divisor))
+        ))
+    }
+
+    /// The smallest interval holding the smallest of `lowers` and the largest
+    /// of `uppers`.
+    fn spanning(lowers: [Number<N>; 4], uppers: [Number<N>; 4]) -> Self {
+        let lower = lowers.into_iter().min().unwrap_or(Number::<N>::ZERO);
+        let upper = uppers.into_iter().max().unwrap_or(Number::<N>::ZERO);
+        Interval { lower, upper }
+    }
+}
+
+impl <const N: usize> From<Number<N>> for Interval<N> {
+    /// Builds the interval holding only `value`.
+    fn from(value: Number<N>) -> Self {
+        Interval { lower: value, upper: value }
+    }
+}
+
+impl <const N: usize> Neg for Interval<N> {
+    type Output = Self;
+
+    /// Negates the interval. Balanced ternary ranges are symmetric, so this
+    /// never overflows.
+    fn neg(self) -> Self::Output {
+        Interval { lower: -self.upper, upper: -self.lower }
+    }
+}
+
+macro_rules! checked_interval_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $checked_fn:ident, $message:literal) => {
+        impl <const N: usize> $op_trait for Interval<N> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                match self.$checked_fn(rhs) {
+                    Some(output) => output,
+                    None => panic!($message)
+                }
+            }
+        }
+
+        impl <const N: usize> $assign_trait for Interval<N> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $op_trait::$op_fn(*self, rhs);
+            }
+        }
+    };
+}
+
+checked_interval_op!(Add, add, AddAssign, add_assign, checked_add, "Interval addition overflowed");
+checked_interval_op!(Sub, sub, SubAssign, sub_assign, checked_sub, "Interval subtraction overflowed");
+checked_interval_op!(Mul, mul, MulAssign, mul_assign, checked_mul, "Interval multiplication overflowed");
+checked_interval_op!(Div, div, DivAssign, div_assign, checked_div, "Interval division by an interval containing zero");
+
+impl <const N: usize> fmt::Display for Interval<N> {
+    /// Formats the interval as `[lower, upper]`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(lower: i32, upper: i32) -> Interval<8> {
+        Interval::new(Number::<8>::from(lower), Number::<8>::from(upper))
+    }
+
+    /// Checks that an interval result contains the result of applying the
+    /// operation to every pair of values from the operands.
+    fn assert_encloses(lhs: Interval<8>, rhs: Interval<8>, result: Interval<8>, op: fn(i32, i32) -> Option<i32>) {
+        let (lhs_lower, lhs_upper) = (i128::try_from(lhs.lower()).unwrap() as i32, i128::try_from(lhs.upper()).unwrap() as i32);
+        let (rhs_lower, rhs_upper) = (i128::try_from(rhs.lower()).unwrap() as i32, i128::try_from(rhs.upper()).unwrap() as i32);
+        for x in lhs_lower..=lhs_upper {
+            for y in rhs_lower..=rhs_upper {
+                if let Some(exact) = op(x, y) {
+                    assert!(result.contains(Number::<8>::from(exact)), "{} not in {}", exact, result);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn set_operations() {
+        let value = interval(-5, 10);
+        assert!(value.contains(Number::<8>::from(-5)) && !value.contains(Number::<8>::from(11)));
+        assert!(value.contains_interval(interval(0, 10)) && !value.contains_interval(interval(0, 11)));
+        assert_eq!(value.intersection(interval(8, 20)), Some(interval(8, 10)));
+        assert_eq!(value.intersection(interval(11, 20)), None);
+        assert_eq!(value.hull(interval(20, 30)), interval(-5, 30));
+        assert!(Interval::from(Number::<8>::from(4)).is_point());
+
+        assert_eq!(value.abs(), interval(0, 10));
+        assert_eq!(interval(-7, -2).abs(), interval(2, 7));
+        assert_eq!(-value, interval(-10, 5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverted_bounds() {
+        interval(3, 2);
+    }
+
+    #[test]
+    fn arithmetic_encloses_exact_results() {
+        let intervals = [interval(-7, -2), interval(-3, 4), interval(0, 0), interval(1, 1), interval(2, 9), interval(-1, 0)];
+        for lhs in intervals {
+            for rhs in intervals {
+                assert_encloses(lhs, rhs, lhs + rhs, |x, y| Some(x + y));
+                assert_encloses(lhs, rhs, lhs - rhs, |x, y| Some(x - y));
+                assert_encloses(lhs, rhs, lhs * rhs, |x, y| Some(x * y));
+                match lhs.checked_div(rhs) {
+                    Some(quotient) => assert_encloses(lhs, rhs, quotient, |x, y| Some(x / y)),
+                    None => assert!(rhs.contains(Number::<8>::ZERO))
+                }
+            }
+        }
+
+        assert_eq!(interval(-7, -2) * interval(-3, 4), interval(-28, 21));
+        assert_eq!(interval(2, 9) - interval(-3, 4), interval(-2, 12));
+        // 7/3 and 8/3 lie between 2 and 3, so the quotient is rounded outwards
+        assert_eq!(interval(7, 8) / interval(3, 3), interval(2, 3));
+        assert_eq!(interval(-8, -7) / interval(3, 3), interval(-3, -2));
+    }
+
+    #[test]
+    fn overflow_is_detected() {
+        let large = interval(3000, 3280);
+        assert_eq!(large.checked_add(interval(0, 1)), None);
+        assert_eq!(large.checked_mul(interval(-1, 2)), None);
+        assert_eq!(Interval::<8>::FULL.checked_mul(interval(-1, 1)), Some(Interval::<8>::FULL));
+        assert_eq!(large.checked_div(interval(-1, 1)), None);
+
+        let mut value = large;
+        value -= interval(0, 280);
+        assert_eq!(value, interval(2720, 3280));
+    }
+
+    #[test]
+    fn fallible_construction() {
+        let (lower, upper) = (Number::<8>::from(-3), Number::<8>::from(5));
+        assert_eq!(Interval::try_new(upper, lower), Err(Error::InvalidBounds));
+        assert_eq!(Interval::try_new(lower, upper), Ok(interval(-3, 5)));
+        assert_eq!(Interval::try_new(upper, upper), Ok(interval(5, 5)));
+    }
+}
@@ -0,0 +1,425 @@
+//! Streams of trits over byte-oriented readers and writers.
+//!
+//! A [`TritWriter`] packs the trits written to it into bytes with one of the
+//! encodings of [`Number`], and a [`TritReader`] unpacks them again, so that
+//! trit algorithms can read from and write to files and sockets directly. The
+//! trits of a stream are laid out as if the whole stream were a single number
+//! packed with [`Number::to_packed_bytes`] or [`Number::encode_2bit`], with the
+//! first trit written in the least significant position.
+//!
+//! Only whole bytes can be written, so a partly filled byte is padded with zero
+//! trits when the writer is flushed or dropped. A reader can't tell that
+//! padding from zero trits that were written, so streams of a known length,
+//! such as a series of numbers of the same width, are best read with
+//! [`TritReader::read_number`].
+//!
+//! Bytes are read and written one at a time, so an unbuffered reader or writer
+//! should be wrapped in a [`BufReader`](std::io::BufReader) or
+//! [`BufWriter`](std::io::BufWriter).
+//! ```
+//! # use balanced_ternary::io::{Codec, TritReader, TritWriter};
+//! # use balanced_ternary::number::Number;
+//! # use balanced_ternary::trit::Trit;
+//! let mut writer = TritWriter::new(Vec::new(), Codec::Packed);
+//! writer.write_number(&Number::<6>::from(-100)).unwrap();
+//! writer.write_trit(Trit::POS).unwrap();
+//! let bytes = writer.into_inner().unwrap();
+//! assert_eq!(bytes.len(), 2);
+//!
+//! let mut reader = TritReader::new(bytes.as_slice(), Codec::Packed);
+//! assert_eq!(reader.read_number::<6>().unwrap(), Number::from(-100));
+//! assert_eq!(reader.read_trit().unwrap(), Some(Trit::POS));
+//! // The padding of the final byte is read as zero trits
+//! assert_eq!(reader.read_trit().unwrap(), Some(Trit::ZERO));
+//! ```
+//!
+//! [`Number`]: crate::number::Number
+//! [`Number::to_packed_bytes`]: crate::number::Number::to_packed_bytes
+//! [`Number::encode_2bit`]: crate::number::Number::encode_2bit
+
+use std::io::{self, Read, Write};
+
+use crate::number::{Number, UnpackBytesError};
+use crate::trit::Trit;
+
+/// The most trits held in a single byte by any codec.
+const MAX_TRITS_PER_BYTE: usize = 5;
+
+/// The encodings of trits into bytes that a stream can use.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Codec {
+    /// Five trits per byte, as in [`Number::to_packed_bytes`]. This is the
+    /// densest encoding.
+    ///
+    /// [`Number::to_packed_bytes`]: crate::number::Number::to_packed_bytes
+    Packed,
+    /// Four trits per byte of two bits each, as in [`Number::encode_2bit`].
+    ///
+    /// [`Number::encode_2bit`]: crate::number::Number::encode_2bit
+    TwoBit
+}
+
+impl Codec {
+    /// Returns the number of trits held in each byte.
+    pub const fn trits_per_byte(self) -> usize {
+        match self {
+            Codec::Packed => 5,
+            Codec::TwoBit => 4
+        }
+    }
+
+    /// Encodes up to a byte's worth of trits, from the least significant.
+    fn encode(self, trits: &[Trit]) -> u8 {
+        match self {
+            Codec::Packed => {
+                let value = trits.iter().rev().fold(0_i16, |value, trit| value * 3 + i16::from(trit.to_i8()));
+                (value + 121) as u8
+            },
+            Codec::TwoBit => trits.iter()
+                .enumerate()
+                .fold(0, |byte, (idx, trit)| byte | trit.to_2bit() << (2 * idx))
+        }
+    }
+
+    /// Decodes a byte into its trits, from the least significant, or `None` if
+    /// the byte isn't a valid encoding.
+    fn decode(self, byte: u8) -> Option<[Trit; MAX_TRITS_PER_BYTE]> {
+        let mut trits = [Trit::ZERO; MAX_TRITS_PER_BYTE];
+        match self {
+            Codec::Packed => {
+                if byte > 242 {
+                    return None;
+                }
+                let mut value = i16::from(byte) - 121;
+                for trit in trits.iter_mut() {
+                    *trit = match value.rem_euclid(3) {
+                        0 => Trit::ZERO,
+                        1 => Trit::POS,
+                        _ => Trit::NEG
+                    };
+                    value = (value - i16::from(trit.to_i8())) / 3;
+                }
+            },
+            Codec::TwoBit => {
+                for (idx, trit) in trits.iter_mut().take(4).enumerate() {
+                    *trit = Trit::from_2bit(byte >> (2 * idx))?;
+                }
+            }
+        }
+        Some(trits)
+    }
+}
+
+/// Writes trits to a byte-oriented writer, packing them with a [`Codec`].
+///
+/// Trits are held back until a whole byte of them has been written, and that
+/// byte goes out with the next trit. If writing it fails, the trit isn't
+/// stored, so writing the same trit again retries the byte. Flushing the
+/// writer writes out a partly filled byte padded with zero trits, so trits
+/// written afterwards start a new byte. Dropping the writer flushes it, but
+/// ignores any error, so call [`into_inner`](Self::into_inner) or
+/// [`flush`](Write::flush) to see errors from the final byte.
+#[derive(Debug)]
+pub struct TritWriter<W: Write> {
+    // Only taken by `into_inner`, which then skips the flush on drop
+    inner: Option<W>,
+    codec: Codec,
+    pending: [Trit; MAX_TRITS_PER_BYTE],
+    pending_len: usize
+}
+
+impl <W: Write> TritWriter<W> {
+    /// Creates a writer packing trits into `inner` with `codec`.
+    pub fn new(inner: W, codec: Codec) -> Self {
+        TritWriter { inner: Some(inner), codec, pending: [Trit::ZERO; MAX_TRITS_PER_BYTE], pending_len: 0 }
+    }
+
+    /// Writes a single trit.
+    pub fn write_trit(&mut self, trit: Trit) -> io::Result<()> {
+        if self.pending_len == self.codec.trits_per_byte() {
+            self.write_pending()?;
+        }
+        self.pending[self.pending_len] = trit;
+        self.pending_len += 1;
+        Ok(())
+    }
+
+    /// Writes a slice of trits, in order.
+    pub fn write_trits(&mut self, trits: &[Trit]) -> io::Result<()> {
+        trits.iter().try_for_each(|trit| self.write_trit(*trit))
+    }
+
+    /// Writes the trits of a number, starting from the least significant.
+    pub fn write_number<const N: usize>(&mut self, number: &Number<N>) -> io::Result<()> {
+        number.iter().try_for_each(|trit| self.write_trit(trit))
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().expect("Writer is only taken when consumed")
+    }
+
+    /// Returns a mutable reference to the underlying writer. Writing to it
+    /// directly puts the bytes before any trits still held back.
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.as_mut().expect("Writer is only taken when consumed")
+    }
+
+    /// Writes out any partly filled byte and returns the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.write_pending()?;
+        Ok(self.inner.take().expect("Writer is only taken when consumed"))
+    }
+
+    /// Writes the held back trits as a byte, padded with zero trits.
+    fn write_pending(&mut self) -> io::Result<()> {
+        if self.pending_len == 0 {
+            return Ok(());
+        }
+        let byte = self.codec.encode(&self.pending[..self.pending_len]);
+        self.get_mut().write_all(&[byte])?;
+        self.pending_len = 0;
+        Ok(())
+    }
+}
+
+impl <W: Write> Write for TritWriter<W> {
+    /// Writes raw bytes to the underlying writer, after writing out any partly
+    /// filled byte of trits.
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.write_pending()?;
+        self.get_mut().write(bytes)
+    }
+
+    /// Writes out any partly filled byte, padded with zero trits, and flushes
+    /// the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+        self.get_mut().flush()
+    }
+}
+
+impl <W: Write> Drop for TritWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.write_pending();
+        }
+    }
+}
+
+/// Reads trits from a byte-oriented reader, unpacking them with a [`Codec`].
+///
+/// A byte that isn't a valid encoding gives an error of the kind
+/// [`InvalidData`](io::ErrorKind::InvalidData), wrapping an
+/// [`UnpackBytesError::InvalidByte`] with the position of the byte in the
+/// stream.
+#[derive(Debug)]
+pub struct TritReader<R: Read> {
+    inner: R,
+    codec: Codec,
+    buffer: [Trit; MAX_TRITS_PER_BYTE],
+    // The next trit of the buffer to be read, and the number of bytes read
+    // so far
+    buffer_pos: usize,
+    bytes_read: usize
+}
+
+impl <R: Read> TritReader<R> {
+    /// Creates a reader unpacking trits from `inner` with `codec`.
+    pub fn new(inner: R, codec: Codec) -> Self {
+        TritReader {
+            inner,
+            codec,
+            buffer: [Trit::ZERO; MAX_TRITS_PER_BYTE],
+            buffer_pos: codec.trits_per_byte(),
+            bytes_read: 0
+        }
+    }
+
+    /// Reads a single trit, or `None` at the end of the stream.
+    pub fn read_trit(&mut self) -> io::Result<Option<Trit>> {
+        if self.buffer_pos == self.codec.trits_per_byte() && !self.fill_buffer()? {
+            return Ok(None);
+        }
+        let trit = self.buffer[self.buffer_pos];
+        self.buffer_pos += 1;
+        Ok(Some(trit))
+    }
+
+    /// Reads trits into `trits` until it is full or the stream ends, returning
+    /// the number of trits read.
+    pub fn read_trits(&mut self, trits: &mut [Trit]) -> io::Result<usize> {
+        for (count, slot) in trits.iter_mut().enumerate() {
+            match self.read_trit()? {
+                Some(trit) => *slot = trit,
+                None => return Ok(count)
+            }
+        }
+        Ok(trits.len())
+    }
+
+    /// Reads N trits as a number, starting from the least significant. Gives
+    /// an error of the kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if
+    /// the stream ends first.
+    pub fn read_number<const N: usize>(&mut self) -> io::Result<Number<N>> {
+        let mut trits = [Trit::ZERO; N];
+        if self.read_trits(&mut trits)? < N {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended part way through a number"));
+        }
+        Ok(Number::<N>::from_trits_le(&trits))
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader. Reading from it
+    /// directly skips the bytes it reads, but not any trits already unpacked.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader, discarding any unpacked trits that
+    /// haven't been read.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Unpacks the next byte into the buffer, returning `false` at the end of
+    /// the stream.
+    fn fill_buffer(&mut self) -> io::Result<bool> {
+        let mut byte = [0];
+        loop {
+            match self.inner.read(&mut byte) {
+                Ok(0) => return Ok(false),
+                Ok(_) => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error)
+            }
+        }
+
+        let position = self.bytes_read;
+        self.bytes_read += 1;
+        self.buffer = self.codec.decode(byte[0]).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, UnpackBytesError::InvalidByte { byte: byte[0], position })
+        })?;
+        self.buffer_pos = 0;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::number_pattern as pattern;
+
+    #[test]
+    fn matches_number_encodings() {
+        let number = pattern::<23>(4);
+        for (codec, expected) in [(Codec::Packed, number.to_packed_bytes()), (Codec::TwoBit, number.encode_2bit())] {
+            let mut writer = TritWriter::new(Vec::new(), codec);
+            writer.write_number(&number).unwrap();
+            assert_eq!(writer.into_inner().unwrap(), expected);
+
+            let mut reader = TritReader::new(expected.as_slice(), codec);
+            assert_eq!(reader.read_number::<23>().unwrap(), number);
+        }
+    }
+
+    #[test]
+    fn streams_of_numbers() {
+        for codec in [Codec::Packed, Codec::TwoBit] {
+            let numbers: Vec<Number<7>> = (1..=50).map(pattern::<7>).collect();
+            let mut bytes = Vec::new();
+            {
+                // Dropping the writer writes out the final byte
+                let mut writer = TritWriter::new(&mut bytes, codec);
+                numbers.iter().try_for_each(|number| writer.write_number(number)).unwrap();
+            }
+            assert_eq!(bytes.len(), (50 * 7_usize).div_ceil(codec.trits_per_byte()));
+
+            let mut reader = TritReader::new(bytes.as_slice(), codec);
+            for number in &numbers {
+                assert_eq!(reader.read_number::<7>().unwrap(), *number);
+            }
+            let error = reader.read_number::<7>().unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        }
+    }
+
+    #[test]
+    fn trit_slices_and_flushing() {
+        let trits = [Trit::POS, Trit::NEG, Trit::ZERO, Trit::NEG, Trit::POS, Trit::POS, Trit::NEG];
+        let mut writer = TritWriter::new(Vec::new(), Codec::TwoBit);
+        writer.write_trits(&trits[..3]).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref().len(), 1);
+        writer.write_trits(&trits[3..]).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let mut reader = TritReader::new(bytes.as_slice(), Codec::TwoBit);
+        let mut read = [Trit::ZERO; 10];
+        assert_eq!(reader.read_trits(&mut read).unwrap(), 8);
+        // The flush padded the first byte with a zero trit
+        assert_eq!(read[..8], [Trit::POS, Trit::NEG, Trit::ZERO, Trit::ZERO, Trit::NEG, Trit::POS, Trit::POS, Trit::NEG]);
+        assert_eq!(reader.read_trit().unwrap(), None);
+    }
+
+    #[test]
+    fn retries_after_would_block() {
+        /// Refuses the first write, then accepts everything.
+        struct Blocking {
+            bytes: Vec<u8>,
+            blocked: bool
+        }
+
+        impl Write for Blocking {
+            fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+                if !self.blocked {
+                    self.blocked = true;
+                    return Err(io::ErrorKind::WouldBlock.into());
+                }
+                self.bytes.extend_from_slice(bytes);
+                Ok(bytes.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        for codec in [Codec::Packed, Codec::TwoBit] {
+            let number = pattern::<23>(5);
+            let mut writer = TritWriter::new(Blocking { bytes: Vec::new(), blocked: false }, codec);
+            let mut failures = 0;
+            for trit in number.iter() {
+                if let Err(error) = writer.write_trit(trit) {
+                    assert_eq!(error.kind(), io::ErrorKind::WouldBlock);
+                    failures += 1;
+                    writer.write_trit(trit).unwrap();
+                }
+            }
+            assert_eq!(failures, 1);
+            let bytes = writer.into_inner().unwrap().bytes;
+            assert_eq!(bytes.len(), 23_usize.div_ceil(codec.trits_per_byte()));
+
+            let mut reader = TritReader::new(bytes.as_slice(), codec);
+            assert_eq!(reader.read_number::<23>().unwrap(), number);
+        }
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let bytes = [121, 243];
+        let mut reader = TritReader::new(&bytes[..], Codec::Packed);
+        assert_eq!(reader.read_number::<5>().unwrap(), Number::<5>::ZERO);
+        let error = reader.read_trit().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let source = error.into_inner().unwrap().downcast::<UnpackBytesError>().unwrap();
+        assert_eq!(*source, UnpackBytesError::InvalidByte { byte: 243, position: 1 });
+
+        let mut reader = TritReader::new(&[0b1100_0000][..], Codec::TwoBit);
+        assert_eq!(reader.read_trit().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
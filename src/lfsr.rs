@@ -0,0 +1,192 @@
+use crate::number::Number;
+use crate::trit::Trit;
+#[cfg(feature = "alloc")]
+use crate::gf3::Poly;
+
+/// The lower coefficients of a primitive feedback polynomial of each degree
+/// from 1 up, packed with the trit of weight 3^i holding the coefficient of
+/// x^i. Each is the sparsest primitive polynomial of its degree.
+const MAXIMAL_FEEDBACK: [i128; 40] = [
+    1, 2, -2, 2, -2, 2, -8, 26, -80, 29,
+    -8, 245, -2, 2, -8, 2186, -2, 1594325, -8, 245,
+    -242, 242, -26, 1594565, -26, 2186, -2186, 1594322, -80, 2,
+    -242, 242, -242, 29, -8, 129140165, -728, 10460353205, -59045, 2,
+];
+
+/// A linear feedback shift register over GF(3), producing a stream of trits.
+///
+/// The register holds the last N trits of a sequence whose next trit is fixed
+/// by the monic feedback polynomial x^N + c(N-1)x^(N-1) + ... + c(0): each
+/// trit s(k+N) is the GF(3) sum of -c(i)s(k+i). When the polynomial is
+/// primitive, any non-zero seed gives a sequence with the maximal period of
+/// 3^N - 1, while the all-zero state only ever produces zeros.
+///
+/// The feedback polynomial is given as a number whose trit of weight 3^i is
+/// the coefficient of x^i, as with the elements of [`Gf3n`](crate::gf3::Gf3n),
+/// and the seed as a number whose lowest trit is output first.
+/// ```
+/// # use balanced_ternary::lfsr::Lfsr3;
+/// # use balanced_ternary::number::Number;
+/// let mut lfsr = Lfsr3::<4>::maximal(Number::<4>::ONE).unwrap();
+/// let first: Number<12> = lfsr.next_number();
+/// assert_eq!(lfsr.by_ref().take(80 - 12).count(), 68);
+/// assert_eq!(lfsr.next_number::<12>(), first);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Lfsr3<const N: usize> {
+    // The coefficients of x^0 to x^(N-1) of the feedback polynomial
+    feedback: [Trit; N],
+    // The next N trits of the sequence, the first to be output at index 0
+    state: [Trit; N]
+}
+
+impl <const N: usize> Lfsr3<N> {
+    const VALID_LENGTH: () = assert!(N > 0, "Shift register must have a length of at least one");
+
+    /// The largest register length with a maximal-length preset.
+    pub const MAX_PRESET_DEGREE: usize = MAXIMAL_FEEDBACK.len();
+
+    /// Builds a register from the lower coefficients of its feedback
+    /// polynomial and the seed it starts from.
+    /// * `feedback` - The coefficients of x^0 to x^(N-1), one per trit
+    /// * `seed` - The first N trits of the sequence, lowest first
+    pub fn new(feedback: Number<N>, seed: Number<N>) -> Self {
+        let () = Self::VALID_LENGTH;
+        Lfsr3 {
+            feedback: core::array::from_fn(|idx| feedback[idx]),
+            state: core::array::from_fn(|idx| seed[idx])
+        }
+    }
+
+    /// Builds a register from a feedback polynomial, which is scaled to be
+    /// monic. Returns `None` if the polynomial doesn't have degree N.
+    #[cfg(feature = "alloc")]
+    pub fn from_poly(feedback: &Poly, seed: Number<N>) -> Option<Self> {
+        let feedback = feedback.monic();
+        if feedback.degree() != Some(N) {
+            return None;
+        }
+        Some(Lfsr3::new(Number::<N>::from_trits_le(&feedback.coeffs()[..N]), seed))
+    }
+
+    /// Builds a register with a primitive feedback polynomial, which has the
+    /// maximal period of 3^N - 1 for any non-zero seed. Returns `None` if N is
+    /// larger than [`MAX_PRESET_DEGREE`](Self::MAX_PRESET_DEGREE).
+    pub fn maximal(seed: Number<N>) -> Option<Self> {
+        Self::maximal_feedback().map(|feedback| Lfsr3::new(feedback, seed))
+    }
+
+    /// Returns the lower coefficients of the preset primitive polynomial of
+    /// degree N, or `None` if there is no preset.
+    pub fn maximal_feedback() -> Option<Number<N>> {
+        let () = Self::VALID_LENGTH;
+        MAXIMAL_FEEDBACK.get(N - 1).map(|feedback| Number::<N>::from_i128(*feedback))
+    }
+
+    /// Returns the lower coefficients of the feedback polynomial.
+    pub fn feedback(&self) -> Number<N> {
+        Number::<N>::from_trits_le(&self.feedback)
+    }
+
+    /// Returns the next N trits of the sequence, lowest first.
+    pub fn state(&self) -> Number<N> {
+        Number::<N>::from_trits_le(&self.state)
+    }
+
+    /// Returns the next trit of the sequence and steps the register.
+    pub fn next_trit(&mut self) -> Trit {
+        let output = self.state[0];
+        let next = self.state.iter().zip(self.feedback).fold(Trit::ZERO, |sum, (state, feedback)| {
+            sum.add(&-(*state * feedback)).result
+        });
+
+        self.state.copy_within(1.., 0);
+        self.state[N - 1] = next;
+        output
+    }
+
+    /// Collects the next M trits of the sequence into a number, the first
+    /// trit becoming the least significant.
+    pub fn next_number<const M: usize>(&mut self) -> Number<M> {
+        Number::<M>::from_rev_iter(self.by_ref().take(M))
+    }
+}
+
+impl <const N: usize> Iterator for Lfsr3<N> {
+    type Item = Trit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_trit())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_maximal<const N: usize>() {
+        let seed = Number::<N>::ONE;
+        let mut lfsr = Lfsr3::<N>::maximal(seed).unwrap();
+        let period = 3_usize.pow(N as u32) - 1;
+
+        // Over a full period the register visits every non-zero state once,
+        // so the sequence holds one fewer zero than each non-zero trit
+        let mut counts = [0; 3];
+        for step in 1..=period {
+            counts[lfsr.next_trit() as usize] += 1;
+            assert_eq!(lfsr.state() == seed, step == period, "Degree {} at step {}", N, step);
+        }
+        let third = period.div_ceil(3);
+        assert_eq!(counts, [third, third - 1, third]);
+    }
+
+    #[test]
+    fn presets_are_maximal() {
+        assert_maximal::<1>();
+        assert_maximal::<2>();
+        assert_maximal::<3>();
+        assert_maximal::<4>();
+        assert_maximal::<5>();
+        assert_maximal::<6>();
+        assert_maximal::<7>();
+        assert_maximal::<8>();
+        assert_maximal::<9>();
+
+        assert!(Lfsr3::<40>::maximal(Number::<40>::ONE).is_some());
+        assert_eq!(Lfsr3::<41>::maximal_feedback(), None);
+    }
+
+    #[test]
+    fn sequences() {
+        // x^2 + x - 1 gives s(k+2) = s(k) - s(k+1)
+        let mut lfsr = Lfsr3::<2>::new(Number::<2>::from("+-"), Number::<2>::from("0+"));
+        assert_eq!(lfsr.feedback(), Lfsr3::<2>::maximal_feedback().unwrap());
+        let sequence: [Trit; 8] = core::array::from_fn(|_| lfsr.next_trit());
+        assert_eq!(Number::<8>::from_trits_le(&sequence), Number::<8>::from("+-0--+0+"));
+        assert_eq!(lfsr.state(), Number::<2>::from("0+"));
+
+        // The zero state is a fixed point
+        let mut zero = Lfsr3::<5>::maximal(Number::<5>::ZERO).unwrap();
+        assert_eq!(zero.next_number::<20>(), Number::<20>::ZERO);
+
+        // Numbers are reproducible from the seed
+        let seed = Number::<16>::from(-1234);
+        let mut lhs = Lfsr3::<16>::maximal(seed).unwrap();
+        let mut rhs = lhs;
+        assert_eq!(lhs.next_number::<32>(), rhs.next_number::<32>());
+        assert_ne!(lhs.next_number::<32>(), Number::<32>::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn polynomial_feedback() {
+        let poly: Poly = "-0+-".parse().unwrap();
+        let lfsr = Lfsr3::<3>::from_poly(&poly, Number::<3>::ONE).unwrap();
+        assert_eq!(lfsr.feedback(), Lfsr3::<3>::maximal_feedback().unwrap());
+        assert!(Lfsr3::<2>::from_poly(&poly, Number::<2>::ONE).is_none());
+    }
+}
@@ -1,3 +1,58 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "generic_const_exprs", feature(generic_const_exprs))]
+#![cfg_attr(feature = "generic_const_exprs", allow(incomplete_features))]
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod big_ternary;
+#[cfg(feature = "alloc")]
+pub mod circuit;
+pub mod codes;
+#[cfg(feature = "std")]
+pub mod container;
+pub mod ct;
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod eval;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fixed;
+#[cfg(feature = "alloc")]
+pub mod float;
+#[cfg(feature = "alloc")]
+pub mod gf3;
+pub mod interval;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod lfsr;
+mod macros;
+#[cfg(feature = "alloc")]
+pub mod ml;
+pub mod modular;
 pub mod number;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod ratio;
+pub mod recoding;
 pub mod trit;
-mod sum_result;
\ No newline at end of file
+#[cfg(feature = "alloc")]
+pub mod trit_vec;
+#[cfg(feature = "alloc")]
+pub mod truth_table;
+pub mod tryte;
+pub mod vm;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod sum_result;
+#[cfg(test)]
+mod test_util;
+
+pub use error::Error;
+
+#[doc(hidden)]
+pub use macros::Literal as __BtLiteral;
\ No newline at end of file
@@ -0,0 +1,72 @@
+use crate::number::Number;
+
+/// Builds a [`Number`](crate::number::Number) constant from a trit string or
+/// an integer, validated at compile time.
+///
+/// The first argument is the width of the number in trits. The second is
+/// either a string of `-`/`0`/`+` trits or an integer expression, which are
+/// converted with [`Number::from_trit_str`](crate::number::Number::from_trit_str)
+/// and [`Number::from_i128`](crate::number::Number::from_i128) respectively.
+/// As the value is computed in a constant, an invalid trit or a value too large
+/// for the number is reported as a compile error rather than a runtime panic.
+/// ```
+/// use balanced_ternary::bt;
+/// use balanced_ternary::number::Number;
+///
+/// assert_eq!(bt!(8; "+0--"), Number::<8>::from("+0--"));
+/// assert_eq!(bt!(8; -42), -Number::<8>::from("+---0"));
+/// ```
+/// ```compile_fail
+/// use balanced_ternary::bt;
+///
+/// let _ = bt!(4; "+0x-");
+/// ```
+/// ```compile_fail
+/// use balanced_ternary::bt;
+///
+/// let _ = bt!(4; 41);
+/// ```
+#[macro_export]
+macro_rules! bt {
+    ($width:expr; $value:expr) => {{
+        const VALUE: $crate::number::Number<$width> = $crate::__BtLiteral($value).into_number::<$width>();
+        VALUE
+    }};
+}
+
+/// Dispatches the value given to [`bt!`] to the matching const constructor
+/// based on its type. Not part of the public API.
+#[doc(hidden)]
+pub struct Literal<T>(pub T);
+
+impl Literal<&str> {
+    pub const fn into_number<const N: usize>(self) -> Number<N> {
+        Number::<N>::from_trit_str(self.0)
+    }
+}
+
+impl Literal<i128> {
+    pub const fn into_number<const N: usize>(self) -> Number<N> {
+        Number::<N>::from_i128(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_from_trit_strings() {
+        assert_eq!(bt!(8; "+0--"), Number::<8>::from("+0--"));
+        assert_eq!(bt!(4; "----"), Number::<4>::from("----"));
+        assert_eq!(bt!(1; "0"), Number::<1>::from("0"));
+    }
+
+    #[test]
+    fn literals_from_integers() {
+        assert_eq!(bt!(8; 23), Number::<8>::from("+0--"));
+        assert_eq!(bt!(8; -42), Number::<8>::from("-+++0"));
+        assert_eq!(bt!(4; 40), Number::<4>::from("++++"));
+        assert_eq!(bt!(4; 3 * 3 - 1), Number::<4>::from("+0-"));
+    }
+}
@@ -0,0 +1,221 @@
+//! Ternary-weight machine learning kernels.
+//!
+//! Networks whose weights are restricted to -1, 0 and 1 replace every
+//! multiplication in a matrix product with an addition, a subtraction or
+//! nothing at all. [`TritMatrix`] stores such weights packed as two bit planes
+//! and multiplies them against activation vectors using only additions and
+//! subtractions.
+
+pub mod quantize;
+
+mod dot;
+
+pub use dot::{dot, dot_trit};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::trit::Trit;
+
+/// The number of weights held in each word of a bit plane.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A matrix of trit weights, packed as a bit plane of positive weights and a
+/// bit plane of negative weights with 64 weights per word.
+///
+/// Each row starts on a new word, so a row of n weights takes 2 * ceil(n / 64)
+/// words, about 2 bits per weight.
+/// ```
+/// # use balanced_ternary::ml::TritMatrix;
+/// # use balanced_ternary::trit::Trit;
+/// let weights = TritMatrix::from_trits(2, 3, &[
+///     Trit::POS, Trit::NEG, Trit::ZERO,
+///     Trit::ZERO, Trit::POS, Trit::POS
+/// ]);
+/// assert_eq!(weights.matvec_i8(&[10, 20, -5]), [-10, 15]);
+/// assert_eq!(weights.matvec_f32(&[0.5, 0.25, 2.0]), [0.25, 2.25]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TritMatrix {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    pos: Vec<u64>,
+    neg: Vec<u64>
+}
+
+impl TritMatrix {
+    /// Builds a matrix with every weight zero.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        let words_per_row = cols.div_ceil(WORD_BITS);
+        TritMatrix { rows, cols, words_per_row, pos: vec![0; rows * words_per_row], neg: vec![0; rows * words_per_row] }
+    }
+
+    /// Builds a matrix from its weights in row-major order.
+    ///
+    /// # Panics
+    /// Panics if there aren't exactly `rows * cols` weights.
+    pub fn from_trits(rows: usize, cols: usize, trits: &[Trit]) -> Self {
+        assert_eq!(trits.len(), rows * cols, "Expected {} weights for a {}x{} matrix", rows * cols, rows, cols);
+        TritMatrix::from_fn(rows, cols, |row, col| trits[row * cols + col])
+    }
+
+    /// Builds a matrix by calling a function with the row and column of each
+    /// weight.
+    pub fn from_fn(rows: usize, cols: usize, mut weight: impl FnMut(usize, usize) -> Trit) -> Self {
+        let mut matrix = TritMatrix::zeros(rows, cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                matrix.set(row, col, weight(row, col));
+            }
+        }
+        matrix
+    }
+
+    /// Returns the number of rows, the length of the output vectors.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns, the length of the input vectors.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the weight at a row and column.
+    ///
+    /// # Panics
+    /// Panics if the position is outside of the matrix.
+    pub fn get(&self, row: usize, col: usize) -> Trit {
+        let (word, mask) = self.locate(row, col);
+        match (self.pos[word] & mask != 0, self.neg[word] & mask != 0) {
+            (true, _) => Trit::POS,
+            (_, true) => Trit::NEG,
+            _ => Trit::ZERO
+        }
+    }
+
+    /// Sets the weight at a row and column.
+    ///
+    /// # Panics
+    /// Panics if the position is outside of the matrix.
+    pub fn set(&mut self, row: usize, col: usize, weight: Trit) {
+        let (word, mask) = self.locate(row, col);
+        self.pos[word] &= !mask;
+        self.neg[word] &= !mask;
+        match weight {
+            Trit::POS => self.pos[word] |= mask,
+            Trit::NEG => self.neg[word] |= mask,
+            Trit::ZERO => {}
+        }
+    }
+
+    /// Returns the number of non-zero weights.
+    pub fn nonzero_count(&self) -> usize {
+        self.pos.iter().chain(&self.neg).map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Multiplies the matrix by a vector of 8 bit activations, accumulating in
+    /// 32 bits. A row can hold up to 2^24 - 1 weights before its sum could
+    /// overflow.
+    ///
+    /// # Panics
+    /// Panics if the length of the input doesn't match the number of columns.
+    pub fn matvec_i8(&self, input: &[i8]) -> Vec<i32> {
+        self.matvec(input, |activation| i32::from(*activation))
+    }
+
+    /// Multiplies the matrix by a vector of floating point activations.
+    ///
+    /// # Panics
+    /// Panics if the length of the input doesn't match the number of columns.
+    pub fn matvec_f32(&self, input: &[f32]) -> Vec<f32> {
+        self.matvec(input, |activation| *activation)
+    }
+
+    /// Accumulates each row by adding the activations under its positive
+    /// weights and subtracting those under its negative weights, visiting only
+    /// the set bits of each plane.
+    fn matvec<T, A>(&self, input: &[T], widen: impl Fn(&T) -> A) -> Vec<A>
+    where A: Default + core::ops::AddAssign + core::ops::SubAssign {
+        assert_eq!(input.len(), self.cols, "Input of length {} doesn't match the {} columns of the matrix", input.len(), self.cols);
+
+        (0..self.rows).map(|row| {
+            let start = row * self.words_per_row;
+            let mut sum = A::default();
+            for (word, (pos, neg)) in self.pos[start..start + self.words_per_row].iter().zip(&self.neg[start..]).enumerate() {
+                let base = word * WORD_BITS;
+                let mut bits = *pos;
+                while bits != 0 {
+                    sum += widen(&input[base + bits.trailing_zeros() as usize]);
+                    bits &= bits - 1;
+                }
+                let mut bits = *neg;
+                while bits != 0 {
+                    sum -= widen(&input[base + bits.trailing_zeros() as usize]);
+                    bits &= bits - 1;
+                }
+            }
+            sum
+        }).collect()
+    }
+
+    fn locate(&self, row: usize, col: usize) -> (usize, u64) {
+        assert!(row < self.rows && col < self.cols, "Position ({}, {}) is outside of a {}x{} matrix", row, col, self.rows, self.cols);
+        (row * self.words_per_row + col / WORD_BITS, 1 << (col % WORD_BITS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+    fn weight(row: usize, col: usize) -> Trit {
+        TRITS[(row * row * 7 + col * 3 + col / 5) % 3]
+    }
+
+    #[test]
+    fn packing() {
+        let mut matrix = TritMatrix::from_fn(3, 130, weight);
+        assert_eq!((matrix.rows(), matrix.cols()), (3, 130));
+        for row in 0..3 {
+            for col in 0..130 {
+                assert_eq!(matrix.get(row, col), weight(row, col));
+            }
+        }
+
+        matrix.set(2, 129, Trit::NEG);
+        matrix.set(2, 129, Trit::POS);
+        assert_eq!(matrix.get(2, 129), Trit::POS);
+        assert_eq!(TritMatrix::zeros(4, 4).nonzero_count(), 0);
+
+        let trits: Vec<Trit> = (0..6).map(|idx| TRITS[idx % 3]).collect();
+        let matrix = TritMatrix::from_trits(2, 3, &trits);
+        assert_eq!(matrix.get(1, 0), Trit::NEG);
+        assert_eq!(matrix.nonzero_count(), 4);
+    }
+
+    #[test]
+    fn products_match_naive_multiplication() {
+        let matrix = TritMatrix::from_fn(7, 200, weight);
+        let input: Vec<i8> = (0..200).map(|col| (col * 37 % 255) as u8 as i8).collect();
+        let expected: Vec<i32> = (0..7).map(|row| {
+            (0..200).map(|col| i32::from(i8::from(weight(row, col))) * i32::from(input[col])).sum()
+        }).collect();
+        assert_eq!(matrix.matvec_i8(&input), expected);
+
+        let input: Vec<f32> = input.iter().map(|activation| f32::from(*activation) / 4.0).collect();
+        let expected: Vec<f32> = expected.iter().map(|sum| *sum as f32 / 4.0).collect();
+        assert_eq!(matrix.matvec_f32(&input), expected);
+
+        assert_eq!(TritMatrix::zeros(0, 5).matvec_i8(&[1; 5]), []);
+    }
+
+    #[test]
+    #[should_panic(expected = "Input of length 4 doesn't match the 5 columns of the matrix")]
+    fn mismatched_input() {
+        let _ = TritMatrix::zeros(2, 5).matvec_i8(&[0; 4]);
+    }
+}
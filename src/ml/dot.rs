@@ -0,0 +1,205 @@
+//! Dot products of trit vectors, accumulated in an `i64` so that no sum of
+//! fewer than 2^32 terms can overflow. With the `simd` feature enabled on
+//! x86_64 these use SSE2, and otherwise fall back to the scalar
+//! implementations.
+
+use crate::trit::Trit;
+
+/// Returns the dot product of a trit vector and a vector of integers, the sum
+/// of the values with a positive trit minus the sum of the values with a
+/// negative trit.
+/// ```
+/// # use balanced_ternary::ml::dot;
+/// # use balanced_ternary::trit::Trit;
+/// let trits = [Trit::POS, Trit::POS, Trit::NEG, Trit::ZERO];
+/// assert_eq!(dot(&trits, &[i32::MAX, i32::MAX, i32::MIN, 7]), 3 * i32::MAX as i64 + 1);
+/// ```
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn dot(trits: &[Trit], values: &[i32]) -> i64 {
+    assert_eq!(trits.len(), values.len(), "Trit and value slices must have the same length");
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    return sse2::dot(trits, values);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    return scalar::dot(trits, values);
+}
+
+/// Returns the dot product of two trit vectors, the number of positions whose
+/// trits are equal and non-zero minus the number whose trits are opposite.
+/// ```
+/// # use balanced_ternary::ml::dot_trit;
+/// # use balanced_ternary::trit::Trit;
+/// let lhs = [Trit::POS, Trit::NEG, Trit::NEG, Trit::ZERO];
+/// let rhs = [Trit::POS, Trit::NEG, Trit::POS, Trit::POS];
+/// assert_eq!(dot_trit(&lhs, &rhs), 1);
+/// ```
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn dot_trit(lhs: &[Trit], rhs: &[Trit]) -> i64 {
+    assert_eq!(lhs.len(), rhs.len(), "Trit slices must have the same length");
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    return sse2::dot_trit(lhs, rhs);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    return scalar::dot_trit(lhs, rhs);
+}
+
+mod scalar {
+    use super::*;
+
+    pub(crate) fn dot(trits: &[Trit], values: &[i32]) -> i64 {
+        trits.iter().zip(values).fold(0, |sum, (trit, value)| match trit {
+            Trit::NEG => sum - *value as i64,
+            Trit::ZERO => sum,
+            Trit::POS => sum + *value as i64
+        })
+    }
+
+    pub(crate) fn dot_trit(lhs: &[Trit], rhs: &[Trit]) -> i64 {
+        lhs.iter().zip(rhs).map(|(lhs, rhs)| (*lhs * *rhs).to_i8() as i64).sum()
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    use core::arch::x86_64::{
+        __m128i, _mm_add_epi64, _mm_and_si128, _mm_cmpeq_epi32, _mm_cmpeq_epi8, _mm_loadu_si128,
+        _mm_movemask_epi8, _mm_set1_epi32, _mm_set1_epi8, _mm_set_epi32, _mm_setzero_si128,
+        _mm_srai_epi32, _mm_storeu_si128, _mm_unpackhi_epi32, _mm_unpacklo_epi32
+    };
+
+    use super::*;
+
+    const LANES: usize = 16;
+
+    // `Trit` is `repr(u8)` with the values 0, 1 and 2 for the negative, zero and
+    // positive trits, so a chunk of trits can be loaded directly as bytes.
+
+    fn load(trits: &[Trit]) -> __m128i {
+        debug_assert!(trits.len() >= LANES);
+        // SAFETY: the slice holds at least 16 single byte trits, and SSE2 is
+        // always available on x86_64
+        unsafe { _mm_loadu_si128(trits.as_ptr().cast()) }
+    }
+
+    /// Sign extends the four `i32` lanes of a vector and adds them to the two
+    /// `i64` lanes of an accumulator.
+    ///
+    /// # Safety
+    /// SSE2 must be available, which it always is on x86_64.
+    unsafe fn add_widened(sum: __m128i, values: __m128i) -> __m128i {
+        let signs = _mm_srai_epi32(values, 31);
+        let sum = _mm_add_epi64(sum, _mm_unpacklo_epi32(values, signs));
+        _mm_add_epi64(sum, _mm_unpackhi_epi32(values, signs))
+    }
+
+    fn horizontal_sum(sum: __m128i) -> i64 {
+        let mut lanes = [0_i64; 2];
+        // SAFETY: storing into a 16 byte array, and SSE2 is always available
+        // on x86_64
+        unsafe { _mm_storeu_si128(lanes.as_mut_ptr().cast(), sum) };
+        lanes[0] + lanes[1]
+    }
+
+    pub(crate) fn dot(trits: &[Trit], values: &[i32]) -> i64 {
+        // The values under positive and negative trits are summed separately,
+        // as negating them in 32 bits would overflow for `i32::MIN`
+        let trit_chunks = trits.chunks_exact(4);
+        let value_chunks = values.chunks_exact(4);
+        let (trit_rest, value_rest) = (trit_chunks.remainder(), value_chunks.remainder());
+
+        // SAFETY: SSE2 is always available on x86_64, and each chunk of values
+        // holds exactly four `i32`s
+        let (pos_sum, neg_sum) = unsafe {
+            let (mut pos_sum, mut neg_sum) = (_mm_setzero_si128(), _mm_setzero_si128());
+            for (trit_chunk, value_chunk) in trit_chunks.zip(value_chunks) {
+                let trits = _mm_set_epi32(
+                    trit_chunk[3] as i32, trit_chunk[2] as i32, trit_chunk[1] as i32, trit_chunk[0] as i32
+                );
+                let values = _mm_loadu_si128(value_chunk.as_ptr().cast());
+                let pos = _mm_and_si128(_mm_cmpeq_epi32(trits, _mm_set1_epi32(Trit::POS as i32)), values);
+                let neg = _mm_and_si128(_mm_cmpeq_epi32(trits, _mm_set1_epi32(Trit::NEG as i32)), values);
+                pos_sum = add_widened(pos_sum, pos);
+                neg_sum = add_widened(neg_sum, neg);
+            }
+            (pos_sum, neg_sum)
+        };
+        horizontal_sum(pos_sum) - horizontal_sum(neg_sum) + scalar::dot(trit_rest, value_rest)
+    }
+
+    pub(crate) fn dot_trit(lhs: &[Trit], rhs: &[Trit]) -> i64 {
+        let lhs_chunks = lhs.chunks_exact(LANES);
+        let rhs_chunks = rhs.chunks_exact(LANES);
+        let (lhs_rest, rhs_rest) = (lhs_chunks.remainder(), rhs_chunks.remainder());
+
+        // Equal non-zero trits add one and opposite non-zero trits subtract
+        // one, so each chunk contributes the difference of two lane counts
+        let mut sum = 0;
+        for (lhs_chunk, rhs_chunk) in lhs_chunks.zip(rhs_chunks) {
+            // SAFETY: SSE2 is always available on x86_64
+            let (equal, lhs_zero, rhs_zero) = unsafe {
+                let (lhs, rhs) = (load(lhs_chunk), load(rhs_chunk));
+                let zero = _mm_set1_epi8(Trit::ZERO as i8);
+                (
+                    _mm_movemask_epi8(_mm_cmpeq_epi8(lhs, rhs)) as u32,
+                    _mm_movemask_epi8(_mm_cmpeq_epi8(lhs, zero)) as u32,
+                    _mm_movemask_epi8(_mm_cmpeq_epi8(rhs, zero)) as u32
+                )
+            };
+            let agree = equal & !lhs_zero;
+            let oppose = !(equal | lhs_zero | rhs_zero) & 0xFFFF;
+            sum += agree.count_ones() as i64 - oppose.count_ones() as i64;
+        }
+        sum + scalar::dot_trit(lhs_rest, rhs_rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::test_util::trit_pattern;
+
+    fn pattern(len: usize, seed: usize) -> Vec<Trit> {
+        trit_pattern(len, seed).collect()
+    }
+
+    #[test]
+    fn kernels_match_scalar() {
+        // Lengths either side of whole chunks of lanes
+        for len in [0, 1, 3, 4, 5, 15, 16, 17, 40, 64, 100] {
+            for seed in 1..8 {
+                let lhs = pattern(len, seed);
+                let rhs = pattern(len, seed + 3);
+                let values: Vec<i32> = (0..len).map(|i| (i as i32 - 50) * 12345 * seed as i32).collect();
+
+                assert_eq!(dot(&lhs, &values), scalar::dot(&lhs, &values));
+                assert_eq!(dot_trit(&lhs, &rhs), scalar::dot_trit(&lhs, &rhs));
+                let nonzero = lhs.iter().filter(|trit| **trit != Trit::ZERO).count();
+                assert_eq!(dot_trit(&lhs, &lhs), nonzero as i64);
+            }
+        }
+    }
+
+    #[test]
+    fn wide_accumulator() {
+        // Sums far outside the range of an `i32` are exact
+        let trits = pattern(1000, 5);
+        let extremes: Vec<i32> = trits.iter().map(|trit| match trit {
+            Trit::NEG => i32::MIN,
+            _ => i32::MAX
+        }).collect();
+        let pos = trits.iter().filter(|trit| **trit == Trit::POS).count() as i64;
+        let neg = trits.iter().filter(|trit| **trit == Trit::NEG).count() as i64;
+        assert_eq!(dot(&trits, &extremes), pos * i32::MAX as i64 - neg * i32::MIN as i64);
+        assert_eq!(dot(&trits, &[i32::MIN; 1000]), (pos - neg) * i32::MIN as i64);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trit slices must have the same length")]
+    fn mismatched_lengths() {
+        dot_trit(&[Trit::POS], &[]);
+    }
+}
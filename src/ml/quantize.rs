@@ -0,0 +1,133 @@
+//! Quantisation of floating point weights to trits and back.
+//!
+//! Weights are divided by a scale and rounded to -1, 0 or 1, so that each
+//! weight is approximated by its trit times the scale. The absmean scheme of
+//! BitNet b1.58 takes the mean absolute weight as the scale.
+
+use alloc::vec::Vec;
+
+use crate::ml::TritMatrix;
+use crate::trit::Trit;
+
+/// Returns the mean of the absolute values of the weights, or zero if there
+/// are none.
+pub fn absmean_scale(weights: &[f32]) -> f32 {
+    if weights.is_empty() {
+        return 0.0;
+    }
+    weights.iter().map(|weight| weight.abs()).sum::<f32>() / weights.len() as f32
+}
+
+/// Quantises weights with the absmean scale, returning the trits along with
+/// the scale to dequantise them by.
+/// ```
+/// # use balanced_ternary::ml::quantize;
+/// # use balanced_ternary::trit::Trit;
+/// let (trits, scale) = quantize::quantize_absmean(&[1.5, -0.25, -1.0, 0.75]);
+/// assert_eq!(scale, 0.875);
+/// assert_eq!(trits, [Trit::POS, Trit::ZERO, Trit::NEG, Trit::POS]);
+/// assert_eq!(quantize::dequantize(&trits, scale), [0.875, 0.0, -0.875, 0.875]);
+/// ```
+pub fn quantize_absmean(weights: &[f32]) -> (Vec<Trit>, f32) {
+    let scale = absmean_scale(weights);
+    (quantize_nearest(weights, scale), scale)
+}
+
+/// Quantises weights by rounding each weight divided by the scale to the
+/// nearest of -1, 0 and 1, with halves rounded away from zero. Every weight
+/// becomes zero if the scale is zero.
+pub fn quantize_nearest(weights: &[f32], scale: f32) -> Vec<Trit> {
+    weights.iter().map(|weight| {
+        match scaled(*weight, scale) {
+            value if value >= 0.5 => Trit::POS,
+            value if value <= -0.5 => Trit::NEG,
+            _ => Trit::ZERO
+        }
+    }).collect()
+}
+
+/// Quantises weights by stochastic rounding, which is unbiased: each weight
+/// divided by the scale is clamped to between -1 and 1, then becomes its sign
+/// with a probability equal to its magnitude and zero otherwise. Every weight
+/// becomes zero if the scale is zero.
+/// * `weights` - The weights to quantise
+/// * `scale` - The scale to divide the weights by
+/// * `uniform` - A source of random numbers uniformly distributed in `0.0..1.0`,
+///   called exactly once per weight in order
+pub fn quantize_stochastic(weights: &[f32], scale: f32, mut uniform: impl FnMut() -> f32) -> Vec<Trit> {
+    weights.iter().map(|weight| {
+        let value = scaled(*weight, scale);
+        let sign = if value < 0.0 {Trit::NEG} else {Trit::POS};
+        if uniform() < value.abs() {sign} else {Trit::ZERO}
+    }).collect()
+}
+
+/// Multiplies each trit by the scale.
+pub fn dequantize(trits: &[Trit], scale: f32) -> Vec<f32> {
+    trits.iter().map(|trit| f32::from(i8::from(*trit)) * scale).collect()
+}
+
+impl TritMatrix {
+    /// Quantises a matrix of weights in row-major order with the absmean
+    /// scale, returning the matrix along with its scale.
+    ///
+    /// # Panics
+    /// Panics if there aren't exactly `rows * cols` weights.
+    pub fn quantize_absmean(rows: usize, cols: usize, weights: &[f32]) -> (Self, f32) {
+        let (trits, scale) = quantize_absmean(weights);
+        (TritMatrix::from_trits(rows, cols, &trits), scale)
+    }
+}
+
+/// Divides a weight by the scale, giving zero for a scale of zero.
+fn scaled(weight: f32, scale: f32) -> f32 {
+    if scale == 0.0 {0.0} else {weight / scale}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rounding() {
+        let weights = [2.0, 1.0, 0.5, 0.49, 0.0, -0.5, -0.51, -7.0];
+        assert_eq!(quantize_nearest(&weights, 1.0), [Trit::POS, Trit::POS, Trit::POS, Trit::ZERO, Trit::ZERO, Trit::NEG, Trit::NEG, Trit::NEG]);
+        assert_eq!(quantize_nearest(&weights, 0.0), [Trit::ZERO; 8]);
+
+        assert_eq!(absmean_scale(&[]), 0.0);
+        assert_eq!(quantize_absmean(&[0.0; 3]), (vec![Trit::ZERO; 3], 0.0));
+        assert_eq!(dequantize(&[Trit::NEG, Trit::POS], 1.5), [-1.5, 1.5]);
+    }
+
+    #[test]
+    fn stochastic_rounding_is_unbiased() {
+        // A low-discrepancy sequence stands in for a random number generator
+        let mut step = 0_u32;
+        let mut uniform = || {
+            step += 1;
+            (step as f32 * 0.618_034).fract()
+        };
+
+        for weight in [0.3_f32, -0.8, 1.0, -2.5, 0.0] {
+            let trits = quantize_stochastic(&[weight; 1000], 1.0, &mut uniform);
+            let mean = trits.iter().map(|trit| f32::from(i8::from(*trit))).sum::<f32>() / 1000.0;
+            assert!((mean - weight.clamp(-1.0, 1.0)).abs() < 0.01, "Mean of {} was {}", weight, mean);
+            assert!(trits.iter().all(|trit| *trit == Trit::ZERO || f32::from(i8::from(*trit)) * weight > 0.0));
+        }
+    }
+
+    #[test]
+    fn quantized_matrices() {
+        let weights: Vec<f32> = (0..12).map(|idx| (idx as f32 - 5.5) / 4.0).collect();
+        let (matrix, scale) = TritMatrix::quantize_absmean(3, 4, &weights);
+        assert_eq!(scale, 0.75);
+        assert_eq!(matrix.get(0, 0), Trit::NEG);
+        assert_eq!(matrix.get(1, 1), Trit::ZERO);
+        assert_eq!(matrix.get(2, 3), Trit::POS);
+
+        // The scaled product approximates the product with the original weights
+        let input = [1.0, 2.0, 3.0, 4.0];
+        let approximate: Vec<f32> = matrix.matvec_f32(&input).iter().map(|sum| sum * scale).collect();
+        assert_eq!(approximate, [-7.5, 2.25, 7.5]);
+    }
+}
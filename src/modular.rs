@@ -0,0 +1,214 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::number::Number;
+
+/// An element of the ring of integers modulo M, held as a number of N trits.
+///
+/// The value is always kept as its canonical residue, the unique number in
+/// the range `0..M` congruent to it, so the derived equality compares ring
+/// elements. Every operation reduces as it goes and none of them can overflow.
+/// The modulus must be positive and fit in N trits, which is checked at
+/// compile time.
+/// ```
+/// # use balanced_ternary::modular::Mod;
+/// # use balanced_ternary::number::Number;
+/// let x = Mod::<8, 13>::new(Number::<8>::from(-3));
+/// assert_eq!(x.value(), Number::<8>::from(10));
+/// assert_eq!(x * x.inverse().unwrap(), Mod::ONE);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mod<const N: usize, const M: u128> {
+    value: Number<N>
+}
+
+impl <const N: usize, const M: u128> Mod<N, M> {
+    // Building the modulus panics if it doesn't fit in N trits
+    const MODULUS: Number<N> = {
+        assert!(M > 0, "Modulus must be positive");
+        assert!(M <= i128::MAX as u128, "Modulus must fit in an i128");
+        Number::<N>::from_i128(M as i128)
+    };
+
+    /// The additive identity.
+    pub const ZERO: Self = Mod { value: Number::<N>::ZERO };
+
+    /// The multiplicative identity, which is zero in the trivial ring modulo 1.
+    pub const ONE: Self = Mod { value: if M == 1 {Number::<N>::ZERO} else {Number::<N>::ONE} };
+
+    /// Builds the ring element congruent to `value`.
+    pub fn new(value: Number<N>) -> Self {
+        Mod { value: value.mod_reduce(Self::MODULUS) }
+    }
+
+    /// Returns the canonical residue, in the range `0..M`.
+    pub fn value(self) -> Number<N> {
+        self.value
+    }
+
+    /// Returns the modulus as a number.
+    pub fn modulus() -> Number<N> {
+        Self::MODULUS
+    }
+
+    /// Returns the multiplicative inverse, or `None` if the value shares a
+    /// factor with the modulus.
+    pub fn inverse(self) -> Option<Self> {
+        self.value.mod_inverse(Self::MODULUS).map(|value| Mod { value })
+    }
+
+    /// Raises the value to the power of `exponent` by repeated squaring.
+    /// * `exponent` - The power to raise the value to
+    pub fn pow(self, mut exponent: u32) -> Self {
+        let mut base = self;
+        let mut output = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                output *= base;
+            }
+            exponent >>= 1;
+            base *= base;
+        }
+        output
+    }
+}
+
+impl <const N: usize, const M: u128> From<Number<N>> for Mod<N, M> {
+    fn from(value: Number<N>) -> Self {
+        Mod::new(value)
+    }
+}
+
+impl <const N: usize, const M: u128> Neg for Mod<N, M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Mod { value: Number::<N>::ZERO.sub_mod(self.value, Self::MODULUS) }
+    }
+}
+
+impl <const N: usize, const M: u128> Add for Mod<N, M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Mod { value: self.value.add_mod(rhs.value, Self::MODULUS) }
+    }
+}
+
+impl <const N: usize, const M: u128> Sub for Mod<N, M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Mod { value: self.value.sub_mod(rhs.value, Self::MODULUS) }
+    }
+}
+
+impl <const N: usize, const M: u128> Mul for Mod<N, M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Mod { value: self.value.mul_mod(rhs.value, Self::MODULUS) }
+    }
+}
+
+impl <const N: usize, const M: u128> AddAssign for Mod<N, M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl <const N: usize, const M: u128> SubAssign for Mod<N, M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl <const N: usize, const M: u128> MulAssign for Mod<N, M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl <const N: usize, const M: u128> fmt::Display for Mod<N, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+/// Sets the residue to zero.
+#[cfg(feature = "zeroize")]
+impl <const N: usize, const M: u128> zeroize::Zeroize for Mod<N, M> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Mod97 = Mod<8, 97>;
+
+    fn residue(value: i32) -> Mod97 {
+        Mod97::new(Number::<8>::from(value))
+    }
+
+    #[test]
+    fn canonical_residues() {
+        assert_eq!(residue(-1).value(), Number::<8>::from(96));
+        assert_eq!(residue(97), Mod97::ZERO);
+        assert_eq!(residue(3280).value(), Number::<8>::from(3280 % 97));
+        assert_eq!(Mod97::modulus(), Number::<8>::from("++--+"));
+        assert_eq!(Mod::<8, 1>::ONE, Mod::<8, 1>::ZERO);
+    }
+
+    #[test]
+    fn ring_operations_match_integers() {
+        for lhs in (-200..200).step_by(7) {
+            for rhs in (-150..150).step_by(11) {
+                let expected = |value: i32| residue(value.rem_euclid(97));
+                assert_eq!(residue(lhs) + residue(rhs), expected(lhs + rhs));
+                assert_eq!(residue(lhs) - residue(rhs), expected(lhs - rhs));
+                assert_eq!(residue(lhs) * residue(rhs), expected(lhs * rhs));
+            }
+            assert_eq!(-residue(lhs), residue(-lhs));
+        }
+
+        let mut value = residue(5);
+        value *= residue(40);
+        value += residue(3);
+        value -= residue(10);
+        assert_eq!(value, residue(193));
+    }
+
+    #[test]
+    fn inverses_and_powers() {
+        for value in 1..97 {
+            let inverse = residue(value).inverse().unwrap();
+            assert_eq!(residue(value) * inverse, Mod97::ONE);
+        }
+        assert_eq!(Mod97::ZERO.inverse(), None);
+        assert_eq!(Mod::<8, 12>::new(Number::<8>::from(8)).inverse(), None);
+
+        // Fermat's little theorem
+        assert_eq!(residue(5).pow(96), Mod97::ONE);
+        assert_eq!(residue(5).pow(95), residue(5).inverse().unwrap());
+        assert_eq!(residue(-2).pow(0), Mod97::ONE);
+        assert_eq!(residue(-2).pow(7), residue(-128));
+
+        // A modulus at the top of the range doesn't overflow
+        let large = Mod::<8, 3280>::new(Number::<8>::from(3279));
+        assert_eq!(large * large, Mod::ONE);
+        assert_eq!(large + large, Mod::new(Number::<8>::from(-2)));
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = Mod97::new(Number::<8>::from(50));
+        secret.zeroize();
+        assert_eq!(secret, Mod97::ZERO);
+    }
+}
@@ -1,16 +1,119 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+#[cfg(feature = "alloc")]
+mod byte_packing;
 mod conversions;
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+mod division;
+mod float_conversions;
+mod formatting;
+mod gray;
 mod binary_ops;
-
-use std::iter::Sum;
-use std::ops::{Neg, Shl, ShlAssign};
-
+mod checked_ops;
+mod comparison;
+mod const_ops;
+pub(crate) mod decimal;
+mod digit_groups;
+mod heptavintimal;
+mod indexing;
+mod inspection;
+mod iteration;
+pub(crate) mod kernels;
+#[cfg(feature = "alloc")]
+mod limbs;
+mod metrics;
+#[cfg(feature = "generic_const_exprs")]
+mod mixed_width;
+mod modular;
+mod nonary;
+#[cfg(feature = "num-traits")]
+mod num_impls;
+mod number_theory;
+mod overflowing;
+mod packed;
+mod parsing;
+mod range;
+#[cfg(feature = "rand")]
+mod rand_impls;
+mod resize;
+mod roots;
+mod rotation;
+mod saturating;
+mod scalar_ops;
+mod sign;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "alloc")]
+pub(crate) mod toom;
+mod tritwise;
+mod try_ops;
+#[cfg(feature = "alloc")]
+mod two_bit;
+mod wrapping;
+
+use core::cmp::Ordering;
+use core::iter::{Product, Sum};
+use core::ops::{Neg, Shl, ShlAssign, Shr, ShrAssign};
+
+use crate::error::Error;
 use crate::trit::Trit;
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicNumber;
+#[cfg(feature = "alloc")]
+pub use byte_packing::UnpackBytesError;
+pub use conversions::{ParseTernaryError, TryFromIntError};
+pub use division::RoundingMode;
+pub use float_conversions::FloatConversionError;
+pub use formatting::{FormatOptions, FormattedNumber};
+#[cfg(feature = "alloc")]
+pub use limbs::LimbNumber;
+#[cfg(feature = "generic_const_exprs")]
+pub use mixed_width::max_width;
+#[cfg(feature = "num-traits")]
+pub use num_impls::FromStrRadixError;
+pub use packed::PackedNumber;
+pub use parsing::ParseOptions;
+pub use range::NumberRange;
+pub use saturating::Saturating;
+pub use wrapping::Wrapping;
+
+// Every value has exactly one array of trits, so the derived `Hash` over the
+// trits agrees with `Eq`
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
 pub struct Number<const N: usize> ([Trit; N]);
 
 impl<const N: usize> Number<N> {
-    const ZERO: Number<N> = Number::<N>([Trit::ZERO; N]);
+    /// The number zero, with every trit set to zero.
+    pub const ZERO: Number<N> = Number::<N>([Trit::ZERO; N]);
+
+    /// The number one. Using this constant with a width of zero trits is a
+    /// compile-time error.
+    pub const ONE: Number<N> = Number::<N>::ZERO.with_lowest_trit(Trit::POS);
+
+    /// The number negative one. Using this constant with a width of zero trits
+    /// is a compile-time error.
+    pub const NEG_ONE: Number<N> = Number::<N>::ZERO.with_lowest_trit(Trit::NEG);
+
+    /// The largest value representable in N trits, (3^N - 1) / 2, with every
+    /// trit set to positive.
+    pub const MAX: Number<N> = Number::<N>([Trit::POS; N]);
+
+    /// The smallest value representable in N trits, -(3^N - 1) / 2, with every
+    /// trit set to negative. The range of a balanced ternary number is symmetric
+    /// around zero, so `MIN == -MAX` and every value has a representable
+    /// negation.
+    pub const MIN: Number<N> = Number::<N>([Trit::NEG; N]);
+
+    const fn with_lowest_trit(mut self, trit: Trit) -> Self {
+        self.0[N-1] = trit;
+        self
+    }
 
     /// Builds a balanced ternary number of length N from the supplied iterator of trits. The
     /// iterator should be in reverse order to allow the number to be populated from least-
@@ -32,7 +135,67 @@ impl<const N: usize> Number<N> {
         }
         output       
     }
-    
+
+    /// Builds a balanced ternary number directly from an array of trits, ordered from the
+    /// most significant trit to the least.
+    /// * `trits` - The trits of the number in big-endian order
+    pub const fn from_trits(trits: [Trit; N]) -> Self {
+        Number::<N>(trits)
+    }
+
+    /// Builds a balanced ternary number from a slice of trits ordered from the most
+    /// significant trit to the least (the same order in which they are written). If fewer
+    /// than N trits are provided then the higher-order trits will be padded with zeros.
+    /// * `trits` - The trits of the number in big-endian order
+    ///
+    /// # Panics
+    /// Panics if more than N trits are provided.
+    pub fn from_trits_be(trits: &[Trit]) -> Self {
+        assert!(trits.len() <= N, "Too many trits supplied for number of {} trits", N);
+
+        let mut output = Number::<N>::ZERO;
+        output.0[N-trits.len()..].copy_from_slice(trits);
+        output
+    }
+
+    /// Builds a balanced ternary number from a slice of trits ordered from the most
+    /// significant trit to the least, as [`Number::from_trits_be`] does, but returns an
+    /// error rather than panicking if there are too many trits.
+    /// * `trits` - The trits of the number in big-endian order
+    /// ```
+    /// # use balanced_ternary::Error;
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::trit::Trit;
+    /// let trits = [Trit::POS; 11];
+    /// assert_eq!(Number::<8>::try_from_trits(&trits), Err(Error::TooManyTrits { length: 11, capacity: 8 }));
+    /// assert_eq!(Number::<8>::try_from_trits(&trits[..2]), Ok(Number::<8>::from("++")));
+    /// ```
+    pub fn try_from_trits(trits: &[Trit]) -> Result<Self, Error> {
+        if trits.len() > N {
+            return Err(Error::TooManyTrits { length: trits.len(), capacity: N });
+        }
+        Ok(Number::<N>::from_trits_be(trits))
+    }
+
+    /// Builds a balanced ternary number from a slice of trits ordered from the least
+    /// significant trit to the most (the same order as indexing and iteration). If fewer
+    /// than N trits are provided then the higher-order trits will be padded with zeros.
+    /// * `trits` - The trits of the number in little-endian order
+    ///
+    /// # Panics
+    /// Panics if more than N trits are provided.
+    pub fn from_trits_le(trits: &[Trit]) -> Self {
+        assert!(trits.len() <= N, "Too many trits supplied for number of {} trits", N);
+
+        Number::<N>::from_rev_iter(trits.iter().copied())
+    }
+
+    /// Views the trits of this number as an array ordered from the most significant trit
+    /// to the least.
+    pub const fn as_trits(&self) -> &[Trit; N] {
+        &self.0
+    }
+
     pub fn inc(&mut self) {
         *self += Trit::POS;
     }
@@ -45,14 +208,73 @@ impl<const N: usize> Number<N> {
 impl <const N: usize> Neg for Number<N> {
     type Output = Self;
     
+    fn neg(mut self) -> Self::Output {
+        kernels::negate(&mut self.0);
+        self
+    }
+}
+
+impl <const N: usize> Neg for &Number<N> {
+    type Output = Number<N>;
+
     fn neg(self) -> Self::Output {
-        Self(self.0.map(Trit::negate))
+        -*self
+    }
+}
+
+/// Numbers are ordered by comparing their trits from the most significant. A
+/// balanced ternary number is unique, and each trit outweighs all of the trits
+/// below it put together, so the first differing trit decides the ordering.
+impl <const N: usize> Ord for Number<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        kernels::compare(&self.0, &other.0)
+    }
+}
+
+impl <const N: usize> PartialOrd for Number<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl <const N: usize> Sum for Number<N> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Number::<N>::ZERO, std::ops::Add::add)
+        iter.fold(Number::<N>::ZERO, core::ops::Add::add)
+    }
+}
+
+impl <'a, const N: usize> Sum<&'a Number<N>> for Number<N> {
+    fn sum<I: Iterator<Item = &'a Number<N>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Sums the values of a stream of trits, wrapping if the total doesn't fit in
+/// N trits.
+impl <const N: usize> Sum<Trit> for Number<N> {
+    fn sum<I: Iterator<Item = Trit>>(iter: I) -> Self {
+        iter.fold(Number::<N>::ZERO, |mut sum, trit| {
+            sum += trit;
+            sum
+        })
+    }
+}
+
+impl <'a, const N: usize> Sum<&'a Trit> for Number<N> {
+    fn sum<I: Iterator<Item = &'a Trit>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl <const N: usize> Product for Number<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Number::<N>::ONE, core::ops::Mul::mul)
+    }
+}
+
+impl <'a, const N: usize> Product<&'a Number<N>> for Number<N> {
+    fn product<I: Iterator<Item = &'a Number<N>>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
@@ -90,6 +312,54 @@ impl <const N: usize> ShlAssign<usize> for Number<N> {
     }
 }
 
+/// Right shift is division by 3^positions. Simply discarding the least-significant
+/// trits of a balanced ternary number rounds the quotient to the nearest integer,
+/// rather than towards zero or negative infinity as with binary integers. There are
+/// never any ties to break, as a power of three is odd and so an integer can never
+/// lie exactly halfway between two of its multiples.
+impl <const N: usize> Shr<usize> for Number<N> {
+    type Output = Self;
+
+    fn shr(self, positions: usize) -> Self::Output {
+        let mut out = Number::<N>::ZERO;
+
+        // Early exit if we right-shift far enough that our number just becomes zero
+        if positions >= N {
+            return out;
+        }
+
+        // Right shift is just copying the correct trits from our value to the
+        // end of our zero-initialised output number
+        out.0[positions..].copy_from_slice(&self.0[..(N-positions)]);
+        out
+    }
+}
+
+impl <const N: usize> ShrAssign<usize> for Number<N> {
+    fn shr_assign(&mut self, positions: usize) {
+        // Early exit if we right-shift far enough that our number just becomes zero
+        if positions >= N {
+            self.0.fill(Trit::ZERO);
+            return;
+        }
+
+        // An in-place right-shift is achieved by rotating our value array by the
+        // specified number of positions and then zeroing out the most-significant
+        // trits.
+        self.0.rotate_right(positions);
+        self.0[..positions].fill(Trit::ZERO);
+    }
+}
+
+/// Sets every trit to zero. As numbers are `Copy` they can't be zeroized on
+/// drop, so copies must be zeroized separately.
+#[cfg(feature = "zeroize")]
+impl <const N: usize> zeroize::Zeroize for Number<N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +395,49 @@ mod tests {
         assert!(num_17 >= num_17_copy);
     }
 
+    #[test]
+    fn constants() {
+        assert_eq!(Number::<4>::ZERO, Number::<4>::from("0000"));
+        assert_eq!(Number::<4>::ONE, Number::<4>::from("+"));
+        assert_eq!(Number::<4>::NEG_ONE, Number::<4>::from("-"));
+        assert_eq!(Number::<4>::MAX, Number::<4>::from("++++"));
+        assert_eq!(Number::<4>::MIN, Number::<4>::from("----"));
+
+        assert_eq!(Number::<4>::MIN, -Number::<4>::MAX);
+        assert_eq!(Number::<4>::NEG_ONE, -Number::<4>::ONE);
+        assert_eq!(i32::try_from(Number::<4>::MAX), Ok(40));
+        assert_eq!(i32::try_from(Number::<4>::MIN), Ok(-40));
+    }
+
+    #[test]
+    fn construction_from_trits() {
+        let num_23 = Number::<6>::from("+0--");
+        let big_endian = [Trit::POS, Trit::ZERO, Trit::NEG, Trit::NEG];
+        let little_endian = [Trit::NEG, Trit::NEG, Trit::ZERO, Trit::POS];
+
+        assert_eq!(Number::<6>::from_trits_be(&big_endian), num_23);
+        assert_eq!(Number::<6>::from_trits_le(&little_endian), num_23);
+        assert_eq!(Number::<4>::from_trits(big_endian), Number::<4>::from("+0--"));
+        assert_eq!(Number::<6>::from_trits_be(&[]), Number::<6>::ZERO);
+        assert_eq!(Number::<6>::try_from_trits(&big_endian), Ok(num_23));
+        assert_eq!(Number::<3>::try_from_trits(&big_endian), Err(Error::TooManyTrits { length: 4, capacity: 3 }));
+
+        assert_eq!(num_23.as_trits(), &[Trit::ZERO, Trit::ZERO, Trit::POS, Trit::ZERO, Trit::NEG, Trit::NEG]);
+        assert_eq!(Number::<6>::from_trits(*num_23.as_trits()), num_23);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many trits supplied for number of 3 trits")]
+    fn construction_from_too_many_trits() {
+        let _ = Number::<3>::from_trits_le(&[Trit::POS; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "11 trits supplied for number of 8 trits")]
+    fn construction_from_over_long_string() {
+        let _ = Number::<8>::from("+++++++++++");
+    }
+
     #[test]
     fn increments() {
         let num_neg_one = Number::<8>::from("-");
@@ -205,6 +518,35 @@ mod tests {
         assert_eq!(num_neg_8 << 8, Number::<8>::from("00000000"));
     }
 
+    #[test]
+    fn right_shift() {
+        let num_50 = Number::<8>::from("+-0--"); // 50
+
+        assert_eq!(num_50 >> 1, Number::<8>::from("+-0-")); // 50 / 3 = 16.67 rounds to 17
+        assert_eq!(num_50 >> 2, Number::<8>::from("+-0"));  // 50 / 9 = 5.56 rounds to 6
+        assert_eq!(num_50 >> 3, Number::<8>::from("+-"));   // 50 / 27 = 1.85 rounds to 2
+        assert_eq!(num_50 >> 4, Number::<8>::from("+"));    // 50 / 81 = 0.62 rounds to 1
+        assert_eq!(num_50 >> 5, Number::<8>::ZERO);
+        assert_eq!(num_50 >> 8, Number::<8>::ZERO);
+
+        // Rounding to nearest is symmetric for negative numbers
+        assert_eq!(-num_50 >> 1, Number::<8>::from("-+0+")); // -50 / 3 = -16.67 rounds to -17
+    }
+
+    #[test]
+    fn in_place_right_shift() {
+        let mut shifting_num = Number::<8>::from("-0+00000");
+
+        shifting_num >>= 1;
+        assert_eq!(shifting_num, Number::<8>::from("0-0+0000"));
+        shifting_num >>= 3;
+        assert_eq!(shifting_num, Number::<8>::from("0000-0+0"));
+        shifting_num >>= 1;
+        assert_eq!(shifting_num, Number::<8>::from("00000-0+"));
+        shifting_num >>= 8;
+        assert_eq!(shifting_num, Number::<8>::ZERO);
+    }
+
     #[test]
     fn in_place_left_shift() {
         let mut shifting_num = Number::<8>::from("-0+"); // -8
@@ -226,4 +568,51 @@ mod tests {
         shifting_num <<= 1;
         assert_eq!(shifting_num, Number::<8>::from("00000000"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = Number::<8>::from("+-0--");
+        secret.zeroize();
+        assert_eq!(secret, Number::<8>::ZERO);
+
+        let mut trit = Trit::POS;
+        trit.zeroize();
+        assert_eq!(trit, Trit::ZERO);
+    }
+
+    #[test]
+    fn hashing() {
+        use std::collections::HashSet;
+
+        // Equal values built in different ways are a single key
+        let keys: HashSet<Number<8>> = [
+            Number::<8>::from(50),
+            Number::<8>::from("+-0--"),
+            Number::<8>::from(-50).abs(),
+            Number::<8>::from(25) + Number::<8>::from(25)
+        ].into_iter().collect();
+        assert_eq!(keys.len(), 1);
+        assert!(keys.contains(&Number::<8>::from_i128(50)));
+
+        let trits: HashSet<Trit> = [Trit::POS, Trit::NEG, -Trit::NEG, Trit::default()].into_iter().collect();
+        assert_eq!(trits.len(), 3);
+    }
+
+    #[test]
+    fn sums_and_products() {
+        let values = [Number::<8>::from(-4), Number::<8>::from(7), Number::<8>::from(3)];
+        assert_eq!(values.iter().sum::<Number<8>>(), Number::<8>::from(6));
+        assert_eq!(values.into_iter().sum::<Number<8>>(), Number::<8>::from(6));
+        assert_eq!(values.iter().product::<Number<8>>(), Number::<8>::from(-84));
+        assert_eq!(values.into_iter().product::<Number<8>>(), Number::<8>::from(-84));
+        assert_eq!(core::iter::empty::<Number<8>>().product::<Number<8>>(), Number::<8>::ONE);
+
+        // The total weight of a stream of trits, which wraps like addition
+        let trits = [Trit::POS, Trit::POS, Trit::NEG, Trit::ZERO, Trit::POS];
+        assert_eq!(trits.iter().sum::<Number<3>>(), Number::<3>::from(2));
+        assert_eq!(core::iter::repeat_n(Trit::POS, 14).sum::<Number<2>>(), Number::<2>::from(14 - 9));
+    }
+}
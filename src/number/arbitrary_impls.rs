@@ -0,0 +1,35 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl<'a, const N: usize> Arbitrary<'a> for Number<N> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut output = Number::<N>::ZERO;
+        for trit in output.0.iter_mut() {
+            *trit = Trit::arbitrary(u)?;
+        }
+        Ok(output)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lower, upper) = Trit::size_hint(depth);
+        (lower * N, upper.map(|upper| upper * N))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumes_trits_from_most_significant() {
+        let bytes = [2, 0, 1, 2, 1, 0, 2, 2];
+        let number = Number::<8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+        let mut u = Unstructured::new(&bytes);
+        for trit in number.0 {
+            assert_eq!(Trit::arbitrary(&mut u), Ok(trit));
+        }
+    }
+}
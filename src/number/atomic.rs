@@ -0,0 +1,240 @@
+use core::fmt;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::number::{Number, PackedNumber};
+
+/// A number of up to 32 trits that can be shared between threads, updated
+/// with lock-free atomic operations.
+///
+/// The number is held in a single `AtomicU64` as the two bitplanes of a
+/// [`PackedNumber`], the positive plane in the low 32 bits and the negative
+/// plane in the high 32 bits. Each value has exactly one such word, so
+/// [`compare_exchange`](Self::compare_exchange) compares values. Arithmetic
+/// wraps on overflow, as with the operators of [`Number`].
+///
+/// Using a width greater than 32 trits is a compile-time error.
+/// ```
+/// # use balanced_ternary::number::{AtomicNumber, Number};
+/// use std::sync::atomic::Ordering;
+///
+/// let counter = AtomicNumber::new(Number::<20>::ZERO);
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| {
+///             for _ in 0..1000 {
+///                 counter.fetch_add(Number::ONE, Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(counter.load(Ordering::Relaxed), Number::from(4000));
+/// ```
+pub struct AtomicNumber<const N: usize>(AtomicU64);
+
+impl <const N: usize> AtomicNumber<N> {
+    const VALID_WIDTH: () = assert!(N <= 32, "Atomic numbers are limited to 32 trits");
+
+    /// Creates a new atomic number.
+    pub const fn new(number: Number<N>) -> Self {
+        let () = Self::VALID_WIDTH;
+        AtomicNumber(AtomicU64::new(Self::encode(number)))
+    }
+
+    /// Consumes the atomic number and returns the value it holds.
+    pub fn into_inner(self) -> Number<N> {
+        Self::decode(self.0.into_inner())
+    }
+
+    /// Loads the value.
+    pub fn load(&self, order: Ordering) -> Number<N> {
+        Self::decode(self.0.load(order))
+    }
+
+    /// Stores a value.
+    pub fn store(&self, number: Number<N>, order: Ordering) {
+        self.0.store(Self::encode(number), order);
+    }
+
+    /// Stores a value, returning the previous value.
+    pub fn swap(&self, number: Number<N>, order: Ordering) -> Number<N> {
+        Self::decode(self.0.swap(Self::encode(number), order))
+    }
+
+    /// Stores `new` if the current value is `current`. Returns the previous
+    /// value, wrapped in `Ok` if it was replaced and `Err` otherwise. The
+    /// orderings are those of [`AtomicU64::compare_exchange`].
+    pub fn compare_exchange(
+        &self,
+        current: Number<N>,
+        new: Number<N>,
+        success: Ordering,
+        failure: Ordering
+    ) -> Result<Number<N>, Number<N>> {
+        self.0.compare_exchange(Self::encode(current), Self::encode(new), success, failure)
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+
+    /// As [`compare_exchange`](Self::compare_exchange), but may fail even when
+    /// the current value is `current`, which is faster on some platforms when
+    /// called in a loop.
+    pub fn compare_exchange_weak(
+        &self,
+        current: Number<N>,
+        new: Number<N>,
+        success: Ordering,
+        failure: Ordering
+    ) -> Result<Number<N>, Number<N>> {
+        self.0.compare_exchange_weak(Self::encode(current), Self::encode(new), success, failure)
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+
+    /// Repeatedly applies a function to the current value and tries to store
+    /// the result until it succeeds or the function returns `None`. Returns
+    /// the previous value, wrapped in `Ok` if it was replaced and `Err`
+    /// otherwise. The orderings are those of [`AtomicU64::fetch_update`].
+    pub fn fetch_update<F>(&self, set_order: Ordering, fetch_order: Ordering, mut f: F) -> Result<Number<N>, Number<N>>
+    where F: FnMut(Number<N>) -> Option<Number<N>> {
+        self.0.fetch_update(set_order, fetch_order, |word| f(Self::decode(word)).map(Self::encode))
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+
+    /// Adds to the value, wrapping on overflow, and returns the previous value.
+    pub fn fetch_add(&self, number: Number<N>, order: Ordering) -> Number<N> {
+        let number = PackedNumber::from_number(number);
+        self.fetch_packed(order, |value| value + number)
+    }
+
+    /// Subtracts from the value, wrapping on overflow, and returns the previous
+    /// value.
+    pub fn fetch_sub(&self, number: Number<N>, order: Ordering) -> Number<N> {
+        let number = PackedNumber::from_number(number);
+        self.fetch_packed(order, |value| value - number)
+    }
+
+    /// Sets the value to the minimum of it and `number`, returning the
+    /// previous value.
+    pub fn fetch_min(&self, number: Number<N>, order: Ordering) -> Number<N> {
+        let number = PackedNumber::from_number(number);
+        self.fetch_packed(order, |value| value.min(number))
+    }
+
+    /// Sets the value to the maximum of it and `number`, returning the
+    /// previous value.
+    pub fn fetch_max(&self, number: Number<N>, order: Ordering) -> Number<N> {
+        let number = PackedNumber::from_number(number);
+        self.fetch_packed(order, |value| value.max(number))
+    }
+
+    /// Applies an operation on the packed value in a compare-and-swap loop,
+    /// returning the previous value.
+    fn fetch_packed(&self, order: Ordering, op: impl Fn(PackedNumber<N>) -> PackedNumber<N>) -> Number<N> {
+        // The loads may not have release semantics, so they keep only the
+        // acquire part of the ordering
+        let fetch_order = match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order
+        };
+        let previous = self.0.fetch_update(order, fetch_order, |word| Some(Self::pack(op(Self::unpack(word)))));
+        match previous {
+            Ok(word) | Err(word) => Self::decode(word)
+        }
+    }
+
+    const fn pack(packed: PackedNumber<N>) -> u64 {
+        let (pos, neg) = packed.to_planes();
+        pos as u64 | (neg as u64) << 32
+    }
+
+    const fn encode(number: Number<N>) -> u64 {
+        Self::pack(PackedNumber::from_number(number))
+    }
+
+    fn unpack(word: u64) -> PackedNumber<N> {
+        PackedNumber::from_planes(u128::from(word as u32), u128::from((word >> 32) as u32))
+    }
+
+    fn decode(word: u64) -> Number<N> {
+        Self::unpack(word).to_number()
+    }
+}
+
+impl <const N: usize> Default for AtomicNumber<N> {
+    fn default() -> Self {
+        AtomicNumber::new(Number::<N>::ZERO)
+    }
+}
+
+impl <const N: usize> From<Number<N>> for AtomicNumber<N> {
+    fn from(number: Number<N>) -> Self {
+        AtomicNumber::new(number)
+    }
+}
+
+impl <const N: usize> fmt::Debug for AtomicNumber<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let atomic = AtomicNumber::new(Number::<4>::from(10));
+        assert_eq!(atomic.fetch_add(Number::from(5), Ordering::SeqCst), Number::from(10));
+        assert_eq!(atomic.fetch_sub(Number::from(20), Ordering::SeqCst), Number::from(15));
+        assert_eq!(atomic.swap(Number::MAX, Ordering::SeqCst), Number::from(-5));
+
+        // Arithmetic wraps as for `Number`
+        assert_eq!(atomic.fetch_add(Number::ONE, Ordering::SeqCst), Number::MAX);
+        assert_eq!(atomic.load(Ordering::SeqCst), Number::MIN);
+
+        assert_eq!(atomic.fetch_max(Number::from(3), Ordering::SeqCst), Number::MIN);
+        assert_eq!(atomic.fetch_min(Number::from(7), Ordering::SeqCst), Number::from(3));
+        assert_eq!(atomic.load(Ordering::SeqCst), Number::from(3));
+
+        let update = atomic.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |value| value.checked_mul(Number::from(-2)));
+        assert_eq!(update, Ok(Number::from(3)));
+        assert_eq!(atomic.into_inner(), Number::from(-6));
+
+        let full = AtomicNumber::new(Number::<32>::MIN);
+        assert_eq!(full.fetch_sub(Number::ONE, Ordering::SeqCst), Number::MIN);
+        assert_eq!(full.load(Ordering::SeqCst), Number::MAX);
+        assert_eq!(format!("{:?}", full), format!("{:?}", Number::<32>::MAX));
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let atomic = AtomicNumber::<8>::default();
+        let one = Number::<8>::ONE;
+        assert_eq!(atomic.compare_exchange(one, -one, Ordering::SeqCst, Ordering::SeqCst), Err(Number::ZERO));
+        assert_eq!(atomic.compare_exchange(Number::ZERO, -one, Ordering::SeqCst, Ordering::SeqCst), Ok(Number::ZERO));
+        assert_eq!(atomic.load(Ordering::SeqCst), -one);
+    }
+
+    #[test]
+    fn concurrent_updates() {
+        let atomic = AtomicNumber::new(Number::<16>::ZERO);
+        let highest = AtomicNumber::new(Number::<16>::MIN);
+        std::thread::scope(|scope| {
+            for thread in 0..4 {
+                let (atomic, highest) = (&atomic, &highest);
+                scope.spawn(move || {
+                    for step in 0..500 {
+                        atomic.fetch_add(Number::from(thread + 1), Ordering::Relaxed);
+                        atomic.fetch_sub(Number::ONE, Ordering::Relaxed);
+                        highest.fetch_max(Number::from(thread * 1000 + step), Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+        assert_eq!(atomic.load(Ordering::Relaxed), Number::from((1 + 2 + 3 + 4 - 4) * 500));
+        assert_eq!(highest.load(Ordering::Relaxed), Number::from(3499));
+    }
+}
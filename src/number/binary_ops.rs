@@ -1,41 +1,30 @@
-use std::iter::from_fn;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::iter::from_fn;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::number::kernels;
+#[cfg(feature = "alloc")]
+use crate::number::toom;
 use crate::number::Number;
 use crate::sum_result::SumResult;
-use crate::trit::Trit;
+use crate::trit::{adders, Trit};
 
 impl <const N: usize> Add for Number<N> {
     type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        // Zip trits from both operands, going in reverse order so carries can propagate upwards
-        let reverse_result_trits = self.0.iter().rev()
-            .zip(rhs.0.iter().rev())
-            // "Scan" as we need an output at each index, with accumulator propagating the carry trit
-            .scan(Trit::ZERO, |carry, (lhs, rhs)| {
-                let SumResult{result, carry: new_carry} = lhs.add_with_carry(rhs, carry);
-                *carry = new_carry;
-                Some(result)
-            });
-        
-        Number::<N>::from_rev_iter(reverse_result_trits)
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
     }
 }
 
 impl <const N: usize> AddAssign for Number<N> {
     fn add_assign(&mut self, rhs: Self) {
-        // Much the same as the Add trait, but mutating self data in-place. As such we replace
-        // "scan" with "for_each" to remove need for output. However we then lose the accumulator
-        // So we need to declare an external `carry` variable.
-        let mut carry = Trit::ZERO;
-        self.0.iter_mut().rev()
-            .zip(rhs.0.iter().rev())
-            .for_each(|(lhs, rhs)| {
-                let SumResult { result, carry: new_carry} = lhs.add_with_carry(rhs, &carry);
-                carry = new_carry;
-                *lhs = result;
-            });
+        // Add the trits pairwise from the least significant upwards so that
+        // carries can propagate, discarding the final carry
+        kernels::add_assign(&mut self.0, &rhs.0, Trit::ZERO);
     }
 }
 
@@ -55,6 +44,157 @@ impl <const N: usize> AddAssign<Trit> for Number<N> {
     }
 }
 
+impl <const N: usize> Number<N> {
+    /// Calculates `self + rhs + carry`, returning the sum along with the carry
+    /// trit that escaped the most significant position. The full result is
+    /// `sum + carry_out * 3^N`.
+    ///
+    /// This allows fixed-width numbers to be chained together as the words of
+    /// a wider number, by feeding the carry out of each word into the addition
+    /// of the next more significant word.
+    /// * `rhs` - The number to add
+    /// * `carry` - The carry trit from the addition of the next less significant word
+    pub const fn carrying_add(mut self, rhs: Self, carry: Trit) -> (Self, Trit) {
+        // Iterators can't be used in a const fn, so walk the indices from the
+        // least significant trit upwards by hand
+        let mut carry = carry;
+        let mut idx = N;
+        while idx > 0 {
+            idx -= 1;
+            let SumResult { result, carry: new_carry } = self.0[idx].add_with_carry(&rhs.0[idx], &carry);
+            carry = new_carry;
+            self.0[idx] = result;
+        }
+        (self, carry)
+    }
+
+    /// Calculates `self - rhs - borrow`, returning the difference along with
+    /// the borrow trit that escaped the most significant position. The full
+    /// result is `difference - borrow_out * 3^N`.
+    /// * `rhs` - The number to subtract
+    /// * `borrow` - The borrow trit from the subtraction of the next less significant word
+    pub fn borrowing_sub(self, rhs: Self, borrow: Trit) -> (Self, Trit) {
+        // Subtracting is adding the negations, after which a carry of +1 out of
+        // the top of the word is the same as a borrow of -1 and vice versa
+        let (difference, carry) = self.carrying_add(-rhs, borrow.negate());
+        (difference, carry.negate())
+    }
+
+    /// Adds three numbers without propagating any carries, returning a sum
+    /// word and a carry word with `self + b + c = sum + 3 * carry` exactly.
+    /// Each position is reduced independently by a full adder, so the carry
+    /// word holds the carry out of every position at the weight of that
+    /// position.
+    /// * `b` - The second number to add
+    /// * `c` - The third number to add
+    pub const fn carry_save_add(self, b: Self, c: Self) -> (Self, Self) {
+        let mut sum = self;
+        let mut carry = Number::<N>::ZERO;
+        let mut idx = 0;
+        while idx < N {
+            let SumResult { result, carry: carry_out } = adders::full_adder(self.0[idx], b.0[idx], c.0[idx]);
+            sum.0[idx] = result;
+            carry.0[idx] = carry_out;
+            idx += 1;
+        }
+        (sum, carry)
+    }
+
+    /// Sums any number of operands, wrapping like repeated addition, by
+    /// accumulating them into a carry-save pair and propagating carries only
+    /// once at the end.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let operands = [40, -13, 7, 100, -2].map(Number::<6>::from);
+    /// assert_eq!(Number::carry_save_sum(operands), Number::<6>::from(132));
+    /// ```
+    pub fn carry_save_sum(operands: impl IntoIterator<Item = Self>) -> Self {
+        let mut sum = Number::<N>::ZERO;
+        let mut carry = Number::<N>::ZERO;
+        for operand in operands {
+            let (new_sum, new_carry) = sum.carry_save_add(carry, operand);
+            sum = new_sum;
+            carry = new_carry << 1;
+        }
+        sum + carry
+    }
+
+    /// Calculates `self * rhs + carry` without discarding any trits, returning
+    /// the low and high words of the double-width result. The full result is
+    /// `low + high * 3^N`, and can never overflow.
+    /// * `rhs` - The number to multiply by
+    /// * `carry` - A number to add to the product, typically the high word of a previous step
+    pub fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+        let (low, high) = self.widening_mul(rhs);
+        let (low, carry_trit) = low.carrying_add(carry, Trit::ZERO);
+
+        // Balanced ternary needs no sign extension, so the carry word only
+        // affects the high word through the carry trit out of the low word
+        let mut high = high;
+        high += carry_trit;
+        (low, high)
+    }
+
+    /// Multiplies by `rhs` without discarding any trits, returning the low and
+    /// high words of the double-width product. The full product is
+    /// `low + high * 3^N`, and can never overflow. The high word is zero
+    /// exactly when `Mul` would not overflow. The pair can be joined into a
+    /// single number with [`Number::concat`].
+    /// * `rhs` - The number to multiply by
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        #[cfg(feature = "alloc")]
+        if N >= toom::TOOM_THRESHOLD {
+            return self.toom_widening_mul(rhs);
+        }
+
+        self.shift_and_add_widening_mul(rhs)
+    }
+
+    /// Widening multiplication by multiplying the trits as polynomials, which
+    /// is asymptotically faster for wide numbers.
+    #[cfg(feature = "alloc")]
+    fn toom_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let lhs_coefficients: Vec<i64> = self.iter().map(|trit| i64::from(trit.to_i8())).collect();
+        let rhs_coefficients: Vec<i64> = rhs.iter().map(|trit| i64::from(trit.to_i8())).collect();
+        let product = toom::convolve(&lhs_coefficients, &rhs_coefficients);
+
+        let mut trits = toom::normalise(&product);
+        let low = Number::<N>::from_rev_iter(trits.by_ref().take(N));
+        let high = Number::<N>::from_rev_iter(trits);
+        (low, high)
+    }
+
+    fn shift_and_add_widening_mul(self, rhs: Self) -> (Self, Self) {
+        let mut low = Number::<N>::ZERO;
+        let mut high = Number::<N>::ZERO;
+
+        // Shift-and-add as with `Mul`, except that the trits shifted out of each
+        // partial product are kept in a high word rather than being discarded
+        for (shift, current_trit) in self.0.iter().rev().enumerate() {
+            if *current_trit == Trit::ZERO {
+                continue;
+            }
+
+            let mut partial_low = rhs << shift;
+            let mut partial_high = Number::<N>::ZERO;
+            partial_high.0[N-shift..].copy_from_slice(&rhs.0[..shift]);
+
+            if *current_trit == Trit::NEG {
+                partial_low = -partial_low;
+                partial_high = -partial_high;
+            }
+
+            let (new_low, carry) = low.carrying_add(partial_low, Trit::ZERO);
+            // The full product always fits in two words so no carry escapes here
+            let (new_high, _) = high.carrying_add(partial_high, carry);
+            low = new_low;
+            high = new_high;
+        }
+
+        (low, high)
+    }
+}
+
 impl <const N: usize> Sub for Number<N> {
     type Output = Self;
 
@@ -74,6 +214,17 @@ impl <const N: usize> Mul for Number<N> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        #[cfg(feature = "alloc")]
+        if N >= toom::TOOM_THRESHOLD {
+            return self.toom_widening_mul(rhs).0;
+        }
+
+        self.shift_and_add_mul(rhs)
+    }
+}
+
+impl <const N: usize> Number<N> {
+    fn shift_and_add_mul(self, rhs: Self) -> Self {
         // Generator that will provide continually left-shifted copies
         // of the rhs operand. This will support the shift-and-add
         // approach of the multiplication.
@@ -110,36 +261,101 @@ impl <const N: usize> MulAssign for Number<N> {
     }
 }
 
-impl <const N: usize> Div for Number<N> {
-    type Output = Self;
+impl <const N: usize> Number<N> {
+    /// Calculates `self * a + b`, accumulating the partial products of the
+    /// multiplication directly into `b`. The result wraps on overflow in the same
+    /// way as `Mul`, and as wrapping arithmetic is exact modulo 3^N the result
+    /// is correct whenever the final value fits, however large the intermediate
+    /// product would have been.
+    /// * `a` - The number to multiply by
+    /// * `b` - The number to add to the product
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        // The same shift-and-add as `Mul`, except that the accumulator starts at
+        // `b` rather than zero
+        let mut a_shifted = a;
+        let a_shifter = move || {
+            let out = a_shifted;
+            a_shifted <<= 1;
+            Some(out)
+        };
 
-    fn div(self, divisor: Self) -> Self::Output {
+        self.0.iter().rev()
+            .zip(from_fn(a_shifter))
+            .fold(b, |accumulator, (current_trit, a_shifted)|
+                match current_trit {
+                    Trit::NEG => accumulator - a_shifted,
+                    Trit::ZERO => accumulator,
+                    Trit::POS => accumulator + a_shifted
+                }
+            )
+    }
+}
+
+impl <const N: usize> Number<N> {
+    /// Raises to the power of `exponent` using square-and-multiply, wrapping on
+    /// overflow in the same way as `Mul`. Any number raised to the power of zero
+    /// is one.
+    pub fn pow(self, exponent: u32) -> Self {
+        self.overflowing_pow(exponent).0
+    }
+}
+
+impl <const N: usize> Number<N> {
+    /// Performs integer division, returning both the quotient and the remainder
+    /// from a single pass. The quotient is rounded towards zero and so the
+    /// remainder takes the sign of the numerator, matching the behaviour of the
+    /// primitive integer types.
+    /// * `divisor` - The number to divide by, which must be non-zero
+    pub fn div_rem(self, divisor: Self) -> (Self, Self) {
         if divisor == Number::<N>::ZERO {
             panic!("Attempt to divide by zero")
         }
 
-        // Integer division implemented with a repeated subtraction approach. We
-        // convert numerator and divisor to positive to perform the division, and
-        // then decide whether to flip the result based on if they originally had
-        // different signs.
+        // Integer division implemented as trit-wise long division. We convert
+        // numerator and divisor to positive to perform the division, and then
+        // decide whether to flip the results based on the original signs.
 
-        let numerator_is_negative = self < Number::<N>::ZERO;
-        let mut abs_remainder = if numerator_is_negative {-self} else {self};
+        let numerator_is_negative = self.is_negative();
+        let mut abs_remainder = self.abs();
 
-        let divisor_is_negative = divisor < Number::<N>::ZERO;
-        let abs_divisor = if divisor_is_negative {-divisor} else {divisor};
+        let divisor_is_negative = divisor.is_negative();
+        let abs_divisor = divisor.abs();
 
+        // The divisor can be shifted left by as many positions as it has leading
+        // zero trits without overflowing. This is always far enough that the
+        // remainder starts out less than three times the shifted divisor.
+        let max_shift = abs_divisor.0.iter()
+            .position(|trit| *trit != Trit::ZERO)
+            .unwrap_or(N);
+
+        // Working from the highest shift down, each quotient digit is found by
+        // subtracting the shifted divisor from the remainder while it still fits.
+        // Since the remainder is always less than three times the shifted divisor
+        // this happens at most twice, giving an unbalanced digit of 0, 1 or 2
+        // which is accumulated into the quotient as it is shifted along.
         let mut quotient = Number::<N>::ZERO;
-        while abs_remainder >= abs_divisor {
-            abs_remainder -= abs_divisor;
-            quotient.inc();
+        for shift in (0..=max_shift).rev() {
+            let shifted_divisor = abs_divisor << shift;
+
+            quotient <<= 1;
+            while abs_remainder >= shifted_divisor {
+                abs_remainder -= shifted_divisor;
+                quotient.inc();
+            }
         }
 
-        if numerator_is_negative ^ divisor_is_negative {
-            -quotient
-        } else {
-            quotient
-        }
+        let quotient = if numerator_is_negative ^ divisor_is_negative {-quotient} else {quotient};
+        let remainder = if numerator_is_negative {-abs_remainder} else {abs_remainder};
+
+        (quotient, remainder)
+    }
+}
+
+impl <const N: usize> Div for Number<N> {
+    type Output = Self;
+
+    fn div(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).0
     }
 }
 
@@ -149,9 +365,67 @@ impl <const N: usize> DivAssign for Number<N> {
     }
 }
 
+impl <const N: usize> Rem for Number<N> {
+    type Output = Self;
+
+    fn rem(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).1
+    }
+}
+
+impl <const N: usize> RemAssign for Number<N> {
+    fn rem_assign(&mut self, divisor: Self) {
+        *self = *self % divisor;
+    }
+}
+
+// Forwards each operator to its by-value implementation for any combination of
+// references, in the same way as the operators on the primitive integer types
+macro_rules! forward_ref_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl <const N: usize> $op_trait<&Number<N>> for Number<N> {
+            type Output = Number<N>;
+
+            fn $op_fn(self, rhs: &Number<N>) -> Self::Output {
+                $op_trait::$op_fn(self, *rhs)
+            }
+        }
+
+        impl <const N: usize> $op_trait<Number<N>> for &Number<N> {
+            type Output = Number<N>;
+
+            fn $op_fn(self, rhs: Number<N>) -> Self::Output {
+                $op_trait::$op_fn(*self, rhs)
+            }
+        }
+
+        impl <const N: usize> $op_trait<&Number<N>> for &Number<N> {
+            type Output = Number<N>;
+
+            fn $op_fn(self, rhs: &Number<N>) -> Self::Output {
+                $op_trait::$op_fn(*self, *rhs)
+            }
+        }
+
+        impl <const N: usize> $assign_trait<&Number<N>> for Number<N> {
+            fn $assign_fn(&mut self, rhs: &Number<N>) {
+                $assign_trait::$assign_fn(self, *rhs);
+            }
+        }
+    };
+}
+
+forward_ref_binary_op!(Add, add, AddAssign, add_assign);
+forward_ref_binary_op!(Sub, sub, SubAssign, sub_assign);
+forward_ref_binary_op!(Mul, mul, MulAssign, mul_assign);
+forward_ref_binary_op!(Div, div, DivAssign, div_assign);
+forward_ref_binary_op!(Rem, rem, RemAssign, rem_assign);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::test_util::number_pattern;
 
     #[test]
     fn binary_operations() {
@@ -226,5 +500,211 @@ mod tests {
         let num_0: Number<8> = Number::<8>::ZERO;
 
         let _ = num_neg_61 / num_0;
-    }  
+    }
+
+    #[test]
+    fn multi_word_addition_and_subtraction() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(num_max.carrying_add(num_one, Trit::ZERO), (Number::<4>::from("----"), Trit::POS));
+        assert_eq!(num_max.carrying_add(num_max, Trit::POS), (Number::<4>::ZERO, Trit::POS));
+        assert_eq!((-num_max).carrying_add(-num_max, Trit::POS), (Number::<4>::from("+-"), Trit::NEG));
+        assert_eq!(num_one.carrying_add(num_one, Trit::NEG), (num_one, Trit::ZERO));
+
+        assert_eq!((-num_max).borrowing_sub(num_one, Trit::ZERO), (num_max, Trit::POS));
+        assert_eq!(num_max.borrowing_sub(-num_one, Trit::NEG), (Number::<4>::from("---0"), Trit::NEG));
+        assert_eq!(num_one.borrowing_sub(num_one, Trit::POS), (-num_one, Trit::ZERO));
+
+        // Chain two 4-trit words into a wider addition: 3280 + 82 = 3362
+        let (low, carry) = num_max.carrying_add(num_one, Trit::ZERO);
+        let (high, carry) = num_max.carrying_add(Number::<4>::from("+"), carry);
+        assert_eq!((high, low, carry), (Number::<4>::from("---0"), Number::<4>::from("----"), Trit::POS));
+    }
+
+    #[test]
+    fn carry_save_addition() {
+        let values = [Number::<4>::MIN, -Number::<4>::ONE, Number::<4>::ZERO, Number::<4>::ONE, Number::<4>::from(13), Number::<4>::MAX];
+        for a in values {
+            for b in values {
+                for c in values {
+                    let (sum, carry) = a.carry_save_add(b, c);
+                    assert_eq!(a.to_i128() + b.to_i128() + c.to_i128(), sum.to_i128() + carry.to_i128() * 3);
+                }
+            }
+        }
+
+        // The operands overflow repeatedly, and wrap the same way as `+`
+        let operands = [Number::<4>::MAX, Number::<4>::MAX, Number::<4>::MAX, Number::<4>::ONE, Number::<4>::MIN, Number::<4>::MAX];
+        assert_eq!(Number::carry_save_sum(operands), operands.into_iter().fold(Number::<4>::ZERO, |sum, operand| sum + operand));
+        // 121 = 40 + 81 and -280 = -37 - 3 * 81
+        assert_eq!(Number::carry_save_sum(operands), Number::<4>::MAX);
+        assert_eq!(Number::carry_save_sum([Number::<4>::MIN; 7]), Number::<4>::from(-37));
+        assert_eq!(Number::<4>::carry_save_sum([]), Number::<4>::ZERO);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn reference_operators() {
+        let num_7 = Number::<8>::from("+-+");
+        let num_3 = Number::<8>::from("+0");
+
+        assert_eq!(&num_7 + &num_3, num_7 + num_3);
+        assert_eq!(num_7 - &num_3, num_7 - num_3);
+        assert_eq!(&num_7 * num_3, num_7 * num_3);
+        assert_eq!(&num_7 / &num_3, num_7 / num_3);
+        assert_eq!(&num_7 % &num_3, num_7 % num_3);
+        assert_eq!(-&num_7, -num_7);
+
+        let mut accumulator = num_7;
+        accumulator += &num_3;
+        accumulator *= &num_3;
+        assert_eq!(accumulator, Number::<8>::from("+0+0")); // (7 + 3) * 3 = 30
+
+        // Generic code over references compiles against these impls
+        fn sum_of_squares<'a, const N: usize>(values: impl Iterator<Item = &'a Number<N>>) -> Number<N> {
+            values.map(|value| value * value).sum()
+        }
+        assert_eq!(sum_of_squares([num_7, num_3].iter()), Number::<8>::from("+-0++")); // 49 + 9 = 58
+    }
+
+    #[test]
+    fn fused_multiply_add() {
+        let num_13 = Number::<4>::from("+++");
+        let num_3 = Number::<4>::from("+0");
+
+        assert_eq!(num_13.mul_add(num_3, Number::<4>::ONE), Number::<4>::MAX); // 13 * 3 + 1 = 40
+        assert_eq!(num_13.mul_add(-num_3, num_13), Number::<4>::from("-00+")); // 13 * -3 + 13 = -26
+
+        // The product overflows but the final result fits
+        let num_4 = Number::<4>::from("++");
+        assert_eq!(num_13.mul_add(num_4, -num_13), Number::<4>::from("+++0")); // 13 * 4 - 13 = 39
+        assert_eq!(num_13.mul_add(num_4, -num_13), num_13 * num_4 - num_13);
+    }
+
+    #[test]
+    fn exponentiation() {
+        let num_neg_2 = Number::<8>::from("-+");
+
+        for exponent in 0..10 {
+            assert_eq!(i32::try_from(num_neg_2.pow(exponent)), Ok((-2_i32).pow(exponent)));
+        }
+
+        // Exponentiation wraps in the same way as multiplication
+        let num_3 = Number::<4>::from("+0");
+        assert_eq!(num_3.pow(4), Number::<4>::ZERO);
+    }
+
+    #[test]
+    fn widening_multiplication() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_13 = Number::<4>::from("+++");
+
+        // 40 * 40 = 1600 = 20 * 81 - 20
+        assert_eq!(num_max.widening_mul(num_max), (Number::<4>::from("-+-+"), Number::<4>::from("+-+-")));
+        assert_eq!(num_max.widening_mul(-num_max), (Number::<4>::from("+-+-"), Number::<4>::from("-+-+")));
+        assert_eq!(num_13.widening_mul(Number::<4>::from("+0")), (num_13 << 1, Number::<4>::ZERO));
+
+        let (low, high) = num_max.widening_mul(num_13);
+        assert_eq!(high.concat::<4, 8>(low), Number::<8>::from(40 * 13));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn wide_multiplication_matches_shift_and_add() {
+        let pattern = number_pattern::<200>;
+
+        for seed in 1..20 {
+            let (lhs, rhs) = (pattern(seed), pattern(seed + 7));
+
+            assert_eq!(lhs.widening_mul(rhs), lhs.shift_and_add_widening_mul(rhs));
+            assert_eq!(lhs * rhs, lhs.shift_and_add_mul(rhs));
+        }
+
+        assert_eq!(Number::<200>::MAX.widening_mul(Number::<200>::MIN),
+            Number::<200>::MAX.shift_and_add_widening_mul(Number::<200>::MIN));
+    }
+
+    #[test]
+    fn multi_word_multiplication() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_one = Number::<4>::from("+");
+
+        // 40 * 40 + 40 = 1640 = 20 * 81 + 20
+        assert_eq!(num_max.carrying_mul(num_max, num_max), (Number::<4>::from("+-+-"), Number::<4>::from("+-+-")));
+        assert_eq!(num_max.carrying_mul(num_one, Number::<4>::ZERO), (num_max, Number::<4>::ZERO));
+        assert_eq!(num_max.carrying_mul(num_one, num_one), (-num_max, num_one));
+    }
+
+    #[test]
+    fn wide_integer_division() {
+        let num_max = Number::<40>::from(&"+".repeat(40)[..]);
+        let num_one = Number::<40>::from("+");
+        let num_neg_three = Number::<40>::from("-0");
+
+        // Runtime of long division depends only on the width and not on the
+        // magnitude of the quotient
+        assert_eq!(num_max / num_one, num_max);
+        assert_eq!(num_max % num_one, Number::<40>::ZERO);
+        assert_eq!(
+            num_max.div_rem(num_neg_three),
+            (Number::<40>::from(&"-".repeat(39)[..]), Number::<40>::from("+"))
+        );
+    }
+
+    #[test]
+    fn division_matches_primitive_integers() {
+        // Exhaustively compare every pair of 5-trit values against primitive division
+        let mut numerator = Number::<5>::from("-----");
+        for _ in 0..243 {
+            let mut divisor = Number::<5>::from("-----");
+            for _ in 0..243 {
+                if divisor != Number::<5>::ZERO {
+                    let (quotient, remainder) = numerator.div_rem(divisor);
+                    assert_eq!(quotient.to_i128(), numerator.to_i128() / divisor.to_i128());
+                    assert_eq!(remainder.to_i128(), numerator.to_i128() % divisor.to_i128());
+                }
+                divisor.inc();
+            }
+            numerator.inc();
+        }
+    }
+
+    #[test]
+    fn integer_remainder() {
+        let num_59 = Number::<8>::from("+-+--");
+        let num_60 = Number::<8>::from("+-+-0");
+        let num_61 = Number::<8>::from("+-+-+");
+        let num_12 = Number::<8>::from("++0");
+
+        assert_eq!(num_59 % num_12, Number::<8>::from("++-")); // 59 % 12 = 11
+        assert_eq!(num_60 % num_12, Number::<8>::ZERO);        // 60 % 12 = 0
+        assert_eq!(num_61 % num_12, Number::<8>::from("+"));   // 61 % 12 = 1
+
+        // Remainder takes the sign of the numerator
+        assert_eq!(-num_59 %  num_12, Number::<8>::from("--+")); // -59 %  12 = -11
+        assert_eq!( num_59 % -num_12, Number::<8>::from("++-")); //  59 % -12 =  11
+        assert_eq!(-num_59 % -num_12, Number::<8>::from("--+")); // -59 % -12 = -11
+
+        let mut temp = num_61;
+        temp %= num_12;
+        assert_eq!(temp, Number::<8>::from("+"));
+    }
+
+    #[test]
+    fn combined_division_and_remainder() {
+        let num_59 = Number::<8>::from("+-+--");
+        let num_12 = Number::<8>::from("++0");
+
+        assert_eq!(num_59.div_rem(num_12), (Number::<8>::from("0++"), Number::<8>::from("++-")));
+        assert_eq!((-num_59).div_rem(num_12), (Number::<8>::from("0--"), Number::<8>::from("--+")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempt to divide by zero")]
+    fn remainder_by_zero() {
+        let num_61 = Number::<8>::from("+-+-+");
+
+        let _ = num_61 % Number::<8>::ZERO;
+    }
 }
\ No newline at end of file
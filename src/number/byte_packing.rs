@@ -0,0 +1,157 @@
+//! A dense encoding of five trits per byte.
+//!
+//! The canonical layout splits the trits of a number into groups of five,
+//! starting from the least significant trit, so that the first byte holds the
+//! lowest five trits. Each byte is the value of its group, in -121..=121,
+//! offset by 121 to give a byte in 0..=242. When the width is not a multiple of
+//! five the final byte is padded out with zero trits in its high positions. A
+//! number of N trits therefore always packs into exactly ceil(N / 5) bytes, and
+//! since 3^5 = 243 is the largest power of three that fits in a byte no denser
+//! byte-aligned encoding is possible. This is the same layout used by the
+//! compact serde representation.
+
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+// Number of trits packed into each byte. 3^5 = 243 is the largest power of
+// three that fits in a byte.
+const TRITS_PER_BYTE: usize = 5;
+
+// The value of a group of five trits lies in -121..=121, so offsetting it by 121
+// gives a byte in 0..=242
+const BYTE_OFFSET: i16 = 121;
+
+/// Reasons that packed or 2-bit encoded bytes could not be decoded into a
+/// [`Number`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnpackBytesError {
+    /// The number of bytes doesn't match the width of the number.
+    WrongLength { length: usize, expected: usize },
+    /// A byte that doesn't encode any group of trits, such as a byte above 242
+    /// in the five trits per byte encoding, was found at the given position.
+    InvalidByte { byte: u8, position: usize },
+    /// One of the padding trits in the final byte was non-zero.
+    NonZeroPadding
+}
+
+impl fmt::Display for UnpackBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnpackBytesError::WrongLength { length, expected } =>
+                write!(f, "{} bytes supplied but expected {}", length, expected),
+            UnpackBytesError::InvalidByte { byte, position } =>
+                write!(f, "invalid packed byte {} at position {}", byte, position),
+            UnpackBytesError::NonZeroPadding =>
+                write!(f, "padding trits of the final byte are non-zero")
+        }
+    }
+}
+
+impl Error for UnpackBytesError {}
+
+impl <const N: usize> Number<N> {
+    /// Packs the number into ceil(N / 5) bytes of five trits each, using the
+    /// layout described in the [module documentation](self).
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let num_23 = Number::<8>::from("+0--");
+    /// // 23 + 121 in the low byte, and three zero trits padded out to five in the high byte
+    /// assert_eq!(num_23.to_packed_bytes(), [144, 121]);
+    /// ```
+    pub fn to_packed_bytes(self) -> Vec<u8> {
+        self.0.rchunks(TRITS_PER_BYTE)
+            .map(|group| {
+                let value = group.iter().fold(0_i16, |value, trit| value * 3 + i16::from(trit.to_i8()));
+                (value + BYTE_OFFSET) as u8
+            })
+            .collect()
+    }
+
+    /// Unpacks bytes produced by [`Number::to_packed_bytes`]. Every number has
+    /// exactly one packed encoding, so this fails if there are the wrong number
+    /// of bytes, a byte is out of range, or any of the padding trits are
+    /// non-zero.
+    /// * `bytes` - The packed bytes, starting from the least significant
+    pub fn from_packed_bytes(bytes: &[u8]) -> Result<Self, UnpackBytesError> {
+        let expected = N.div_ceil(TRITS_PER_BYTE);
+        if bytes.len() != expected {
+            return Err(UnpackBytesError::WrongLength { length: bytes.len(), expected });
+        }
+
+        let mut trits = Vec::with_capacity(bytes.len() * TRITS_PER_BYTE);
+        for (position, byte) in bytes.iter().enumerate() {
+            if *byte > 242 {
+                return Err(UnpackBytesError::InvalidByte { byte: *byte, position });
+            }
+
+            // Peel off trits from least significant, in the same manner as
+            // converting from a primitive integer
+            let mut value = i16::from(*byte) - BYTE_OFFSET;
+            for _ in 0..TRITS_PER_BYTE {
+                let trit = match value.rem_euclid(3) {
+                    0 => Trit::ZERO,
+                    1 => Trit::POS,
+                    _ => Trit::NEG
+                };
+                value = (value - i16::from(trit.to_i8())) / 3;
+                trits.push(trit);
+            }
+        }
+
+        if trits[N..].iter().any(|trit| *trit != Trit::ZERO) {
+            return Err(UnpackBytesError::NonZeroPadding);
+        }
+
+        Ok(Number::<N>::from_rev_iter(trits.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::number_pattern as pattern;
+
+    fn round_trip<const N: usize>() {
+        for number in [Number::<N>::ZERO, Number::<N>::MAX, Number::<N>::MIN, pattern(1), pattern(2), pattern(7)] {
+            let bytes = number.to_packed_bytes();
+            assert_eq!(bytes.len(), N.div_ceil(5));
+            assert_eq!(Number::<N>::from_packed_bytes(&bytes), Ok(number));
+        }
+    }
+
+    #[test]
+    fn round_trips_every_width() {
+        round_trip::<0>();
+        round_trip::<1>();
+        round_trip::<2>();
+        round_trip::<3>();
+        round_trip::<4>();
+        round_trip::<5>();
+        round_trip::<6>();
+        round_trip::<9>();
+        round_trip::<10>();
+        round_trip::<11>();
+        round_trip::<40>();
+        round_trip::<81>();
+        round_trip::<128>();
+    }
+
+    #[test]
+    fn canonical_layout() {
+        assert_eq!(Number::<5>::from("+++++").to_packed_bytes(), [242]);
+        assert_eq!(Number::<5>::from("-----").to_packed_bytes(), [0]);
+        assert_eq!(Number::<7>::from("-+0+-0+").to_packed_bytes(), [121 + 19, 121 - 2]);
+        assert!(Number::<0>::ZERO.to_packed_bytes().is_empty());
+    }
+
+    #[test]
+    fn invalid_packed_bytes() {
+        assert_eq!(Number::<8>::from_packed_bytes(&[121]), Err(UnpackBytesError::WrongLength { length: 1, expected: 2 }));
+        assert_eq!(Number::<8>::from_packed_bytes(&[243, 121]), Err(UnpackBytesError::InvalidByte { byte: 243, position: 0 }));
+        assert_eq!(Number::<8>::from_packed_bytes(&[121, 121 + 27]), Err(UnpackBytesError::NonZeroPadding));
+    }
+}
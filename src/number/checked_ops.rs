@@ -0,0 +1,155 @@
+use crate::number::Number;
+
+impl <const N: usize> Number<N> {
+    /// Checked addition. Returns `None` if the sum cannot be represented in N
+    /// trits.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if the difference cannot be
+    /// represented in N trits.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.wrapping_neg())
+    }
+
+    /// Checked multiplication. Returns `None` if the product cannot be
+    /// represented in N trits.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None
+        }
+    }
+
+    /// Checked exponentiation. Returns `None` if the result cannot be
+    /// represented in N trits.
+    pub fn checked_pow(self, exponent: u32) -> Option<Self> {
+        match self.overflowing_pow(exponent) {
+            (power, false) => Some(power),
+            (_, true) => None
+        }
+    }
+
+    /// Checked division. Returns `None` if `divisor` is zero. Unlike two's
+    /// complement integers there is no `MIN / -1` overflow case, as the range
+    /// of a balanced ternary number is symmetric.
+    pub fn checked_div(self, divisor: Self) -> Option<Self> {
+        if divisor == Number::<N>::ZERO {
+            None
+        } else {
+            Some(self / divisor)
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `divisor` is zero.
+    pub fn checked_rem(self, divisor: Self) -> Option<Self> {
+        if divisor == Number::<N>::ZERO {
+            None
+        } else {
+            Some(self % divisor)
+        }
+    }
+
+    /// Checked negation. The range of a balanced ternary number is symmetric
+    /// around zero so this never fails, but is provided for parity with the
+    /// other checked operations.
+    pub const fn checked_neg(self) -> Option<Self> {
+        Some(self.wrapping_neg())
+    }
+
+    /// Checked left shift. Returns `None` if any non-zero trits would be
+    /// shifted out of the most significant position, i.e. if multiplying by
+    /// 3^`positions` overflows. Shifting zero by any amount succeeds.
+    pub const fn checked_shl(self, positions: usize) -> Option<Self> {
+        match self.overflowing_shl(positions) {
+            (shifted, false) => Some(shifted),
+            (_, true) => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_addition() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_min = Number::<4>::from("----"); // -40
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(num_max.checked_add(-num_one), Some(Number::<4>::from("+++0")));
+        assert_eq!(num_max.checked_add(num_min), Some(Number::<4>::ZERO));
+        assert_eq!(num_max.checked_add(num_one), None);
+        assert_eq!(num_min.checked_add(-num_one), None);
+    }
+
+    #[test]
+    fn checked_subtraction() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_min = Number::<4>::from("----"); // -40
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(num_min.checked_sub(-num_one), Some(Number::<4>::from("---0")));
+        assert_eq!(num_min.checked_sub(num_one), None);
+        assert_eq!(num_max.checked_sub(num_min), None);
+    }
+
+    #[test]
+    fn checked_multiplication() {
+        let num_13 = Number::<4>::from("+++");
+        let num_3 = Number::<4>::from("+0");
+        let num_neg_2 = Number::<4>::from("-+");
+        let num_4 = Number::<4>::from("++");
+
+        assert_eq!(num_13.checked_mul(num_3), Some(Number::<4>::from("+++0"))); // 39
+        assert_eq!(num_13.checked_mul(num_neg_2), Some(Number::<4>::from("-00+"))); // -26
+        assert_eq!(num_13.checked_mul(num_4), None); // 52 > 40
+
+        // Intermediate partial products overflow but the final product does not
+        let num_2 = Number::<4>::from("+-");
+        let num_20 = Number::<4>::from("+-+-");
+        assert_eq!(num_2.checked_mul(num_20), Some(Number::<4>::from("++++"))); // 40
+    }
+
+    #[test]
+    fn checked_exponentiation() {
+        let num_3 = Number::<4>::from("+0");
+
+        assert_eq!(num_3.checked_pow(3), Some(Number::<4>::from("+000"))); // 27
+        assert_eq!(num_3.checked_pow(4), None); // 81 > 40
+        assert_eq!(Number::<4>::NEG_ONE.checked_pow(u32::MAX), Some(Number::<4>::NEG_ONE));
+        assert_eq!(Number::<4>::ZERO.checked_pow(0), Some(Number::<4>::ONE));
+    }
+
+    #[test]
+    fn checked_division() {
+        let num_40 = Number::<4>::from("++++");
+        let num_3 = Number::<4>::from("+0");
+
+        assert_eq!(num_40.checked_div(num_3), Some(Number::<4>::from("+++"))); // 13
+        assert_eq!(num_40.checked_rem(num_3), Some(Number::<4>::from("+")));
+        assert_eq!(num_40.checked_div(Number::<4>::ZERO), None);
+        assert_eq!(num_40.checked_rem(Number::<4>::ZERO), None);
+    }
+
+    #[test]
+    fn checked_negation() {
+        let num_min = Number::<4>::from("----");
+
+        assert_eq!(num_min.checked_neg(), Some(Number::<4>::from("++++")));
+    }
+
+    #[test]
+    fn checked_left_shift() {
+        let num_4 = Number::<4>::from("++");
+
+        assert_eq!(num_4.checked_shl(2), Some(Number::<4>::from("++00")));
+        assert_eq!(num_4.checked_shl(3), None);
+        assert_eq!(Number::<4>::ZERO.checked_shl(10), Some(Number::<4>::ZERO));
+    }
+}
@@ -0,0 +1,103 @@
+use core::cmp::Ordering;
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Returns the point midway between two numbers, rounded towards zero, as
+    /// if calculated with enough trits that `self + rhs` couldn't overflow.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// assert_eq!(Number::<4>::MAX.midpoint(Number::<4>::MAX), Number::<4>::MAX);
+    /// assert_eq!(Number::<4>::from(-3).midpoint(Number::<4>::from(6)), Number::<4>::ONE);
+    /// assert_eq!(Number::<4>::from(3).midpoint(Number::<4>::from(-6)), -Number::<4>::ONE);
+    /// ```
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // Halving each number first keeps the sum of the halves in range. The
+        // full sum is twice the sum of the halves plus the sum of the
+        // remainders, which can move the rounded midpoint by one.
+        let (lhs_half, lhs_remainder) = self.halve();
+        let (rhs_half, rhs_remainder) = rhs.halve();
+        let half_sum = lhs_half + rhs_half;
+
+        let remainder = lhs_remainder.add(&rhs_remainder);
+        if remainder.carry != Trit::ZERO {
+            // The remainders sum to two, or minus two
+            half_sum + remainder.carry
+        } else if half_sum.signum() == -remainder.result {
+            // A remainder of one against the sign of the halves pulls the
+            // midpoint towards zero
+            half_sum + remainder.result
+        } else {
+            half_sum
+        }
+    }
+
+    /// Returns the absolute difference between two numbers, which needs up to
+    /// N + 1 trits. It is given as the low N trits along with a carry trit of
+    /// weight 3^N, which is zero or positive, in the same way as
+    /// [`carrying_add`](Self::carrying_add).
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::trit::Trit;
+    /// let (lhs, rhs) = (Number::<4>::from(-10), Number::<4>::from(25));
+    /// assert_eq!(lhs.abs_diff(rhs), (Number::<4>::from(35), Trit::ZERO));
+    ///
+    /// // The difference of 80 is 81 - 1
+    /// assert_eq!(Number::<4>::MIN.abs_diff(Number::<4>::MAX), (-Number::<4>::ONE, Trit::POS));
+    /// ```
+    pub const fn abs_diff(self, rhs: Self) -> (Self, Trit) {
+        let (larger, smaller) = if matches!(self.const_cmp(&rhs), Ordering::Less) {(rhs, self)} else {(self, rhs)};
+        larger.carrying_add(smaller.wrapping_neg(), Trit::ZERO)
+    }
+
+    /// Divides by two, rounding towards zero, returning the quotient and the
+    /// remainder.
+    fn halve(self) -> (Self, Trit) {
+        // Two doesn't fit in a single trit, but every quotient is then zero
+        if N == 1 {
+            return (Number::<N>::ZERO, self.0[0]);
+        }
+        let (quotient, remainder) = self.div_rem(Number::<N>::from_i128(2));
+        (quotient, remainder.signum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<const N: usize>() -> impl Iterator<Item = Number<N>> {
+        let max = Number::<N>::MAX.to_i128();
+        (-max..=max).map(Number::<N>::from_i128)
+    }
+
+    fn check_all<const N: usize>() {
+        let modulus = 3_i128.pow(N as u32);
+        for lhs in all::<N>() {
+            for rhs in all::<N>() {
+                let (lhs_value, rhs_value) = (lhs.to_i128(), rhs.to_i128());
+                assert_eq!(lhs.midpoint(rhs).to_i128(), (lhs_value + rhs_value) / 2, "{} and {}", lhs_value, rhs_value);
+
+                let (low, carry) = lhs.abs_diff(rhs);
+                assert_eq!(low.to_i128() + carry.to_i8() as i128 * modulus, (lhs_value - rhs_value).abs());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_integers() {
+        check_all::<1>();
+        check_all::<2>();
+        check_all::<3>();
+    }
+
+    #[test]
+    fn wide_midpoints() {
+        let max = Number::<100>::MAX;
+        assert_eq!(max.midpoint(max), max);
+        assert_eq!(max.midpoint(Number::<100>::MIN), Number::<100>::ZERO);
+        assert_eq!(max.midpoint(max - Number::<100>::from(2)), max - Number::<100>::ONE);
+        assert_eq!(Number::<100>::MIN.midpoint(Number::<100>::MIN + Number::<100>::ONE), Number::<100>::MIN + Number::<100>::ONE);
+    }
+}
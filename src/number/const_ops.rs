@@ -0,0 +1,206 @@
+use core::cmp::Ordering;
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+// Trait methods can't be called in const contexts, so these inherent methods
+// provide const equivalents of construction and comparison. Negation, addition,
+// subtraction and shifting are available through the const `wrapping_*`,
+// `checked_*` and `overflowing_*` methods.
+impl <const N: usize> Number<N> {
+    /// Builds a number from a string of `-`/`0`/`+` trits, in the same manner as
+    /// `From<&str>`, in a const context. Panics if the string is empty, contains
+    /// any other character or has more than N trits, which becomes a compile
+    /// error when evaluating a constant.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// const TWENTY_THREE: Number<8> = Number::<8>::from_trit_str("+0--");
+    /// assert_eq!(TWENTY_THREE, Number::<8>::from("+0--"));
+    /// ```
+    pub const fn from_trit_str(encoded: &str) -> Self {
+        let bytes = encoded.as_bytes();
+        if bytes.is_empty() {
+            panic!("Cannot build balanced ternary number from empty string");
+        }
+        if bytes.len() > N {
+            panic!("Too many trits for balanced ternary number");
+        }
+
+        // Populate the lowest trits with those from the string, leaving the
+        // higher-order trits as zero padding
+        let mut output = Number::<N>::ZERO;
+        let offset = N - bytes.len();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            output.0[offset + idx] = match bytes[idx] {
+                b'-' => Trit::NEG,
+                b'0' => Trit::ZERO,
+                b'+' => Trit::POS,
+                _ => panic!("Fail to parse invalid trit")
+            };
+            idx += 1;
+        }
+        output
+    }
+
+    /// Builds a number from an `i128` in a const context. Panics if the value
+    /// needs more than N trits, which becomes a compile error when evaluating a
+    /// constant.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// const FORTY_TWO: Number<8> = Number::<8>::from_i128(42);
+    /// assert_eq!(FORTY_TWO, Number::<8>::from("+---0"));
+    /// ```
+    pub const fn from_i128(value: i128) -> Self {
+        let mut output = Number::<N>::ZERO;
+        let mut remaining = value;
+        let mut idx = N;
+
+        // Peel off trits from the least significant, where a remainder of 2 is
+        // represented as a trit of -1 with a carry into the next trit
+        while remaining != 0 {
+            if idx == 0 {
+                panic!("Value is too large for balanced ternary number");
+            }
+            idx -= 1;
+
+            let quotient = remaining.div_euclid(3);
+            (output.0[idx], remaining) = match remaining.rem_euclid(3) {
+                0 => (Trit::ZERO, quotient),
+                1 => (Trit::POS, quotient),
+                _ => (Trit::NEG, quotient + 1)
+            };
+        }
+        output
+    }
+
+    /// Equality comparison usable in const contexts. Equivalent to `==`.
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        matches!(self.const_cmp(other), Ordering::Equal)
+    }
+
+    /// Ordering comparison usable in const contexts. Equivalent to `Ord::cmp`.
+    pub const fn const_cmp(&self, other: &Self) -> Ordering {
+        // The first differing trit from the most significant end decides the
+        // ordering, as it outweighs all of the less significant trits combined
+        let mut idx = 0;
+        while idx < N {
+            let (lhs, rhs) = (self.0[idx].to_i8(), other.0[idx].to_i8());
+            if lhs < rhs {
+                return Ordering::Less;
+            }
+            if lhs > rhs {
+                return Ordering::Greater;
+            }
+            idx += 1;
+        }
+        Ordering::Equal
+    }
+
+    /// The smaller of two numbers, usable in const contexts. Equivalent to
+    /// `Ord::min`.
+    pub const fn const_min(self, other: Self) -> Self {
+        if matches!(self.const_cmp(&other), Ordering::Greater) {other} else {self}
+    }
+
+    /// The larger of two numbers, usable in const contexts. Equivalent to
+    /// `Ord::max`.
+    pub const fn const_max(self, other: Self) -> Self {
+        if matches!(self.const_cmp(&other), Ordering::Greater) {self} else {other}
+    }
+
+    /// Restricts a number to the interval `[min, max]`, usable in const
+    /// contexts. Equivalent to `Ord::clamp`.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// const LIMIT: Number<4> = Number::<4>::from_i128(30);
+    /// const CLAMPED: Number<4> = Number::<4>::MAX.const_clamp(LIMIT.wrapping_neg(), LIMIT);
+    /// assert_eq!(CLAMPED, LIMIT);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub const fn const_clamp(self, min: Self, max: Self) -> Self {
+        if matches!(min.const_cmp(&max), Ordering::Greater) {
+            panic!("Clamp minimum must not be greater than the maximum")
+        }
+        self.const_max(min).const_min(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUM_23: Number<8> = Number::<8>::from_trit_str("+0--");
+    const NUM_33: Number<8> = Number::<8>::from_i128(33);
+    const NUM_56: Number<8> = NUM_23.wrapping_add(NUM_33);
+    const NUM_NEG_10: Number<8> = NUM_23.wrapping_sub(NUM_33);
+    const NUM_207: Number<8> = NUM_23.wrapping_shl(2);
+    const NUM_NEG_23: Number<8> = NUM_23.wrapping_neg();
+    const OVERFLOW: Option<Number<8>> = Number::<8>::MAX.checked_add(NUM_23);
+
+    #[test]
+    fn const_construction() {
+        assert_eq!(NUM_23, Number::<8>::from("+0--"));
+        assert_eq!(NUM_33, Number::<8>::from("++-0"));
+        assert_eq!(Number::<8>::from_i128(-3280), Number::<8>::MIN);
+        assert_eq!(Number::<8>::from_i128(0), Number::<8>::ZERO);
+        assert_eq!(Number::<81>::from_i128(i128::MIN), Number::<81>::try_from(i128::MIN).unwrap());
+        assert_eq!(Number::<81>::from_i128(i128::MAX), Number::<81>::try_from(i128::MAX).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Value is too large for balanced ternary number")]
+    fn const_construction_overflow() {
+        let _ = Number::<8>::from_i128(3281);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fail to parse invalid trit")]
+    fn const_construction_invalid_trit() {
+        let _ = Number::<8>::from_trit_str("+0x-");
+    }
+
+    #[test]
+    fn const_arithmetic() {
+        assert_eq!(NUM_56, Number::<8>::from("+-0+-"));
+        assert_eq!(NUM_NEG_10, Number::<8>::from("-0-"));
+        assert_eq!(NUM_207, Number::<8>::from("+0--00"));
+        assert_eq!(NUM_NEG_23, -NUM_23);
+        assert_eq!(OVERFLOW, None);
+    }
+
+    #[test]
+    fn const_comparisons() {
+        assert!(NUM_23.const_eq(&Number::<8>::from("+0--")));
+        assert!(!NUM_23.const_eq(&NUM_33));
+
+        assert_eq!(NUM_23.const_cmp(&NUM_33), Ordering::Less);
+        assert_eq!(NUM_33.const_cmp(&NUM_23), Ordering::Greater);
+        assert_eq!(NUM_NEG_10.const_cmp(&Number::<8>::ZERO), Ordering::Less);
+        assert_eq!(NUM_23.const_cmp(&NUM_23), NUM_23.cmp(&NUM_23));
+    }
+
+    #[test]
+    fn const_min_max_and_clamp() {
+        let numbers: Vec<Number<3>> = (-13..=13).map(Number::<3>::from).collect();
+        for lhs in &numbers {
+            for rhs in &numbers {
+                assert_eq!(lhs.const_min(*rhs), Ord::min(*lhs, *rhs));
+                assert_eq!(lhs.const_max(*rhs), Ord::max(*lhs, *rhs));
+                if lhs <= rhs {
+                    for value in &numbers {
+                        assert_eq!(value.const_clamp(*lhs, *rhs), Ord::clamp(*value, *lhs, *rhs));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Clamp minimum must not be greater than the maximum")]
+    fn const_clamp_empty_interval() {
+        Number::<6>::ZERO.const_clamp(Number::<6>::ONE, Number::<6>::ZERO);
+    }
+}
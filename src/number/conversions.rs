@@ -1,40 +1,316 @@
-use std::fmt;
+use core::error::Error;
+use core::fmt::{self, Write};
+use core::iter::from_fn;
+use core::str::FromStr;
 
-use crate::{number::Number, trit::Trit};
+use crate::{number::{Number, ParseOptions}, trit::Trit};
 
+/// Reasons that a string could not be parsed into a [`Number`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseTernaryError {
+    /// The input string contained no trits.
+    Empty,
+    /// A character other than `-`, `0` or `+`, or another trit accepted by the
+    /// [`ParseOptions`](crate::number::ParseOptions) in use, was found at the
+    /// given character position (counted from the left of the string).
+    InvalidTrit { character: char, position: usize },
+    /// More trits were supplied than can be stored in the number.
+    TooLong { length: usize, capacity: usize },
+    /// A character that isn't a digit of the notation being parsed, such as
+    /// base 27 or decimal, was found at the given character position.
+    InvalidDigit { character: char, position: usize },
+    /// The value is too large in magnitude to be stored in the number.
+    OutOfRange
+}
+
+impl fmt::Display for ParseTernaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseTernaryError::Empty =>
+                write!(f, "cannot parse balanced ternary number from empty string"),
+            ParseTernaryError::InvalidTrit { character, position } =>
+                write!(f, "invalid trit {:?} at position {}", character, position),
+            ParseTernaryError::TooLong { length, capacity } =>
+                write!(f, "{} trits supplied but number can only hold {}", length, capacity),
+            ParseTernaryError::InvalidDigit { character, position } =>
+                write!(f, "invalid digit {:?} at position {}", character, position),
+            ParseTernaryError::OutOfRange =>
+                write!(f, "value is out of range of the number")
+        }
+    }
+}
+
+impl Error for ParseTernaryError {}
+
+/// Builds a number from a string of trits, for literals known to be valid.
+/// Fewer than N trits are padded with zeros. Parse the string with
+/// [`FromStr`] to handle invalid or over-long input without panicking; a
+/// `TryFrom<&str>` can't be given alongside this conversion, as the standard
+/// library already derives an infallible one from it.
+///
+/// # Panics
+/// Panics if a character isn't `-`, `0` or `+`, or if there are more than N
+/// trits.
 impl <const N: usize> From<&str> for Number<N> {
     fn from(encoded: &str) -> Self {
+        let length = encoded.chars().count();
+        assert!(length <= N, "{} trits supplied for number of {} trits", length, N);
+
         // View character slice as slice of trits, starting from right
         // hand size (lowest significant trit)
         let trits = encoded.chars()
             .rev()
-            .map(Trit::from);
+            .map(|character| Trit::from_char(character)
+                .unwrap_or_else(|| panic!("Fail to parse invalid trit {}", character)));
 
         Number::<N>::from_rev_iter(trits)
     }
 }
 
-impl<const N: usize> From<Number<N>> for i32 {
-    fn from(number: Number<N>) -> i32 {
-        // Proceed through trits from lowest-order to highest
-        number.0.iter().rev()
-            // Add index since we will need it to determine value at that position
-            .enumerate()
-            .map(|(idx, trit)| match trit {
-                Trit::NEG => -3_i32.pow(idx as u32),
-                Trit::ZERO => 0_i32,
-                Trit::POS => 3_i32.pow(idx as u32)
-            })
-            .sum()
+impl <const N: usize> FromStr for Number<N> {
+    type Err = ParseTernaryError;
+
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        Number::<N>::parse_with(encoded, ParseOptions::new())
+    }
+}
+
+/// The error returned when converting between a [`Number`] and a primitive
+/// integer fails because the value is out of range of the target type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryFromIntError(pub(crate) ());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+impl Error for TryFromIntError {}
+
+impl <const N: usize> Number<N> {
+    /// Converts a signed magnitude into balanced ternary, returning the N least
+    /// significant trits along with whether any non-zero trits were lost.
+    pub(crate) fn from_magnitude(is_negative: bool, mut magnitude: u128) -> (Self, bool) {
+        // Each step takes the remainder mod 3 as the next trit. A remainder of 2
+        // is represented as a trit of -1 with a carry of +1 into the next trit.
+        let mut trits = from_fn(|| {
+            if magnitude == 0 {
+                return None;
+            }
+            let trit = match magnitude % 3 {
+                0 => Trit::ZERO,
+                1 => Trit::POS,
+                _ => Trit::NEG
+            };
+            magnitude = magnitude / 3 + u128::from(trit == Trit::NEG);
+            Some(trit)
+        });
+
+        let number = Number::<N>::from_rev_iter(trits.by_ref().take(N));
+        let overflowed = trits.next().is_some();
+        let number = if is_negative {-number} else {number};
+
+        (number, overflowed)
+    }
+
+    /// Converts into a sign and magnitude, or `None` if the magnitude doesn't
+    /// fit in a `u128`.
+    fn to_magnitude(self) -> Option<(bool, u128)> {
+        let is_negative = self < Number::<N>::ZERO;
+        let abs = if is_negative {-self} else {self};
+
+        // Horner's method from the most significant trit down. The leading trit
+        // of a positive number is always +, and every prefix of its trits is then
+        // also positive and smaller than the whole, so overflowing part way
+        // through means the magnitude doesn't fit.
+        let magnitude = abs.0.iter()
+            .try_fold(0_u128, |value, trit| {
+                let shifted = value.checked_mul(3)?;
+                match trit {
+                    Trit::NEG => Some(shifted - 1),
+                    Trit::ZERO => Some(shifted),
+                    Trit::POS => shifted.checked_add(1)
+                }
+            })?;
+
+        Some((is_negative, magnitude))
+    }
+}
+
+// Conversions from the narrower primitive integers wrap modulo 3^N, keeping
+// only the N least significant trits in the same way that `From<&str>` does.
+// Use `TryFrom<i64>` for a checked conversion from these types.
+macro_rules! from_small_int {
+    ($($int:ty),*) => {$(
+        impl <const N: usize> From<$int> for Number<N> {
+            fn from(value: $int) -> Self {
+                Number::<N>::from_magnitude(value < 0, u128::from(value.unsigned_abs())).0
+            }
+        }
+    )*};
+}
+
+macro_rules! try_from_large_int {
+    ($($int:ty),*) => {$(
+        impl <const N: usize> TryFrom<$int> for Number<N> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                match Number::<N>::from_magnitude(value < 0, u128::from(value.unsigned_abs())) {
+                    (number, false) => Ok(number),
+                    (_, true) => Err(TryFromIntError(()))
+                }
+            }
+        }
+    )*};
+}
+
+from_small_int!(i8, i16, i32);
+try_from_large_int!(i64, i128);
+
+impl <const N: usize> TryFrom<u128> for Number<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        match Number::<N>::from_magnitude(false, value) {
+            (number, false) => Ok(number),
+            (_, true) => Err(TryFromIntError(()))
+        }
+    }
+}
+
+impl <const N: usize> TryFrom<u64> for Number<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Number::<N>::try_from(u128::from(value))
+    }
+}
+
+impl <const N: usize> TryFrom<usize> for Number<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Number::<N>::try_from(value as u128)
+    }
+}
+
+impl <const N: usize> Number<N> {
+    const FITS_IN_I128: () = assert!(N <= 80, "Number is too wide to always fit in an i128");
+
+    /// Converts to an `i128`. Every number of up to 80 trits fits in an `i128`,
+    /// so this conversion cannot fail; using it with a wider number is a
+    /// compile-time error. Use `i128::try_from` for wider numbers instead.
+    pub const fn to_i128(self) -> i128 {
+        let () = Self::FITS_IN_I128;
+
+        let mut value = 0_i128;
+        let mut idx = 0;
+        while idx < N {
+            value = value * 3 + self.0[idx].to_i8() as i128;
+            idx += 1;
+        }
+        value
+    }
+}
+
+impl<const N: usize> TryFrom<Number<N>> for i128 {
+    type Error = TryFromIntError;
+
+    fn try_from(number: Number<N>) -> Result<Self, Self::Error> {
+        let (is_negative, magnitude) = number.to_magnitude().ok_or(TryFromIntError(()))?;
+        if is_negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }.ok_or(TryFromIntError(()))
+    }
+}
+
+macro_rules! try_into_small_int {
+    ($($int:ty),*) => {$(
+        impl<const N: usize> TryFrom<Number<N>> for $int {
+            type Error = TryFromIntError;
+
+            fn try_from(number: Number<N>) -> Result<Self, Self::Error> {
+                <$int>::try_from(i128::try_from(number)?).map_err(|_| TryFromIntError(()))
+            }
+        }
+    )*};
+}
+
+try_into_small_int!(i8, i16, i32, i64);
+
+/// A small fixed-capacity buffer for rendering the decimal suffix of a number
+/// without allocating.
+struct DecimalBuffer {
+    bytes: [u8; 48],
+    len: usize
+}
+
+impl DecimalBuffer {
+    fn new() -> Self {
+        DecimalBuffer { bytes: [0; 48], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ever written to with complete `str` fragments
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Write for DecimalBuffer {
+    fn write_str(&mut self, fragment: &str) -> fmt::Result {
+        let end = self.len + fragment.len();
+        self.bytes.get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(fragment.as_bytes());
+        self.len = end;
+        Ok(())
     }
 }
 
 impl<const N: usize> fmt::Display for Number<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for i in 0..N {
-            write!(f, "{}", self.0[i])?
+        // Render the decimal suffix first so that the length of the complete
+        // representation is known, allowing width, fill and alignment flags to
+        // apply to the output as a whole rather than to each individual trit
+        let mut decimal = DecimalBuffer::new();
+        match i128::try_from(*self) {
+            Ok(value) if f.sign_plus() => write!(decimal, " ({:+})", value)?,
+            Ok(value) => write!(decimal, " ({})", value)?,
+            Err(_) => ()
         }
-        write!(f, " ({})", i32::from(*self))
+
+        let padding = f.width().unwrap_or(0).saturating_sub(N + decimal.as_str().len());
+        let (pre_padding, post_padding) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (0, padding)
+        };
+
+        for _ in 0..pre_padding {
+            f.write_char(f.fill())?;
+        }
+        for trit in self.0.iter() {
+            write!(f, "{}", trit)?;
+        }
+        f.write_str(decimal.as_str())?;
+        for _ in 0..post_padding {
+            f.write_char(f.fill())?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Number<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Number<{}>(", N)?;
+        for trit in self.0.iter() {
+            write!(f, "{}", trit)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -48,4 +324,106 @@ mod tests {
         
         assert_eq!(format!("{}", num_50), "000+-0-- (50)");
     }
+
+    #[test]
+    fn output_formatting_flags() {
+        let num_50 = Number::<8>::from("+-0--");
+        let num_neg_50 = -num_50;
+
+        assert_eq!(format!("{:+}", num_50), "000+-0-- (+50)");
+        assert_eq!(format!("{:+}", num_neg_50), "000-+0++ (-50)");
+        assert_eq!(format!("{:>16}", num_50), "   000+-0-- (50)");
+        assert_eq!(format!("{:<16}|", num_50), "000+-0-- (50)   |");
+        assert_eq!(format!("{:*^17}", num_50), "**000+-0-- (50)**");
+    }
+
+    #[test]
+    fn debug_representation() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(format!("{:?}", num_50), "Number<8>(000+-0--)");
+    }
+
+    #[test]
+    fn from_small_integers() {
+        assert_eq!(Number::<8>::from(0_i8), Number::<8>::ZERO);
+        assert_eq!(Number::<8>::from(-128_i8), Number::<8>::from("-+++-+"));
+        assert_eq!(Number::<16>::from(1234_i16), Number::<16>::from("+--0+-0+"));
+        assert_eq!(Number::<21>::from(i32::MAX), Number::<21>::from("+-0--0-000--+++-+-+0+"));
+        assert_eq!(Number::<21>::from(i32::MIN), Number::<21>::from("-+0++0+000++---+-+--+"));
+
+        // Values too large for the number wrap around modulo 3^N
+        assert_eq!(Number::<4>::from(41_i32), Number::<4>::from("----"));
+        assert_eq!(Number::<4>::from(-41_i32), Number::<4>::from("++++"));
+    }
+
+    #[test]
+    fn try_from_large_integers() {
+        assert_eq!(Number::<8>::try_from(3280_i64), Ok(Number::<8>::from("++++++++")));
+        assert_eq!(Number::<8>::try_from(-3280_i128), Ok(Number::<8>::from("--------")));
+        assert_eq!(Number::<8>::try_from(3280_u64), Ok(Number::<8>::from("++++++++")));
+        assert_eq!(Number::<8>::try_from(3280_u128), Ok(Number::<8>::from("++++++++")));
+        assert_eq!(Number::<8>::try_from(3280_usize), Ok(Number::<8>::from("++++++++")));
+
+        assert_eq!(Number::<8>::try_from(3281_i64), Err(TryFromIntError(())));
+        assert_eq!(Number::<8>::try_from(-3281_i128), Err(TryFromIntError(())));
+        assert_eq!(Number::<8>::try_from(3281_u64), Err(TryFromIntError(())));
+        assert_eq!(Number::<8>::try_from(u128::MAX), Err(TryFromIntError(())));
+        assert_eq!(Number::<8>::try_from(usize::MAX), Err(TryFromIntError(())));
+
+        // Extremes of the widest types need a wide enough number
+        let num_min = Number::<82>::try_from(i128::MIN).unwrap();
+        assert_eq!(-num_min, Number::<82>::try_from(1_u128 << 127).unwrap());
+        assert!(Number::<82>::try_from(u128::MAX).is_ok());
+        assert!(Number::<81>::try_from(u128::MAX).is_err());
+    }
+
+    #[test]
+    fn try_into_integers() {
+        let num_50 = Number::<8>::from("+-0--");
+        let num_max = Number::<8>::from("++++++++"); // 3280
+
+        assert_eq!(i8::try_from(num_50), Ok(50));
+        assert_eq!(i8::try_from(-num_50), Ok(-50));
+        assert_eq!(i8::try_from(num_max), Err(TryFromIntError(())));
+        assert_eq!(i16::try_from(num_max), Ok(3280));
+        assert_eq!(i32::try_from(-num_max), Ok(-3280));
+        assert_eq!(i64::try_from(num_max), Ok(3280));
+        assert_eq!(i128::try_from(num_max), Ok(3280));
+
+        assert_eq!(i32::try_from(Number::<21>::from(i32::MIN)), Ok(i32::MIN));
+        assert_eq!(i32::try_from(-Number::<21>::from(i32::MIN)), Err(TryFromIntError(())));
+        assert_eq!(i128::try_from(Number::<82>::try_from(i128::MIN).unwrap()), Ok(i128::MIN));
+        assert_eq!(i128::try_from(Number::<82>::try_from(u128::MAX).unwrap()), Err(TryFromIntError(())));
+
+        // Leading zeros beyond the range of the type don't matter
+        assert_eq!(i8::try_from(Number::<100>::from("+-0--")), Ok(50));
+    }
+
+    #[test]
+    fn infallible_into_i128() {
+        assert_eq!(Number::<8>::from("+-0--").to_i128(), 50);
+        assert_eq!(Number::<80>::from(&"+".repeat(80)[..]).to_i128(), (3_i128.pow(80) - 1) / 2);
+        assert_eq!(Number::<80>::from(&"-".repeat(80)[..]).to_i128(), -(3_i128.pow(80) - 1) / 2);
+    }
+
+    #[test]
+    fn parse_valid_strings() {
+        assert_eq!("+-0--".parse::<Number<8>>(), Ok(Number::<8>::from("+-0--")));
+        assert_eq!("--------".parse::<Number<8>>(), Ok(Number::<8>::from("--------")));
+        assert_eq!("0".parse::<Number<8>>(), Ok(Number::<8>::ZERO));
+    }
+
+    #[test]
+    fn parse_invalid_strings() {
+        assert_eq!("".parse::<Number<8>>(), Err(ParseTernaryError::Empty));
+        assert_eq!(
+            "+-x-".parse::<Number<8>>(),
+            Err(ParseTernaryError::InvalidTrit { character: 'x', position: 2 })
+        );
+        assert_eq!(
+            "+0-+0-+0-".parse::<Number<8>>(),
+            Err(ParseTernaryError::TooLong { length: 9, capacity: 8 })
+        );
+    }
 }
\ No newline at end of file
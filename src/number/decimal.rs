@@ -0,0 +1,174 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::number::{Number, ParseTernaryError};
+
+// Decimal digits are converted 18 at a time, the most that always fit in an
+// `i64`, so that wide numbers need far fewer divisions and multiplications
+pub(crate) const DIGITS_PER_CHUNK: usize = 18;
+#[cfg(feature = "alloc")]
+pub(crate) const CHUNK_DIVISOR: i64 = 1_000_000_000_000_000_000;
+
+/// Splits a decimal string into its sign and digits, accepting a leading `+`,
+/// `-` or Unicode minus sign `−`. Every digit is validated so that the first
+/// invalid character can be reported along with its position in the original
+/// string.
+pub(crate) fn split_decimal(encoded: &str) -> Result<(bool, &str), ParseTernaryError> {
+    let (is_negative, digits) = match encoded.strip_prefix(['-', '\u{2212}']) {
+        Some(digits) => (true, digits),
+        None => (false, encoded.strip_prefix('+').unwrap_or(encoded))
+    };
+    if digits.is_empty() {
+        return Err(ParseTernaryError::Empty);
+    }
+
+    let sign_length = encoded.chars().count() - digits.chars().count();
+    for (position, character) in digits.chars().enumerate() {
+        if !character.is_ascii_digit() {
+            return Err(ParseTernaryError::InvalidDigit { character, position: position + sign_length });
+        }
+    }
+    Ok((is_negative, digits))
+}
+
+/// Splits validated decimal digits into chunks of up to 18 digits from the
+/// most significant, each with its value and the power of ten it shifts the
+/// preceding digits by.
+pub(crate) fn decimal_chunks(digits: &str) -> impl Iterator<Item = (i64, i64)> + '_ {
+    // The first chunk takes any remainder so that the rest are all full
+    let first_length = match digits.len() % DIGITS_PER_CHUNK {
+        0 => DIGITS_PER_CHUNK,
+        length => length
+    };
+    let (first, rest) = digits.split_at(first_length);
+    core::iter::once(first)
+        .chain(rest.as_bytes().chunks(DIGITS_PER_CHUNK).map(|chunk| core::str::from_utf8(chunk).unwrap_or_default()))
+        .map(|chunk| {
+            let value = chunk.bytes().fold(0, |value, digit| value * 10 + i64::from(digit - b'0'));
+            (value, 10_i64.pow(chunk.len() as u32))
+        })
+}
+
+impl <const N: usize> Number<N> {
+    /// Parses a conventional decimal integer, with an optional leading `+`,
+    /// `-` or Unicode minus sign `−`, such as `"-123456"`. Unlike
+    /// `num_traits::Num::from_str_radix` this isn't limited to values that fit
+    /// in an `i128`, and fails with [`ParseTernaryError::OutOfRange`] if the
+    /// value doesn't fit in N trits.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// assert_eq!(Number::<8>::from_decimal_str("-50"), Ok(Number::<8>::from("-+0++")));
+    /// ```
+    pub fn from_decimal_str(encoded: &str) -> Result<Self, ParseTernaryError> {
+        let (is_negative, digits) = split_decimal(encoded)?;
+
+        // The magnitude is accumulated a chunk at a time, which is exact since
+        // the range of a balanced ternary number is symmetric around zero
+        let mut magnitude = Number::<N>::ZERO;
+        for (chunk, scale) in decimal_chunks(digits) {
+            let chunk = Number::<N>::try_from(chunk).map_err(|_| ParseTernaryError::OutOfRange)?;
+            if magnitude != Number::<N>::ZERO {
+                magnitude = Number::<N>::try_from(scale).ok()
+                    .and_then(|scale| magnitude.checked_mul(scale))
+                    .ok_or(ParseTernaryError::OutOfRange)?;
+            }
+            magnitude = magnitude.checked_add(chunk).ok_or(ParseTernaryError::OutOfRange)?;
+        }
+
+        Ok(if is_negative {-magnitude} else {magnitude})
+    }
+
+    /// Formats the value as a conventional decimal integer, with a leading `-`
+    /// if it is negative. Unlike the decimal suffix of the `Display` output,
+    /// this works for numbers of any width.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// assert_eq!(Number::<8>::from("-+0++").to_decimal_string(), "-50");
+    /// assert_eq!(Number::<100>::MAX.to_decimal_string(), "257688760366005665518230564882810636351053761000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal_string(self) -> String {
+        if let Ok(value) = i128::try_from(self) {
+            return value.to_string();
+        }
+
+        // Too wide for an `i128`, so at least 81 trits and wide enough to hold
+        // the chunk divisor. The chunks are peeled off from the least
+        // significant.
+        let divisor = Number::<N>::try_from(CHUNK_DIVISOR).unwrap_or(Number::<N>::ONE);
+        let mut magnitude = self.abs();
+        let mut chunks = Vec::new();
+        while magnitude != Number::<N>::ZERO {
+            let (quotient, remainder) = magnitude.div_rem(divisor);
+            chunks.push(i128::try_from(remainder).unwrap_or_default());
+            magnitude = quotient;
+        }
+
+        write_chunks(self.is_negative(), &chunks)
+    }
+}
+
+/// Writes chunks of 18 decimal digits, given from the least significant, with
+/// every chunk but the leading one padded out with zeros.
+#[cfg(feature = "alloc")]
+pub(crate) fn write_chunks(is_negative: bool, chunks: &[i128]) -> String {
+    use core::fmt::Write;
+
+    let mut output = String::new();
+    if is_negative {
+        output.push('-');
+    }
+    let mut chunks = chunks.iter().rev();
+    let _ = write!(output, "{}", chunks.next().copied().unwrap_or_default());
+    for chunk in chunks {
+        let _ = write!(output, "{:0width$}", chunk, width = DIGITS_PER_CHUNK);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Number::<8>::from_decimal_str("50"), Ok(Number::<8>::from("+-0--")));
+        assert_eq!(Number::<8>::from_decimal_str("+50"), Ok(Number::<8>::from("+-0--")));
+        assert_eq!(Number::<8>::from_decimal_str("\u{2212}50"), Ok(Number::<8>::from("-+0++")));
+        assert_eq!(Number::<8>::from_decimal_str("-0"), Ok(Number::<8>::ZERO));
+        assert_eq!(Number::<2>::from_decimal_str("0004"), Ok(Number::<2>::MAX));
+
+        assert_eq!(Number::<8>::from_decimal_str(""), Err(ParseTernaryError::Empty));
+        assert_eq!(Number::<8>::from_decimal_str("-"), Err(ParseTernaryError::Empty));
+        assert_eq!(Number::<8>::from_decimal_str("-1x0"), Err(ParseTernaryError::InvalidDigit { character: 'x', position: 2 }));
+        assert_eq!(Number::<8>::from_decimal_str("3280"), Ok(Number::<8>::MAX));
+        assert_eq!(Number::<8>::from_decimal_str("3281"), Err(ParseTernaryError::OutOfRange));
+        assert_eq!(Number::<8>::from_decimal_str("-3281"), Err(ParseTernaryError::OutOfRange));
+        assert_eq!(Number::<2>::from_decimal_str("5"), Err(ParseTernaryError::OutOfRange));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn wide_numbers() {
+        // (3^100 - 1) / 2 has 48 digits, which spans three chunks
+        let max = "257688760366005665518230564882810636351053761000";
+        assert_eq!(Number::<100>::from_decimal_str(max), Ok(Number::<100>::MAX));
+        assert_eq!(Number::<100>::from_decimal_str(&format!("-{}", max)), Ok(Number::<100>::MIN));
+        assert_eq!(Number::<100>::from_decimal_str("257688760366005665518230564882810636351053761001"), Err(ParseTernaryError::OutOfRange));
+        assert_eq!(Number::<100>::MIN.to_decimal_string(), format!("-{}", max));
+
+        // A chunk of all zeros must keep its padding
+        let power = Number::<100>::from_decimal_str("1000000000000000000000000000000000000001").unwrap();
+        assert_eq!(power.to_decimal_string(), "1000000000000000000000000000000000000001");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn round_trips() {
+        for value in -9841..=9841 {
+            let number = Number::<9>::from(value);
+            assert_eq!(number.to_decimal_string(), value.to_string());
+            assert_eq!(Number::<9>::from_decimal_str(&value.to_string()), Ok(number));
+        }
+    }
+}
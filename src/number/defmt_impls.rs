@@ -0,0 +1,22 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Logs every trit from the most significant, the same trits written by
+/// `Display` but without the decimal value. The trits are written to a buffer
+/// on the stack and sent as a single string of one byte per trit, so nothing
+/// is allocated on the target.
+impl <const N: usize> defmt::Format for Number<N> {
+    fn format(&self, f: defmt::Formatter) {
+        let mut buffer = [0_u8; N];
+        for (byte, trit) in buffer.iter_mut().zip(self.0.iter()) {
+            *byte = match trit {
+                Trit::NEG => b'-',
+                Trit::ZERO => b'0',
+                Trit::POS => b'+'
+            };
+        }
+        // Only ASCII characters were written
+        let trits = core::str::from_utf8(&buffer).unwrap_or_default();
+        defmt::write!(f, "{=str}", trits);
+    }
+}
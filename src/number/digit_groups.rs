@@ -0,0 +1,68 @@
+//! Conversion between numbers and strings in bases that are powers of three,
+//! where each digit stands for a fixed group of trits and so no arithmetic is
+//! needed. Each alphabet lists its digits in order of value, from the most
+//! negative to the most positive, so the middle digit of an alphabet is zero.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::number::{Number, ParseTernaryError};
+use crate::trit::Trit;
+
+/// Writes one digit for each group of `group_width` trits, starting from the
+/// least significant, so that any short group becomes the leading digit.
+#[cfg(feature = "alloc")]
+pub(super) fn to_digit_string<const N: usize>(number: Number<N>, group_width: usize, alphabet: &[u8]) -> String {
+    let offset = (alphabet.len() / 2) as i8;
+    number.as_trits()
+        .rchunks(group_width)
+        .rev()
+        .map(|group| {
+            let value = group.iter().fold(0_i8, |value, trit| value * 3 + trit.to_i8());
+            char::from(alphabet[(value + offset) as usize])
+        })
+        .collect()
+}
+
+/// Parses digits written by [`to_digit_string`], with letters in either case,
+/// failing if the value doesn't fit in N trits.
+pub(super) fn from_digit_str<const N: usize>(encoded: &str, group_width: usize, alphabet: &[u8]) -> Result<Number<N>, ParseTernaryError> {
+    if encoded.is_empty() {
+        return Err(ParseTernaryError::Empty);
+    }
+
+    // Validate every character first, so that the reported position of an
+    // invalid digit is always the leftmost one
+    for (position, character) in encoded.chars().enumerate() {
+        if digit_value(character, alphabet).is_none() {
+            return Err(ParseTernaryError::InvalidDigit { character, position });
+        }
+    }
+
+    let mut trits = [Trit::ZERO; N];
+    for (digit_idx, character) in encoded.chars().rev().enumerate() {
+        let mut value = digit_value(character, alphabet).unwrap_or_default();
+        for group_idx in 0..group_width {
+            let trit = match value.rem_euclid(3) {
+                0 => Trit::ZERO,
+                1 => Trit::POS,
+                _ => Trit::NEG
+            };
+            value = (value - trit.to_i8()) / 3;
+
+            let trit_idx = digit_idx * group_width + group_idx;
+            if trit_idx < N {
+                trits[N - 1 - trit_idx] = trit;
+            } else if trit != Trit::ZERO {
+                return Err(ParseTernaryError::OutOfRange);
+            }
+        }
+    }
+    Ok(Number::<N>::from_trits(trits))
+}
+
+/// The value of the group of trits that a digit stands for.
+fn digit_value(character: char, alphabet: &[u8]) -> Option<i8> {
+    let index = alphabet.iter().position(|digit| char::from(*digit) == character.to_ascii_uppercase())?;
+    Some(index as i8 - (alphabet.len() / 2) as i8)
+}
@@ -0,0 +1,299 @@
+use core::cmp::Ordering;
+
+use crate::number::Number;
+
+/// The direction in which to round a result that can't be represented exactly.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards zero, discarding the fractional part. This is how `Div`
+    /// rounds.
+    TowardZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest value, with ties rounded away from zero.
+    NearestTiesAway,
+    /// Round to the nearest value, with ties rounded to the even neighbour.
+    NearestTiesEven
+}
+
+impl <const N: usize> Number<N> {
+    /// Calculates the quotient of Euclidean division, which is the quotient
+    /// for which the remainder returned by [`Number::rem_euclid`] is never
+    /// negative. This rounds towards negative infinity for positive divisors
+    /// and towards positive infinity for negative divisors.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_euclid(self, divisor: Self) -> Self {
+        // Truncating division leaves a negative remainder for negative numerators,
+        // so step the quotient one further away from zero to make it positive
+        let (quotient, remainder) = self.div_rem(divisor);
+        if remainder.is_negative() {
+            if divisor.is_positive() {quotient - Number::<N>::ONE} else {quotient + Number::<N>::ONE}
+        } else {
+            quotient
+        }
+    }
+
+    /// Calculates the least non-negative remainder of `self` divided by
+    /// `divisor`, which always lies in the range `0..|divisor|`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn rem_euclid(self, divisor: Self) -> Self {
+        let remainder = self % divisor;
+        if remainder.is_negative() {remainder + divisor.abs()} else {remainder}
+    }
+
+    /// Calculates the quotient of `self` and `divisor`, rounded towards
+    /// negative infinity.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_floor(self, divisor: Self) -> Self {
+        // Truncation rounded towards positive infinity whenever the exact
+        // quotient was negative, which is when a remainder is left with the
+        // opposite sign to the divisor
+        let (quotient, remainder) = self.div_rem(divisor);
+        if remainder.signum() == divisor.signum().negate() {quotient - Number::<N>::ONE} else {quotient}
+    }
+
+    /// Calculates the remainder matching [`Number::div_floor`], which takes the
+    /// sign of the divisor.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn rem_floor(self, divisor: Self) -> Self {
+        let remainder = self % divisor;
+        if remainder.signum() == divisor.signum().negate() {remainder + divisor} else {remainder}
+    }
+
+    /// Calculates the quotient of `self` and `divisor`, rounded towards
+    /// positive infinity.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_ceil(self, divisor: Self) -> Self {
+        // Truncation rounded towards negative infinity whenever the exact
+        // quotient was positive, which is when a remainder is left with the
+        // same sign as the divisor
+        let (quotient, remainder) = self.div_rem(divisor);
+        if remainder.signum() == divisor.signum() {quotient + Number::<N>::ONE} else {quotient}
+    }
+
+    /// Calculates the remainder matching [`Number::div_ceil`], which takes the
+    /// opposite sign to the divisor.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn rem_ceil(self, divisor: Self) -> Self {
+        let remainder = self % divisor;
+        if remainder.signum() == divisor.signum() {remainder - divisor} else {remainder}
+    }
+
+    /// Calculates the quotient of `self` and `divisor`, rounded in the
+    /// direction given by `mode`.
+    ///
+    /// Only an even divisor can leave an exact tie between two quotients, so
+    /// the nearest modes only differ for even divisors. Dividing by a power of
+    /// three with `>>` rounds to the nearest value without any ties at all.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rounded(self, divisor: Self, mode: RoundingMode) -> Self {
+        let (quotient, remainder) = self.div_rem(divisor);
+        if remainder.is_zero() {
+            return quotient;
+        }
+
+        // The exact quotient lies strictly between the truncated quotient and
+        // its neighbour one step further from zero
+        let away_from_zero = if self.signum() == divisor.signum() {
+            quotient + Number::<N>::ONE
+        } else {
+            quotient - Number::<N>::ONE
+        };
+
+        let round_away = match mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::Floor => away_from_zero < quotient,
+            RoundingMode::Ceil => away_from_zero > quotient,
+            RoundingMode::NearestTiesAway | RoundingMode::NearestTiesEven => {
+                // Compare the remainder against what is left of the divisor,
+                // rather than doubling it, so that nothing can overflow
+                let abs_remainder = remainder.abs();
+                let abs_rest = divisor.abs() - abs_remainder;
+                match abs_remainder.cmp(&abs_rest) {
+                    Ordering::Less => false,
+                    Ordering::Greater => true,
+                    Ordering::Equal if mode == RoundingMode::NearestTiesAway => true,
+                    Ordering::Equal => quotient.is_odd()
+                }
+            }
+        };
+
+        if round_away {away_from_zero} else {quotient}
+    }
+
+    /// Returns the multiple of `rhs` nearest to `self` in the direction of the
+    /// sign of `rhs`: the smallest multiple greater than or equal to `self` if
+    /// `rhs` is positive, and the largest multiple less than or equal to it if
+    /// `rhs` is negative. Wraps on overflow, in the same way as `Add`.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let (num_16, num_8) = (Number::<6>::from(16), Number::<6>::from(8));
+    /// assert_eq!(num_16.next_multiple_of(num_8), Number::<6>::from(16));
+    /// assert_eq!(Number::<6>::from(23).next_multiple_of(num_8), Number::<6>::from(24));
+    /// assert_eq!(Number::<6>::from(23).next_multiple_of(-num_8), Number::<6>::from(16));
+    /// assert_eq!(Number::<6>::from(-23).next_multiple_of(num_8), Number::<6>::from(-16));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    pub fn next_multiple_of(self, rhs: Self) -> Self {
+        // The remainder of rounding the quotient towards positive infinity
+        // takes the opposite sign to `rhs`, so removing it moves towards the
+        // sign of `rhs`
+        self - self.rem_ceil(rhs)
+    }
+
+    /// Checked [`Number::next_multiple_of`]. Returns `None` if `rhs` is zero or
+    /// the result cannot be represented in N trits.
+    pub fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        self.checked_sub(self.rem_ceil(rhs))
+    }
+
+    /// Returns `true` if `self` is a multiple of `rhs`. Zero is the only
+    /// multiple of zero.
+    pub fn is_multiple_of(self, rhs: Self) -> bool {
+        if rhs.is_zero() {self.is_zero()} else {(self % rhs).is_zero()}
+    }
+
+    /// Every power of three is odd, so a number is odd exactly when it has an
+    /// odd count of non-zero trits.
+    fn is_odd(self) -> bool {
+        (self.count_pos() + self.count_neg()) % 2 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euclidean_division() {
+        let num_7 = Number::<8>::from("+-+");
+        let num_3 = Number::<8>::from("+0");
+
+        // 7 = 3 * 2 + 1
+        assert_eq!(num_7.div_euclid(num_3), Number::<8>::from("+-"));
+        assert_eq!(num_7.rem_euclid(num_3), Number::<8>::ONE);
+        // 7 = -3 * -2 + 1
+        assert_eq!(num_7.div_euclid(-num_3), Number::<8>::from("-+"));
+        assert_eq!(num_7.rem_euclid(-num_3), Number::<8>::ONE);
+        // -7 = 3 * -3 + 2
+        assert_eq!((-num_7).div_euclid(num_3), Number::<8>::from("-0"));
+        assert_eq!((-num_7).rem_euclid(num_3), Number::<8>::from("+-"));
+        // -7 = -3 * 3 + 2
+        assert_eq!((-num_7).div_euclid(-num_3), Number::<8>::from("+0"));
+        assert_eq!((-num_7).rem_euclid(-num_3), Number::<8>::from("+-"));
+    }
+
+    #[test]
+    fn floor_and_ceiling_division() {
+        let num_7 = Number::<8>::from("+-+");
+        let num_3 = Number::<8>::from("+0");
+
+        assert_eq!(num_7.div_floor(num_3), Number::<8>::from("+-"));     // 2.33 rounds to 2
+        assert_eq!(num_7.div_floor(-num_3), Number::<8>::from("-0"));    // -2.33 rounds to -3
+        assert_eq!((-num_7).div_floor(num_3), Number::<8>::from("-0"));  // -2.33 rounds to -3
+        assert_eq!((-num_7).div_floor(-num_3), Number::<8>::from("+-")); // 2.33 rounds to 2
+
+        assert_eq!(num_7.div_ceil(num_3), Number::<8>::from("+0"));      // 2.33 rounds to 3
+        assert_eq!(num_7.div_ceil(-num_3), Number::<8>::from("-+"));     // -2.33 rounds to -2
+        assert_eq!((-num_7).div_ceil(num_3), Number::<8>::from("-+"));   // -2.33 rounds to -2
+        assert_eq!((-num_7).div_ceil(-num_3), Number::<8>::from("+0"));  // 2.33 rounds to 3
+
+        assert_eq!(num_7.rem_floor(-num_3), Number::<8>::from("-+"));    // 7 = -3 * -3 - 2
+        assert_eq!(num_7.rem_ceil(num_3), Number::<8>::from("-+"));      // 7 = 3 * 3 - 2
+    }
+
+    #[test]
+    fn rounded_division() {
+        let num_7 = Number::<8>::from("+-+");
+        let num_5 = Number::<8>::from("+--");
+        let num_2 = Number::<8>::from("+-");
+
+        // 7 / 2 = 3.5 is a tie between 3 and 4
+        assert_eq!(num_7.div_rounded(num_2, RoundingMode::TowardZero), Number::<8>::from("+0"));
+        assert_eq!(num_7.div_rounded(num_2, RoundingMode::Floor), Number::<8>::from("+0"));
+        assert_eq!(num_7.div_rounded(num_2, RoundingMode::Ceil), Number::<8>::from("++"));
+        assert_eq!(num_7.div_rounded(num_2, RoundingMode::NearestTiesAway), Number::<8>::from("++"));
+        assert_eq!(num_7.div_rounded(num_2, RoundingMode::NearestTiesEven), Number::<8>::from("++"));
+
+        // -5 / 2 = -2.5 is a tie between -2 and -3
+        assert_eq!((-num_5).div_rounded(num_2, RoundingMode::TowardZero), Number::<8>::from("-+"));
+        assert_eq!((-num_5).div_rounded(num_2, RoundingMode::Floor), Number::<8>::from("-0"));
+        assert_eq!((-num_5).div_rounded(num_2, RoundingMode::Ceil), Number::<8>::from("-+"));
+        assert_eq!((-num_5).div_rounded(num_2, RoundingMode::NearestTiesAway), Number::<8>::from("-0"));
+        assert_eq!((-num_5).div_rounded(num_2, RoundingMode::NearestTiesEven), Number::<8>::from("-+"));
+
+        // 7 / 5 = 1.4 has no tie to break
+        assert_eq!(num_7.div_rounded(num_5, RoundingMode::NearestTiesAway), Number::<8>::ONE);
+        assert_eq!(num_7.div_rounded(-num_5, RoundingMode::NearestTiesEven), Number::<8>::NEG_ONE);
+
+        // Nearest rounding by a power of three matches a right shift
+        let num_3 = Number::<8>::from("+0");
+        for value in -100..=100_i32 {
+            let number = Number::<8>::from(value);
+            assert_eq!(number.div_rounded(num_3, RoundingMode::NearestTiesAway), number >> 1);
+        }
+    }
+
+    #[test]
+    fn division_variants_match_primitive() {
+        for numerator in -121..=121_i32 {
+            for divisor in (-121..=121_i32).filter(|divisor| *divisor != 0) {
+                let (num_n, num_d) = (Number::<5>::from(numerator), Number::<5>::from(divisor));
+                let floor = (f64::from(numerator) / f64::from(divisor)).floor() as i32;
+                let ceil = (f64::from(numerator) / f64::from(divisor)).ceil() as i32;
+
+                assert_eq!(num_n.div_euclid(num_d), Number::<5>::from(numerator.div_euclid(divisor)));
+                assert_eq!(num_n.rem_euclid(num_d), Number::<5>::from(numerator.rem_euclid(divisor)));
+                assert_eq!(num_n.div_floor(num_d), Number::<5>::from(floor));
+                assert_eq!(num_n.rem_floor(num_d), Number::<5>::from(numerator - floor * divisor));
+                assert_eq!(num_n.div_ceil(num_d), Number::<5>::from(ceil));
+                assert_eq!(num_n.rem_ceil(num_d), Number::<5>::from(numerator - ceil * divisor));
+
+                let nearest = (f64::from(numerator) / f64::from(divisor)).round_ties_even() as i32;
+                assert_eq!(num_n.div_rounded(num_d, RoundingMode::NearestTiesEven), Number::<5>::from(nearest));
+                let nearest = (f64::from(numerator) / f64::from(divisor)).round() as i32;
+                assert_eq!(num_n.div_rounded(num_d, RoundingMode::NearestTiesAway), Number::<5>::from(nearest));
+                assert_eq!(num_n.div_rounded(num_d, RoundingMode::Floor), Number::<5>::from(floor));
+                assert_eq!(num_n.div_rounded(num_d, RoundingMode::Ceil), Number::<5>::from(ceil));
+
+                let multiple = Some(ceil * divisor).filter(|multiple| multiple.abs() <= 121);
+                assert_eq!(num_n.checked_next_multiple_of(num_d), multiple.map(Number::<5>::from));
+                assert_eq!(num_n.is_multiple_of(num_d), numerator % divisor == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn multiples() {
+        let max = Number::<5>::MAX;
+        assert_eq!(max.next_multiple_of(Number::<5>::from(2)), Number::<5>::MIN);
+        assert_eq!(max.checked_next_multiple_of(Number::<5>::from(2)), None);
+        assert_eq!(max.checked_next_multiple_of(Number::<5>::from(-2)), Some(max - Number::<5>::ONE));
+        assert_eq!(max.checked_next_multiple_of(Number::<5>::ZERO), None);
+
+        assert!(Number::<5>::ZERO.is_multiple_of(Number::<5>::ZERO));
+        assert!(!max.is_multiple_of(Number::<5>::ZERO));
+        assert!(Number::<5>::from(-27).is_multiple_of(Number::<5>::from(9)));
+    }
+}
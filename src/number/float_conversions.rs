@@ -0,0 +1,249 @@
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+
+use crate::number::{Number, RoundingMode};
+
+/// Reasons that a floating point value could not be converted into a
+/// [`Number`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FloatConversionError {
+    /// The value was not a number.
+    NaN,
+    /// The value was positive or negative infinity.
+    Infinite,
+    /// The rounded value is too large in magnitude to be stored in the number.
+    OutOfRange
+}
+
+impl fmt::Display for FloatConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FloatConversionError::NaN => write!(f, "cannot convert NaN to a balanced ternary number"),
+            FloatConversionError::Infinite => write!(f, "cannot convert infinity to a balanced ternary number"),
+            FloatConversionError::OutOfRange => write!(f, "floating point value is out of range of the number")
+        }
+    }
+}
+
+impl Error for FloatConversionError {}
+
+impl <const N: usize> Number<N> {
+    /// Converts a floating point value to the integer given by rounding it
+    /// with `mode`. Every integer-valued `f64` converts exactly, however large,
+    /// so long as it fits in N trits.
+    /// * `value` - The value to convert, which must be finite
+    /// * `mode` - The direction in which to round any fractional part
+    /// ```
+    /// # use balanced_ternary::number::{Number, RoundingMode};
+    /// assert_eq!(Number::<4>::from_f64_round(-2.5, RoundingMode::NearestTiesEven), Ok(Number::<4>::from(-2)));
+    /// assert_eq!(Number::<4>::from_f64_round(-2.5, RoundingMode::Floor), Ok(Number::<4>::from(-3)));
+    /// ```
+    pub fn from_f64_round(value: f64, mode: RoundingMode) -> Result<Self, FloatConversionError> {
+        if value.is_nan() {
+            return Err(FloatConversionError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(FloatConversionError::Infinite);
+        }
+
+        // Split into an integer mantissa and a power of two, working from the
+        // bits so that no floating point rounding is involved
+        let bits = value.to_bits();
+        let is_negative = bits >> 63 == 1;
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = u128::from(bits & ((1 << 52) - 1));
+        let (mantissa, exponent) = match biased_exponent {
+            0 => (fraction, -1074),
+            _ => (fraction | 1 << 52, biased_exponent - 1075)
+        };
+
+        let magnitude = if exponent >= 0 {
+            // Already an integer, though possibly too large for a `u128`, so
+            // scale up by any remaining powers of two within the number
+            let shift = exponent.min(74) as u32;
+            let (mut magnitude, overflowed) = Number::<N>::from_magnitude(false, mantissa << shift);
+            if overflowed {
+                return Err(FloatConversionError::OutOfRange);
+            }
+            let mut remaining = (exponent as u32) - shift;
+            while remaining > 0 {
+                let step = remaining.min(64);
+                magnitude = Number::<N>::try_from(1_u128 << step).ok()
+                    .and_then(|scale| magnitude.checked_mul(scale))
+                    .ok_or(FloatConversionError::OutOfRange)?;
+                remaining -= step;
+            }
+            magnitude
+        } else {
+            // Compare the fractional part against one half to decide whether
+            // to round the magnitude up
+            let shift = exponent.unsigned_abs();
+            let (integer, fraction_cmp, has_fraction) = if shift >= 128 {
+                (0, Ordering::Less, mantissa != 0)
+            } else {
+                let fraction = mantissa & ((1 << shift) - 1);
+                (mantissa >> shift, fraction.cmp(&(1 << (shift - 1))), fraction != 0)
+            };
+
+            let round_up = match mode {
+                RoundingMode::TowardZero => false,
+                RoundingMode::Floor => is_negative && has_fraction,
+                RoundingMode::Ceil => !is_negative && has_fraction,
+                RoundingMode::NearestTiesAway => fraction_cmp.is_ge(),
+                RoundingMode::NearestTiesEven => fraction_cmp.is_gt() || (fraction_cmp.is_eq() && integer % 2 == 1)
+            };
+
+            match Number::<N>::from_magnitude(false, integer + u128::from(round_up)) {
+                (magnitude, false) => magnitude,
+                (_, true) => return Err(FloatConversionError::OutOfRange)
+            }
+        };
+
+        // The range of a balanced ternary number is symmetric, so negating the
+        // magnitude can't overflow
+        Ok(if is_negative {-magnitude} else {magnitude})
+    }
+
+    /// Converts a floating point value to the integer given by rounding it
+    /// with `mode`, in the same manner as [`Number::from_f64_round`].
+    pub fn from_f32_round(value: f32, mode: RoundingMode) -> Result<Self, FloatConversionError> {
+        // Every f32 is exactly representable as an f64
+        Number::<N>::from_f64_round(f64::from(value), mode)
+    }
+
+    /// Converts to the nearest `f64`, with ties rounded to even. The result is
+    /// exact whenever the value is representable, and is infinite if the
+    /// value is beyond the range of an `f64`.
+    pub fn to_f64(self) -> f64 {
+        match self.to_float_parts() {
+            Ok(value) => value as f64,
+            Err((mantissa, exponent)) => mantissa as f64 * pow2_f64(exponent)
+        }
+    }
+
+    /// Converts to the nearest `f32`, with ties rounded to even. The result is
+    /// exact whenever the value is representable, and is infinite if the
+    /// value is beyond the range of an `f32`.
+    pub fn to_f32(self) -> f32 {
+        match self.to_float_parts() {
+            Ok(value) => value as f32,
+            Err((mantissa, exponent)) => mantissa as f32 * pow2_f32(exponent)
+        }
+    }
+
+    /// Returns the value as an `i128` if it fits, which converts to a float
+    /// with correct rounding using a primitive cast. Otherwise returns a
+    /// 64-bit signed mantissa and a power of two. The mantissa keeps a sticky
+    /// low bit that is set if any lower bits were discarded, which is enough
+    /// for a primitive cast of it to round correctly.
+    fn to_float_parts(self) -> Result<i128, (i64, u32)> {
+        if let Ok(value) = i128::try_from(self) {
+            return Ok(value);
+        }
+
+        // Only numbers of more than 80 trits overflow an `i128`, and so these
+        // powers of two always fit
+        let threshold = Number::<N>::try_from(1_u128 << 120).unwrap_or(Number::<N>::MAX);
+        let divisor = Number::<N>::try_from(1_u128 << 32).unwrap_or(Number::<N>::ONE);
+
+        let mut magnitude = self.abs();
+        let mut exponent = 0;
+        let mut sticky = false;
+        while magnitude >= threshold {
+            let (quotient, remainder) = magnitude.div_rem(divisor);
+            sticky |= remainder != Number::<N>::ZERO;
+            magnitude = quotient;
+            exponent += 32;
+        }
+
+        // Now between 2^88 and 2^120, so take the top 63 bits
+        let magnitude = i128::try_from(magnitude).unwrap_or_default() as u128;
+        let shift = 65 - magnitude.leading_zeros();
+        sticky |= magnitude & ((1 << shift) - 1) != 0;
+        let mantissa = (magnitude >> shift) as i64 | i64::from(sticky);
+
+        Err((if self.is_negative() {-mantissa} else {mantissa}, exponent + shift))
+    }
+}
+
+/// 2^exponent as an `f64`, or infinity if it is out of range.
+fn pow2_f64(exponent: u32) -> f64 {
+    if exponent > 1023 {f64::INFINITY} else {f64::from_bits(u64::from(exponent + 1023) << 52)}
+}
+
+/// 2^exponent as an `f32`, or infinity if it is out of range.
+fn pow2_f32(exponent: u32) -> f32 {
+    if exponent > 127 {f32::INFINITY} else {f32::from_bits((exponent + 127) << 23)}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_modes() {
+        let cases = [
+            // value, toward zero, floor, ceil, ties away, ties even
+            (2.5, [2, 2, 3, 3, 2]),
+            (-2.5, [-2, -3, -2, -3, -2]),
+            (3.5, [3, 3, 4, 4, 4]),
+            (-0.4, [0, -1, 0, 0, 0]),
+            (0.6, [0, 0, 1, 1, 1]),
+            (7.0, [7, 7, 7, 7, 7]),
+            (1e-300, [0, 0, 1, 0, 0]),
+            (-5e-324, [0, -1, 0, 0, 0])
+        ];
+        let modes = [
+            RoundingMode::TowardZero, RoundingMode::Floor, RoundingMode::Ceil,
+            RoundingMode::NearestTiesAway, RoundingMode::NearestTiesEven
+        ];
+
+        for (value, expected) in cases {
+            for (mode, expected) in modes.iter().zip(expected) {
+                assert_eq!(Number::<8>::from_f64_round(value, *mode), Ok(Number::<8>::from(expected)), "{} {:?}", value, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert_eq!(Number::<8>::from_f64_round(f64::NAN, RoundingMode::Floor), Err(FloatConversionError::NaN));
+        assert_eq!(Number::<8>::from_f64_round(f64::NEG_INFINITY, RoundingMode::Floor), Err(FloatConversionError::Infinite));
+        assert_eq!(Number::<8>::from_f32_round(f32::INFINITY, RoundingMode::Floor), Err(FloatConversionError::Infinite));
+
+        assert_eq!(Number::<8>::from_f64_round(3280.4, RoundingMode::NearestTiesEven), Ok(Number::<8>::MAX));
+        assert_eq!(Number::<8>::from_f64_round(3280.5, RoundingMode::NearestTiesAway), Err(FloatConversionError::OutOfRange));
+        assert_eq!(Number::<8>::from_f64_round(-1e10, RoundingMode::Floor), Err(FloatConversionError::OutOfRange));
+        assert_eq!(Number::<81>::from_f64_round(1e300, RoundingMode::Floor), Err(FloatConversionError::OutOfRange));
+    }
+
+    #[test]
+    fn large_values_convert_exactly() {
+        // 2^200 is exactly representable and needs 127 trits
+        let power = Number::<200>::from_f64_round(2_f64.powi(200), RoundingMode::TowardZero).unwrap();
+        assert_eq!(power, Number::<200>::try_from(1_u128 << 100).unwrap().pow(2));
+        assert_eq!(power.to_f64(), 2_f64.powi(200));
+        assert_eq!((-power).to_f32(), f32::NEG_INFINITY);
+
+        let max = Number::<700>::from_f64_round(f64::MAX, RoundingMode::TowardZero).unwrap();
+        assert_eq!(max.to_f64(), f64::MAX);
+        assert_eq!((max + max).to_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn conversion_to_float_rounds_to_nearest() {
+        assert_eq!(Number::<8>::from("+-0--").to_f64(), 50.0);
+        assert_eq!(Number::<8>::MIN.to_f32(), -3280.0);
+
+        // (3^100 - 1) / 2 to the nearest f64, compared against a decimal literal
+        assert_eq!(Number::<100>::MAX.to_f64(), 2.576_887_603_660_056_7e47);
+        assert_eq!(Number::<100>::MIN.to_f32(), f32::NEG_INFINITY);
+
+        // 2^130 + 1 must round down, but 2^130 + 2^77 + 1 is just over half way
+        let base = Number::<100>::try_from(1_u128 << 65).unwrap().pow(2);
+        assert_eq!((base + Number::<100>::ONE).to_f64(), 2_f64.powi(130));
+        let over_half = base + Number::<100>::try_from(1_u128 << 77).unwrap() + Number::<100>::ONE;
+        assert_eq!(over_half.to_f64(), 2_f64.powi(130) + 2_f64.powi(78));
+    }
+}
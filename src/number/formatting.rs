@@ -0,0 +1,153 @@
+use core::fmt::{self, Write};
+
+use crate::number::Number;
+
+/// How [`Number::format_with`] writes a number.
+///
+/// The default options write every trit with `-`, `0` and `+`, the same as
+/// the trits written by [`Display`](fmt::Display) but without the decimal
+/// value. The symbols can be replaced, leading zeros can be dropped, and the
+/// trits can be split into groups of a fixed size, counted from the least
+/// significant trit as with thousands separators.
+/// ```
+/// # use balanced_ternary::number::{FormatOptions, Number};
+/// let number = Number::<12>::from(-9841);
+/// let options = FormatOptions::new().with_grouping(3, '_');
+/// assert_eq!(number.format_with(&options).to_string(), "000_---_---_---");
+///
+/// let options = options.with_digits('T', '0', '1').with_leading_zeros(false);
+/// assert_eq!(number.format_with(&options).to_string(), "TTT_TTT_TTT");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FormatOptions {
+    // The symbols of the negative, zero and positive trits, indexed by
+    // `trit as usize`
+    digits: [char; 3],
+    group_size: usize,
+    separator: char,
+    leading_zeros: bool
+}
+
+impl FormatOptions {
+    /// The default options, writing every trit as `-`, `0` or `+` without any
+    /// grouping.
+    pub const fn new() -> Self {
+        FormatOptions { digits: ['-', '0', '+'], group_size: 0, separator: ' ', leading_zeros: true }
+    }
+
+    /// Sets the symbols written for the negative, zero and positive trits.
+    pub const fn with_digits(mut self, neg: char, zero: char, pos: char) -> Self {
+        self.digits = [neg, zero, pos];
+        self
+    }
+
+    /// Separates every `size` trits with `separator`, counting from the least
+    /// significant trit. A size of zero turns grouping off.
+    pub const fn with_grouping(mut self, size: usize, separator: char) -> Self {
+        self.group_size = size;
+        self.separator = separator;
+        self
+    }
+
+    /// Sets whether the zero trits above the most significant non-zero trit
+    /// are written. Without them, zero is written as a single zero trit.
+    pub const fn with_leading_zeros(mut self, enabled: bool) -> Self {
+        self.leading_zeros = enabled;
+        self
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::new()
+    }
+}
+
+/// A number written with a set of [`FormatOptions`], from
+/// [`Number::format_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct FormattedNumber<'a, const N: usize> {
+    number: Number<N>,
+    options: &'a FormatOptions
+}
+
+impl <const N: usize> Number<N> {
+    /// Returns a wrapper that displays this number with the given options, so
+    /// it can be used with `write!` or turned into a string with `to_string`.
+    pub fn format_with(self, options: &FormatOptions) -> FormattedNumber<'_, N> {
+        FormattedNumber { number: self, options }
+    }
+}
+
+impl <const N: usize> fmt::Display for FormattedNumber<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = self.options;
+        let length = if options.leading_zeros {
+            N
+        } else {
+            (self.number.significant_trits() as usize).max(1).min(N)
+        };
+
+        for (idx, trit) in self.number.0[N - length..].iter().enumerate() {
+            // The remaining count of trits says where the group boundaries lie
+            let remaining = length - idx;
+            if idx > 0 && options.group_size > 0 && remaining % options.group_size == 0 {
+                f.write_char(options.separator)?;
+            }
+            f.write_char(options.digits[*trit as usize])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_display() {
+        let options = FormatOptions::default();
+        for value in -40..=40 {
+            let number = Number::<4>::from(value);
+            let display = format!("{}", number);
+            assert_eq!(number.format_with(&options).to_string(), display[..4]);
+        }
+    }
+
+    #[test]
+    fn leading_zeros_and_groups() {
+        let num_50 = Number::<8>::from("+-0--");
+        let options = FormatOptions::new().with_leading_zeros(false);
+        assert_eq!(num_50.format_with(&options).to_string(), "+-0--");
+        assert_eq!(Number::<8>::ZERO.format_with(&options).to_string(), "0");
+        assert_eq!(Number::<0>::ZERO.format_with(&options).to_string(), "");
+
+        let grouped = options.with_grouping(2, ' ');
+        assert_eq!(num_50.format_with(&grouped).to_string(), "+ -0 --");
+        assert_eq!(num_50.format_with(&grouped.with_leading_zeros(true)).to_string(), "00 0+ -0 --");
+        assert_eq!(num_50.format_with(&grouped.with_grouping(5, ',')).to_string(), "+-0--");
+        assert_eq!(num_50.format_with(&grouped.with_grouping(0, ',')).to_string(), "+-0--");
+    }
+
+    #[test]
+    fn alternate_digits() {
+        let options = FormatOptions::new().with_digits('N', 'Z', 'P');
+        assert_eq!(Number::<4>::from("+-0").format_with(&options).to_string(), "ZPNZ");
+
+        // Multi-byte symbols are written whole
+        let options = FormatOptions::new().with_digits('\u{2212}', '0', '1').with_grouping(2, '\u{2009}');
+        assert_eq!(Number::<4>::from("+-0").format_with(&options).to_string(), "01\u{2009}\u{2212}0");
+    }
+
+    #[test]
+    fn round_trips_through_parsing() {
+        use crate::number::ParseOptions;
+
+        let format = FormatOptions::new().with_digits('T', '0', '1').with_grouping(3, '_').with_leading_zeros(false);
+        let parse = ParseOptions::new().with_10t_digits(true).with_underscores(true);
+        for value in -9841..=9841 {
+            let number = Number::<9>::from(value);
+            assert_eq!(Number::<9>::parse_with(&number.format_with(&format).to_string(), parse), Ok(number));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::number::Number;
+
+impl <const N: usize> Number<N> {
+    /// Converts this number to its ternary Gray code, in which successive
+    /// integers differ in exactly one trit. This holds across the wrap from
+    /// [`MAX`](Self::MAX) to [`MIN`](Self::MIN) as well, and zero maps to zero.
+    ///
+    /// Each trit of the code is the difference modulo three between a trit of
+    /// the number and the trit above it. Incrementing adds one modulo three to
+    /// every trit up to and including the first that doesn't carry, so only the
+    /// difference at that trit changes.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// assert_eq!(Number::<4>::from(2).to_gray(), Number::<4>::from("++"));
+    /// assert_eq!(Number::<4>::from(3).to_gray(), Number::<4>::from("+-"));
+    /// assert_eq!(Number::<4>::from("+-").from_gray(), Number::<4>::from(3));
+    /// ```
+    pub const fn to_gray(self) -> Self {
+        let mut output = self;
+        let mut idx = 1;
+        while idx < N {
+            output.0[idx] = self.0[idx].add(&self.0[idx - 1].negate()).result;
+            idx += 1;
+        }
+        output
+    }
+
+    /// Converts a ternary Gray code back to the number it encodes, the inverse
+    /// of [`to_gray`](Self::to_gray).
+    pub const fn from_gray(self) -> Self {
+        let mut output = self;
+        let mut idx = 1;
+        while idx < N {
+            output.0[idx] = self.0[idx].add(&output.0[idx - 1]).result;
+            idx += 1;
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn successive_codes_differ_in_one_trit() {
+        let mut number = Number::<5>::MIN;
+        let mut previous = number.to_gray();
+        for _ in 0..243 {
+            number.inc();
+            let code = number.to_gray();
+            assert_eq!(code.hamming_distance(&previous), 1, "Gray codes around {}", number);
+            assert_eq!(code.from_gray(), number);
+            previous = code;
+        }
+        assert_eq!(number, Number::<5>::MIN);
+    }
+
+    #[test]
+    fn gray_codes() {
+        assert_eq!(Number::<8>::ZERO.to_gray(), Number::<8>::ZERO);
+        assert_eq!(Number::<8>::from(1).to_gray(), Number::<8>::from("+"));
+        assert_eq!(Number::<8>::from(-1).to_gray(), Number::<8>::from("-"));
+        assert_eq!(Number::<8>::from(4).to_gray(), Number::<8>::from("+0"));
+        assert_eq!(Number::<8>::MAX.to_gray(), Number::<8>::from("+0000000"));
+        assert_eq!(Number::<0>::ZERO.to_gray(), Number::<0>::ZERO);
+    }
+}
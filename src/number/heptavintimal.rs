@@ -0,0 +1,78 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::number::{digit_groups, Number, ParseTernaryError};
+
+// Each heptavintimal digit stands for three trits, with a value in -13..=13.
+// The digits are those of the value modulo 27 from the alphabet 0-9 then A-Q,
+// so zero and the positive values keep their familiar digits while -13..=-1
+// are written E..Q. Listed here in order of value.
+const ALPHABET: &[u8; 27] = b"EFGHIJKLMNOPQ0123456789ABCD";
+
+const TRITS_PER_DIGIT: usize = 3;
+
+impl <const N: usize> Number<N> {
+    /// Formats the number in heptavintimal (base 27), with each digit standing
+    /// for a group of three trits, starting from the least significant. A
+    /// group's value in -13..=13 is written as the digit of that value modulo
+    /// 27, from the alphabet `0`-`9` then `A`-`Q`, so 0 to 13 are written `0` to
+    /// `D` and -13 to -1 are written `E` to `Q`. No sign is needed, and the
+    /// string always has ceil(N / 3) digits.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// // 50 is 0+- 0-- in groups of three trits, which are 2 and -4
+    /// assert_eq!(Number::<6>::from("+-0--").to_base27_string(), "2N");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_base27_string(self) -> String {
+        digit_groups::to_digit_string(self, TRITS_PER_DIGIT, ALPHABET)
+    }
+
+    /// Parses a heptavintimal string as written by [`Number::to_base27_string`],
+    /// with letters in either case. Fewer digits than ceil(N / 3) may be given,
+    /// and extra leading zero digits are allowed, but the value must fit in N
+    /// trits.
+    /// * `encoded` - The base 27 digits, most significant first
+    pub fn from_base27_str(encoded: &str) -> Result<Self, ParseTernaryError> {
+        digit_groups::from_digit_str(encoded, TRITS_PER_DIGIT, ALPHABET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn formatting() {
+        assert_eq!(Number::<9>::from("+++").to_base27_string(), "00D");
+        assert_eq!(Number::<9>::from("---").to_base27_string(), "00E");
+        assert_eq!(Number::<9>::from("--").to_base27_string(), "00N");
+        assert_eq!(Number::<9>::MAX.to_base27_string(), "DDD");
+        assert_eq!(Number::<4>::from("-0++").to_base27_string(), "Q4");
+        assert_eq!(Number::<0>::ZERO.to_base27_string(), "");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Number::<9>::from_base27_str("2N"), Ok(Number::<9>::from("+-0--")));
+        assert_eq!(Number::<9>::from_base27_str("2n"), Ok(Number::<9>::from("+-0--")));
+        assert_eq!(Number::<4>::from_base27_str("Q4"), Ok(Number::<4>::from("-0++")));
+        assert_eq!(Number::<3>::from_base27_str("000D"), Ok(Number::<3>::MAX));
+
+        assert_eq!(Number::<9>::from_base27_str(""), Err(ParseTernaryError::Empty));
+        assert_eq!(Number::<9>::from_base27_str("2RZ"), Err(ParseTernaryError::InvalidDigit { character: 'R', position: 1 }));
+        // The high digit needs two trits, but only one is available
+        assert_eq!(Number::<4>::from_base27_str("44"), Err(ParseTernaryError::OutOfRange));
+        assert_eq!(Number::<3>::from_base27_str("10"), Err(ParseTernaryError::OutOfRange));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn round_trips() {
+        for value in -9841..=9841 {
+            let number = Number::<9>::from(value);
+            assert_eq!(Number::<9>::from_base27_str(&number.to_base27_string()), Ok(number));
+        }
+    }
+}
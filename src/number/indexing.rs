@@ -0,0 +1,95 @@
+use core::ops::{Index, IndexMut};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+// Trits are indexed by position from the least significant trit, so that the
+// trit at index `i` has a weight of 3^i. Note that this is the reverse of the
+// order in which trits are written in a string, where the most significant
+// trit comes first.
+impl <const N: usize> Number<N> {
+    /// Returns the trit at position `index`, counted from the least
+    /// significant trit (which has a weight of 3^0), or `None` if the index is
+    /// not less than N.
+    pub const fn get_trit(&self, index: usize) -> Option<Trit> {
+        if index < N {
+            Some(self.0[N-1-index])
+        } else {
+            None
+        }
+    }
+
+    /// Sets the trit at position `index`, counted from the least significant
+    /// trit (which has a weight of 3^0).
+    ///
+    /// # Panics
+    /// Panics if the index is not less than N.
+    pub fn set_trit(&mut self, index: usize, trit: Trit) {
+        self[index] = trit;
+    }
+}
+
+/// Indexes trits by position from the least significant trit, matching
+/// [`Number::get_trit`]. Panics if the index is not less than N.
+impl <const N: usize> Index<usize> for Number<N> {
+    type Output = Trit;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < N, "Trit index {} out of range for number of {} trits", index, N);
+        &self.0[N-1-index]
+    }
+}
+
+impl <const N: usize> IndexMut<usize> for Number<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < N, "Trit index {} out of range for number of {} trits", index, N);
+        &mut self.0[N-1-index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trits_are_indexed_from_least_significant() {
+        let num_23 = Number::<8>::from("+0--");
+
+        assert_eq!(num_23[0], Trit::NEG);
+        assert_eq!(num_23[1], Trit::NEG);
+        assert_eq!(num_23[2], Trit::ZERO);
+        assert_eq!(num_23[3], Trit::POS);
+        assert_eq!(num_23[7], Trit::ZERO);
+
+        assert_eq!(num_23.get_trit(0), Some(Trit::NEG));
+        assert_eq!(num_23.get_trit(3), Some(Trit::POS));
+        assert_eq!(num_23.get_trit(8), None);
+    }
+
+    #[test]
+    fn trits_can_be_modified() {
+        let mut number = Number::<8>::from("+0--");
+
+        number[2] = Trit::POS;
+        assert_eq!(number, Number::<8>::from("++--"));
+
+        number.set_trit(7, Trit::NEG);
+        assert_eq!(number, Number::<8>::from("-000++--"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Trit index 8 out of range for number of 8 trits")]
+    fn index_out_of_range() {
+        let number = Number::<8>::from("+0--");
+
+        let _ = number[8];
+    }
+
+    #[test]
+    #[should_panic(expected = "Trit index 9 out of range for number of 8 trits")]
+    fn set_out_of_range() {
+        let mut number = Number::<8>::from("+0--");
+
+        number.set_trit(9, Trit::POS);
+    }
+}
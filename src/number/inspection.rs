@@ -0,0 +1,197 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Returns the number of zero trits before the most significant non-zero
+    /// trit. This is N for a value of zero.
+    pub fn leading_zeros(&self) -> u32 {
+        self.0.iter().take_while(|trit| **trit == Trit::ZERO).count() as u32
+    }
+
+    /// Returns the number of zero trits after the least significant non-zero
+    /// trit, which is the largest power of three dividing the value. This is N
+    /// for a value of zero.
+    pub fn trailing_zeros(&self) -> u32 {
+        self.0.iter().rev().take_while(|trit| **trit == Trit::ZERO).count() as u32
+    }
+
+    /// Returns the number of trits needed to represent this value, i.e. the
+    /// width of the number without its leading zeros. This is 0 for a value of
+    /// zero.
+    pub fn significant_trits(&self) -> u32 {
+        N as u32 - self.leading_zeros()
+    }
+
+    /// Returns the base 3 logarithm of the absolute value of this number,
+    /// rounded down.
+    ///
+    /// # Panics
+    /// Panics if the number is zero.
+    pub fn ilog3(&self) -> u32 {
+        self.checked_ilog3().expect("Argument of integer logarithm must be non-zero")
+    }
+
+    /// Returns the base 3 logarithm of the absolute value of this number,
+    /// rounded down, or `None` if the number is zero.
+    pub fn checked_ilog3(&self) -> Option<u32> {
+        let mut significant = self.0.iter().skip_while(|trit| **trit == Trit::ZERO);
+        let leading = *significant.next()?;
+        let exponent = self.significant_trits() - 1;
+
+        // A number whose leading trit has a weight of 3^k lies within half of 3^k
+        // either side of 3^k. The lower trits then decide whether the magnitude
+        // falls short of 3^k, which is the case when the first non-zero trit
+        // after the leading trit has the opposite sign.
+        match significant.find(|trit| **trit != Trit::ZERO) {
+            Some(trit) if *trit == leading.negate() => Some(exponent - 1),
+            _ => Some(exponent)
+        }
+    }
+
+    /// Returns `true` if this number is 3^k for some k, which is when it has a
+    /// single positive trit and no other non-zero trits.
+    pub fn is_power_of_three(&self) -> bool {
+        let mut significant = self.0.iter().filter(|trit| **trit != Trit::ZERO);
+        significant.next() == Some(&Trit::POS) && significant.next().is_none()
+    }
+
+    /// Returns the smallest power of three greater than or equal to this
+    /// number, which is one for any number less than one. Wraps to zero on
+    /// overflow, in the same way as [`pow`](Self::pow).
+    pub fn next_power_of_three(self) -> Self {
+        self.checked_next_power_of_three().unwrap_or(Number::<N>::ZERO)
+    }
+
+    /// Returns the smallest power of three greater than or equal to this
+    /// number, or `None` if it cannot be represented in N trits.
+    pub fn checked_next_power_of_three(self) -> Option<Self> {
+        if self.is_power_of_three() {
+            return Some(self);
+        }
+        if self.signum() != Trit::POS {
+            return (N > 0).then_some(Number::<N>::ONE);
+        }
+
+        let exponent = self.ilog3() as usize + 1;
+        (exponent < N).then(|| Number::<N>::ONE << exponent)
+    }
+
+    /// Returns the number of positive trits in this number.
+    pub fn count_pos(&self) -> usize {
+        self.count_trit(Trit::POS)
+    }
+
+    /// Returns the number of negative trits in this number.
+    pub fn count_neg(&self) -> usize {
+        self.count_trit(Trit::NEG)
+    }
+
+    /// Returns the number of zero trits in this number, including any leading
+    /// zeros.
+    pub fn count_zero(&self) -> usize {
+        self.count_trit(Trit::ZERO)
+    }
+
+    /// Returns the number of negative, zero and positive trits in this number,
+    /// in that order so the array can be indexed by `trit as usize`. The counts
+    /// always sum to N.
+    pub fn trit_counts(&self) -> [usize; 3] {
+        let mut counts = [0; 3];
+        for trit in self.0 {
+            counts[trit as usize] += 1;
+        }
+        counts
+    }
+
+    fn count_trit(&self, target: Trit) -> usize {
+        self.0.iter().filter(|trit| **trit == target).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_counts() {
+        let num_neg_24 = Number::<8>::from("-+00");
+
+        assert_eq!(num_neg_24.leading_zeros(), 4);
+        assert_eq!(num_neg_24.trailing_zeros(), 2);
+        assert_eq!(num_neg_24.significant_trits(), 4);
+
+        assert_eq!(Number::<8>::ZERO.leading_zeros(), 8);
+        assert_eq!(Number::<8>::ZERO.trailing_zeros(), 8);
+        assert_eq!(Number::<8>::ZERO.significant_trits(), 0);
+    }
+
+    #[test]
+    fn integer_logarithm() {
+        assert_eq!(Number::<8>::from("+").ilog3(), 0);   // 1
+        assert_eq!(Number::<8>::from("+-").ilog3(), 0);  // 2
+        assert_eq!(Number::<8>::from("+0").ilog3(), 1);  // 3
+        assert_eq!(Number::<8>::from("++").ilog3(), 1);  // 4
+        assert_eq!(Number::<8>::from("+--").ilog3(), 1); // 5
+        assert_eq!(Number::<8>::from("+-+").ilog3(), 1); // 7
+        assert_eq!(Number::<8>::from("+00").ilog3(), 2); // 9
+        assert_eq!(Number::<8>::from("+0-").ilog3(), 1); // 8
+        assert_eq!(Number::<8>::from("+00-").ilog3(), 2); // 26
+        assert_eq!(Number::<8>::from("+000").ilog3(), 3); // 27
+
+        // The logarithm is of the absolute value
+        assert_eq!(Number::<8>::from("-0+").ilog3(), 1); // -8
+        assert_eq!(Number::<8>::from("-00").ilog3(), 2); // -9
+        assert_eq!(Number::<8>::from("-").ilog3(), 0);   // -1
+
+        assert_eq!(Number::<8>::ZERO.checked_ilog3(), None);
+    }
+
+    #[test]
+    fn integer_logarithm_matches_primitive() {
+        let mut number = Number::<6>::from("+");
+        for value in 1..=364_u32 {
+            assert_eq!(number.ilog3(), value.ilog(3));
+            assert_eq!((-number).ilog3(), value.ilog(3));
+            number.inc();
+        }
+    }
+
+    #[test]
+    fn powers_of_three() {
+        let mut power = 1;
+        for value in -100..=250_i32 {
+            let number = Number::<6>::from(value);
+            while power < value {
+                power *= 3;
+            }
+            assert_eq!(number.is_power_of_three(), value == power, "{}", value);
+            assert_eq!(number.next_power_of_three(), Number::<6>::from(power));
+        }
+
+        // The largest power of three is 3^(N-1)
+        assert_eq!(Number::<6>::from(243).checked_next_power_of_three(), Some(Number::<6>::from(243)));
+        assert_eq!(Number::<6>::from(244).checked_next_power_of_three(), None);
+        assert_eq!(Number::<6>::MAX.next_power_of_three(), Number::<6>::ZERO);
+        assert!(!Number::<6>::ZERO.is_power_of_three());
+        assert!(!Number::<6>::from(-3).is_power_of_three());
+    }
+
+    #[test]
+    fn trit_histogram() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(num_50.count_pos(), 1);
+        assert_eq!(num_50.count_neg(), 3);
+        assert_eq!(num_50.count_zero(), 4);
+        assert_eq!(num_50.trit_counts(), [3, 4, 1]);
+        assert_eq!(num_50.trit_counts()[Trit::NEG as usize], num_50.count_neg());
+
+        assert_eq!(Number::<8>::ZERO.trit_counts(), [0, 8, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Argument of integer logarithm must be non-zero")]
+    fn integer_logarithm_of_zero() {
+        let _ = Number::<8>::ZERO.ilog3();
+    }
+}
@@ -0,0 +1,161 @@
+use core::iter::{Copied, FromIterator, Rev};
+use core::{array, slice};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+// All iteration over the trits of a number runs from the least significant trit
+// to the most significant, matching the indexing order and the order expected by
+// `Number::from_rev_iter`. This means that a number's trits can be collected
+// straight back into an equal number.
+
+impl <const N: usize> Number<N> {
+    /// Returns an iterator over the trits of this number, starting from the
+    /// least significant trit.
+    pub fn iter(&self) -> Copied<Rev<slice::Iter<'_, Trit>>> {
+        self.0.iter().rev().copied()
+    }
+
+    /// Builds a number by calling `f` with the position of each trit, where
+    /// position 0 is the least significant trit, as in indexing.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::trit::Trit;
+    /// let alternating = Number::<4>::from_fn(|idx| if idx % 2 == 0 {Trit::POS} else {Trit::NEG});
+    /// assert_eq!(alternating, Number::<4>::from("-+-+"));
+    /// ```
+    pub fn from_fn(mut f: impl FnMut(usize) -> Trit) -> Self {
+        Number::<N>(array::from_fn(|idx| f(N - 1 - idx)))
+    }
+
+    /// Applies `f` to every trit of this number.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::trit::Trit;
+    /// // Clearing the negative trits
+    /// let number = Number::<6>::from("+-0-+");
+    /// assert_eq!(number.map_trits(|trit| trit.max(Trit::ZERO)), Number::<6>::from("+000+"));
+    /// ```
+    pub fn map_trits(self, mut f: impl FnMut(Trit) -> Trit) -> Self {
+        Number::<N>(self.0.map(&mut f))
+    }
+
+    /// Combines the trits in each position of two numbers with `f`, which is
+    /// given the trit of `self` first.
+    pub fn zip_with(self, rhs: Self, mut f: impl FnMut(Trit, Trit) -> Trit) -> Self {
+        Number::<N>(array::from_fn(|idx| f(self.0[idx], rhs.0[idx])))
+    }
+
+    /// Folds every trit into an accumulator, starting from the least
+    /// significant trit.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let number = Number::<6>::from("+-0-+");
+    /// let (value, _) = number.fold_trits((0, 1), |(value, weight), trit| (value + weight * (trit as i32 - 1), weight * 3));
+    /// assert_eq!(value, 52);
+    /// ```
+    pub fn fold_trits<B>(&self, init: B, f: impl FnMut(B, Trit) -> B) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns `true` if any trit satisfies the predicate.
+    pub fn any(&self, mut predicate: impl FnMut(Trit) -> bool) -> bool {
+        self.0.iter().any(|trit| predicate(*trit))
+    }
+
+    /// Returns `true` if every trit satisfies the predicate. This is `true` for
+    /// a number of no trits.
+    pub fn all(&self, mut predicate: impl FnMut(Trit) -> bool) -> bool {
+        self.0.iter().all(|trit| predicate(*trit))
+    }
+}
+
+impl <const N: usize> IntoIterator for Number<N> {
+    type Item = Trit;
+    type IntoIter = Rev<array::IntoIter<Trit, N>>;
+
+    /// Consumes the number, yielding its trits from the least significant.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().rev()
+    }
+}
+
+impl <'a, const N: usize> IntoIterator for &'a Number<N> {
+    type Item = &'a Trit;
+    type IntoIter = Rev<slice::Iter<'a, Trit>>;
+
+    /// Yields references to the trits of the number from the least significant.
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().rev()
+    }
+}
+
+/// Collects trits, supplied from the least significant, into a number. If
+/// fewer than N trits are supplied then the higher-order trits are padded with
+/// zeros.
+///
+/// # Panics
+/// Panics if more than N trits are supplied. Use [`Number::from_rev_iter`] to
+/// silently discard the excess trits instead.
+impl <const N: usize> FromIterator<Trit> for Number<N> {
+    fn from_iter<I: IntoIterator<Item = Trit>>(source: I) -> Self {
+        let mut source = source.into_iter();
+        let output = Number::<N>::from_rev_iter(source.by_ref().take(N));
+
+        if source.next().is_some() {
+            panic!("Too many trits supplied for number of {} trits", N);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterates_from_least_significant() {
+        let num_23 = Number::<6>::from("+0--");
+        let expected = [Trit::NEG, Trit::NEG, Trit::ZERO, Trit::POS, Trit::ZERO, Trit::ZERO];
+
+        assert!(num_23.iter().eq(expected));
+        assert!(num_23.into_iter().eq(expected));
+        assert!((&num_23).into_iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn collects_from_least_significant() {
+        let num_23 = Number::<6>::from("+0--");
+
+        assert_eq!(num_23.into_iter().collect::<Number<6>>(), num_23);
+        assert_eq!([Trit::NEG, Trit::NEG, Trit::ZERO, Trit::POS].into_iter().collect::<Number<6>>(), num_23);
+        assert_eq!(core::iter::empty().collect::<Number<6>>(), Number::<6>::from("0"));
+    }
+
+    #[test]
+    fn combinators() {
+        let num_23 = Number::<6>::from("+0--");
+        assert_eq!(Number::<6>::from_fn(|idx| num_23[idx]), num_23);
+        assert_eq!(num_23.map_trits(Trit::negate), -num_23);
+        assert_eq!(num_23.zip_with(Number::<6>::from("++++"), |lhs, rhs| lhs.min(rhs)), num_23 & Number::<6>::from("++++"));
+
+        let trits = num_23.fold_trits([Trit::ZERO; 6], |mut trits, trit| {
+            trits.rotate_right(1);
+            trits[0] = trit;
+            trits
+        });
+        assert_eq!(Number::<6>::from_trits(trits), num_23);
+
+        assert!(num_23.any(|trit| trit == Trit::NEG));
+        assert!(!num_23.all(|trit| trit != Trit::ZERO));
+        assert!(Number::<6>::MAX.all(|trit| trit == Trit::POS));
+        assert!(Number::<0>::ZERO.all(|_| false));
+        assert!(!Number::<0>::ZERO.any(|_| true));
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many trits supplied for number of 3 trits")]
+    fn collecting_too_many_trits() {
+        let _ = [Trit::POS; 4].into_iter().collect::<Number<3>>();
+    }
+}
@@ -0,0 +1,280 @@
+//! Loops over the trits of a number that are shared by the operators. With the
+//! `simd` feature enabled on x86_64 these process 16 trits at a time using SSE2,
+//! which every x86_64 processor supports, and otherwise fall back to the scalar
+//! implementations. Both operate on the trits in storage order, from the most
+//! significant trit to the least.
+
+use core::cmp::Ordering;
+
+use crate::trit::Trit;
+
+/// The sum and carry trits for each possible total of two trits and a carry,
+/// indexed by the total plus three.
+const SUM_TABLE: [(Trit, Trit); 7] = [
+    (Trit::ZERO, Trit::NEG),
+    (Trit::POS, Trit::NEG),
+    (Trit::NEG, Trit::ZERO),
+    (Trit::ZERO, Trit::ZERO),
+    (Trit::POS, Trit::ZERO),
+    (Trit::NEG, Trit::POS),
+    (Trit::ZERO, Trit::POS)
+];
+
+/// Negates every trit in place.
+pub(crate) fn negate(trits: &mut [Trit]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    sse2::negate(trits);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    scalar::negate(trits);
+}
+
+/// Compares two numbers of the same width by their trits.
+pub(crate) fn compare(lhs: &[Trit], rhs: &[Trit]) -> Ordering {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    return sse2::compare(lhs, rhs);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    return scalar::compare(lhs, rhs);
+}
+
+/// Adds `rhs` and `carry` into `lhs`, returning the carry out of the most
+/// significant trit.
+pub(crate) fn add_assign(lhs: &mut [Trit], rhs: &[Trit], carry: Trit) -> Trit {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    return sse2::add_assign(lhs, rhs, carry);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    return scalar::add_assign(lhs, rhs, carry);
+}
+
+mod scalar {
+    use super::*;
+
+    pub(crate) fn negate(trits: &mut [Trit]) {
+        for trit in trits {
+            *trit = trit.negate();
+        }
+    }
+
+    pub(crate) fn compare(lhs: &[Trit], rhs: &[Trit]) -> Ordering {
+        lhs.cmp(rhs)
+    }
+
+    pub(crate) fn add_assign(lhs: &mut [Trit], rhs: &[Trit], mut carry: Trit) -> Trit {
+        for (lhs, rhs) in lhs.iter_mut().rev().zip(rhs.iter().rev()) {
+            let total = lhs.to_i8() + rhs.to_i8() + carry.to_i8();
+            (*lhs, carry) = SUM_TABLE[(total + 3) as usize];
+        }
+        carry
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod sse2 {
+    use core::arch::x86_64::{
+        __m128i, _mm_add_epi8, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_set1_epi8,
+        _mm_storeu_si128, _mm_sub_epi8
+    };
+
+    use super::*;
+
+    const LANES: usize = 16;
+
+    // `Trit` is `repr(u8)` with the values 0, 1 and 2 for the negative, zero and
+    // positive trits, so a chunk of trits can be loaded directly as bytes. Every
+    // store writes back one of those three values.
+
+    fn load(trits: &[Trit]) -> __m128i {
+        debug_assert!(trits.len() >= LANES);
+        // SAFETY: the slice holds at least 16 single byte trits, and SSE2 is
+        // always available on x86_64
+        unsafe { _mm_loadu_si128(trits.as_ptr().cast()) }
+    }
+
+    pub(crate) fn negate(trits: &mut [Trit]) {
+        let mut chunks = trits.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            // Negation maps the stored byte b to 2 - b
+            // SAFETY: as for `load`, and the result is always 0, 1 or 2
+            unsafe {
+                let negated = _mm_sub_epi8(_mm_set1_epi8(2), load(chunk));
+                _mm_storeu_si128(chunk.as_mut_ptr().cast(), negated);
+            }
+        }
+        scalar::negate(chunks.into_remainder());
+    }
+
+    pub(crate) fn compare(lhs: &[Trit], rhs: &[Trit]) -> Ordering {
+        let lhs_chunks = lhs.chunks_exact(LANES);
+        let rhs_chunks = rhs.chunks_exact(LANES);
+        let (lhs_rest, rhs_rest) = (lhs_chunks.remainder(), rhs_chunks.remainder());
+
+        // Find the first chunk containing a differing trit, and within it the
+        // lowest lane whose bytes are not equal
+        for (lhs_chunk, rhs_chunk) in lhs_chunks.zip(rhs_chunks) {
+            // SAFETY: SSE2 is always available on x86_64
+            let equal = unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(load(lhs_chunk), load(rhs_chunk))) } as u32;
+            if equal != 0xFFFF {
+                let lane = (!equal).trailing_zeros() as usize;
+                return lhs_chunk[lane].cmp(&rhs_chunk[lane]);
+            }
+        }
+        scalar::compare(lhs_rest, rhs_rest)
+    }
+
+    pub(crate) fn add_assign(lhs: &mut [Trit], rhs: &[Trit], mut carry: Trit) -> Trit {
+        // The carries have to ripple through the trits one at a time, but the
+        // pairwise sums of each chunk are found at once beforehand. Chunks are
+        // taken from the least significant end so that the leftover trits are
+        // the most significant ones.
+        let mut lhs_chunks = lhs.rchunks_exact_mut(LANES);
+        let mut rhs_chunks = rhs.rchunks_exact(LANES);
+        for (lhs_chunk, rhs_chunk) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+            let mut pair_sums = [0_u8; LANES];
+            // SAFETY: as for `load`, storing into a 16 byte array
+            unsafe {
+                _mm_storeu_si128(pair_sums.as_mut_ptr().cast(), _mm_add_epi8(load(lhs_chunk), load(rhs_chunk)));
+            }
+
+            // Each pair sum is offset by 2 from the sum of the trit values
+            for (lhs, pair_sum) in lhs_chunk.iter_mut().zip(pair_sums).rev() {
+                let total = pair_sum as i8 - 2 + carry.to_i8();
+                (*lhs, carry) = SUM_TABLE[(total + 3) as usize];
+            }
+        }
+        scalar::add_assign(lhs_chunks.into_remainder(), rhs_chunks.remainder(), carry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::trit_pattern;
+
+    const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+    fn pattern(len: usize, seed: usize) -> Vec<Trit> {
+        trit_pattern(len, seed).collect()
+    }
+
+    #[test]
+    fn addition_table() {
+        for lhs in TRITS {
+            for rhs in TRITS {
+                for carry in TRITS {
+                    let mut sum = [lhs];
+                    let carry_out = add_assign(&mut sum, &[rhs], carry);
+                    let expected = lhs.add_with_carry(&rhs, &carry);
+                    assert_eq!((sum[0], carry_out), (expected.result, expected.carry));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn kernels_match_scalar() {
+        // Lengths either side of whole chunks of lanes
+        for len in [0, 1, 15, 16, 17, 40, 64, 100] {
+            for seed in 1..8 {
+                let lhs = pattern(len, seed);
+                let rhs = pattern(len, seed + 3);
+
+                let mut negated = lhs.clone();
+                negate(&mut negated);
+                let mut expected = lhs.clone();
+                scalar::negate(&mut expected);
+                assert_eq!(negated, expected);
+
+                assert_eq!(compare(&lhs, &rhs), scalar::compare(&lhs, &rhs));
+                assert_eq!(compare(&lhs, &lhs), Ordering::Equal);
+
+                for carry in TRITS {
+                    let mut sum = lhs.clone();
+                    let mut expected = lhs.clone();
+                    assert_eq!(add_assign(&mut sum, &rhs, carry), scalar::add_assign(&mut expected, &rhs, carry));
+                    assert_eq!(sum, expected);
+                }
+            }
+        }
+    }
+
+    // Widths either side of one, two and three chunks of lanes
+    const WIDTHS: [usize; 13] = [0, 1, 15, 16, 17, 31, 32, 33, 47, 48, 49, 64, 100];
+
+    #[test]
+    fn addition_matches_carry_chain() {
+        for len in WIDTHS {
+            for seed in 1..10 {
+                let lhs = pattern(len, seed);
+                let rhs = pattern(len, seed + 5);
+                for carry in TRITS {
+                    // Ripple the carry from the least significant trit, one
+                    // trit at a time
+                    let mut expected = lhs.clone();
+                    let mut expected_carry = carry;
+                    for (lhs, rhs) in expected.iter_mut().zip(&rhs).rev() {
+                        let sum = lhs.add_with_carry(rhs, &expected_carry);
+                        (*lhs, expected_carry) = (sum.result, sum.carry);
+                    }
+
+                    let mut sum = lhs.clone();
+                    assert_eq!(add_assign(&mut sum, &rhs, carry), expected_carry, "width {len}, seed {seed}");
+                    assert_eq!(sum, expected, "width {len}, seed {seed}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ordering_matches_lexicographic() {
+        fn lexicographic(lhs: &[Trit], rhs: &[Trit]) -> Ordering {
+            for (lhs, rhs) in lhs.iter().zip(rhs) {
+                if lhs != rhs {
+                    return if lhs.to_i8() < rhs.to_i8() { Ordering::Less } else { Ordering::Greater };
+                }
+            }
+            Ordering::Equal
+        }
+
+        for len in WIDTHS {
+            for seed in 1..10 {
+                let lhs = pattern(len, seed);
+                let other = pattern(len, seed + 5);
+                assert_eq!(compare(&lhs, &other), lexicographic(&lhs, &other));
+
+                // Differ in a single trit, so that every lane and the leftover
+                // trits decide the ordering in turn
+                for idx in 0..len {
+                    for trit in TRITS {
+                        let mut rhs = lhs.clone();
+                        rhs[idx] = trit;
+                        assert_eq!(compare(&lhs, &rhs), lexicographic(&lhs, &rhs), "width {len}, trit {idx}");
+                        assert_eq!(compare(&rhs, &lhs), lexicographic(&rhs, &lhs), "width {len}, trit {idx}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn kernels_match_scalar_for_any_trits(
+            trits in proptest::collection::vec((crate::trit::strategy(), crate::trit::strategy()), 0..200),
+            carry in crate::trit::strategy()
+        ) {
+            let (lhs, rhs): (Vec<Trit>, Vec<Trit>) = trits.into_iter().unzip();
+
+            let mut negated = lhs.clone();
+            negate(&mut negated);
+            let mut expected = lhs.clone();
+            scalar::negate(&mut expected);
+            proptest::prop_assert_eq!(negated, expected);
+
+            proptest::prop_assert_eq!(compare(&lhs, &rhs), scalar::compare(&lhs, &rhs));
+
+            let mut sum = lhs.clone();
+            let mut expected = lhs.clone();
+            proptest::prop_assert_eq!(add_assign(&mut sum, &rhs, carry), scalar::add_assign(&mut expected, &rhs, carry));
+            proptest::prop_assert_eq!(sum, expected);
+        }
+    }
+}
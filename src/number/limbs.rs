@@ -0,0 +1,288 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// The number of trits stored in each limb. Balanced limbs of 39 trits fit in an
+/// `i64` with enough headroom to add two of them without overflowing.
+const TRITS_PER_LIMB: usize = 39;
+
+/// The weight of one limb relative to the next, 3^39.
+const LIMB_RADIX: i128 = 3_i128.pow(TRITS_PER_LIMB as u32);
+
+/// A balanced ternary number of N trits stored as limbs of 39 trits each, with
+/// every limb held as a native integer in the balanced range of a 39 trit
+/// number. The least significant limb is stored first.
+///
+/// Addition and multiplication work on whole limbs with native integer
+/// arithmetic, only resolving carries between limbs once per operation, which
+/// is much faster than the per-trit loops of [`Number`] when N is in the
+/// hundreds. As with [`Number`] every value has a single representation and
+/// arithmetic wraps modulo 3^N.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct LimbNumber<const N: usize> {
+    limbs: Vec<i64>
+}
+
+impl <const N: usize> LimbNumber<N> {
+    const LIMB_COUNT: usize = N.div_ceil(TRITS_PER_LIMB);
+
+    /// The number zero.
+    pub fn zero() -> Self {
+        LimbNumber::<N> { limbs: vec![0; Self::LIMB_COUNT] }
+    }
+
+    /// Converts the trits of a number into limbs.
+    pub fn from_number(number: Number<N>) -> Self {
+        let trits: Vec<Trit> = number.iter().collect();
+        let limbs = trits.chunks(TRITS_PER_LIMB)
+            .map(|chunk| chunk.iter().rev().fold(0_i64, |value, trit| value * 3 + i64::from(trit.to_i8())))
+            .collect();
+        LimbNumber::<N> { limbs }
+    }
+
+    /// Converts the limbs back into an array of trits.
+    pub fn to_number(&self) -> Number<N> {
+        Number::<N>::from_rev_iter(self.limbs.iter().flat_map(|limb| {
+            let mut value = *limb;
+            (0..TRITS_PER_LIMB).map(move |_| {
+                let (trit, digit) = match value.rem_euclid(3) {
+                    0 => (Trit::ZERO, 0),
+                    1 => (Trit::POS, 1),
+                    _ => (Trit::NEG, -1)
+                };
+                value = (value - digit) / 3;
+                trit
+            })
+        }))
+    }
+
+    /// Builds a number from limb values that may lie outside the balanced range
+    /// of a limb, carrying the excess of each limb into the next and wrapping
+    /// the most significant limb to the width of the number.
+    fn normalise(values: impl IntoIterator<Item = i128>) -> Self {
+        let mut carry = 0_i128;
+        let mut limbs: Vec<i64> = values.into_iter()
+            .map(|value| {
+                let (limb, next_carry) = balanced_div_rem(value + carry, LIMB_RADIX);
+                carry = next_carry;
+                limb as i64
+            })
+            .collect();
+
+        // The most significant limb may hold fewer than 39 trits, and anything
+        // beyond those is wrapped away
+        if let Some(top) = limbs.last_mut() {
+            let top_trits = N - TRITS_PER_LIMB * (Self::LIMB_COUNT - 1);
+            let (limb, _) = balanced_div_rem(i128::from(*top), 3_i128.pow(top_trits as u32));
+            *top = limb as i64;
+        }
+        LimbNumber::<N> { limbs }
+    }
+}
+
+/// Divides `value` by the odd `radix`, returning a remainder in the balanced
+/// range `-(radix - 1) / 2..=(radix - 1) / 2` along with the quotient.
+fn balanced_div_rem(value: i128, radix: i128) -> (i128, i128) {
+    let mut remainder = value.rem_euclid(radix);
+    if remainder > radix / 2 {
+        remainder -= radix;
+    }
+    (remainder, (value - remainder) / radix)
+}
+
+impl <const N: usize> From<Number<N>> for LimbNumber<N> {
+    fn from(number: Number<N>) -> Self {
+        LimbNumber::<N>::from_number(number)
+    }
+}
+
+impl <const N: usize> From<&LimbNumber<N>> for Number<N> {
+    fn from(limbs: &LimbNumber<N>) -> Self {
+        limbs.to_number()
+    }
+}
+
+impl <const N: usize> From<LimbNumber<N>> for Number<N> {
+    fn from(limbs: LimbNumber<N>) -> Self {
+        limbs.to_number()
+    }
+}
+
+impl <const N: usize> Neg for LimbNumber<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        LimbNumber::<N> { limbs: self.limbs.iter().map(|limb| -limb).collect() }
+    }
+}
+
+impl <const N: usize> Add for LimbNumber<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        LimbNumber::<N>::normalise(self.limbs.iter().zip(&rhs.limbs).map(|(l, r)| i128::from(*l) + i128::from(*r)))
+    }
+}
+
+impl <const N: usize> AddAssign for LimbNumber<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = core::mem::take(self) + rhs;
+    }
+}
+
+impl <const N: usize> Sub for LimbNumber<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl <const N: usize> SubAssign for LimbNumber<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = core::mem::take(self) - rhs;
+    }
+}
+
+impl <const N: usize> Mul for LimbNumber<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Schoolbook multiplication of the limbs, discarding any partial
+        // products that lie entirely above the width of the number. The
+        // accumulated columns are renormalised after each row so that they stay
+        // small enough to accumulate another row of limb products.
+        let mut columns = vec![0_i128; LimbNumber::<N>::LIMB_COUNT];
+        for (i, lhs_limb) in self.limbs.iter().enumerate() {
+            for (j, rhs_limb) in rhs.limbs.iter().take(columns.len() - i).enumerate() {
+                columns[i + j] += i128::from(*lhs_limb) * i128::from(*rhs_limb);
+            }
+            let normalised = LimbNumber::<N>::normalise(columns.iter().copied());
+            columns = normalised.limbs.iter().copied().map(i128::from).collect();
+        }
+        LimbNumber::<N> { limbs: columns.into_iter().map(|limb| limb as i64).collect() }
+    }
+}
+
+impl <const N: usize> MulAssign for LimbNumber<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = core::mem::take(self) * rhs;
+    }
+}
+
+impl <const N: usize> Default for LimbNumber<N> {
+    fn default() -> Self {
+        LimbNumber::<N>::zero()
+    }
+}
+
+impl <const N: usize> Ord for LimbNumber<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Each limb outweighs all of the limbs below it put together, so the
+        // most significant differing limb decides the ordering
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl <const N: usize> PartialOrd for LimbNumber<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <const N: usize> fmt::Debug for LimbNumber<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LimbNumber<{}>(", N)?;
+        for trit in self.to_number().as_trits() {
+            write!(f, "{}", trit)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Sets every limb to zero. Numbers are also zeroized when dropped.
+#[cfg(feature = "zeroize")]
+impl <const N: usize> zeroize::Zeroize for LimbNumber<N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.limbs.as_mut_slice());
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl <const N: usize> Drop for LimbNumber<N> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl <const N: usize> zeroize::ZeroizeOnDrop for LimbNumber<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::number_pattern as pattern;
+
+    #[test]
+    fn conversion_round_trip() {
+        let num_50 = Number::<8>::from("+-0--");
+        let limbs_50 = LimbNumber::from(num_50);
+
+        assert_eq!(Number::from(&limbs_50), num_50);
+        assert_eq!(format!("{:?}", limbs_50), "LimbNumber<8>(000+-0--)");
+
+        for seed in 1..10 {
+            let number = pattern::<200>(seed);
+            assert_eq!(LimbNumber::from(number).to_number(), number);
+        }
+        assert_eq!(LimbNumber::from(Number::<200>::MIN).to_number(), Number::<200>::MIN);
+    }
+
+    #[test]
+    fn arithmetic_matches_number() {
+        for seed in 1..20 {
+            let (lhs, rhs) = (pattern::<200>(seed), pattern::<200>(seed + 7));
+            let (limbs_lhs, limbs_rhs) = (LimbNumber::from(lhs), LimbNumber::from(rhs));
+
+            assert_eq!((limbs_lhs.clone() + limbs_rhs.clone()).to_number(), lhs + rhs);
+            assert_eq!((limbs_lhs.clone() - limbs_rhs.clone()).to_number(), lhs - rhs);
+            assert_eq!((limbs_lhs.clone() * limbs_rhs.clone()).to_number(), lhs * rhs);
+            assert_eq!((-limbs_lhs.clone()).to_number(), -lhs);
+            assert_eq!(limbs_lhs.cmp(&limbs_rhs), lhs.cmp(&rhs));
+        }
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        let max = LimbNumber::from(Number::<100>::MAX);
+        let one = LimbNumber::from(Number::<100>::ONE);
+
+        assert_eq!((max.clone() + one).to_number(), Number::<100>::MIN);
+        assert_eq!((max.clone() * max.clone()).to_number(), Number::<100>::MAX * Number::<100>::MAX);
+
+        // Narrow numbers fit in a single partially filled limb
+        for lhs in -40..=40_i32 {
+            for rhs in -40..=40_i32 {
+                let (lhs, rhs) = (Number::<4>::from(lhs), Number::<4>::from(rhs));
+                assert_eq!((LimbNumber::from(lhs) * LimbNumber::from(rhs)).to_number(), lhs * rhs);
+                assert_eq!((LimbNumber::from(lhs) + LimbNumber::from(rhs)).to_number(), lhs + rhs);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = LimbNumber::from(pattern::<200>(3));
+        secret.zeroize();
+        assert_eq!(secret.to_number(), Number::<200>::ZERO);
+        assert_eq!(secret, LimbNumber::from(Number::<200>::ZERO));
+    }
+}
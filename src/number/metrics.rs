@@ -0,0 +1,41 @@
+use crate::number::Number;
+use crate::trit::metrics;
+
+impl <const N: usize> Number<N> {
+    /// Returns the number of trit positions at which two numbers differ.
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        metrics::hamming_distance(&self.0, &other.0)
+    }
+
+    /// Returns the number of trit positions at which two numbers hold the same
+    /// trit, including any shared leading zeros.
+    pub fn matching_trits(&self, other: &Self) -> usize {
+        metrics::matching_trits(&self.0, &other.0)
+    }
+
+    /// Returns the signed agreement between the trits of two numbers, +1 for
+    /// each position where both are non-zero with the same sign and -1 where
+    /// they have opposite signs. See [`metrics::agreement`].
+    pub fn agreement(&self, other: &Self) -> isize {
+        metrics::agreement(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_metrics() {
+        let lhs = Number::<8>::from("+-0+0");
+        let rhs = Number::<8>::from("--++0");
+
+        assert_eq!(lhs.hamming_distance(&rhs), 2);
+        assert_eq!(lhs.matching_trits(&rhs), 6);
+        assert_eq!(lhs.agreement(&rhs), 1);
+
+        assert_eq!(lhs.hamming_distance(&-lhs), 3);
+        assert_eq!(lhs.agreement(&-lhs), -3);
+        assert_eq!(Number::<8>::MAX.agreement(&Number::<8>::MIN), -8);
+    }
+}
@@ -0,0 +1,67 @@
+//! Arithmetic between numbers of different widths, producing a number as wide
+//! as the wider operand. This relies on the incomplete `generic_const_exprs`
+//! language feature to name the output width, and so needs a nightly compiler.
+//!
+//! These are methods rather than operator impls, as an `Add<Number<M>>` impl for
+//! every `Number<N>` would overlap with the existing same-width operators when
+//! N == M.
+
+use crate::number::Number;
+
+/// The width of the result of mixed-width arithmetic on numbers of N and M
+/// trits.
+pub const fn max_width(n: usize, m: usize) -> usize {
+    if n > m {n} else {m}
+}
+
+impl <const N: usize> Number<N> {
+    /// Adds a number of a different width, padding the narrower operand with
+    /// zeros to the width of the wider one. As with the same-width operators
+    /// the sum wraps on overflow.
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    /// # use balanced_ternary::number::Number;
+    /// let sum: Number<16> = Number::<8>::from(40).add_mixed(Number::<16>::from(-1000));
+    /// assert_eq!(sum, Number::<16>::from(-960));
+    /// ```
+    pub fn add_mixed<const M: usize>(self, rhs: Number<M>) -> Number<{max_width(N, M)}>
+    where [(); max_width(N, M)]: {
+        self.resize::<{max_width(N, M)}>() + rhs.resize::<{max_width(N, M)}>()
+    }
+
+    /// Subtracts a number of a different width, padding the narrower operand
+    /// with zeros to the width of the wider one. As with the same-width
+    /// operators the difference wraps on overflow.
+    pub fn sub_mixed<const M: usize>(self, rhs: Number<M>) -> Number<{max_width(N, M)}>
+    where [(); max_width(N, M)]: {
+        self.resize::<{max_width(N, M)}>() - rhs.resize::<{max_width(N, M)}>()
+    }
+
+    /// Multiplies by a number of a different width, padding the narrower
+    /// operand with zeros to the width of the wider one. As with the same-width
+    /// operators the product wraps on overflow.
+    pub fn mul_mixed<const M: usize>(self, rhs: Number<M>) -> Number<{max_width(N, M)}>
+    where [(); max_width(N, M)]: {
+        self.resize::<{max_width(N, M)}>() * rhs.resize::<{max_width(N, M)}>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_width_arithmetic() {
+        let num_40 = Number::<4>::MAX;
+        let num_neg_1000 = Number::<8>::from(-1000);
+
+        assert_eq!(num_40.add_mixed(num_neg_1000), Number::<8>::from(-960));
+        assert_eq!(num_neg_1000.add_mixed(num_40), Number::<8>::from(-960));
+        assert_eq!(num_40.sub_mixed(num_neg_1000), Number::<8>::from(1040));
+        assert_eq!(num_40.mul_mixed(Number::<8>::from(-80)), Number::<8>::from(-3200));
+
+        // The result wraps at the wider width
+        assert_eq!(Number::<4>::MAX.add_mixed(Number::<2>::ONE), Number::<4>::MIN);
+    }
+}
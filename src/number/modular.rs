@@ -0,0 +1,169 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Reduces this number to its canonical residue modulo `modulus`, the unique
+    /// value in the range `0..|modulus|` that is congruent to it.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    pub fn mod_reduce(self, modulus: Self) -> Self {
+        self.rem_euclid(modulus)
+    }
+
+    /// Raises to the power of `exponent` modulo `modulus`, returning the
+    /// canonical residue. All of the intermediate values are kept reduced, so
+    /// unlike [`Number::pow`] this never overflows.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero or `exponent` is negative.
+    pub fn mod_pow(self, exponent: Self, modulus: Self) -> Self {
+        assert!(!exponent.is_negative(), "Exponent of modular exponentiation cannot be negative");
+
+        let modulus = modulus.abs();
+        let mut base = self.mod_reduce(modulus);
+        let mut result = Number::<N>::ONE.mod_reduce(modulus);
+
+        // Square-and-multiply needs the exponent's digits, but the negative trits
+        // of a balanced exponent would need the inverse of the base. Instead each
+        // balanced trit is converted to an unbalanced digit of 0, 1 or 2 from the
+        // least significant end, borrowing one from the remaining trits when
+        // the trit is negative.
+        let mut exponent = exponent;
+        while !exponent.is_zero() {
+            let trit = exponent.0[N-1];
+            exponent >>= 1;
+
+            let square = base.mul_mod(base, modulus);
+            match trit {
+                Trit::POS => result = result.mul_mod(base, modulus),
+                Trit::NEG => {
+                    result = result.mul_mod(square, modulus);
+                    exponent.dec();
+                },
+                Trit::ZERO => {}
+            }
+            base = square.mul_mod(base, modulus);
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of this number modulo `modulus` as a
+    /// canonical residue, or `None` if no inverse exists because the number and
+    /// the modulus have a common factor.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    pub fn mod_inverse(self, modulus: Self) -> Option<Self> {
+        let reduced = self.mod_reduce(modulus);
+        let (gcd, x, _) = reduced.extended_gcd(modulus);
+        if gcd == Number::<N>::ONE {
+            Some(x.mod_reduce(modulus))
+        } else {
+            None
+        }
+    }
+
+    /// Adds two canonical residues of a positive modulus. Comparing against the
+    /// difference from the modulus keeps every intermediate value within the
+    /// range of the modulus, so this can't overflow.
+    pub(crate) fn add_mod(self, rhs: Self, modulus: Self) -> Self {
+        let difference = self - (modulus - rhs);
+        if difference.is_negative() {difference + modulus} else {difference}
+    }
+
+    /// Subtracts two canonical residues of a positive modulus.
+    pub(crate) fn sub_mod(self, rhs: Self, modulus: Self) -> Self {
+        let difference = self - rhs;
+        if difference.is_negative() {difference + modulus} else {difference}
+    }
+
+    /// Multiplies two canonical residues of a positive modulus by accumulating
+    /// the trits of `rhs` from the most significant, tripling the accumulator
+    /// between them. Every step is a modular addition, so the full double-width
+    /// product is never needed.
+    pub(crate) fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let mut product = Number::<N>::ZERO;
+        for trit in rhs.0 {
+            let doubled = product.add_mod(product, modulus);
+            product = doubled.add_mod(product, modulus);
+            match trit {
+                Trit::POS => product = product.add_mod(self, modulus),
+                Trit::NEG => product = product.sub_mod(self, modulus),
+                Trit::ZERO => {}
+            }
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_residues() {
+        let num_7 = Number::<8>::from("+-+");
+        let num_3 = Number::<8>::from("+0");
+
+        assert_eq!(num_7.mod_reduce(num_3), Number::<8>::ONE);
+        assert_eq!((-num_7).mod_reduce(num_3), Number::<8>::from("+-")); // -7 = 2 mod 3
+        assert_eq!(num_7.mod_reduce(-num_3), Number::<8>::ONE);
+        assert_eq!((-num_7).mod_reduce(-num_3), Number::<8>::from("+-"));
+        assert_eq!(num_3.mod_reduce(num_3), Number::<8>::ZERO);
+    }
+
+    #[test]
+    fn modular_exponentiation() {
+        let num_4 = Number::<8>::from("++");
+        let num_13 = Number::<8>::from("+++");
+        let num_497 = Number::<8>::from("+-00++-");
+
+        assert_eq!(num_4.mod_pow(num_13, num_497), Number::<8>::from("+--++++")); // 445
+        assert_eq!(num_4.mod_pow(Number::<8>::ZERO, num_497), Number::<8>::ONE);
+        assert_eq!(num_4.mod_pow(num_13, Number::<8>::ONE), Number::<8>::ZERO);
+
+        // Moduli near the top of the range don't overflow the intermediate values
+        for base in [2_i64, 3, 1000, -1093] {
+            for exponent in [0_i64, 1, 2, 5, 100, 1093] {
+                let modulus = 3280_i64;
+                let mut expected = 1 % modulus;
+                for _ in 0..exponent {
+                    expected = (expected * base).rem_euclid(modulus);
+                }
+
+                let result = Number::<8>::try_from(base).unwrap()
+                    .mod_pow(Number::<8>::try_from(exponent).unwrap(), Number::<8>::MAX);
+                assert_eq!(i64::try_from(result), Ok(expected), "{base}^{exponent} mod {modulus}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Exponent of modular exponentiation cannot be negative")]
+    fn modular_exponentiation_with_negative_exponent() {
+        let _ = Number::<8>::ONE.mod_pow(Number::<8>::NEG_ONE, Number::<8>::MAX);
+    }
+
+    #[test]
+    fn modular_inverse() {
+        let num_3 = Number::<8>::from("+0");
+        let num_11 = Number::<8>::from("++-");
+        let num_12 = Number::<8>::from("++0");
+
+        assert_eq!(num_3.mod_inverse(num_11), Some(Number::<8>::from("++"))); // 3 * 4 = 12 = 1 mod 11
+        assert_eq!((-num_3).mod_inverse(num_11), Some(Number::<8>::from("+-+"))); // -3 * 7 = -21 = 1 mod 11
+        assert_eq!(num_3.mod_inverse(num_12), None);
+
+        let modulus = 3280_i64;
+        for value in 1..modulus {
+            let inverse = Number::<8>::try_from(value).unwrap().mod_inverse(Number::<8>::MAX);
+            let is_coprime = value % 2 != 0 && value % 5 != 0 && value % 41 != 0;
+
+            assert_eq!(inverse.is_some(), is_coprime, "inverse of {value}");
+            if let Some(inverse) = inverse {
+                assert_eq!((value * i64::try_from(inverse).unwrap()) % modulus, 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::number::{digit_groups, Number, ParseTernaryError};
+
+// Each balanced nonary digit stands for two trits, with a value in -4..=4. The
+// non-negative digits are written as usual and the negative digits count back
+// from the end of the alphabet, so -1 is Z and -4 is W. Listed here in order
+// of value.
+const ALPHABET: &[u8; 9] = b"WXYZ01234";
+
+const TRITS_PER_DIGIT: usize = 2;
+
+impl <const N: usize> Number<N> {
+    /// Formats the number in balanced nonary (base 9), with each digit
+    /// standing for a pair of trits, starting from the least significant. Each
+    /// digit is signed, with a value in -4..=4: 0 to 4 are written `0` to `4`,
+    /// and -1 to -4 are written `Z` to `W`. No sign is needed, and the string
+    /// always has ceil(N / 2) digits.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// // 50 is 0+ -0 -- in pairs of trits, which are 1, -3 and -4
+    /// assert_eq!(Number::<6>::from("+-0--").to_nonary_string(), "1XW");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_nonary_string(self) -> String {
+        digit_groups::to_digit_string(self, TRITS_PER_DIGIT, ALPHABET)
+    }
+
+    /// Parses a balanced nonary string as written by
+    /// [`Number::to_nonary_string`], with letters in either case. Fewer digits
+    /// than ceil(N / 2) may be given, and extra leading zero digits are
+    /// allowed, but the value must fit in N trits.
+    /// * `encoded` - The signed base 9 digits, most significant first
+    pub fn from_nonary_str(encoded: &str) -> Result<Self, ParseTernaryError> {
+        digit_groups::from_digit_str(encoded, TRITS_PER_DIGIT, ALPHABET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn formatting() {
+        assert_eq!(Number::<6>::from("++").to_nonary_string(), "004");
+        assert_eq!(Number::<6>::from("--").to_nonary_string(), "00W");
+        assert_eq!(Number::<6>::from("-").to_nonary_string(), "00Z");
+        assert_eq!(Number::<5>::MAX.to_nonary_string(), "144");
+        assert_eq!(Number::<5>::MIN.to_nonary_string(), "ZWW");
+        assert_eq!(Number::<0>::ZERO.to_nonary_string(), "");
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Number::<6>::from_nonary_str("1XW"), Ok(Number::<6>::from("+-0--")));
+        assert_eq!(Number::<6>::from_nonary_str("1xw"), Ok(Number::<6>::from("+-0--")));
+        assert_eq!(Number::<5>::from_nonary_str("0ZWW"), Ok(Number::<5>::MIN));
+
+        assert_eq!(Number::<6>::from_nonary_str(""), Err(ParseTernaryError::Empty));
+        assert_eq!(Number::<6>::from_nonary_str("15"), Err(ParseTernaryError::InvalidDigit { character: '5', position: 1 }));
+        // The high digit needs two trits, but only one is available
+        assert_eq!(Number::<5>::from_nonary_str("300"), Err(ParseTernaryError::OutOfRange));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn round_trips() {
+        for value in -9841..=9841 {
+            let number = Number::<9>::from(value);
+            assert_eq!(Number::<9>::from_nonary_str(&number.to_nonary_string()), Ok(number));
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use core::error::Error;
+use core::fmt;
+use core::num::ParseIntError;
+
+use num_traits::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, Num, One, Pow, Signed, Zero};
+
+use crate::number::{Number, TryFromIntError};
+use crate::trit::Trit;
+
+/// The error returned by [`Num::from_str_radix`] for a [`Number`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FromStrRadixError {
+    /// The string was not a valid integer in the given radix.
+    Parse(ParseIntError),
+    /// The parsed integer does not fit in the number.
+    OutOfRange(TryFromIntError)
+}
+
+impl fmt::Display for FromStrRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FromStrRadixError::Parse(error) => fmt::Display::fmt(error, f),
+            FromStrRadixError::OutOfRange(error) => fmt::Display::fmt(error, f)
+        }
+    }
+}
+
+impl Error for FromStrRadixError {}
+
+impl <const N: usize> Zero for Number<N> {
+    fn zero() -> Self {
+        Number::<N>::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Number::<N>::is_zero(*self)
+    }
+}
+
+impl <const N: usize> One for Number<N> {
+    fn one() -> Self {
+        Number::<N>::ONE
+    }
+}
+
+impl <const N: usize> Num for Number<N> {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Parses a conventional signed integer in the given radix, such as a
+    /// decimal string for a radix of 10. Values are parsed through an `i128`,
+    /// so wider values can't be parsed this way. Use `str::parse` for the
+    /// balanced ternary `-`/`0`/`+` notation.
+    fn from_str_radix(encoded: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let value = i128::from_str_radix(encoded, radix).map_err(FromStrRadixError::Parse)?;
+        Number::<N>::try_from(value).map_err(FromStrRadixError::OutOfRange)
+    }
+}
+
+impl <const N: usize> Signed for Number<N> {
+    fn abs(&self) -> Self {
+        Number::<N>::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {Number::<N>::ZERO} else {*self - *other}
+    }
+
+    fn signum(&self) -> Self {
+        match Number::<N>::signum(*self) {
+            Trit::POS => Number::<N>::ONE,
+            Trit::ZERO => Number::<N>::ZERO,
+            Trit::NEG => Number::<N>::NEG_ONE
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        Number::<N>::is_positive(*self)
+    }
+
+    fn is_negative(&self) -> bool {
+        Number::<N>::is_negative(*self)
+    }
+}
+
+impl <const N: usize> Bounded for Number<N> {
+    fn min_value() -> Self {
+        Number::<N>::MIN
+    }
+
+    fn max_value() -> Self {
+        Number::<N>::MAX
+    }
+}
+
+// Explicit paths are used to call the inherent methods, as method call syntax
+// on `&self` would resolve back to the trait methods being implemented
+impl <const N: usize> CheckedAdd for Number<N> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Number::<N>::checked_add(*self, *rhs)
+    }
+}
+
+impl <const N: usize> CheckedSub for Number<N> {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Number::<N>::checked_sub(*self, *rhs)
+    }
+}
+
+impl <const N: usize> CheckedMul for Number<N> {
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Number::<N>::checked_mul(*self, *rhs)
+    }
+}
+
+impl <const N: usize> CheckedDiv for Number<N> {
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        Number::<N>::checked_div(*self, *rhs)
+    }
+}
+
+impl <const N: usize> Pow<u32> for Number<N> {
+    type Output = Self;
+
+    /// Raises to the power of `exponent`, wrapping on overflow in the same way
+    /// as `Mul`.
+    fn pow(self, exponent: u32) -> Self::Output {
+        Number::<N>::pow(self, exponent)
+    }
+}
+
+impl <const N: usize> Euclid for Number<N> {
+    fn div_euclid(&self, divisor: &Self) -> Self {
+        Number::<N>::div_euclid(*self, *divisor)
+    }
+
+    fn rem_euclid(&self, divisor: &Self) -> Self {
+        Number::<N>::rem_euclid(*self, *divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identities_and_bounds() {
+        assert!(Number::<4>::zero().is_zero());
+        assert_eq!(Number::<4>::one(), Number::<4>::from("+"));
+        assert_eq!(Number::<4>::min_value(), Number::<4>::from("----"));
+        assert_eq!(Number::<4>::max_value(), Number::<4>::from("++++"));
+    }
+
+    #[test]
+    fn radix_parsing() {
+        assert_eq!(Number::<8>::from_str_radix("-50", 10), Ok(Number::<8>::from("-+0++")));
+        assert_eq!(Number::<8>::from_str_radix("32", 16), Ok(Number::<8>::from("+-0--")));
+        assert!(matches!(Number::<8>::from_str_radix("+-0", 10), Err(FromStrRadixError::Parse(_))));
+        assert!(matches!(Number::<4>::from_str_radix("41", 10), Err(FromStrRadixError::OutOfRange(_))));
+    }
+
+    #[test]
+    fn signed_operations() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(Signed::abs(&-num_50), num_50);
+        assert_eq!(Signed::signum(&-num_50), Number::<8>::from("-"));
+        assert_eq!(Signed::abs_sub(&num_50, &-num_50), Number::<8>::from("++-0+"));
+        assert_eq!(Signed::abs_sub(&-num_50, &num_50), Number::<8>::ZERO);
+        assert!(Signed::is_positive(&num_50));
+        assert!(Signed::is_negative(&-num_50));
+    }
+
+    #[test]
+    fn checked_operations() {
+        let num_max = Number::<4>::from("++++");
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(CheckedAdd::checked_add(&num_max, &num_one), None);
+        assert_eq!(CheckedSub::checked_sub(&num_max, &num_one), Some(Number::<4>::from("+++0")));
+        assert_eq!(CheckedMul::checked_mul(&num_max, &-num_one), Some(-num_max));
+        assert_eq!(CheckedDiv::checked_div(&num_max, &Number::<4>::ZERO), None);
+    }
+
+    #[test]
+    fn power_and_euclidean_division() {
+        let num_3 = Number::<8>::from("+0");
+        let num_7 = Number::<8>::from("+-+");
+
+        assert_eq!(Pow::pow(num_3, 4), Number::<8>::from("+0000"));
+        assert_eq!(Pow::pow(num_7, 0), Number::<8>::one());
+
+        assert_eq!(Euclid::div_euclid(&-num_7, &num_3), Number::<8>::from("-0")); // -7 = 3 * -3 + 2
+        assert_eq!(Euclid::rem_euclid(&-num_7, &num_3), Number::<8>::from("+-"));
+        assert_eq!(Euclid::div_euclid(&-num_7, &-num_3), Number::<8>::from("+0")); // -7 = -3 * 3 + 2
+        assert_eq!(Euclid::rem_euclid(&-num_7, &-num_3), Number::<8>::from("+-"));
+    }
+}
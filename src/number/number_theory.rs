@@ -0,0 +1,100 @@
+use crate::number::Number;
+
+impl <const N: usize> Number<N> {
+    /// Returns the greatest common divisor of this number and `other`. The
+    /// result is always non-negative, and the greatest common divisor of zero
+    /// and zero is zero.
+    pub fn gcd(self, other: Self) -> Self {
+        // Euclid's algorithm on the absolute values. The range of a balanced
+        // ternary number is symmetric, so taking the absolute value never fails.
+        let mut a = self.abs();
+        let mut b = other.abs();
+        while !b.is_zero() {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    /// Returns the least common multiple of this number and `other`. The result
+    /// is always non-negative, is zero if either number is zero, and wraps on
+    /// overflow in the same way as `Mul`.
+    pub fn lcm(self, other: Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Number::<N>::ZERO;
+        }
+
+        // Dividing before multiplying keeps the intermediate value no larger
+        // than the result
+        (self.abs() / self.gcd(other)) * other.abs()
+    }
+
+    /// Returns the greatest common divisor of this number and `other` along
+    /// with a pair of Bézout coefficients `(gcd, x, y)` such that
+    /// `self * x + other * y == gcd`. As with [`Number::gcd`] the divisor is
+    /// always non-negative.
+    pub fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+        // The extended Euclidean algorithm, tracking the coefficients of each
+        // remainder. The coefficients never grow larger in magnitude than the
+        // inputs, so none of the intermediate values can overflow.
+        let (mut old_r, mut r) = (self, other);
+        let (mut old_x, mut x) = (Number::<N>::ONE, Number::<N>::ZERO);
+        let (mut old_y, mut y) = (Number::<N>::ZERO, Number::<N>::ONE);
+
+        while !r.is_zero() {
+            let (quotient, remainder) = old_r.div_rem(r);
+            (old_r, r) = (r, remainder);
+            (old_x, x) = (x, old_x - quotient * x);
+            (old_y, y) = (y, old_y - quotient * y);
+        }
+
+        if old_r.is_negative() {
+            (-old_r, -old_x, -old_y)
+        } else {
+            (old_r, old_x, old_y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greatest_common_divisor() {
+        let num_12 = Number::<8>::from("++0");
+        let num_18 = Number::<8>::from("+-00");
+        let num_6 = Number::<8>::from("+-0");
+
+        assert_eq!(num_12.gcd(num_18), num_6);
+        assert_eq!((-num_12).gcd(num_18), num_6);
+        assert_eq!(num_12.gcd(-num_18), num_6);
+        assert_eq!(num_12.gcd(Number::<8>::ZERO), num_12);
+        assert_eq!(Number::<8>::ZERO.gcd(-num_12), num_12);
+        assert_eq!(Number::<8>::ZERO.gcd(Number::<8>::ZERO), Number::<8>::ZERO);
+    }
+
+    #[test]
+    fn least_common_multiple() {
+        let num_12 = Number::<8>::from("++0");
+        let num_18 = Number::<8>::from("+-00");
+        let num_36 = Number::<8>::from("++00");
+
+        assert_eq!(num_12.lcm(num_18), num_36);
+        assert_eq!((-num_12).lcm(num_18), num_36);
+        assert_eq!(num_12.lcm(Number::<8>::ZERO), Number::<8>::ZERO);
+    }
+
+    #[test]
+    fn bezout_coefficients() {
+        for a in -40..=40_i32 {
+            for b in -40..=40_i32 {
+                let (num_a, num_b) = (Number::<4>::from(a), Number::<4>::from(b));
+                let (gcd, x, y) = num_a.extended_gcd(num_b);
+
+                assert_eq!(gcd, num_a.gcd(num_b));
+                assert_eq!(i32::try_from(x).unwrap() * a + i32::try_from(y).unwrap() * b,
+                    i32::try_from(gcd).unwrap(), "extended_gcd({a}, {b})");
+            }
+        }
+    }
+}
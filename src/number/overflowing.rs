@@ -0,0 +1,119 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Calculates `self + rhs`, returning the wrapped sum along with a boolean
+    /// indicating whether a carry was lost from the most significant trit.
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (sum, carry) = self.carrying_add(rhs, Trit::ZERO);
+        (sum, !matches!(carry, Trit::ZERO))
+    }
+
+    /// Calculates `self - rhs`, returning the wrapped difference along with a
+    /// boolean indicating whether a carry was lost from the most significant
+    /// trit.
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.overflowing_add(rhs.wrapping_neg())
+    }
+
+    /// Calculates `self * rhs`, returning the wrapped product along with a
+    /// boolean indicating whether any trits of the full product were lost.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        // Balanced ternary has a unique representation for every value, so the
+        // product fits exactly when the high word of the full product is zero
+        let (low, high) = self.widening_mul(rhs);
+        (low, high != Number::<N>::ZERO)
+    }
+
+    /// Raises to the power of `exponent`, returning the wrapped result along
+    /// with a boolean indicating whether an overflow occurred.
+    pub fn overflowing_pow(self, mut exponent: u32) -> (Self, bool) {
+        if exponent == 0 {
+            return (Number::<N>::ONE, false);
+        }
+
+        // Square-and-multiply, stopping before the final squaring so that it
+        // can't report an overflow for a square the result never needed. Any
+        // base other than zero or one in magnitude grows with every step, so an
+        // intermediate overflow always means the full result overflows too.
+        let mut base = self;
+        let mut result = Number::<N>::ONE;
+        let mut overflowed = false;
+        while exponent > 1 {
+            if exponent & 1 == 1 {
+                let (product, product_overflowed) = result.overflowing_mul(base);
+                result = product;
+                overflowed |= product_overflowed;
+            }
+            let (square, square_overflowed) = base.overflowing_mul(base);
+            base = square;
+            overflowed |= square_overflowed;
+            exponent >>= 1;
+        }
+
+        let (product, product_overflowed) = result.overflowing_mul(base);
+        (product, overflowed | product_overflowed)
+    }
+
+    /// Shifts left by `positions` trits, returning the shifted value along
+    /// with a boolean indicating whether any non-zero trits were shifted out of
+    /// the most significant position.
+    pub const fn overflowing_shl(self, positions: usize) -> (Self, bool) {
+        let mut overflowed = false;
+        let mut idx = 0;
+        while idx < positions && idx < N {
+            overflowed |= !matches!(self.0[idx], Trit::ZERO);
+            idx += 1;
+        }
+        (self.wrapping_shl(positions), overflowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflowing_addition_and_subtraction() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_min = Number::<4>::from("----"); // -40
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(num_max.overflowing_add(-num_one), (Number::<4>::from("+++0"), false));
+        assert_eq!(num_max.overflowing_add(num_one), (num_min, true));
+        assert_eq!(num_min.overflowing_sub(num_one), (num_max, true));
+        assert_eq!(num_min.overflowing_sub(num_min), (Number::<4>::ZERO, false));
+    }
+
+    #[test]
+    fn overflowing_multiplication() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_two = Number::<4>::from("+-");
+
+        assert_eq!(num_two.overflowing_mul(Number::<4>::from("+-+-")), (num_max, false));
+        assert_eq!(num_max.overflowing_mul(num_two), (Number::<4>::from("-"), true)); // 80 wraps to -1
+    }
+
+    #[test]
+    fn overflowing_exponentiation() {
+        let num_3 = Number::<4>::from("+0");
+        let num_neg_2 = Number::<4>::from("-+");
+
+        assert_eq!(num_3.overflowing_pow(0), (Number::<4>::ONE, false));
+        // Squaring the base again after the last multiplication would overflow
+        assert_eq!(num_3.overflowing_pow(3), (Number::<4>::from("+000"), false)); // 27
+        assert_eq!(num_3.overflowing_pow(4), (Number::<4>::ZERO, true)); // 81 wraps to 0
+        assert_eq!(num_neg_2.overflowing_pow(5), (Number::<4>::from("--++"), false)); // -32
+        assert_eq!(num_neg_2.overflowing_pow(6), (Number::<4>::from("-+0+"), true)); // 64 wraps to -17
+    }
+
+    #[test]
+    fn overflowing_left_shift() {
+        let num_4 = Number::<4>::from("++");
+
+        assert_eq!(num_4.overflowing_shl(2), (Number::<4>::from("++00"), false));
+        assert_eq!(num_4.overflowing_shl(3), (Number::<4>::from("+000"), true));
+        assert_eq!(num_4.overflowing_shl(4), (Number::<4>::ZERO, true));
+        assert_eq!(Number::<4>::ZERO.overflowing_shl(8), (Number::<4>::ZERO, false));
+    }
+}
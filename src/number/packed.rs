@@ -0,0 +1,290 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// A balanced ternary number of up to 128 trits, stored as two bitplanes rather
+/// than an array of trits. Bit i of the positive plane is set when the trit at
+/// position i (with a weight of 3^i) is positive, and likewise for the
+/// negative plane, so no bit is ever set in both planes.
+///
+/// Negation, comparison and tritwise logic on the planes operate on every trit
+/// at once with a handful of word operations, rather than looping over the
+/// trits. Converting to and from [`Number`] is per-trit, so this is best suited
+/// to holding values across many such operations.
+///
+/// Using a width greater than 128 trits is a compile-time error.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct PackedNumber<const N: usize> {
+    pos: u128,
+    neg: u128
+}
+
+impl <const N: usize> PackedNumber<N> {
+    /// The number zero.
+    pub const ZERO: PackedNumber<N> = PackedNumber::<N> { pos: 0, neg: 0 };
+
+    const VALID_WIDTH: () = assert!(N <= 128, "Packed numbers are limited to 128 trits");
+
+    /// Only the lowest N bits of each plane are used.
+    const MASK: u128 = if N >= 128 {u128::MAX} else {(1 << N) - 1};
+
+    /// Packs the trits of a number into bitplanes.
+    pub const fn from_number(number: Number<N>) -> Self {
+        let () = Self::VALID_WIDTH;
+
+        let mut packed = PackedNumber::<N>::ZERO;
+        let mut idx = 0;
+        while idx < N {
+            let bit = 1 << (N - 1 - idx);
+            match number.0[idx] {
+                Trit::POS => packed.pos |= bit,
+                Trit::NEG => packed.neg |= bit,
+                Trit::ZERO => {}
+            }
+            idx += 1;
+        }
+        packed
+    }
+
+    /// Unpacks the bitplanes into an array of trits.
+    pub const fn to_number(self) -> Number<N> {
+        let mut number = Number::<N>::ZERO;
+        let mut idx = 0;
+        while idx < N {
+            let bit = 1 << (N - 1 - idx);
+            if self.pos & bit != 0 {
+                number.0[idx] = Trit::POS;
+            } else if self.neg & bit != 0 {
+                number.0[idx] = Trit::NEG;
+            }
+            idx += 1;
+        }
+        number
+    }
+
+    /// Returns the trit at position `index`, counting from the least
+    /// significant trit, or `None` if the index is out of range.
+    pub const fn get_trit(self, index: usize) -> Option<Trit> {
+        if index >= N {
+            None
+        } else if self.pos & (1 << index) != 0 {
+            Some(Trit::POS)
+        } else if self.neg & (1 << index) != 0 {
+            Some(Trit::NEG)
+        } else {
+            Some(Trit::ZERO)
+        }
+    }
+
+    /// Returns the positive and negative bitplanes.
+    pub(super) const fn to_planes(self) -> (u128, u128) {
+        (self.pos, self.neg)
+    }
+
+    /// Builds a number from its positive and negative bitplanes, which must
+    /// not share any set bits or have bits set above the lowest N.
+    pub(super) const fn from_planes(pos: u128, neg: u128) -> Self {
+        PackedNumber::<N> { pos, neg }
+    }
+
+    /// Tritwise minimum of two numbers, the three-valued analogue of a bitwise
+    /// AND. A trit is negative if either trit is negative, and positive only if
+    /// both trits are positive.
+    pub const fn tmin(self, rhs: Self) -> Self {
+        PackedNumber::<N> { pos: self.pos & rhs.pos, neg: self.neg | rhs.neg }
+    }
+
+    /// Tritwise maximum of two numbers, the three-valued analogue of a bitwise
+    /// OR. A trit is positive if either trit is positive, and negative only if
+    /// both trits are negative.
+    pub const fn tmax(self, rhs: Self) -> Self {
+        PackedNumber::<N> { pos: self.pos | rhs.pos, neg: self.neg & rhs.neg }
+    }
+
+    /// Adds the trits at each position without carrying between positions,
+    /// returning the trits of the sums along with the carries out of each
+    /// position.
+    const fn half_add(self, rhs: Self) -> (Self, Self) {
+        let self_zero = !(self.pos | self.neg);
+        let rhs_zero = !(rhs.pos | rhs.neg);
+
+        // A zero trit leaves the other trit unchanged, opposite trits cancel,
+        // and equal trits give the opposite trit with a carry of the same sign
+        let sum = PackedNumber::<N> {
+            pos: (self.pos & rhs_zero) | (rhs.pos & self_zero) | (self.neg & rhs.neg),
+            neg: (self.neg & rhs_zero) | (rhs.neg & self_zero) | (self.pos & rhs.pos)
+        };
+        let carry = PackedNumber::<N> { pos: self.pos & rhs.pos, neg: self.neg & rhs.neg };
+        (sum, carry)
+    }
+}
+
+impl <const N: usize> From<Number<N>> for PackedNumber<N> {
+    fn from(number: Number<N>) -> Self {
+        PackedNumber::<N>::from_number(number)
+    }
+}
+
+impl <const N: usize> From<PackedNumber<N>> for Number<N> {
+    fn from(packed: PackedNumber<N>) -> Self {
+        packed.to_number()
+    }
+}
+
+impl <const N: usize> Neg for PackedNumber<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        PackedNumber::<N> { pos: self.neg, neg: self.pos }
+    }
+}
+
+impl <const N: usize> Add for PackedNumber<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Add every position at once, then keep adding the carries shifted up
+        // a position until there are none left. Carries out of the most
+        // significant trit are masked away, so the sum wraps in the same way as
+        // addition of `Number`.
+        let (mut sum, mut carry) = self.half_add(rhs);
+        while carry != PackedNumber::<N>::ZERO {
+            let shifted = PackedNumber::<N> {
+                pos: (carry.pos << 1) & Self::MASK,
+                neg: (carry.neg << 1) & Self::MASK
+            };
+            (sum, carry) = sum.half_add(shifted);
+        }
+        sum
+    }
+}
+
+impl <const N: usize> AddAssign for PackedNumber<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl <const N: usize> Sub for PackedNumber<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl <const N: usize> SubAssign for PackedNumber<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl <const N: usize> Ord for PackedNumber<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The most significant position at which the trits differ decides the
+        // ordering, just as with the derived ordering of `Number`
+        let differing = (self.pos ^ other.pos) | (self.neg ^ other.neg);
+        if differing == 0 {
+            return Ordering::Equal;
+        }
+
+        let highest = 1 << (127 - differing.leading_zeros());
+        let trit_value = |packed: &Self| {
+            i8::from(packed.pos & highest != 0) - i8::from(packed.neg & highest != 0)
+        };
+        trit_value(self).cmp(&trit_value(other))
+    }
+}
+
+impl <const N: usize> PartialOrd for PackedNumber<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <const N: usize> fmt::Debug for PackedNumber<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PackedNumber<{}>(", N)?;
+        for trit in self.to_number().as_trits() {
+            write!(f, "{}", trit)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Clears both bitplanes, leaving zero.
+#[cfg(feature = "zeroize")]
+impl <const N: usize> zeroize::Zeroize for PackedNumber<N> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.pos);
+        zeroize::Zeroize::zeroize(&mut self.neg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing_round_trip() {
+        let num_50 = Number::<8>::from("+-0--");
+        let packed_50 = PackedNumber::from(num_50);
+
+        assert_eq!(Number::from(packed_50), num_50);
+        assert_eq!(packed_50.get_trit(0), Some(Trit::NEG));
+        assert_eq!(packed_50.get_trit(4), Some(Trit::POS));
+        assert_eq!(packed_50.get_trit(7), Some(Trit::ZERO));
+        assert_eq!(packed_50.get_trit(8), None);
+        assert_eq!(format!("{:?}", packed_50), "PackedNumber<8>(000+-0--)");
+
+        let max = PackedNumber::from(Number::<128>::MAX);
+        assert_eq!(max.to_number(), Number::<128>::MAX);
+        assert_eq!((-max).to_number(), Number::<128>::MIN);
+    }
+
+    #[test]
+    fn arithmetic_and_comparison_match_number() {
+        let numbers: Vec<Number<4>> = (-40..=40).map(Number::<4>::from).collect();
+        for lhs in &numbers {
+            for rhs in &numbers {
+                let (packed_lhs, packed_rhs) = (PackedNumber::from(*lhs), PackedNumber::from(*rhs));
+
+                assert_eq!((packed_lhs + packed_rhs).to_number(), *lhs + *rhs);
+                assert_eq!((packed_lhs - packed_rhs).to_number(), *lhs - *rhs);
+                assert_eq!(packed_lhs.cmp(&packed_rhs), lhs.cmp(rhs));
+            }
+            assert_eq!((-PackedNumber::from(*lhs)).to_number(), -*lhs);
+        }
+    }
+
+    #[test]
+    fn wrapping_addition() {
+        let max = PackedNumber::from(Number::<128>::MAX);
+        let one = PackedNumber::from(Number::<128>::ONE);
+
+        assert_eq!((max + one).to_number(), Number::<128>::MIN);
+        assert_eq!((max + max).to_number(), Number::<128>::MAX + Number::<128>::MAX);
+    }
+
+    #[test]
+    fn tritwise_logic() {
+        let lhs = PackedNumber::from(Number::<3>::from("-0+"));
+        let rhs = PackedNumber::from(Number::<3>::from("0+-"));
+
+        assert_eq!(lhs.tmin(rhs).to_number(), Number::<3>::from("-0-"));
+        assert_eq!(lhs.tmax(rhs).to_number(), Number::<3>::from("0++"));
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = PackedNumber::from(Number::<8>::from("+-0--"));
+        secret.zeroize();
+        assert_eq!(secret, PackedNumber::<8>::ZERO);
+    }
+}
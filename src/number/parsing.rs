@@ -0,0 +1,221 @@
+use crate::number::{Number, ParseTernaryError};
+use crate::trit::Trit;
+
+/// The combining macron which, written after a `1`, makes the digit negative.
+const COMBINING_MACRON: char = '\u{0304}';
+
+/// Which notations are accepted by [`Number::parse_with`].
+///
+/// The default options are strict, accepting only the `-`, `0` and `+` trits
+/// that [`FromStr`](core::str::FromStr) does. Each option then accepts another
+/// notation in use elsewhere, and any mix of the enabled notations may appear
+/// in the same string:
+/// * `1`, `0` and `T` for one, zero and minus one, with `T` in either case.
+/// * `P`, `Z` and `N` for positive, zero and negative, in either case.
+/// * The Unicode minus sign `−` and the overlined one `1̄`, written as a `1`
+///   followed by a combining macron, for minus one.
+/// * Underscores between trits, as in Rust's integer literals.
+/// * Whitespace between trits.
+///
+/// Separators may appear anywhere in the string, but don't count as trits, so
+/// a string of nothing but separators is still empty.
+/// ```
+/// # use balanced_ternary::number::{Number, ParseOptions};
+/// let options = ParseOptions::new().with_10t_digits(true).with_underscores(true);
+/// assert_eq!(Number::<8>::parse_with("1T_0T", options), Ok(Number::<8>::from("+-0-")));
+/// assert_eq!(Number::<8>::parse_with("+-0-", options), Ok(Number::<8>::from("+-0-")));
+/// assert!(Number::<8>::parse_with("PN0N", options).is_err());
+///
+/// let lenient = ParseOptions::lenient();
+/// assert_eq!(Number::<8>::parse_with("pn 0\u{2212}", lenient), Ok(Number::<8>::from("+-0-")));
+/// assert_eq!(Number::<8>::parse_with("1 1\u{0304} 0 1\u{0304}", lenient), Ok(Number::<8>::from("+-0-")));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    digits_10t: bool,
+    digits_pzn: bool,
+    unicode_digits: bool,
+    underscores: bool,
+    whitespace: bool
+}
+
+impl ParseOptions {
+    /// The strict options, accepting only `-`, `0` and `+`.
+    pub const fn new() -> Self {
+        ParseOptions {
+            digits_10t: false,
+            digits_pzn: false,
+            unicode_digits: false,
+            underscores: false,
+            whitespace: false
+        }
+    }
+
+    /// Options accepting every notation and separator.
+    pub const fn lenient() -> Self {
+        ParseOptions {
+            digits_10t: true,
+            digits_pzn: true,
+            unicode_digits: true,
+            underscores: true,
+            whitespace: true
+        }
+    }
+
+    /// Sets whether `1`, `0` and `T` are accepted.
+    pub const fn with_10t_digits(mut self, enabled: bool) -> Self {
+        self.digits_10t = enabled;
+        self
+    }
+
+    /// Sets whether `P`, `Z` and `N` are accepted.
+    pub const fn with_pzn_digits(mut self, enabled: bool) -> Self {
+        self.digits_pzn = enabled;
+        self
+    }
+
+    /// Sets whether the Unicode minus sign `−` and overlined one `1̄` are
+    /// accepted.
+    pub const fn with_unicode_digits(mut self, enabled: bool) -> Self {
+        self.unicode_digits = enabled;
+        self
+    }
+
+    /// Sets whether underscores are accepted between trits.
+    pub const fn with_underscores(mut self, enabled: bool) -> Self {
+        self.underscores = enabled;
+        self
+    }
+
+    /// Sets whether whitespace is accepted between trits.
+    pub const fn with_whitespace(mut self, enabled: bool) -> Self {
+        self.whitespace = enabled;
+        self
+    }
+
+    fn is_separator(&self, character: char) -> bool {
+        (self.underscores && character == '_') || (self.whitespace && character.is_whitespace())
+    }
+
+    /// Reads a single character trit.
+    fn trit(&self, character: char) -> Option<Trit> {
+        match character {
+            '-' | '0' | '+' => Trit::from_char(character),
+            '1' if self.digits_10t => Some(Trit::POS),
+            'T' | 't' if self.digits_10t => Some(Trit::NEG),
+            'P' | 'p' if self.digits_pzn => Some(Trit::POS),
+            'Z' | 'z' if self.digits_pzn => Some(Trit::ZERO),
+            'N' | 'n' if self.digits_pzn => Some(Trit::NEG),
+            '\u{2212}' if self.unicode_digits => Some(Trit::NEG),
+            _ => None
+        }
+    }
+
+    /// Visits the trits of a string from the most significant, skipping
+    /// separators, and stops at the first invalid character.
+    fn for_each_trit(&self, encoded: &str, mut visit: impl FnMut(Trit)) -> Result<(), ParseTernaryError> {
+        // Positions count characters, so a combining mark moves every later
+        // position along by one
+        let mut characters = encoded.chars().peekable();
+        let mut position = 0;
+        while let Some(character) = characters.next() {
+            let overlined = self.unicode_digits && character == '1'
+                && characters.next_if_eq(&COMBINING_MACRON).is_some();
+            if overlined {
+                visit(Trit::NEG);
+            } else if !self.is_separator(character) {
+                visit(self.trit(character).ok_or(ParseTernaryError::InvalidTrit { character, position })?);
+            }
+            position += 1 + usize::from(overlined);
+        }
+        Ok(())
+    }
+}
+
+impl <const N: usize> Number<N> {
+    /// Parses a balanced ternary string, accepting the notations and separators
+    /// enabled in `options`. Parsing with the default options is the same as
+    /// [`FromStr`](core::str::FromStr).
+    /// * `encoded` - The trits, most significant first
+    /// * `options` - The notations to accept
+    pub fn parse_with(encoded: &str, options: ParseOptions) -> Result<Self, ParseTernaryError> {
+        // Validate and count the trits before building the number, so that the
+        // reported position of an invalid trit is always the leftmost one
+        let mut length = 0;
+        options.for_each_trit(encoded, |_| length += 1)?;
+
+        if length == 0 {
+            return Err(ParseTernaryError::Empty);
+        }
+        if length > N {
+            return Err(ParseTernaryError::TooLong { length, capacity: N });
+        }
+
+        let mut output = Number::<N>::ZERO;
+        let mut idx = N - length;
+        options.for_each_trit(encoded, |trit| {
+            output.0[idx] = trit;
+            idx += 1;
+        })?;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_parsing() {
+        let strict = ParseOptions::default();
+        assert_eq!(strict, ParseOptions::new());
+        assert_eq!(Number::<8>::parse_with("+0--", strict), Ok(Number::<8>::from("+0--")));
+        assert_eq!(Number::<8>::parse_with("", strict), Err(ParseTernaryError::Empty));
+        assert_eq!(Number::<8>::parse_with("+0_-", strict), Err(ParseTernaryError::InvalidTrit { character: '_', position: 2 }));
+        assert_eq!(Number::<8>::parse_with("1T", strict), Err(ParseTernaryError::InvalidTrit { character: '1', position: 0 }));
+        assert_eq!(Number::<2>::parse_with("+0-", strict), Err(ParseTernaryError::TooLong { length: 3, capacity: 2 }));
+    }
+
+    #[test]
+    fn alphabets() {
+        let expected = Ok(Number::<8>::from("+0-+-"));
+        let options = ParseOptions::new().with_10t_digits(true);
+        assert_eq!(Number::<8>::parse_with("10T1T", options), expected);
+        assert_eq!(Number::<8>::parse_with("10t1t", options), expected);
+        assert_eq!(Number::<8>::parse_with("10TPN", options), Err(ParseTernaryError::InvalidTrit { character: 'P', position: 3 }));
+
+        let options = ParseOptions::new().with_pzn_digits(true);
+        assert_eq!(Number::<8>::parse_with("PZNPN", options), expected);
+        assert_eq!(Number::<8>::parse_with("pznpn", options), expected);
+        assert_eq!(Number::<8>::parse_with("+z-p-", options), expected);
+
+        let options = ParseOptions::new().with_unicode_digits(true);
+        assert_eq!(Number::<8>::parse_with("+0\u{2212}+\u{2212}", options), expected);
+        assert_eq!(Number::<8>::parse_with("+01\u{0304}+1\u{0304}", options), expected);
+        // A plain one is only accepted with the `10T` digits
+        assert_eq!(Number::<8>::parse_with("1", options), Err(ParseTernaryError::InvalidTrit { character: '1', position: 0 }));
+        assert_eq!(Number::<8>::parse_with("1\u{0304}1", options.with_10t_digits(true)), Ok(Number::<8>::from("-+")));
+    }
+
+    #[test]
+    fn separators() {
+        let options = ParseOptions::new().with_underscores(true);
+        assert_eq!(Number::<8>::parse_with("_+0_--_", options), Ok(Number::<8>::from("+0--")));
+        assert_eq!(Number::<8>::parse_with("+0 --", options), Err(ParseTernaryError::InvalidTrit { character: ' ', position: 2 }));
+        assert_eq!(Number::<8>::parse_with("___", options), Err(ParseTernaryError::Empty));
+
+        let options = ParseOptions::new().with_whitespace(true);
+        assert_eq!(Number::<8>::parse_with(" +0\t-\n- ", options), Ok(Number::<8>::from("+0--")));
+
+        // Separators don't count towards the length
+        assert_eq!(Number::<2>::parse_with("+ _ -", ParseOptions::lenient()), Ok(Number::<2>::from("+-")));
+    }
+
+    #[test]
+    fn positions_count_characters() {
+        let lenient = ParseOptions::lenient();
+        assert_eq!(Number::<8>::parse_with("1\u{0304}1\u{0304}x", lenient), Err(ParseTernaryError::InvalidTrit { character: 'x', position: 4 }));
+        assert_eq!(Number::<8>::parse_with("\u{2212}\u{2212}x", lenient), Err(ParseTernaryError::InvalidTrit { character: 'x', position: 2 }));
+        assert_eq!(Number::<8>::parse_with("\u{0304}", lenient), Err(ParseTernaryError::InvalidTrit { character: '\u{0304}', position: 0 }));
+    }
+}
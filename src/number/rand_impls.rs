@@ -0,0 +1,43 @@
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Samples each trit independently and uniformly, which gives every one of the
+/// 3^N representable values an equal probability.
+impl <const N: usize> Distribution<Number<N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Number<N> {
+        Number::<N>(core::array::from_fn(|_| rng.gen::<Trit>()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn samples_every_trit() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let trits: BTreeSet<Trit> = (0..100).map(|_| rng.gen()).collect();
+
+        assert_eq!(trits.len(), 3);
+    }
+
+    #[test]
+    fn samples_every_number() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let values: BTreeSet<i128> = (0..5000)
+            .map(|_| rng.gen::<Number<4>>().to_i128())
+            .collect();
+
+        // All 81 values between -40 and 40 inclusive
+        assert_eq!(values.len(), 81);
+        assert!(values.iter().all(|value| (-40..=40).contains(value)));
+    }
+}
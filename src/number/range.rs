@@ -0,0 +1,180 @@
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+
+use crate::number::Number;
+
+impl <const N: usize> Number<N> {
+    /// Returns an iterator over every value in a range of numbers, counting up
+    /// with [`inc`](Self::inc). Any kind of range may be given, with unbounded
+    /// ends running to [`MIN`](Self::MIN) and [`MAX`](Self::MAX). The iterator
+    /// is double-ended, so the range can also be counted down with `rev`.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let values: Vec<i128> = Number::<4>::range(Number::from(-2)..=Number::from(2)).map(|value| value.to_i128()).collect();
+    /// assert_eq!(values, [-2, -1, 0, 1, 2]);
+    /// assert_eq!(Number::<3>::range(..).count(), 27);
+    /// assert_eq!(Number::<3>::range(..Number::MIN).next(), None);
+    /// ```
+    pub fn range(range: impl RangeBounds<Number<N>>) -> NumberRange<N> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(*start),
+            Bound::Excluded(start) => start.checked_add(Number::<N>::ONE),
+            Bound::Unbounded => Some(Number::<N>::MIN)
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => Some(*end),
+            Bound::Excluded(end) => end.checked_sub(Number::<N>::ONE),
+            Bound::Unbounded => Some(Number::<N>::MAX)
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end => NumberRange { front: start, back: end, exhausted: false },
+            _ => NumberRange { front: Number::<N>::ZERO, back: Number::<N>::ZERO, exhausted: true }
+        }
+    }
+}
+
+/// The number of steps of [`Number::inc`] from `start` to `end`, which must be
+/// no greater than `end`, or `None` if that doesn't fit in a `usize`.
+fn steps_between<const N: usize>(start: &Number<N>, end: &Number<N>) -> Option<usize> {
+    // The difference of two numbers can be too wide for N trits, in which case
+    // it can still be found directly if the ends and their difference all fit
+    // in an `i128`
+    match end.checked_sub(*start) {
+        Some(difference) => i128::try_from(difference).ok(),
+        None => i128::try_from(*end).ok().zip(i128::try_from(*start).ok()).and_then(|(end, start)| end.checked_sub(start))
+    }.and_then(|steps| usize::try_from(steps).ok())
+}
+
+/// An iterator over the values in a range of numbers, from
+/// [`Number::range`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NumberRange<const N: usize> {
+    // The next values to be yielded from each end, which are only meaningful
+    // while the range isn't exhausted
+    front: Number<N>,
+    back: Number<N>,
+    exhausted: bool
+}
+
+impl <const N: usize> Iterator for NumberRange<N> {
+    type Item = Number<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = self.front;
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.front.inc();
+        }
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted {
+            return (0, Some(0));
+        }
+        match steps_between(&self.front, &self.back).and_then(|steps| steps.checked_add(1)) {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None)
+        }
+    }
+}
+
+impl <const N: usize> DoubleEndedIterator for NumberRange<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = self.back;
+        if self.front == self.back {
+            self.exhausted = true;
+        } else {
+            self.back.dec();
+        }
+        Some(value)
+    }
+}
+
+impl <const N: usize> FusedIterator for NumberRange<N> {}
+
+/// Lets ranges of numbers be iterated over directly, as in
+/// `for n in Number::<8>::ZERO..=bound`. Requires a nightly compiler.
+#[cfg(feature = "step_trait")]
+impl <const N: usize> core::iter::Step for Number<N> {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        if start > end {
+            return (0, None);
+        }
+        match steps_between(start, end) {
+            Some(steps) => (steps, Some(steps)),
+            None => (usize::MAX, None)
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        // A count too wide for N trits can still be in range from a negative
+        // start, but half of any count in range always fits
+        let half = Number::<N>::try_from(count as i128 / 2).ok()?;
+        let rest = if count % 2 == 1 {Number::<N>::ONE} else {Number::<N>::ZERO};
+        start.checked_add(half)?.checked_add(half)?.checked_add(rest)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let half = Number::<N>::try_from(count as i128 / 2).ok()?;
+        let rest = if count % 2 == 1 {Number::<N>::ONE} else {Number::<N>::ZERO};
+        start.checked_sub(half)?.checked_sub(half)?.checked_sub(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        let all: Vec<Number<2>> = Number::<2>::range(..).collect();
+        assert_eq!(all.len(), 9);
+        assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(all[0], Number::<2>::MIN);
+        assert_eq!(all[8], Number::<2>::MAX);
+
+        let (start, end) = (Number::<5>::from(-7), Number::<5>::from(12));
+        assert_eq!(Number::<5>::range(start..end).map(|value| value.to_i128()).collect::<Vec<_>>(), (-7..12).collect::<Vec<_>>());
+        assert_eq!(Number::<5>::range(start..=end).rev().map(|value| value.to_i128()).collect::<Vec<_>>(), (-7..=12).rev().collect::<Vec<_>>());
+        assert_eq!(Number::<5>::range((Bound::Excluded(start), Bound::Unbounded)).size_hint(), (128, Some(128)));
+        assert_eq!(Number::<5>::range(end..start).next(), None);
+        assert_eq!(Number::<5>::range(Number::<5>::MAX..).collect::<Vec<_>>(), [Number::<5>::MAX]);
+        assert_eq!(Number::<5>::range((Bound::Excluded(Number::<5>::MAX), Bound::Unbounded)).next(), None);
+
+        // Both ends meet in the middle
+        let mut range = Number::<5>::range(start..=end);
+        assert_eq!(range.next(), Some(start));
+        assert_eq!(range.next_back(), Some(end));
+        assert_eq!(range.size_hint(), (18, Some(18)));
+        assert_eq!(range.by_ref().rev().last(), Some(Number::<5>::from(-6)));
+        assert_eq!(range.next(), None);
+
+        // Ranges too long to count
+        assert_eq!(Number::<60>::range(..).size_hint(), (usize::MAX, None));
+        assert_eq!(Number::<81>::range(Number::from_i128(i128::MIN)..=Number::from_i128(i128::MAX)).size_hint(), (usize::MAX, None));
+        assert_eq!(Number::<200>::range(Number::<200>::ZERO..=Number::<200>::ONE).count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "step_trait")]
+    fn native_ranges() {
+        let bound = Number::<3>::from(4);
+        let values: Vec<i128> = (Number::<3>::ZERO..=bound).map(|value| value.to_i128()).collect();
+        assert_eq!(values, [0, 1, 2, 3, 4]);
+        assert_eq!((Number::<3>::MIN..=Number::<3>::MAX).count(), 27);
+        assert_eq!((Number::<3>::MIN..Number::<3>::MAX).nth(20), Some(Number::<3>::from(7)));
+        assert_eq!((Number::<3>::MIN..=Number::<3>::MAX).step_by(13).last(), Some(Number::<3>::from(13)));
+        assert_eq!((Number::<3>::ZERO..=bound).next_back(), Some(bound));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::number::{Number, TryFromIntError};
+use crate::trit::Trit;
+
+/// Compile-time checks that a high and a low width sum to a total width. These
+/// are associated constants rather than inline `const` blocks, which the
+/// `generic_const_exprs` feature rejects.
+struct Widths<const HIGH: usize, const LOW: usize, const TOTAL: usize>;
+
+impl <const HIGH: usize, const LOW: usize, const TOTAL: usize> Widths<HIGH, LOW, TOTAL> {
+    const CONCAT: () = assert!(HIGH + LOW == TOTAL, "Output width must be the sum of the input widths");
+    const SPLIT: () = assert!(HIGH + LOW == TOTAL, "Output widths must sum to the input width");
+}
+
+// A generic `TryFrom<Number<N>> for Number<M>` would overlap with the standard
+// library's reflexive `TryFrom<T> for T` when N == M, so fallible width conversion
+// is provided by `try_resize` instead.
+impl <const N: usize> Number<N> {
+    /// Converts to a number of M trits, keeping its value wherever possible.
+    ///
+    /// Widening pads the number with zero trits in the new higher-order
+    /// positions. This is all that is required to preserve the value, as a
+    /// balanced ternary number carries its sign in its leading non-zero trit
+    /// and so needs no equivalent of two's complement sign extension.
+    ///
+    /// Narrowing keeps the M least significant trits, in the same manner as
+    /// [`Number::truncate`].
+    pub fn resize<const M: usize>(self) -> Number<M> {
+        Number::<M>::from_rev_iter(self.iter())
+    }
+
+    /// Converts to a number of M trits, failing if any non-zero trits would be
+    /// lost because the value doesn't fit.
+    pub fn try_resize<const M: usize>(self) -> Result<Number<M>, TryFromIntError> {
+        if self.iter().skip(M).all(|trit| trit == Trit::ZERO) {
+            Ok(self.resize())
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+
+    /// Converts to a number of M trits by keeping only the M least significant
+    /// trits, which wraps the value modulo 3^M. If M is larger than N then the
+    /// number is padded with zeros, as with [`Number::resize`].
+    pub fn truncate<const M: usize>(self) -> Number<M> {
+        self.resize()
+    }
+
+    /// Joins this number, as the high-order trits, with `low` as the low-order
+    /// trits, giving a number of O = N + M trits whose value is
+    /// `self * 3^M + low`. The output width is given explicitly as generic
+    /// arithmetic on const parameters is not yet stable; a width other than
+    /// N + M is a compile-time error.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let joined: Number<6> = Number::<2>::from("+-").concat(Number::<4>::from("0-0+"));
+    /// assert_eq!(joined, Number::<6>::from("+-0-0+"));
+    /// ```
+    pub fn concat<const M: usize, const O: usize>(self, low: Number<M>) -> Number<O> {
+        let () = Widths::<N, M, O>::CONCAT;
+
+        let mut output = Number::<O>::ZERO;
+        output.0[..N].copy_from_slice(&self.0);
+        output.0[N..].copy_from_slice(&low.0);
+        output
+    }
+
+    /// Splits this number into its H high-order trits and L low-order trits,
+    /// such that `self == high * 3^L + low`. This is the inverse of
+    /// [`Number::concat`]. The widths must sum to N, otherwise it is a
+    /// compile-time error.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// let (high, low) = Number::<6>::from("+-0-0+").split::<2, 4>();
+    /// assert_eq!(high, Number::<2>::from("+-"));
+    /// assert_eq!(low, Number::<4>::from("0-0+"));
+    /// ```
+    pub fn split<const H: usize, const L: usize>(self) -> (Number<H>, Number<L>) {
+        let () = Widths::<H, L, N>::SPLIT;
+
+        let mut high = Number::<H>::ZERO;
+        let mut low = Number::<L>::ZERO;
+        high.0.copy_from_slice(&self.0[..H]);
+        low.0.copy_from_slice(&self.0[H..]);
+        (high, low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widening_preserves_value() {
+        let num_neg_50 = Number::<5>::from("-+0++");
+
+        assert_eq!(num_neg_50.resize::<8>(), Number::<8>::from("000-+0++"));
+        assert_eq!(num_neg_50.try_resize::<8>(), Ok(Number::<8>::from("-+0++")));
+        assert_eq!(num_neg_50.resize::<5>(), num_neg_50);
+    }
+
+    #[test]
+    fn narrowing_keeps_low_trits() {
+        let num_neg_50 = Number::<8>::from("-+0++");
+
+        assert_eq!(num_neg_50.resize::<5>(), Number::<5>::from("-+0++"));
+        assert_eq!(num_neg_50.truncate::<3>(), Number::<3>::from("0++"));
+        assert_eq!(num_neg_50.truncate::<0>(), Number::<0>::ZERO);
+    }
+
+    #[test]
+    fn concatenation_and_splitting() {
+        let num_5 = Number::<3>::from("+--");
+        let num_neg_4 = Number::<3>::from("0--");
+
+        // 5 * 27 - 4 = 131
+        let joined: Number<6> = num_5.concat(num_neg_4);
+        assert_eq!(joined, Number::<6>::from("+--0--"));
+        assert_eq!(joined.split::<3, 3>(), (num_5, num_neg_4));
+        assert_eq!(joined.split::<0, 6>(), (Number::<0>::ZERO, joined));
+        assert_eq!(joined.split::<6, 0>(), (joined, Number::<0>::ZERO));
+    }
+
+    #[test]
+    fn fallible_narrowing() {
+        let num_neg_50 = Number::<8>::from("-+0++");
+
+        assert_eq!(num_neg_50.try_resize::<5>(), Ok(Number::<5>::from("-+0++")));
+        assert_eq!(num_neg_50.try_resize::<4>(), Err(TryFromIntError(())));
+        assert_eq!(Number::<8>::ZERO.try_resize::<0>(), Ok(Number::<0>::ZERO));
+    }
+}
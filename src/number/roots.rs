@@ -0,0 +1,111 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Returns the square root of this number, rounded down.
+    ///
+    /// # Panics
+    /// Panics if the number is negative.
+    pub fn isqrt(self) -> Self {
+        self.checked_isqrt().expect("Argument of integer square root cannot be negative")
+    }
+
+    /// Returns the square root of this number rounded down, or `None` if the
+    /// number is negative.
+    pub fn checked_isqrt(self) -> Option<Self> {
+        if self.is_negative() {
+            None
+        } else {
+            Some(self.floor_root(2))
+        }
+    }
+
+    /// Returns the cube root of this number, rounded down. Cube roots of negative
+    /// numbers are rounded towards negative infinity, so the cube of the result
+    /// never exceeds the number.
+    pub fn icbrt(self) -> Self {
+        let abs_root = self.abs().floor_root(3);
+        if !self.is_negative() {
+            abs_root
+        } else if abs_root.pow(3) == self.abs() {
+            -abs_root
+        } else {
+            // An inexact root of a negative number was rounded towards zero, so
+            // step one further away to round it down
+            let mut root = -abs_root;
+            root.dec();
+            root
+        }
+    }
+
+    /// Finds the largest non-negative root whose power of `degree` does not
+    /// exceed this non-negative number.
+    fn floor_root(self, degree: u32) -> Self {
+        // The root is built one trit at a time from the most significant end. At
+        // each position the lower trits can still make up any value within half
+        // a unit of the current trit's weight, so the trit is chosen as the
+        // largest one for which the smallest value it can still reach is not
+        // too large. The smallest value has every lower trit set negative, and
+        // any negative candidate is clamped to zero as only non-negative roots
+        // are considered.
+        let mut root = Number::<N>::ZERO;
+        for idx in 0..N {
+            for trit in [Trit::POS, Trit::ZERO, Trit::NEG] {
+                let mut smallest = root;
+                smallest.0[idx] = trit;
+                smallest.0[idx+1..].fill(Trit::NEG);
+
+                let smallest = if smallest.is_negative() {Number::<N>::ZERO} else {smallest};
+                if smallest.checked_pow(degree).is_some_and(|power| power <= self) {
+                    root.0[idx] = trit;
+                    break;
+                }
+            }
+        }
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_root() {
+        assert_eq!(Number::<8>::ZERO.isqrt(), Number::<8>::ZERO);
+        assert_eq!(Number::<8>::from("+-0--").isqrt(), Number::<8>::from("+-+")); // sqrt(50) = 7.07
+        assert_eq!(Number::<8>::from("+-+0+").isqrt(), Number::<8>::from("+0-")); // sqrt(64) = 8
+        assert_eq!(Number::<8>::from("-").checked_isqrt(), None);
+
+        let mut number = Number::<6>::ZERO;
+        for value in 0..=364_u32 {
+            assert_eq!(number.isqrt(), Number::<6>::from(value.isqrt() as i32));
+            number.inc();
+        }
+
+        // Roots of values near the top of the range don't overflow
+        assert_eq!(Number::<4>::MAX.isqrt(), Number::<4>::from("+-0")); // sqrt(40) = 6.32
+        assert_eq!(Number::<1>::MAX.isqrt(), Number::<1>::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Argument of integer square root cannot be negative")]
+    fn square_root_of_negative() {
+        let _ = Number::<8>::from("-").isqrt();
+    }
+
+    #[test]
+    fn cube_root() {
+        assert_eq!(Number::<8>::from("+000").icbrt(), Number::<8>::from("+0")); // cbrt(27) = 3
+        assert_eq!(Number::<8>::from("+00-").icbrt(), Number::<8>::from("+-")); // cbrt(26) = 2.96
+        assert_eq!(Number::<8>::from("-000").icbrt(), Number::<8>::from("-0")); // cbrt(-27) = -3
+        assert_eq!(Number::<8>::from("-00+").icbrt(), Number::<8>::from("-0")); // cbrt(-26) rounds to -3
+
+        let mut number = Number::<6>::MIN;
+        for value in -364..=364_i32 {
+            let expected = (-8..=8).rev().find(|root: &i32| root.pow(3) <= value).unwrap();
+            assert_eq!(number.icbrt(), Number::<6>::from(expected), "cbrt({value})");
+            number.inc();
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::number::Number;
+
+impl <const N: usize> Number<N> {
+    /// Rotates the trits towards the most significant position by `positions`,
+    /// with the trits shifted out of the top wrapping around to the least
+    /// significant positions. Rotating by N or more wraps around modulo N.
+    pub fn rotate_left(mut self, positions: usize) -> Self {
+        if N > 0 {
+            self.0.rotate_left(positions % N);
+        }
+        self
+    }
+
+    /// Rotates the trits towards the least significant position by `positions`,
+    /// with the trits shifted out of the bottom wrapping around to the most
+    /// significant positions. Rotating by N or more wraps around modulo N.
+    pub fn rotate_right(mut self, positions: usize) -> Self {
+        if N > 0 {
+            self.0.rotate_right(positions % N);
+        }
+        self
+    }
+
+    /// Reverses the order of the trits, so that the least significant trit
+    /// becomes the most significant and vice versa.
+    pub fn reverse_trits(mut self) -> Self {
+        self.0.reverse();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let number = Number::<6>::from("+0--0+");
+
+        assert_eq!(number.rotate_left(1), Number::<6>::from("0--0++"));
+        assert_eq!(number.rotate_left(4), Number::<6>::from("0++0--"));
+        assert_eq!(number.rotate_right(1), Number::<6>::from("++0--0"));
+        assert_eq!(number.rotate_right(4), Number::<6>::from("--0++0"));
+
+        // Rotations wrap around modulo the width, and undo each other
+        assert_eq!(number.rotate_left(6), number);
+        assert_eq!(number.rotate_left(7), number.rotate_left(1));
+        assert_eq!(number.rotate_left(5).rotate_right(5), number);
+        assert_eq!(Number::<0>::ZERO.rotate_left(3), Number::<0>::ZERO);
+    }
+
+    #[test]
+    fn reversal() {
+        let number = Number::<6>::from("+0--0");
+
+        assert_eq!(number.reverse_trits(), Number::<6>::from("0--0+0"));
+        assert_eq!(number.reverse_trits().reverse_trits(), number);
+    }
+}
@@ -0,0 +1,155 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Provides intentionally-saturating arithmetic on a balanced ternary number.
+///
+/// Results that do not fit are clamped to [`Number::MAX`] (all `+` trits) or
+/// [`Number::MIN`] (all `-` trits), mirroring `std::num::Saturating` for the
+/// primitive integers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl <const N: usize> Number<N> {
+    /// Saturating addition. Clamps to the largest or smallest representable
+    /// value instead of overflowing.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        // The carry escaping the most significant trit indicates the direction
+        // in which the sum overflowed
+        match self.carrying_add(rhs, Trit::ZERO) {
+            (sum, Trit::ZERO) => sum,
+            (_, Trit::POS) => Number::<N>::MAX,
+            (_, Trit::NEG) => Number::<N>::MIN
+        }
+    }
+
+    /// Saturating subtraction. Clamps to the largest or smallest representable
+    /// value instead of overflowing.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.saturating_add(-rhs)
+    }
+
+    /// Saturating multiplication. Clamps to the largest or smallest
+    /// representable value instead of overflowing.
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        // Any non-zero high word means the product overflowed, and the sign of
+        // that high word is the sign of the full product
+        let (low, high) = self.widening_mul(rhs);
+        match high.0.iter().find(|trit| **trit != Trit::ZERO) {
+            None => low,
+            Some(Trit::POS) => Number::<N>::MAX,
+            Some(_) => Number::<N>::MIN
+        }
+    }
+
+    /// Saturating division. Balanced ternary division can never overflow, so
+    /// this is equivalent to plain division and panics if `divisor` is zero.
+    pub fn saturating_div(self, divisor: Self) -> Self {
+        self / divisor
+    }
+
+    /// Saturating negation. Balanced ternary negation can never overflow, so
+    /// this is equivalent to plain negation.
+    pub fn saturating_neg(self) -> Self {
+        -self
+    }
+}
+
+impl <const N: usize> fmt::Display for Saturating<Number<N>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl <const N: usize> Neg for Saturating<Number<N>> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Saturating(self.0.saturating_neg())
+    }
+}
+
+macro_rules! saturating_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $method:ident) => {
+        impl <const N: usize> $op_trait for Saturating<Number<N>> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                Saturating(self.0.$method(rhs.0))
+            }
+        }
+
+        impl <const N: usize> $assign_trait for Saturating<Number<N>> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = Saturating(self.0.$method(rhs.0));
+            }
+        }
+    };
+}
+
+saturating_binary_op!(Add, add, AddAssign, add_assign, saturating_add);
+saturating_binary_op!(Sub, sub, SubAssign, sub_assign, saturating_sub);
+saturating_binary_op!(Mul, mul, MulAssign, mul_assign, saturating_mul);
+saturating_binary_op!(Div, div, DivAssign, div_assign, saturating_div);
+
+// The remainder of a division is never larger than its operands, so the plain
+// operator already has saturating semantics
+impl <const N: usize> Rem for Saturating<Number<N>> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Saturating(self.0 % rhs.0)
+    }
+}
+
+impl <const N: usize> RemAssign for Saturating<Number<N>> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = Saturating(self.0 % rhs.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_arithmetic() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_min = Number::<4>::from("----"); // -40
+        let num_one = Number::<4>::from("+");
+        let num_two = Number::<4>::from("+-");
+
+        assert_eq!(num_max.saturating_add(num_one), num_max);
+        assert_eq!(num_min.saturating_add(-num_one), num_min);
+        assert_eq!(num_max.saturating_add(-num_one), Number::<4>::from("+++0"));
+
+        assert_eq!(num_min.saturating_sub(num_one), num_min);
+        assert_eq!(num_max.saturating_sub(num_min), num_max);
+
+        assert_eq!(num_max.saturating_mul(num_two), num_max);
+        assert_eq!(num_max.saturating_mul(-num_two), num_min);
+        assert_eq!(num_min.saturating_mul(-num_two), num_max);
+        assert_eq!(Number::<4>::from("+-+-").saturating_mul(num_two), num_max); // Exactly 40
+    }
+
+    #[test]
+    fn saturating_operators() {
+        let num_max = Saturating(Number::<4>::from("++++")); // 40
+        let num_min = Saturating(Number::<4>::from("----")); // -40
+        let num_one = Saturating(Number::<4>::from("+"));
+
+        assert_eq!(num_max + num_one, num_max);
+        assert_eq!(num_min - num_one, num_min);
+        assert_eq!(num_min * num_min, num_max);
+        assert_eq!(-num_min, num_max);
+
+        let mut temp = num_min;
+        temp += num_one;
+        assert_eq!(temp, Saturating(Number::<4>::from("---0")));
+        temp *= num_max;
+        assert_eq!(temp, num_min);
+    }
+}
@@ -0,0 +1,116 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Add<Trit> for Number<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Trit) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl <const N: usize> Sub<Trit> for Number<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Trit) -> Self::Output {
+        Add::add(self, rhs.negate())
+    }
+}
+
+impl <const N: usize> SubAssign<Trit> for Number<N> {
+    fn sub_assign(&mut self, rhs: Trit) {
+        AddAssign::add_assign(self, rhs.negate());
+    }
+}
+
+impl <const N: usize> Mul<Trit> for Number<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Trit) -> Self::Output {
+        // Multiplying by a single trit either negates, clears or keeps the
+        // number, so there's no need for the full multiplier
+        match rhs {
+            Trit::NEG => -self,
+            Trit::ZERO => Number::<N>::ZERO,
+            Trit::POS => self
+        }
+    }
+}
+
+impl <const N: usize> MulAssign<Trit> for Number<N> {
+    fn mul_assign(&mut self, rhs: Trit) {
+        *self = *self * rhs;
+    }
+}
+
+// Arithmetic with the primitive integers converts the integer to a number of
+// the same width first. Both the conversion and the arithmetic wrap modulo 3^N,
+// so the result is the same as if the integer had fitted.
+macro_rules! int_binary_op {
+    ($int:ty, $op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl <const N: usize> $op_trait<$int> for Number<N> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: $int) -> Self::Output {
+                let rhs = Number::<N>::from_magnitude(rhs < 0, u128::from(rhs.unsigned_abs())).0;
+                $op_trait::$op_fn(self, rhs)
+            }
+        }
+
+        impl <const N: usize> $assign_trait<$int> for Number<N> {
+            fn $assign_fn(&mut self, rhs: $int) {
+                *self = $op_trait::$op_fn(*self, rhs);
+            }
+        }
+    };
+}
+
+int_binary_op!(i32, Add, add, AddAssign, add_assign);
+int_binary_op!(i32, Sub, sub, SubAssign, sub_assign);
+int_binary_op!(i32, Mul, mul, MulAssign, mul_assign);
+int_binary_op!(i64, Add, add, AddAssign, add_assign);
+int_binary_op!(i64, Sub, sub, SubAssign, sub_assign);
+int_binary_op!(i64, Mul, mul, MulAssign, mul_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trit_operators() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(num_50 + Trit::POS, Number::<8>::from("+-0-0"));
+        assert_eq!(num_50 - Trit::POS, Number::<8>::from("+--++"));
+        assert_eq!(num_50 * Trit::NEG, -num_50);
+        assert_eq!(num_50 * Trit::ZERO, Number::<8>::ZERO);
+        assert_eq!(num_50 * Trit::POS, num_50);
+
+        let mut number = num_50;
+        number -= Trit::NEG;
+        number *= Trit::NEG;
+        assert_eq!(number, Number::<8>::from("-+0+0")); // -(50 + 1)
+    }
+
+    #[test]
+    fn integer_operators() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(num_50 + 5, Number::<8>::from(55));
+        assert_eq!(num_50 - 60_i64, Number::<8>::from(-10));
+        assert_eq!(num_50 * -3, Number::<8>::from(-150));
+
+        let mut number = num_50;
+        number += 1_i64;
+        number *= 2;
+        number -= 2;
+        assert_eq!(number, Number::<8>::from(100));
+
+        // Integers wider than the number wrap in the same way as the arithmetic
+        assert_eq!(Number::<4>::ZERO + 81_i64, Number::<4>::ZERO);
+        assert_eq!(Number::<4>::ONE * i64::MAX, Number::<4>::from((i64::MAX % 81) as i32));
+    }
+}
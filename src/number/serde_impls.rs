@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Serializes as a `-`/`0`/`+` trit string without leading zeros for
+/// human-readable formats, or as the packed bytes of [`Number::to_packed_bytes`]
+/// for compact binary formats.
+impl <const N: usize> Serialize for Number<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&SignificantTrits(self))
+        } else {
+            serializer.serialize_bytes(&self.to_packed_bytes())
+        }
+    }
+}
+
+impl <'de, const N: usize> Deserialize<'de> for Number<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NumberVisitor::<N>)
+        } else {
+            deserializer.deserialize_bytes(NumberVisitor::<N>)
+        }
+    }
+}
+
+/// Displays only the significant trits of a number, or a single `0` for zero.
+struct SignificantTrits<'a, const N: usize>(&'a Number<N>);
+
+impl <const N: usize> fmt::Display for SignificantTrits<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut trits = self.0.0.iter().skip_while(|trit| **trit == Trit::ZERO).peekable();
+        if trits.peek().is_none() {
+            return write!(f, "0");
+        }
+        trits.try_for_each(|trit| write!(f, "{}", trit))
+    }
+}
+
+struct NumberVisitor<const N: usize>;
+
+impl <'de, const N: usize> Visitor<'de> for NumberVisitor<N> {
+    type Value = Number<N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a balanced ternary number of at most {} trits", N)
+    }
+
+    fn visit_str<E: de::Error>(self, encoded: &str) -> Result<Self::Value, E> {
+        encoded.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Number::<N>::from_packed_bytes(bytes)
+            .map_err(|_| E::invalid_value(de::Unexpected::Bytes(bytes), &self))
+    }
+
+    // Some formats represent byte arrays as a sequence of individual bytes
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    use super::*;
+
+    #[test]
+    fn human_readable_representation() {
+        assert_tokens(&Number::<8>::from("+0--").readable(), &[Token::Str("+0--")]);
+        assert_tokens(&Number::<8>::from("--------").readable(), &[Token::Str("--------")]);
+        assert_tokens(&Number::<8>::ZERO.readable(), &[Token::Str("0")]);
+
+        assert_de_tokens_error::<serde_test::Readable<Number<4>>>(
+            &[Token::Str("+0-0-")],
+            "5 trits supplied but number can only hold 4"
+        );
+    }
+
+    #[test]
+    fn compact_representation() {
+        // 23 in the low byte, and three zero trits padded out to five in the high byte
+        assert_tokens(&Number::<8>::from("+0--").compact(), &[Token::Bytes(&[144, 121])]);
+        assert_tokens(&Number::<5>::from("+++++").compact(), &[Token::Bytes(&[242])]);
+        assert_tokens(&Number::<5>::from("-----").compact(), &[Token::Bytes(&[0])]);
+        assert_tokens(&Number::<0>::ZERO.compact(), &[Token::Bytes(&[])]);
+    }
+
+    #[test]
+    fn invalid_compact_representation() {
+        // Wrong number of bytes, an out-of-range byte, and a non-zero padding trit
+        for bytes in [&[121][..], &[243, 121], &[121, 122 + 27]] {
+            assert!(Number::<8>::from_packed_bytes(bytes).is_err());
+        }
+    }
+}
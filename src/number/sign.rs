@@ -0,0 +1,72 @@
+use crate::number::Number;
+use crate::trit::Trit;
+
+impl <const N: usize> Number<N> {
+    /// Returns the sign of this number as a trit. The sign of a balanced ternary
+    /// number is simply its most significant non-zero trit, or zero if there is
+    /// none.
+    pub const fn signum(self) -> Trit {
+        let mut idx = 0;
+        while idx < N {
+            if !matches!(self.0[idx], Trit::ZERO) {
+                return self.0[idx];
+            }
+            idx += 1;
+        }
+        Trit::ZERO
+    }
+
+    /// Returns `true` if the number is greater than zero.
+    pub const fn is_positive(self) -> bool {
+        matches!(self.signum(), Trit::POS)
+    }
+
+    /// Returns `true` if the number is less than zero.
+    pub const fn is_negative(self) -> bool {
+        matches!(self.signum(), Trit::NEG)
+    }
+
+    /// Returns `true` if every trit of the number is zero.
+    pub const fn is_zero(self) -> bool {
+        matches!(self.signum(), Trit::ZERO)
+    }
+
+    /// Returns the absolute value of this number. The range of a balanced
+    /// ternary number is symmetric around zero, so unlike the primitive integer
+    /// types this can never overflow.
+    pub const fn abs(self) -> Self {
+        if self.is_negative() {self.wrapping_neg()} else {self}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_queries() {
+        let num_50 = Number::<8>::from("+-0--");
+        let num_neg_50 = Number::<8>::from("-+0++");
+
+        assert_eq!(num_50.signum(), Trit::POS);
+        assert_eq!(num_neg_50.signum(), Trit::NEG);
+        assert_eq!(Number::<8>::ZERO.signum(), Trit::ZERO);
+
+        assert!(num_50.is_positive() && !num_50.is_negative() && !num_50.is_zero());
+        assert!(!num_neg_50.is_positive() && num_neg_50.is_negative() && !num_neg_50.is_zero());
+        assert!(!Number::<8>::ZERO.is_positive() && !Number::<8>::ZERO.is_negative());
+        assert!(Number::<8>::ZERO.is_zero());
+    }
+
+    #[test]
+    fn absolute_value() {
+        let num_50 = Number::<8>::from("+-0--");
+
+        assert_eq!(num_50.abs(), num_50);
+        assert_eq!((-num_50).abs(), num_50);
+        assert_eq!(Number::<8>::ZERO.abs(), Number::<8>::ZERO);
+
+        // The most negative value has a representable absolute value
+        assert_eq!(Number::<8>::MIN.abs(), Number::<8>::MAX);
+    }
+}
@@ -0,0 +1,33 @@
+//! Proptest strategies for generating [`Number`] values.
+
+use proptest::collection::vec;
+use proptest::strategy::Strategy;
+
+use crate::number::Number;
+use crate::trit;
+
+/// A strategy producing every N-trit number with equal probability. Values
+/// shrink trit by trit towards zero.
+pub fn any_number<const N: usize>() -> impl Strategy<Value = Number<N>> {
+    vec(trit::strategy(), N)
+        .prop_map(|trits| Number::<N>::from_rev_iter(trits.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn double_negation_is_identity(number in any_number::<12>()) {
+            assert_eq!(-(-number), number);
+        }
+
+        #[test]
+        fn addition_commutes(lhs in any_number::<12>(), rhs in any_number::<12>()) {
+            assert_eq!(lhs + rhs, rhs + lhs);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Toom-3 multiplication for wide numbers.
+//!
+//! The trits of each operand are treated as the coefficients of a polynomial,
+//! which evaluated at 3 gives the value of the number. The polynomials are
+//! multiplied with Toom-Cook 3-way splitting, and the carries are only resolved
+//! once at the end when the product's coefficients are normalised back into
+//! balanced trits. Splitting into three is a natural fit for base 3, and the
+//! coefficient arithmetic uses native integers rather than trit-by-trit
+//! addition.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::trit::Trit;
+
+/// The width at which multiplication of `Number` switches from shift-and-add to
+/// Toom-3.
+pub(crate) const TOOM_THRESHOLD: usize = 81;
+
+/// Below this many coefficients the recursion falls back to the quadratic
+/// convolution, which is faster for short polynomials.
+const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// From this many coefficients the five products of each Toom-3 step are
+/// computed in parallel with the `rayon` feature.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// Multiplies two polynomials given as slices of coefficients, lowest power
+/// first, returning the coefficients of the product.
+pub(crate) fn convolve(a: &[i64], b: &[i64]) -> Vec<i64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < SCHOOLBOOK_THRESHOLD {
+        return schoolbook(a, b);
+    }
+
+    // Split both polynomials into three parts of k coefficients, so that
+    // a = a0 + a1 y + a2 y^2 with y = x^k
+    let k = a.len().max(b.len()).div_ceil(3);
+    let [a0, a1, a2] = split(a, k);
+    let [b0, b1, b2] = split(b, k);
+
+    // Evaluate the product at 0, 1, -1, -2 and infinity
+    let (a_1, a_neg_1, a_neg_2) = evaluate(&a0, &a1, &a2);
+    let (b_1, b_neg_1, b_neg_2) = evaluate(&b0, &b1, &b2);
+    let [r_0, r_1, r_neg_1, r_neg_2, r_inf] = products([
+        (&a0, &b0),
+        (&a_1, &b_1),
+        (&a_neg_1, &b_neg_1),
+        (&a_neg_2, &b_neg_2),
+        (&a2, &b2)
+    ]);
+
+    // Interpolate the coefficients of the product in y, using Bodrato's
+    // sequence. Every division here is exact.
+    let len = r_0.len();
+    let mut r_2 = vec![0; len];
+    let mut r_3 = vec![0; len];
+    let mut r_1_out = vec![0; len];
+    for i in 0..len {
+        let third = (r_neg_2[i] - r_1[i]) / 3;
+        let half = (r_1[i] - r_neg_1[i]) / 2;
+        let even = r_neg_1[i] - r_0[i];
+        r_3[i] = (even - third) / 2 + 2 * r_inf[i];
+        r_2[i] = even + half - r_inf[i];
+        r_1_out[i] = half - r_3[i];
+    }
+
+    let mut product = vec![0; 4 * k + len];
+    for (power, coefficients) in [r_0, r_1_out, r_2, r_3, r_inf].iter().enumerate() {
+        for (i, coefficient) in coefficients.iter().enumerate() {
+            product[power * k + i] += coefficient;
+        }
+    }
+    product.truncate(a.len() + b.len() - 1);
+    product
+}
+
+/// Resolves the carries between the coefficients of a polynomial evaluated at
+/// 3, producing balanced trits from the least significant upwards. Trits keep
+/// being produced until both the coefficients and the carry are exhausted.
+pub(crate) fn normalise(coefficients: &[i64]) -> impl Iterator<Item = Trit> + '_ {
+    let mut coefficients = coefficients.iter();
+    let mut carry = 0_i64;
+    core::iter::from_fn(move || {
+        let value = match coefficients.next() {
+            Some(coefficient) => coefficient + carry,
+            None if carry != 0 => carry,
+            None => return None
+        };
+
+        let (trit, digit) = match value.rem_euclid(3) {
+            0 => (Trit::ZERO, 0),
+            1 => (Trit::POS, 1),
+            _ => (Trit::NEG, -1)
+        };
+        carry = (value - digit) / 3;
+        Some(trit)
+    })
+}
+
+/// Multiplies each pair of polynomials, which all have the same length.
+fn products(pairs: [(&[i64], &[i64]); 5]) -> [Vec<i64>; 5] {
+    #[cfg(feature = "rayon")]
+    if pairs[0].0.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        let mut products: [Vec<i64>; 5] = Default::default();
+        products.par_iter_mut().zip(pairs.par_iter()).for_each(|(product, (a, b))| *product = convolve(a, b));
+        return products;
+    }
+    pairs.map(|(a, b)| convolve(a, b))
+}
+
+fn schoolbook(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut product = vec![0; a.len() + b.len() - 1];
+    for (i, a_coefficient) in a.iter().enumerate() {
+        if *a_coefficient == 0 {
+            continue;
+        }
+        for (j, b_coefficient) in b.iter().enumerate() {
+            product[i + j] += a_coefficient * b_coefficient;
+        }
+    }
+    product
+}
+
+/// Splits a polynomial into three parts of `k` coefficients each, padding with
+/// zeros.
+fn split(coefficients: &[i64], k: usize) -> [Vec<i64>; 3] {
+    core::array::from_fn(|part| {
+        let start = (part * k).min(coefficients.len());
+        let end = ((part + 1) * k).min(coefficients.len());
+        let mut part = coefficients[start..end].to_vec();
+        part.resize(k, 0);
+        part
+    })
+}
+
+/// Evaluates `p0 + p1 y + p2 y^2` at 1, -1 and -2.
+fn evaluate(p0: &[i64], p1: &[i64], p2: &[i64]) -> (Vec<i64>, Vec<i64>, Vec<i64>) {
+    let mut at_1 = Vec::with_capacity(p0.len());
+    let mut at_neg_1 = Vec::with_capacity(p0.len());
+    let mut at_neg_2 = Vec::with_capacity(p0.len());
+    for i in 0..p0.len() {
+        let even = p0[i] + p2[i];
+        at_1.push(even + p1[i]);
+        at_neg_1.push(even - p1[i]);
+        at_neg_2.push(2 * (even - p1[i] + p2[i]) - p0[i]);
+    }
+    (at_1, at_neg_1, at_neg_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convolution_matches_schoolbook() {
+        for (a_len, b_len) in [(32, 32), (33, 40), (100, 100), (250, 31), (400, 299)] {
+            let a: Vec<i64> = (0..a_len).map(|i: i64| (i * i + 3 * i) % 3 - 1).collect();
+            let b: Vec<i64> = (0..b_len).map(|i: i64| (i * i * 5 + i / 2) % 3 - 1).collect();
+            assert_eq!(convolve(&a, &b), schoolbook(&a, &b), "{a_len} x {b_len}");
+        }
+    }
+
+    #[test]
+    fn normalisation() {
+        // 2 + 5 * 3 = 17 = +-0-
+        let trits: Vec<Trit> = normalise(&[2, 5]).collect();
+        assert_eq!(trits, [Trit::NEG, Trit::ZERO, Trit::NEG, Trit::POS]);
+
+        // -4 = --
+        let trits: Vec<Trit> = normalise(&[-4]).collect();
+        assert_eq!(trits, [Trit::NEG, Trit::NEG]);
+    }
+}
@@ -0,0 +1,96 @@
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+use crate::number::Number;
+use crate::trit::{logic, Trit};
+
+impl <const N: usize> Number<N> {
+    /// Tritwise minimum of two numbers, the three-valued analogue of a bitwise
+    /// AND. Each trit of the result is the lesser of the trits in that position.
+    pub const fn tmin(self, rhs: Self) -> Self {
+        self.tritwise(rhs, &logic::AND_TABLE)
+    }
+
+    /// Tritwise maximum of two numbers, the three-valued analogue of a bitwise
+    /// OR. Each trit of the result is the greater of the trits in that position.
+    pub const fn tmax(self, rhs: Self) -> Self {
+        self.tritwise(rhs, &logic::OR_TABLE)
+    }
+
+    /// Tritwise negation, the three-valued analogue of a bitwise NOT. In
+    /// balanced ternary this is the same as arithmetic negation.
+    pub const fn tnot(self) -> Self {
+        self.wrapping_neg()
+    }
+
+    /// Combines the trits in each position of two numbers by looking them up
+    /// in a truth table.
+    const fn tritwise(mut self, rhs: Self, table: &[[Trit; 3]; 3]) -> Self {
+        let mut idx = 0;
+        while idx < N {
+            self.0[idx] = table[self.0[idx] as usize][rhs.0[idx] as usize];
+            idx += 1;
+        }
+        self
+    }
+}
+
+impl <const N: usize> BitAnd for Number<N> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.tmin(rhs)
+    }
+}
+
+impl <const N: usize> BitAndAssign for Number<N> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.tmin(rhs);
+    }
+}
+
+impl <const N: usize> BitOr for Number<N> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.tmax(rhs)
+    }
+}
+
+impl <const N: usize> BitOrAssign for Number<N> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.tmax(rhs);
+    }
+}
+
+impl <const N: usize> Not for Number<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.tnot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tritwise_operations() {
+        let lhs = Number::<9>::from("---000+++");
+        let rhs = Number::<9>::from("-0+-0+-0+");
+
+        assert_eq!(lhs.tmin(rhs), Number::<9>::from("----00-0+"));
+        assert_eq!(lhs.tmax(rhs), Number::<9>::from("-0+00++++"));
+        assert_eq!(lhs.tnot(), Number::<9>::from("+++000---"));
+
+        assert_eq!(lhs & rhs, lhs.tmin(rhs));
+        assert_eq!(lhs | rhs, lhs.tmax(rhs));
+        assert_eq!(!lhs, -lhs);
+
+        let mut mask = lhs;
+        mask &= Number::<9>::from("000000000");
+        assert_eq!(mask, Number::<9>::from("---000000"));
+        mask |= Number::<9>::ZERO;
+        assert_eq!(mask, Number::<9>::ZERO);
+    }
+}
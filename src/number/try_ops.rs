@@ -0,0 +1,153 @@
+use crate::error::Error;
+use crate::number::{Number, RoundingMode};
+
+impl <const N: usize> Number<N> {
+    /// Fallible [`Number::div_rem`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_rem(self, divisor: Self) -> Result<(Self, Self), Error> {
+        Ok(self.div_rem(divisor.nonzero()?))
+    }
+
+    /// Fallible division, rounding towards zero as `Div` does. Returns
+    /// [`Error::DivisionByZero`] if `divisor` is zero.
+    pub fn try_div(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self / divisor.nonzero()?)
+    }
+
+    /// Fallible remainder, as `Rem`. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_rem(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self % divisor.nonzero()?)
+    }
+
+    /// Fallible [`Number::div_euclid`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_euclid(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self.div_euclid(divisor.nonzero()?))
+    }
+
+    /// Fallible [`Number::rem_euclid`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_rem_euclid(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self.rem_euclid(divisor.nonzero()?))
+    }
+
+    /// Fallible [`Number::div_floor`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_floor(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self.div_floor(divisor.nonzero()?))
+    }
+
+    /// Fallible [`Number::div_ceil`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_ceil(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self.div_ceil(divisor.nonzero()?))
+    }
+
+    /// Fallible [`Number::div_rounded`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_rounded(self, divisor: Self, mode: RoundingMode) -> Result<Self, Error> {
+        Ok(self.div_rounded(divisor.nonzero()?, mode))
+    }
+
+    /// Fallible [`Number::next_multiple_of`]. Returns
+    /// [`Error::DivisionByZero`] if `rhs` is zero and [`Error::OutOfRange`] if
+    /// the result cannot be represented in N trits.
+    pub fn try_next_multiple_of(self, rhs: Self) -> Result<Self, Error> {
+        self.checked_next_multiple_of(rhs.nonzero()?).ok_or(Error::OutOfRange)
+    }
+
+    /// Fallible [`Number::mod_reduce`]. Returns [`Error::DivisionByZero`] if
+    /// `modulus` is zero.
+    pub fn try_mod_reduce(self, modulus: Self) -> Result<Self, Error> {
+        Ok(self.mod_reduce(modulus.nonzero()?))
+    }
+
+    /// Fallible [`Number::mod_pow`]. Returns [`Error::DivisionByZero`] if
+    /// `modulus` is zero and [`Error::NegativeOperand`] if `exponent` is
+    /// negative.
+    pub fn try_mod_pow(self, exponent: Self, modulus: Self) -> Result<Self, Error> {
+        let modulus = modulus.nonzero()?;
+        if exponent.is_negative() {
+            return Err(Error::NegativeOperand);
+        }
+        Ok(self.mod_pow(exponent, modulus))
+    }
+
+    /// Fallible [`Number::mod_inverse`]. Returns [`Error::DivisionByZero`] if
+    /// `modulus` is zero, and otherwise the inverse if there is one.
+    pub fn try_mod_inverse(self, modulus: Self) -> Result<Option<Self>, Error> {
+        Ok(self.mod_inverse(modulus.nonzero()?))
+    }
+
+    /// Fallible [`Number::isqrt`]. Returns [`Error::NegativeOperand`] if the
+    /// number is negative.
+    pub fn try_isqrt(self) -> Result<Self, Error> {
+        self.checked_isqrt().ok_or(Error::NegativeOperand)
+    }
+
+    /// Fallible [`Number::ilog3`]. Returns [`Error::ZeroOperand`] if the number
+    /// is zero.
+    pub fn try_ilog3(&self) -> Result<u32, Error> {
+        self.checked_ilog3().ok_or(Error::ZeroOperand)
+    }
+
+    /// Fallible `Ord::clamp`. Returns [`Error::InvalidBounds`] if `min` is
+    /// greater than `max`.
+    pub fn try_clamp(self, min: Self, max: Self) -> Result<Self, Error> {
+        if min > max {
+            return Err(Error::InvalidBounds);
+        }
+        Ok(self.const_clamp(min, max))
+    }
+
+    /// Passes through a non-zero divisor, or returns
+    /// [`Error::DivisionByZero`].
+    fn nonzero(self) -> Result<Self, Error> {
+        if self.is_zero() {Err(Error::DivisionByZero)} else {Ok(self)}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_instead_of_panics() {
+        let (num_7, zero) = (Number::<8>::from(7), Number::<8>::ZERO);
+        assert_eq!(num_7.try_div_rem(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_div(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_rem(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_div_euclid(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_rem_euclid(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_div_floor(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_div_ceil(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_div_rounded(zero, RoundingMode::Floor), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_next_multiple_of(zero), Err(Error::DivisionByZero));
+        assert_eq!(Number::<8>::MAX.try_next_multiple_of(Number::from(3)), Err(Error::OutOfRange));
+        assert_eq!(num_7.try_mod_reduce(zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_mod_pow(Number::ONE, zero), Err(Error::DivisionByZero));
+        assert_eq!(num_7.try_mod_pow(Number::NEG_ONE, Number::from(5)), Err(Error::NegativeOperand));
+        assert_eq!(num_7.try_mod_inverse(zero), Err(Error::DivisionByZero));
+        assert_eq!((-num_7).try_isqrt(), Err(Error::NegativeOperand));
+        assert_eq!(zero.try_ilog3(), Err(Error::ZeroOperand));
+        assert_eq!(num_7.try_clamp(num_7, zero), Err(Error::InvalidBounds));
+    }
+
+    #[test]
+    fn successes_match_panicking_forms() {
+        let (num_7, num_neg_3) = (Number::<8>::from(7), Number::<8>::from(-3));
+        assert_eq!(num_7.try_div_rem(num_neg_3), Ok(num_7.div_rem(num_neg_3)));
+        assert_eq!(num_7.try_div(num_neg_3), Ok(num_7 / num_neg_3));
+        assert_eq!(num_7.try_rem(num_neg_3), Ok(num_7 % num_neg_3));
+        assert_eq!(num_7.try_div_euclid(num_neg_3), Ok(num_7.div_euclid(num_neg_3)));
+        assert_eq!(num_7.try_rem_euclid(num_neg_3), Ok(num_7.rem_euclid(num_neg_3)));
+        assert_eq!(num_7.try_div_rounded(num_neg_3, RoundingMode::NearestTiesEven), Ok(Number::from(-2)));
+        assert_eq!(num_7.try_next_multiple_of(num_neg_3), Ok(Number::from(6)));
+        assert_eq!(num_7.try_mod_pow(Number::from(2), Number::from(5)), Ok(Number::from(4)));
+        assert_eq!(num_7.try_mod_inverse(Number::from(5)), Ok(Some(Number::from(3))));
+        assert_eq!(num_7.try_isqrt(), Ok(Number::from(2)));
+        assert_eq!(num_7.try_ilog3(), Ok(1));
+        assert_eq!(num_7.try_clamp(num_neg_3, num_neg_3), Ok(num_neg_3));
+    }
+}
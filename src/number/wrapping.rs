@@ -0,0 +1,148 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Provides intentionally-wrapped arithmetic on a balanced ternary number.
+///
+/// Results that do not fit are reduced modulo 3^N into the balanced range of
+/// the number, which is what the plain operators on [`Number`] already do.
+/// This wrapper exists to make that policy explicit at the type level, in the
+/// same way as `std::num::Wrapping` does for the primitive integers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+impl <const N: usize> Number<N> {
+    /// Wrapping addition. Any carry out of the most significant trit is
+    /// discarded.
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.carrying_add(rhs, Trit::ZERO).0
+    }
+
+    /// Wrapping subtraction. Any carry out of the most significant trit is
+    /// discarded.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs.wrapping_neg())
+    }
+
+    /// Wrapping multiplication. Trits of the product beyond the N least
+    /// significant are discarded.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    /// Wrapping division. Balanced ternary division can never overflow, so
+    /// this is equivalent to plain division and panics if `divisor` is zero.
+    pub fn wrapping_div(self, divisor: Self) -> Self {
+        self / divisor
+    }
+
+    /// Wrapping remainder. Equivalent to plain remainder and panics if
+    /// `divisor` is zero.
+    pub fn wrapping_rem(self, divisor: Self) -> Self {
+        self % divisor
+    }
+
+    /// Wrapping negation. Balanced ternary negation can never overflow, so
+    /// this is equivalent to plain negation.
+    pub const fn wrapping_neg(mut self) -> Self {
+        let mut idx = 0;
+        while idx < N {
+            self.0[idx] = self.0[idx].negate();
+            idx += 1;
+        }
+        self
+    }
+
+    /// Wrapping left shift. Trits shifted out of the most significant position
+    /// are discarded, which is the same behaviour as `<<`. Unlike the primitive
+    /// integers the shift amount is not masked, so shifting by N or more trits
+    /// gives zero.
+    pub const fn wrapping_shl(self, positions: usize) -> Self {
+        let mut output = Number::<N>::ZERO;
+        let mut idx = positions;
+        while idx < N {
+            output.0[idx - positions] = self.0[idx];
+            idx += 1;
+        }
+        output
+    }
+}
+
+impl <const N: usize> fmt::Display for Wrapping<Number<N>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl <const N: usize> Neg for Wrapping<Number<N>> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Wrapping(self.0.wrapping_neg())
+    }
+}
+
+macro_rules! wrapping_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident, $method:ident) => {
+        impl <const N: usize> $op_trait for Wrapping<Number<N>> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                Wrapping(self.0.$method(rhs.0))
+            }
+        }
+
+        impl <const N: usize> $assign_trait for Wrapping<Number<N>> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = Wrapping(self.0.$method(rhs.0));
+            }
+        }
+    };
+}
+
+wrapping_binary_op!(Add, add, AddAssign, add_assign, wrapping_add);
+wrapping_binary_op!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
+wrapping_binary_op!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
+wrapping_binary_op!(Div, div, DivAssign, div_assign, wrapping_div);
+wrapping_binary_op!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_arithmetic() {
+        let num_max = Number::<4>::from("++++"); // 40
+        let num_min = Number::<4>::from("----"); // -40
+        let num_one = Number::<4>::from("+");
+
+        assert_eq!(num_max.wrapping_add(num_one), num_min);
+        assert_eq!(num_min.wrapping_sub(num_one), num_max);
+        assert_eq!(num_max.wrapping_mul(Number::<4>::from("-")), num_min);
+        assert_eq!(num_min.wrapping_neg(), num_max);
+    }
+
+    #[test]
+    fn wrapping_operators() {
+        let num_max = Wrapping(Number::<4>::from("++++")); // 40
+        let num_min = Wrapping(Number::<4>::from("----")); // -40
+        let num_one = Wrapping(Number::<4>::from("+"));
+        let num_two = Wrapping(Number::<4>::from("+-"));
+
+        assert_eq!(num_max + num_one, num_min);
+        assert_eq!(num_min - num_one, num_max);
+        assert_eq!(num_max * num_two, Wrapping(Number::<4>::from("-")));   // 80 wraps to -1
+        assert_eq!(num_max / num_two, Wrapping(Number::<4>::from("+-+-"))); // 20
+        assert_eq!(num_max % num_two, Wrapping(Number::<4>::ZERO));
+        assert_eq!(-num_max, num_min);
+
+        let mut temp = num_max;
+        temp += num_one;
+        assert_eq!(temp, num_min);
+        temp -= num_one;
+        assert_eq!(temp, num_max);
+    }
+}
@@ -0,0 +1,100 @@
+//! Bulk arithmetic over slices of numbers, split across the rayon thread pool.
+//!
+//! Each function works on any numeric type of the crate, such as [`Number`]
+//! and [`BigTernary`], and gives the same result as the equivalent sequential
+//! loop.
+//! ```
+//! # use balanced_ternary::number::Number;
+//! # use balanced_ternary::parallel;
+//! let lhs: Vec<Number<16>> = (0..1000).map(Number::from).collect();
+//! let rhs: Vec<Number<16>> = (0..1000).map(|value| Number::from(-2 * value)).collect();
+//! let sums = parallel::add(&lhs, &rhs);
+//! assert_eq!(sums[10], Number::from(-10));
+//! assert_eq!(parallel::sum(&sums), Number::from(-499500));
+//! ```
+//!
+//! [`Number`]: crate::number::Number
+//! [`BigTernary`]: crate::big_ternary::BigTernary
+
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use rayon::prelude::*;
+
+/// Adds two slices element by element.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn add<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where T: Clone + Send + Sync + Add<Output = T> {
+    zip_with(lhs, rhs, |lhs, rhs| lhs + rhs)
+}
+
+/// Subtracts the elements of `rhs` from those of `lhs`.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn sub<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where T: Clone + Send + Sync + Sub<Output = T> {
+    zip_with(lhs, rhs, |lhs, rhs| lhs - rhs)
+}
+
+/// Multiplies two slices element by element.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn mul<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where T: Clone + Send + Sync + Mul<Output = T> {
+    zip_with(lhs, rhs, |lhs, rhs| lhs * rhs)
+}
+
+/// Negates every element of a slice.
+pub fn neg<T>(values: &[T]) -> Vec<T>
+where T: Clone + Send + Sync + Neg<Output = T> {
+    values.par_iter().map(|value| -value.clone()).collect()
+}
+
+/// Sums the elements of a slice, combining partial sums from each thread.
+pub fn sum<T>(values: &[T]) -> T
+where T: Clone + Send + Sync + Sum {
+    values.par_iter().cloned().sum()
+}
+
+/// Returns the dot product of two slices, the sum of the products of their
+/// elements.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn dot<T>(lhs: &[T], rhs: &[T]) -> T
+where T: Clone + Send + Sync + Mul<Output = T> + Sum {
+    assert_eq!(lhs.len(), rhs.len(), "Slices must have the same length");
+    lhs.par_iter().zip(rhs).map(|(lhs, rhs)| lhs.clone() * rhs.clone()).sum()
+}
+
+fn zip_with<T: Clone + Send + Sync>(lhs: &[T], rhs: &[T], op: impl Fn(T, T) -> T + Send + Sync) -> Vec<T> {
+    assert_eq!(lhs.len(), rhs.len(), "Slices must have the same length");
+    lhs.par_iter().zip(rhs).map(|(lhs, rhs)| op(lhs.clone(), rhs.clone())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::big_ternary::BigTernary;
+    use crate::number::Number;
+
+    #[test]
+    fn matches_sequential() {
+        let lhs: Vec<Number<20>> = (-500..500).map(|value| Number::from(value * 7)).collect();
+        let rhs: Vec<Number<20>> = (-500..500).map(|value| Number::from(value % 13)).collect();
+
+        let expected: Vec<Number<20>> = lhs.iter().zip(&rhs).map(|(lhs, rhs)| *lhs * *rhs).collect();
+        assert_eq!(mul(&lhs, &rhs), expected);
+        assert_eq!(dot(&lhs, &rhs), expected.into_iter().sum());
+        assert_eq!(sub(&add(&lhs, &rhs), &rhs), lhs);
+        assert_eq!(neg(&lhs).into_iter().sum::<Number<20>>(), -lhs.iter().copied().sum::<Number<20>>());
+
+        let big: Vec<BigTernary> = (1..200).map(|value| BigTernary::from(3).pow(value)).collect();
+        assert_eq!(sum(&big), (BigTernary::from(3).pow(200) - BigTernary::from(3)) / BigTernary::from(2));
+    }
+}
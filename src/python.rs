@@ -0,0 +1,591 @@
+//! Python bindings, built as the `balanced_ternary` extension module.
+//!
+//! The module has three classes:
+//! * `Trit`, a single trit, created from -1, 0 or 1 or from a trit character.
+//! * `Number`, an integer of a fixed width in trits, given when it is created
+//!   and 27 by default. As Python has no const generics it is backed by a
+//!   [`TritVec`], and its arithmetic wraps at the width of the wider operand
+//!   in the same way as that of [`Number`].
+//! * `BigTernary`, an integer of unbounded precision, backed by a
+//!   [`BigTernary`].
+//!
+//! Numbers are created from Python integers of any size or from trit strings,
+//! and Python integers can be mixed into their arithmetic and comparisons.
+//! Floor division and remainder round towards negative infinity, matching the
+//! `//` and `%` of Python integers rather than the `Div` and `Rem` of this
+//! crate.
+//!
+//! The module is built as a dynamic library, renamed to `balanced_ternary.so`
+//! (or `balanced_ternary.pyd` on Windows) so that Python can import it:
+//! ```text
+//! cargo rustc --release --features python,pyo3/extension-module --crate-type cdylib
+//! ```
+//! ```text
+//! >>> from balanced_ternary import BigTernary, Number
+//! >>> x = Number("+0--", width=8)
+//! >>> int(x * 3 + 1)
+//! 70
+//! >>> str(-BigTernary(7))
+//! '-+-'
+//! ```
+//!
+//! [`Number`]: crate::number::Number
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyLong, PyString};
+
+use crate::big_ternary::BigTernary;
+use crate::error::Error;
+use crate::number::ParseTernaryError;
+use crate::trit::Trit;
+use crate::trit_vec::TritVec;
+
+/// The width of a `Number` created without one, in trits.
+const DEFAULT_WIDTH: usize = 27;
+
+/// Raises the Python exception matching an error of this crate.
+fn to_py_err(error: Error) -> PyErr {
+    match error {
+        Error::DivisionByZero => PyZeroDivisionError::new_err(error.to_string()),
+        Error::OutOfRange => PyOverflowError::new_err(error.to_string()),
+        _ => PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Reads a Python integer of any size, through its decimal string.
+fn big_from_int(value: &Bound<'_, PyAny>) -> PyResult<BigTernary> {
+    let decimal = value.downcast::<PyLong>()?.str()?;
+    BigTernary::from_decimal_str(&decimal.to_cow()?).map_err(|error| to_py_err(error.into()))
+}
+
+/// Builds a Python integer of any size, through its decimal string.
+fn int_from_big(py: Python<'_>, value: &BigTernary) -> PyResult<PyObject> {
+    Ok(py.get_type_bound::<PyLong>().call1((value.to_decimal_string(),))?.unbind())
+}
+
+/// Reads a Python integer or trit string.
+fn big_from_int_or_str(value: &Bound<'_, PyAny>) -> PyResult<BigTernary> {
+    match value.downcast::<PyString>() {
+        Ok(text) => text.to_cow()?.parse().map_err(|error: ParseTernaryError| to_py_err(error.into())),
+        Err(_) => big_from_int(value)
+    }
+}
+
+/// Divides rounding towards negative infinity, as Python's `divmod` does for
+/// integers, so that the remainder takes the sign of the divisor.
+fn floor_div_rem(lhs: &BigTernary, rhs: &BigTernary) -> PyResult<(BigTernary, BigTernary)> {
+    let (quotient, remainder) = lhs.try_div_rem(rhs).map_err(to_py_err)?;
+    if !remainder.is_zero() && remainder.signum() != rhs.signum() {
+        Ok((quotient - BigTernary::from(1), remainder + rhs))
+    } else {
+        Ok((quotient, remainder))
+    }
+}
+
+/// A single balanced ternary digit.
+#[pyclass(name = "Trit", module = "balanced_ternary", frozen, eq, hash)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct PyTrit(Trit);
+
+#[pymethods]
+impl PyTrit {
+    #[classattr]
+    const NEG: PyTrit = PyTrit(Trit::NEG);
+    #[classattr]
+    const ZERO: PyTrit = PyTrit(Trit::ZERO);
+    #[classattr]
+    const POS: PyTrit = PyTrit(Trit::POS);
+
+    /// Creates a trit from -1, 0 or 1, or from a character such as `-`, `0`
+    /// or `+`.
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(value) = value.extract::<i8>() {
+            return Trit::try_from(value).map(PyTrit)
+                .map_err(|_| PyValueError::new_err("trits can only be -1, 0 or 1"));
+        }
+
+        let text = value.extract::<String>()?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(character), None) => Trit::try_from(character).map(PyTrit)
+                .map_err(|error| PyValueError::new_err(error.to_string())),
+            _ => Err(PyValueError::new_err("expected a single trit character"))
+        }
+    }
+
+    fn __int__(&self) -> i8 {
+        self.0.into()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Trit('{}')", self.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        PyTrit(-self.0)
+    }
+
+    fn __mul__(&self, other: PyRef<'_, PyTrit>) -> Self {
+        PyTrit(self.0 * other.0)
+    }
+}
+
+/// An operand of `Number` arithmetic, which is either another `Number` or a
+/// Python integer.
+enum NumberOperand {
+    Number(TritVec),
+    Int(BigTernary)
+}
+
+impl NumberOperand {
+    fn value(&self) -> BigTernary {
+        match self {
+            NumberOperand::Number(trits) => BigTernary::from(trits),
+            NumberOperand::Int(value) => value.clone()
+        }
+    }
+}
+
+impl <'py> FromPyObject<'py> for NumberOperand {
+    fn extract_bound(value: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match value.downcast::<PyNumber>() {
+            Ok(number) => Ok(NumberOperand::Number(number.get().0.clone())),
+            Err(_) => big_from_int(value).map(NumberOperand::Int)
+        }
+    }
+}
+
+/// A balanced ternary integer of a fixed width.
+#[pyclass(name = "Number", module = "balanced_ternary", frozen)]
+#[derive(Clone)]
+pub struct PyNumber(TritVec);
+
+impl PyNumber {
+    fn overflow(width: usize) -> PyErr {
+        PyOverflowError::new_err(format!("value doesn't fit in {} trits", width))
+    }
+
+    /// Sets the width of a value, raising `OverflowError` if it doesn't fit.
+    fn with_width(value: BigTernary, width: usize) -> PyResult<Self> {
+        if value.significant_trits() > width {
+            return Err(Self::overflow(width));
+        }
+        let mut trits = TritVec::from(value);
+        trits.resize(width);
+        Ok(PyNumber(trits))
+    }
+
+    /// Resolves an operand to trits, giving an integer the width of this
+    /// number.
+    fn operand(&self, other: NumberOperand) -> PyResult<TritVec> {
+        match other {
+            NumberOperand::Number(trits) => Ok(trits),
+            NumberOperand::Int(value) => Ok(Self::with_width(value, self.0.len())?.0)
+        }
+    }
+
+    /// Applies a floor division or remainder, at the width of the wider
+    /// operand. Neither result can be wider than the dividend or divisor.
+    fn floor_op(lhs: &TritVec, rhs: &TritVec, remainder: bool) -> PyResult<Self> {
+        let (quotient, modulus) = floor_div_rem(&BigTernary::from(lhs), &BigTernary::from(rhs))?;
+        Self::with_width(if remainder {modulus} else {quotient}, lhs.len().max(rhs.len()))
+    }
+}
+
+#[pymethods]
+impl PyNumber {
+    /// Creates a number from a Python integer or trit string, which must fit
+    /// in `width` trits.
+    #[new]
+    #[pyo3(signature = (value, width = DEFAULT_WIDTH))]
+    fn new(value: &Bound<'_, PyAny>, width: usize) -> PyResult<Self> {
+        Self::with_width(big_from_int_or_str(value)?, width)
+    }
+
+    /// The width of the number in trits.
+    #[getter]
+    fn width(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the trits from the least significant.
+    fn trits(&self) -> Vec<PyTrit> {
+        self.0.iter().map(PyTrit).collect()
+    }
+
+    fn __int__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        int_from_big(py, &BigTernary::from(&self.0))
+    }
+
+    fn __index__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.__int__(py)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Number('{}', width={})", BigTernary::from(&self.0), self.0.len())
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    /// Hashes as the equal Python integer does.
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        self.__int__(py)?.bind(py).hash()
+    }
+
+    fn __richcmp__(&self, other: NumberOperand, op: CompareOp) -> bool {
+        op.matches(BigTernary::from(&self.0).cmp(&other.value()))
+    }
+
+    fn __neg__(&self) -> Self {
+        PyNumber(-&self.0)
+    }
+
+    fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __abs__(&self) -> Self {
+        PyNumber(self.0.abs())
+    }
+
+    fn __add__(&self, other: NumberOperand) -> PyResult<Self> {
+        Ok(PyNumber(&self.0 + &self.operand(other)?))
+    }
+
+    fn __radd__(&self, other: NumberOperand) -> PyResult<Self> {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: NumberOperand) -> PyResult<Self> {
+        Ok(PyNumber(&self.0 - &self.operand(other)?))
+    }
+
+    fn __rsub__(&self, other: NumberOperand) -> PyResult<Self> {
+        Ok(PyNumber(&self.operand(other)? - &self.0))
+    }
+
+    fn __mul__(&self, other: NumberOperand) -> PyResult<Self> {
+        Ok(PyNumber(&self.0 * &self.operand(other)?))
+    }
+
+    fn __rmul__(&self, other: NumberOperand) -> PyResult<Self> {
+        self.__mul__(other)
+    }
+
+    fn __floordiv__(&self, other: NumberOperand) -> PyResult<Self> {
+        Self::floor_op(&self.0, &self.operand(other)?, false)
+    }
+
+    fn __rfloordiv__(&self, other: NumberOperand) -> PyResult<Self> {
+        Self::floor_op(&self.operand(other)?, &self.0, false)
+    }
+
+    fn __mod__(&self, other: NumberOperand) -> PyResult<Self> {
+        Self::floor_op(&self.0, &self.operand(other)?, true)
+    }
+
+    fn __rmod__(&self, other: NumberOperand) -> PyResult<Self> {
+        Self::floor_op(&self.operand(other)?, &self.0, true)
+    }
+
+    /// Raises the number to a power, raising `OverflowError` if the result
+    /// doesn't fit in the width rather than wrapping.
+    fn __pow__(&self, exponent: u32, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if modulo.is_some() {
+            return Err(PyValueError::new_err("three-argument pow is not supported"));
+        }
+        let (base, width) = (BigTernary::from(&self.0), self.0.len());
+        // Any base other than -1, 0 and 1 overflows once the exponent reaches
+        // twice the width, as 2^(2w) > 3^w, so the power isn't computed
+        if base.significant_trits() > 1 && exponent as usize >= 2 * width {
+            return Err(Self::overflow(width));
+        }
+        Self::with_width(base.pow(exponent), width)
+    }
+
+    fn __lshift__(&self, positions: usize) -> Self {
+        PyNumber(self.0.clone() << positions)
+    }
+
+    fn __rshift__(&self, positions: usize) -> Self {
+        PyNumber(self.0.clone() >> positions)
+    }
+}
+
+/// An operand of `BigTernary` arithmetic, which is either another
+/// `BigTernary` or a Python integer.
+struct BigOperand(BigTernary);
+
+impl <'py> FromPyObject<'py> for BigOperand {
+    fn extract_bound(value: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match value.downcast::<PyBigTernary>() {
+            Ok(big) => Ok(BigOperand(big.get().0.clone())),
+            Err(_) => big_from_int(value).map(BigOperand)
+        }
+    }
+}
+
+/// A balanced ternary integer of unbounded precision.
+#[pyclass(name = "BigTernary", module = "balanced_ternary", frozen)]
+#[derive(Clone)]
+pub struct PyBigTernary(BigTernary);
+
+#[pymethods]
+impl PyBigTernary {
+    /// Creates an integer from a Python integer or trit string.
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        big_from_int_or_str(value).map(PyBigTernary)
+    }
+
+    /// The number of trits needed to represent the integer.
+    fn significant_trits(&self) -> usize {
+        self.0.significant_trits()
+    }
+
+    fn __int__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        int_from_big(py, &self.0)
+    }
+
+    fn __index__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.__int__(py)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BigTernary('{}')", self.0)
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    /// Hashes as the equal Python integer does.
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        self.__int__(py)?.bind(py).hash()
+    }
+
+    fn __richcmp__(&self, other: BigOperand, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    fn __neg__(&self) -> Self {
+        PyBigTernary(-&self.0)
+    }
+
+    fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __abs__(&self) -> Self {
+        PyBigTernary(self.0.abs())
+    }
+
+    fn __add__(&self, other: BigOperand) -> Self {
+        PyBigTernary(&self.0 + &other.0)
+    }
+
+    fn __radd__(&self, other: BigOperand) -> Self {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: BigOperand) -> Self {
+        PyBigTernary(&self.0 - &other.0)
+    }
+
+    fn __rsub__(&self, other: BigOperand) -> Self {
+        PyBigTernary(&other.0 - &self.0)
+    }
+
+    fn __mul__(&self, other: BigOperand) -> Self {
+        PyBigTernary(&self.0 * &other.0)
+    }
+
+    fn __rmul__(&self, other: BigOperand) -> Self {
+        self.__mul__(other)
+    }
+
+    fn __floordiv__(&self, other: BigOperand) -> PyResult<Self> {
+        Ok(PyBigTernary(floor_div_rem(&self.0, &other.0)?.0))
+    }
+
+    fn __rfloordiv__(&self, other: BigOperand) -> PyResult<Self> {
+        Ok(PyBigTernary(floor_div_rem(&other.0, &self.0)?.0))
+    }
+
+    fn __mod__(&self, other: BigOperand) -> PyResult<Self> {
+        Ok(PyBigTernary(floor_div_rem(&self.0, &other.0)?.1))
+    }
+
+    fn __rmod__(&self, other: BigOperand) -> PyResult<Self> {
+        Ok(PyBigTernary(floor_div_rem(&other.0, &self.0)?.1))
+    }
+
+    fn __pow__(&self, exponent: u32, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if modulo.is_some() {
+            return Err(PyValueError::new_err("three-argument pow is not supported"));
+        }
+        Ok(PyBigTernary(self.0.pow(exponent)))
+    }
+
+    fn __lshift__(&self, positions: usize) -> Self {
+        PyBigTernary(&self.0 << positions)
+    }
+
+    fn __rshift__(&self, positions: usize) -> Self {
+        PyBigTernary(&self.0 >> positions)
+    }
+}
+
+/// The `balanced_ternary` Python module.
+#[pymodule]
+fn balanced_ternary(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyTrit>()?;
+    module.add_class::<PyNumber>()?;
+    module.add_class::<PyBigTernary>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn floor_division_matches_python() {
+        // (a, b, a // b, a % b) as evaluated by Python
+        let cases = [(7, 2, 3, 1), (-7, 2, -4, 1), (7, -2, -4, -1), (-7, -2, 3, -1), (6, -3, -2, 0)];
+        for (lhs, rhs, quotient, remainder) in cases {
+            let result = floor_div_rem(&BigTernary::from(lhs), &BigTernary::from(rhs)).unwrap();
+            assert_eq!(result, (BigTernary::from(quotient), BigTernary::from(remainder)));
+        }
+        assert!(floor_div_rem(&BigTernary::from(1), &BigTernary::ZERO).is_err());
+    }
+
+    /// Runs Python code with the classes of the module in scope, failing on
+    /// any exception, including a failed `assert`.
+    fn run_python(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "balanced_ternary").unwrap();
+            balanced_ternary(&module).unwrap();
+            if let Err(error) = py.run_bound(code, Some(&module.dict()), None) {
+                let traceback = error.traceback_bound(py).and_then(|traceback| traceback.format().ok()).unwrap_or_default();
+                panic!("{}{}", traceback, error);
+            }
+        });
+    }
+
+    #[test]
+    fn trits() {
+        run_python(r#"
+assert int(Trit(-1)) == -1 and int(Trit("+")) == 1
+assert Trit("0") == Trit.ZERO and Trit(1) == Trit.POS and Trit("-") == Trit.NEG
+assert -Trit.POS == Trit.NEG and Trit.NEG * Trit.NEG == Trit.POS
+assert str(Trit.NEG) == "-" and repr(Trit.POS) == "Trit('+')"
+assert hash(Trit(1)) == hash(Trit("+"))
+for invalid in (2, "++", "x", ""):
+    try:
+        Trit(invalid)
+    except ValueError:
+        pass
+    else:
+        raise AssertionError(invalid)
+"#);
+    }
+
+    #[test]
+    fn fixed_width_numbers() {
+        run_python(r#"
+x = Number("+0--", width=8)
+assert x.width == 8 and int(x) == 23
+assert str(x) == "0000+0--" and repr(x) == "Number('+0--', width=8)"
+assert Number(5).width == 27 and Number(-(3 ** 27 - 1) // 2).width == 27
+assert Number(5, width=3).trits() == [Trit.NEG, Trit.NEG, Trit.POS]
+assert not Number(0) and Number(-1)
+assert [10, 20, 30][Number(1, width=2)] == 20
+
+# Python integers mix into arithmetic on either side, at the width of the number
+assert x * 3 + 1 == 70 and isinstance(x * 3 + 1, Number)
+assert 1 + x == 24 and 100 - x == 77 and 2 * x == 46
+assert -x == -23 and abs(-x) == 23 and +x == x
+assert (x * 3 + 1).width == 8
+assert Number(1, width=2) << 1 == 3 and Number(9, width=4) >> 2 == 1
+
+# Arithmetic wraps at the width of the wider operand, as with Number
+assert Number(40, width=4) + 1 == -40
+assert (Number(40, width=4) + Number(1, width=5)).width == 5
+assert Number(40, width=4) + Number(1, width=5) == 41
+
+# Division and remainder round as those of Python integers do
+assert Number(-7, width=4) // 2 == -4 and Number(-7, width=4) % 2 == 1
+assert 7 // Number(-2, width=4) == -4 and 7 % Number(-2, width=4) == -1
+
+assert Number(3, width=8) ** 4 == 81 and Number(-1, width=4) ** 1000001 == -1
+assert Number(0, width=4) ** 4000000000 == 0 and Number(5, width=4) ** 0 == 1
+
+# Equal values hash the same as the equal Python integer
+assert hash(Number(-5, width=4)) == hash(-5) and hash(Number(7)) == hash(7)
+assert {Number(7, width=4): "seven"}[7] == "seven"
+assert Number(7, width=4) == Number(7, width=9) and Number(7) < 8 and Number(7) >= Number(-7)
+
+def raises(error, operation):
+    try:
+        operation()
+    except error:
+        return True
+    return False
+
+assert raises(OverflowError, lambda: Number(41, width=4))
+assert raises(OverflowError, lambda: Number("+0000", width=4))
+assert raises(OverflowError, lambda: Number(1, width=2) + 100)
+assert raises(OverflowError, lambda: Number(3, width=4) ** 4)
+assert raises(OverflowError, lambda: Number(2, width=4) ** 4000000000)
+assert raises(ZeroDivisionError, lambda: x // 0)
+assert raises(ZeroDivisionError, lambda: x % Number(0, width=2))
+assert raises(ValueError, lambda: Number("+x"))
+assert raises(ValueError, lambda: pow(x, 2, 5))
+assert raises(TypeError, lambda: x + 1.5)
+"#);
+    }
+
+    #[test]
+    fn unbounded_integers() {
+        run_python(r#"
+big = BigTernary(10 ** 30)
+assert int(big) == 10 ** 30 and hash(big) == hash(10 ** 30)
+assert str(-BigTernary(7)) == "-+-" and repr(BigTernary(7)) == "BigTernary('+-+')"
+assert BigTernary("+0--") == 23 and BigTernary(0).significant_trits() == 0
+assert BigTernary(3) ** 40 == 3 ** 40 and (BigTernary(3) ** 40).significant_trits() == 41
+assert big * big == 10 ** 60 and big - 1 == 10 ** 30 - 1 and 2 - BigTernary(5) == -3
+assert BigTernary(-7) // 2 == -4 and BigTernary(-7) % 2 == 1 and 7 % BigTernary(-2) == -1
+assert BigTernary(1) << 2 == 9 and BigTernary(9) >> 2 == 1
+assert not BigTernary(0) and abs(BigTernary(-4)) == 4 and BigTernary(4) > -5
+
+try:
+    BigTernary(1) % 0
+except ZeroDivisionError:
+    pass
+else:
+    raise AssertionError("division by zero")
+"#);
+    }
+}
@@ -0,0 +1,433 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+#[cfg(feature = "alloc")]
+use crate::big_ternary::BigTernary;
+use crate::error::Error;
+use crate::number::{Number, RoundingMode};
+
+/// The integer types that a [`Ratio`] can be built from, which are [`Number`]
+/// and, with the `alloc` feature, [`BigTernary`](crate::big_ternary::BigTernary).
+pub trait RatioInteger:
+    Clone + Ord
+    + Neg<Output = Self> + Add<Output = Self> + Sub<Output = Self>
+    + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    /// Returns zero.
+    fn zero() -> Self;
+
+    /// Returns one.
+    fn one() -> Self;
+
+    /// Returns the non-negative greatest common divisor of two integers.
+    fn gcd(&self, other: &Self) -> Self;
+}
+
+impl <const N: usize> RatioInteger for Number<N> {
+    fn zero() -> Self {
+        Number::<N>::ZERO
+    }
+
+    fn one() -> Self {
+        Number::<N>::ONE
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        Number::<N>::gcd(*self, *other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl RatioInteger for BigTernary {
+    fn zero() -> Self {
+        BigTernary::ZERO
+    }
+
+    fn one() -> Self {
+        BigTernary::from(1)
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        BigTernary::gcd(self, other)
+    }
+}
+
+/// A rational number, the ratio of two balanced ternary integers.
+///
+/// The ratio is always kept in lowest terms with a positive denominator, so
+/// that every value has exactly one representation and the derived equality
+/// compares values. Over a [`Number`] the arithmetic wraps on overflow of the
+/// numerator or denominator in the same way as [`Number`] itself, although
+/// common factors are cancelled before multiplying to keep the intermediate
+/// values small, and comparisons never overflow. Over a
+/// [`BigTernary`](crate::big_ternary::BigTernary) the arithmetic is exact.
+/// ```
+/// # use balanced_ternary::number::Number;
+/// # use balanced_ternary::ratio::Ratio;
+/// let third = Ratio::new(Number::<8>::from(2), Number::<8>::from(6));
+/// assert_eq!(*third.numer(), Number::<8>::ONE);
+/// assert_eq!(third + third + third, Ratio::from(Number::<8>::ONE));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T
+}
+
+impl <T: RatioInteger> Ratio<T> {
+    /// Builds the ratio `numer / denom`, reducing it to lowest terms.
+    /// * `numer` - The numerator
+    /// * `denom` - The denominator, which must be non-zero
+    ///
+    /// # Panics
+    /// Panics if `denom` is zero.
+    pub fn new(numer: T, denom: T) -> Self {
+        if denom == T::zero() {
+            panic!("Denominator of a ratio must be non-zero")
+        }
+
+        let divisor = numer.gcd(&denom);
+        let (numer, denom) = (numer / divisor.clone(), denom / divisor);
+        if denom < T::zero() {
+            Ratio { numer: -numer, denom: -denom }
+        } else {
+            Ratio { numer, denom }
+        }
+    }
+
+    /// Fallible [`Ratio::new`]. Returns [`Error::DivisionByZero`] if `denom`
+    /// is zero.
+    pub fn try_new(numer: T, denom: T) -> Result<Self, Error> {
+        if denom == T::zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Ratio::new(numer, denom))
+    }
+
+    /// Returns the numerator, which carries the sign of the ratio.
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    /// Splits the ratio into its numerator and denominator.
+    pub fn into_parts(self) -> (T, T) {
+        (self.numer, self.denom)
+    }
+
+    /// Returns `true` if the ratio is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numer == T::zero()
+    }
+
+    /// Returns `true` if the ratio is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.numer < T::zero()
+    }
+
+    /// Returns `true` if the ratio is a whole number.
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    /// Returns the absolute value of the ratio.
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {-self.clone()} else {self.clone()}
+    }
+
+    /// Returns the reciprocal of the ratio.
+    ///
+    /// # Panics
+    /// Panics if the ratio is zero.
+    pub fn recip(&self) -> Self {
+        Ratio::new(self.denom.clone(), self.numer.clone())
+    }
+
+    /// Fallible [`Ratio::recip`]. Returns [`Error::DivisionByZero`] if the
+    /// ratio is zero.
+    pub fn try_recip(&self) -> Result<Self, Error> {
+        Ratio::try_new(self.denom.clone(), self.numer.clone())
+    }
+
+    /// Fallible division. Returns [`Error::DivisionByZero`] if `divisor` is
+    /// zero.
+    pub fn try_div(self, divisor: Self) -> Result<Self, Error> {
+        Ok(self * divisor.try_recip()?)
+    }
+
+    /// Rounds the ratio to an integer in the given direction.
+    /// * `mode` - How to round a ratio that is not a whole number
+    pub fn round(&self, mode: RoundingMode) -> T {
+        let (quotient, remainder) = self.floor_div();
+        if remainder == T::zero() {
+            return quotient;
+        }
+
+        // The floor is below the value by `remainder / denom`, which is strictly
+        // between zero and one
+        let round_up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::TowardZero => self.is_negative(),
+            RoundingMode::NearestTiesAway | RoundingMode::NearestTiesEven => {
+                match (remainder.clone() + remainder).cmp(&self.denom) {
+                    Ordering::Less => false,
+                    Ordering::Greater => true,
+                    Ordering::Equal if mode == RoundingMode::NearestTiesAway => !self.is_negative(),
+                    // The floor and ceiling differ by one, so exactly one is even
+                    Ordering::Equal => quotient.clone() % (T::one() + T::one()) != T::zero()
+                }
+            }
+        };
+        if round_up {quotient + T::one()} else {quotient}
+    }
+
+    /// Divides the numerator by the denominator, rounding the quotient towards
+    /// negative infinity so that the remainder is never negative.
+    fn floor_div(&self) -> (T, T) {
+        let quotient = self.numer.clone() / self.denom.clone();
+        let remainder = self.numer.clone() % self.denom.clone();
+        if remainder < T::zero() {
+            (quotient - T::one(), remainder + self.denom.clone())
+        } else {
+            (quotient, remainder)
+        }
+    }
+}
+
+impl <T: RatioInteger> From<T> for Ratio<T> {
+    fn from(integer: T) -> Self {
+        Ratio { numer: integer, denom: T::one() }
+    }
+}
+
+impl <T: RatioInteger> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Compare the integer parts, and if they are equal compare the
+        // fractional parts through their reciprocals, as in a continued
+        // fraction. Unlike cross-multiplying, this can never overflow.
+        let (mut lhs, mut rhs) = (self.clone(), other.clone());
+        let mut reversed = false;
+        loop {
+            let (lhs_int, lhs_rem) = lhs.floor_div();
+            let (rhs_int, rhs_rem) = rhs.floor_div();
+            let ordering = match lhs_int.cmp(&rhs_int) {
+                Ordering::Equal => match (lhs_rem == T::zero(), rhs_rem == T::zero()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => {
+                        // Both fractional parts are in lowest terms already
+                        (lhs, rhs) = (
+                            Ratio { numer: lhs.denom, denom: lhs_rem },
+                            Ratio { numer: rhs.denom, denom: rhs_rem }
+                        );
+                        reversed = !reversed;
+                        continue;
+                    }
+                },
+                ordering => ordering
+            };
+            return if reversed {ordering.reverse()} else {ordering};
+        }
+    }
+}
+
+impl <T: RatioInteger> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <T: RatioInteger> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Ratio { numer: -self.numer, denom: self.denom }
+    }
+}
+
+impl <T: RatioInteger> Add for Ratio<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Scaling to the least common multiple of the denominators rather than
+        // their product keeps the numerators small
+        let divisor = self.denom.gcd(&rhs.denom);
+        let lhs_scale = rhs.denom.clone() / divisor.clone();
+        let rhs_scale = self.denom.clone() / divisor;
+        let numer = self.numer * lhs_scale.clone() + rhs.numer * rhs_scale;
+        Ratio::new(numer, self.denom * lhs_scale)
+    }
+}
+
+impl <T: RatioInteger> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Add::add(self, -rhs)
+    }
+}
+
+impl <T: RatioInteger> Mul for Ratio<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancelling across the fractions first leaves a product that is
+        // already in lowest terms
+        let lhs_divisor = self.numer.gcd(&rhs.denom);
+        let rhs_divisor = rhs.numer.gcd(&self.denom);
+        Ratio {
+            numer: (self.numer / lhs_divisor.clone()) * (rhs.numer / rhs_divisor.clone()),
+            denom: (self.denom / rhs_divisor) * (rhs.denom / lhs_divisor)
+        }
+    }
+}
+
+/// # Panics
+/// Panics if `divisor` is zero.
+impl <T: RatioInteger> Div for Ratio<T> {
+    type Output = Self;
+
+    fn div(self, divisor: Self) -> Self::Output {
+        Mul::mul(self, divisor.recip())
+    }
+}
+
+impl <T: RatioInteger> AddAssign for Ratio<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl <T: RatioInteger> SubAssign for Ratio<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl <T: RatioInteger> MulAssign for Ratio<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl <T: RatioInteger> DivAssign for Ratio<T> {
+    fn div_assign(&mut self, divisor: Self) {
+        *self = self.clone() / divisor;
+    }
+}
+
+impl <T: RatioInteger + fmt::Display> fmt::Display for Ratio<T> {
+    /// Formats the numerator and denominator separated by a `/`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(numer: i32, denom: i32) -> Ratio<Number<12>> {
+        Ratio::new(Number::<12>::from(numer), Number::<12>::from(denom))
+    }
+
+    #[test]
+    fn normalisation() {
+        let value = ratio(-12, -18);
+        assert_eq!(*value.numer(), Number::<12>::from(2));
+        assert_eq!(*value.denom(), Number::<12>::from(3));
+        assert_eq!(ratio(4, -6), ratio(-2, 3));
+        assert_eq!(ratio(0, -5), ratio(0, 1));
+        assert!(ratio(10, 5).is_integer());
+        assert!(ratio(1, -5).is_negative());
+        assert_eq!(ratio(3, -7).recip(), ratio(-7, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_denominator() {
+        ratio(1, 0);
+    }
+
+    #[test]
+    fn fallible_construction() {
+        let zero = Number::<12>::ZERO;
+        assert_eq!(Ratio::try_new(Number::<12>::ONE, zero), Err(Error::DivisionByZero));
+        assert_eq!(Ratio::try_new(Number::<12>::from(2), Number::<12>::from(-4)), Ok(ratio(-1, 2)));
+        assert_eq!(Ratio::from(zero).try_recip(), Err(Error::DivisionByZero));
+        assert_eq!(ratio(1, 2).try_div(Ratio::from(zero)), Err(Error::DivisionByZero));
+        assert_eq!(ratio(1, 2).try_div(ratio(3, 4)), Ok(ratio(2, 3)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(ratio(1, 2) + ratio(1, 3), ratio(5, 6));
+        assert_eq!(ratio(1, 6) + ratio(1, 3), ratio(1, 2));
+        assert_eq!(ratio(1, 2) - ratio(3, 4), ratio(-1, 4));
+        assert_eq!(ratio(4, 9) * ratio(-3, 8), ratio(-1, 6));
+        assert_eq!(ratio(0, 1) * ratio(5, 7), ratio(0, 1));
+        assert_eq!(ratio(2, 3) / ratio(-4, 9), ratio(-3, 2));
+        assert_eq!(-ratio(2, 3), ratio(-2, 3));
+
+        let mut value = ratio(1, 3);
+        value += ratio(1, 6);
+        value *= ratio(4, 1);
+        value -= ratio(1, 2);
+        value /= ratio(3, 2);
+        assert_eq!(value, ratio(1, 1));
+
+        // Cancelling before multiplying avoids overflowing 12 trits
+        let large = ratio(200_000, 3);
+        assert_eq!(large * ratio(3, 200_000), ratio(1, 1));
+    }
+
+    #[test]
+    fn ordering() {
+        let values = [ratio(-7, 2), ratio(-1, 1), ratio(-1, 3), ratio(0, 1), ratio(2, 7), ratio(1, 3), ratio(5, 13), ratio(2, 5), ratio(3, 1)];
+        for (i, lhs) in values.iter().enumerate() {
+            for (j, rhs) in values.iter().enumerate() {
+                assert_eq!(lhs.cmp(rhs), i.cmp(&j));
+            }
+        }
+
+        // Cross-multiplying these would overflow 12 trits
+        assert!(ratio(265_720, 265_719) < ratio(265_719, 265_718));
+    }
+
+    #[test]
+    fn rounding() {
+        let cases = [
+            (ratio(7, 2), [3, 3, 4, 4, 4]),
+            (ratio(5, 2), [2, 2, 3, 3, 2]),
+            (ratio(-5, 2), [-2, -3, -2, -3, -2]),
+            (ratio(-7, 3), [-2, -3, -2, -2, -2]),
+            (ratio(8, 3), [2, 2, 3, 3, 3]),
+            (ratio(-4, 1), [-4, -4, -4, -4, -4])
+        ];
+        let modes = [RoundingMode::TowardZero, RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::NearestTiesAway, RoundingMode::NearestTiesEven];
+        for (value, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(value.round(mode), Number::<12>::from(expected), "{:?} {:?}", value, mode);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn big_ratios() {
+        let power = BigTernary::from(3).pow(60);
+        let value = Ratio::new(power.clone(), &power * &BigTernary::from(-2));
+        assert_eq!(value, Ratio::new(BigTernary::from(-1), BigTernary::from(2)));
+        assert_eq!(value.round(RoundingMode::NearestTiesEven), BigTernary::ZERO);
+
+        let sum = Ratio::new(BigTernary::from(1), power.clone()) + Ratio::new(BigTernary::from(2), power.clone());
+        assert_eq!(sum, Ratio::new(BigTernary::from(1), power >> 1));
+    }
+}
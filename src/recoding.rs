@@ -0,0 +1,242 @@
+//! Signed-digit recodings of integers, for the double-and-add and
+//! triple-and-add loops of scalar multiplication.
+//!
+//! A scalar k is written as a sum of digits d(i) times radix^i, where each
+//! digit is either zero or a small odd multiple (in binary) or non-multiple of
+//! three (in ternary). Runs of zeros between the digits mean fewer additions:
+//! with a window of width w, any w consecutive digits hold at most one non-zero
+//! digit, and only the positive digits up to [`SignedDigits::digit_bound`] need
+//! to be precomputed since the negative ones are their negations.
+//!
+//! Ternary recoding with a width of one is exactly balanced ternary, and binary
+//! recoding with a width of two is the non-adjacent form (NAF).
+//! ```
+//! # use balanced_ternary::recoding::SignedDigits;
+//! // Triple-and-add, with integers standing in for the group elements
+//! let point = 7_i128;
+//! let digits = SignedDigits::ternary(-1_000_003, 2);
+//! let mut output = 0;
+//! for digit in digits.digits().iter().rev() {
+//!     output = 3 * output + i128::from(*digit) * point;
+//! }
+//! assert_eq!(output, -7_000_021);
+//! ```
+
+/// The largest number of digits in any recoding, enough for the 128 binary
+/// digits of `i128::MIN` and a carry out of the top.
+const MAX_DIGITS: usize = 129;
+
+/// A signed-digit representation of an integer, held with the least
+/// significant digit first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SignedDigits {
+    radix: u32,
+    width: u32,
+    digits: [i32; MAX_DIGITS],
+    len: usize
+}
+
+impl SignedDigits {
+    /// The largest window width of a binary recoding.
+    pub const MAX_BINARY_WIDTH: u32 = 16;
+
+    /// The largest window width of a ternary recoding.
+    pub const MAX_TERNARY_WIDTH: u32 = 10;
+
+    /// Recodes a value in its binary width-w non-adjacent form, whose non-zero
+    /// digits are odd with an absolute value below 2^(w-1). A width of two
+    /// gives the standard non-adjacent form with digits of -1, 0 and 1.
+    /// * `value` - The integer to recode
+    /// * `width` - The window width w
+    ///
+    /// # Panics
+    /// Panics if the width isn't between 2 and [`MAX_BINARY_WIDTH`](Self::MAX_BINARY_WIDTH).
+    pub fn naf(value: i128, width: u32) -> Self {
+        assert!((2..=Self::MAX_BINARY_WIDTH).contains(&width), "Width of a binary recoding must be between 2 and {}", Self::MAX_BINARY_WIDTH);
+        Self::recode(value, 2, width)
+    }
+
+    /// Recodes a value in width-w signed ternary form, whose non-zero digits
+    /// aren't multiples of three and have an absolute value of at most
+    /// (3^w - 1) / 2. A width of one gives the trits of balanced ternary.
+    /// * `value` - The integer to recode
+    /// * `width` - The window width w
+    ///
+    /// # Panics
+    /// Panics if the width isn't between 1 and [`MAX_TERNARY_WIDTH`](Self::MAX_TERNARY_WIDTH).
+    pub fn ternary(value: i128, width: u32) -> Self {
+        assert!((1..=Self::MAX_TERNARY_WIDTH).contains(&width), "Width of a ternary recoding must be between 1 and {}", Self::MAX_TERNARY_WIDTH);
+        Self::recode(value, 3, width)
+    }
+
+    /// Returns the radix of the recoding, 2 or 3.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Returns the window width of the recoding.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the largest absolute value a digit can take, which is the
+    /// largest multiple of the point a scalar multiplication must precompute.
+    pub fn digit_bound(&self) -> i32 {
+        match self.radix {
+            2 => (1 << (self.width - 1)) - 1,
+            _ => (3_i32.pow(self.width) - 1) / 2
+        }
+    }
+
+    /// Returns the digits from the least significant to the most, without any
+    /// leading zeros. Iterate in reverse for a left-to-right loop.
+    pub fn digits(&self) -> &[i32] {
+        &self.digits[..self.len]
+    }
+
+    /// Returns the number of digits, which is zero for a value of zero.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no digits, i.e. the value is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of non-zero digits, the number of additions a
+    /// scalar multiplication performs.
+    pub fn weight(&self) -> usize {
+        self.digits().iter().filter(|digit| **digit != 0).count()
+    }
+
+    /// Evaluates the digits back to the integer they represent.
+    pub fn value(&self) -> i128 {
+        // A prefix of the digits can overshoot the range of an i128 even when
+        // the whole value doesn't, which wrapping arithmetic undoes
+        self.digits().iter().rev().fold(0_i128, |output, digit| {
+            output.wrapping_mul(i128::from(self.radix)).wrapping_add(i128::from(*digit))
+        })
+    }
+
+    fn recode(value: i128, radix: u32, width: u32) -> Self {
+        let mut output = SignedDigits { radix, width, digits: [0; MAX_DIGITS], len: 0 };
+
+        // Negation maps a recoding to a recoding as the digit ranges are
+        // symmetric, so only the magnitude is recoded. Adding a carry to a
+        // magnitude of at most 2^127 can't overflow.
+        let sign = if value < 0 {-1} else {1};
+        let modulus = u128::from(radix).pow(width);
+        let half = modulus / 2;
+        let mut magnitude = value.unsigned_abs();
+        while magnitude != 0 {
+            let residue = magnitude % modulus;
+            let digit = if residue.is_multiple_of(u128::from(radix)) {
+                0
+            } else if residue > half {
+                magnitude += modulus - residue;
+                residue as i32 - modulus as i32
+            } else {
+                magnitude -= residue;
+                residue as i32
+            };
+            output.digits[output.len] = sign * digit;
+            output.len += 1;
+            magnitude /= u128::from(radix);
+        }
+        output
+    }
+}
+
+/// Overwrites every digit, leaving a recoding of zero with the same radix and
+/// width.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SignedDigits {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.digits);
+        zeroize::Zeroize::zeroize(&mut self.len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Number;
+
+    fn assert_valid(digits: &SignedDigits, value: i128) {
+        assert_eq!(digits.value(), value);
+        assert_ne!(digits.digits().last(), Some(&0));
+
+        let radix = digits.radix() as i32;
+        let width = digits.width() as usize;
+        for (idx, digit) in digits.digits().iter().enumerate() {
+            if *digit != 0 {
+                assert_ne!(digit % radix, 0, "Digit {} of {}", idx, value);
+                assert!(digit.abs() <= digits.digit_bound(), "Digit {} of {}", idx, value);
+                assert!(digits.digits()[idx + 1..].iter().take(width - 1).all(|digit| *digit == 0), "Window at {} of {}", idx, value);
+            }
+        }
+    }
+
+    fn values() -> impl Iterator<Item = i128> {
+        (-1000..=1000).chain([i128::MIN, i128::MIN + 1, i128::MAX, i128::MAX - 1, 1 << 100, -(3_i128.pow(80))])
+    }
+
+    #[test]
+    fn binary_recodings() {
+        for width in 2..=SignedDigits::MAX_BINARY_WIDTH {
+            for value in values() {
+                assert_valid(&SignedDigits::naf(value, width), value);
+            }
+        }
+
+        // 7 = 8 - 1 and 15 = 16 - 1 in the non-adjacent form
+        assert_eq!(SignedDigits::naf(7, 2).digits(), &[-1, 0, 0, 1]);
+        assert_eq!(SignedDigits::naf(-15, 2).digits(), &[1, 0, 0, 0, -1]);
+        assert_eq!(SignedDigits::naf(15, 3).digits(), &[-1, 0, 0, 0, 1]);
+        assert_eq!(SignedDigits::naf(15, 4).digits(), &[-1, 0, 0, 0, 1]);
+        assert_eq!(SignedDigits::naf(15, 5).digits(), &[15]);
+        assert_eq!(SignedDigits::naf(i128::MIN, 2).len(), 128);
+        assert!(SignedDigits::naf(0, 4).is_empty());
+    }
+
+    #[test]
+    fn ternary_recodings() {
+        for width in 1..=SignedDigits::MAX_TERNARY_WIDTH {
+            for value in values() {
+                assert_valid(&SignedDigits::ternary(value, width), value);
+            }
+        }
+
+        // A width of one gives the trits of balanced ternary
+        for value in -1000..=1000 {
+            let digits = SignedDigits::ternary(value, 1);
+            let number = Number::<8>::try_from(value).unwrap();
+            let trits: [i32; 8] = core::array::from_fn(|idx| digits.digits().get(idx).copied().unwrap_or(0));
+            assert_eq!(trits, core::array::from_fn(|idx| i32::from(i8::from(number[idx]))));
+        }
+
+        assert_eq!(SignedDigits::ternary(-1_000_003, 2).weight(), 6);
+        assert_eq!(SignedDigits::ternary(20, 2).digits(), &[2, 0, 2]);
+        assert_eq!(SignedDigits::ternary(20, 2).digit_bound(), 4);
+        assert_eq!(SignedDigits::ternary(i128::MAX, 1).len(), 81);
+    }
+
+    #[test]
+    #[should_panic(expected = "Width of a binary recoding must be between 2 and 16")]
+    fn invalid_width() {
+        let _ = SignedDigits::naf(5, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut digits = SignedDigits::ternary(-1_000_003, 2);
+        digits.zeroize();
+        assert!(digits.is_empty());
+        assert_eq!(digits.value(), 0);
+        assert_eq!(digits, SignedDigits::ternary(0, 2));
+    }
+}
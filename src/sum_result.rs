@@ -1,7 +1,12 @@
 use crate::trit::Trit;
 
-#[derive(Debug, PartialEq)]
+/// The result of adding trits: the trit in the position of the sum, and the
+/// carry into the next more significant position. The total is
+/// `result + 3 * carry`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SumResult {
+    /// The trit of the sum in the position of the operands.
     pub result: Trit,
+    /// The carry into the next more significant position.
     pub carry: Trit
-}
\ No newline at end of file
+}
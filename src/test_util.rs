@@ -0,0 +1,19 @@
+//! Fixtures shared by the unit tests of several modules.
+
+// Not every combination of features has tests using every fixture
+#![allow(dead_code)]
+
+use crate::number::Number;
+use crate::trit::Trit;
+
+/// Returns `len` trits from a cheap deterministic spread of patterns, with a
+/// different pattern for each non-zero `seed`.
+pub fn trit_pattern(len: usize, seed: usize) -> impl Iterator<Item = Trit> {
+    (0..len).map(move |i| [Trit::NEG, Trit::ZERO, Trit::POS][(i * i * seed + i / seed) % 3])
+}
+
+/// Returns a number filled with [`trit_pattern`], from the least significant
+/// trit.
+pub fn number_pattern<const N: usize>(seed: usize) -> Number<N> {
+    Number::<N>::from_rev_iter(trit_pattern(N, seed))
+}
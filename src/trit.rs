@@ -1,8 +1,15 @@
-use std::fmt;
+pub mod adders;
+pub mod logic;
+pub mod metrics;
 
-use crate::sum_result::SumResult;
+use core::error::Error;
+use core::fmt;
+use core::ops::{Mul, MulAssign, Neg};
 
-#[derive(Clone, Copy, Default, Eq, Ord, PartialEq, PartialOrd)]
+use crate::number::TryFromIntError;
+pub use crate::sum_result::SumResult;
+
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u8)]
 pub enum Trit {NEG, #[default]ZERO, POS}
 
@@ -24,25 +31,129 @@ impl fmt::Debug for Trit {
     }
 }
 
-impl From<char> for Trit {
-    fn from(encoded: char) -> Self {
+/// The error returned when converting a character that isn't a trit symbol
+/// into a [`Trit`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseTritError {
+    /// The character that could not be converted.
+    pub character: char
+}
+
+impl fmt::Display for ParseTritError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid trit {:?}", self.character)
+    }
+}
+
+impl Error for ParseTritError {}
+
+/// Accepts the `-`/`0`/`+` symbols used for display, along with the common
+/// alternative notations: `1` for a positive trit, and `T` (a "one" with a bar
+/// over it) or the Unicode minus sign `−` for a negative trit.
+impl TryFrom<char> for Trit {
+    type Error = ParseTritError;
+
+    fn try_from(encoded: char) -> Result<Self, Self::Error> {
         match encoded {
-            '-' => Trit::NEG,
-            '0' => Trit::ZERO,
-            '+' => Trit::POS,
-            _ => panic!("Fail to parse invalid trit {}", encoded)
+            '1' => Ok(Trit::POS),
+            'T' | 't' | '\u{2212}' => Ok(Trit::NEG),
+            _ => Trit::from_char(encoded).ok_or(ParseTritError { character: encoded })
+        }
+    }
+}
+
+impl From<Trit> for i8 {
+    fn from(trit: Trit) -> Self {
+        trit.to_i8()
+    }
+}
+
+impl TryFrom<i8> for Trit {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            -1 => Ok(Trit::NEG),
+            0 => Ok(Trit::ZERO),
+            1 => Ok(Trit::POS),
+            _ => Err(TryFromIntError(()))
         }
     }
 }
 
-impl From<&char> for Trit {
-    fn from(encoded: &char) -> Self {
-        Trit::from(*encoded)
+impl Neg for Trit {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+/// The product of two trits is always a trit, so multiplication never carries.
+impl Mul for Trit {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match rhs {
+            Trit::NEG => self.negate(),
+            Trit::ZERO => Trit::ZERO,
+            Trit::POS => self
+        }
+    }
+}
+
+impl MulAssign for Trit {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
     }
 }
 
 impl Trit {
-    pub fn negate(self) -> Self {
+    /// Decodes a single trit from its `-`/`0`/`+` symbol, returning `None` for
+    /// any other character.
+    pub(crate) fn from_char(encoded: char) -> Option<Self> {
+        match encoded {
+            '-' => Some(Trit::NEG),
+            '0' => Some(Trit::ZERO),
+            '+' => Some(Trit::POS),
+            _ => None
+        }
+    }
+
+    /// The integer value of this trit: -1, 0 or 1.
+    pub(crate) const fn to_i8(self) -> i8 {
+        self as i8 - 1
+    }
+
+    /// Encodes this trit in two bits, as used by [`Number::encode_2bit`]: `00`
+    /// for zero, `01` for positive and `10` for negative.
+    ///
+    /// [`Number::encode_2bit`]: crate::number::Number::encode_2bit
+    pub const fn to_2bit(self) -> u8 {
+        match self {
+            Trit::NEG => 0b10,
+            Trit::ZERO => 0b00,
+            Trit::POS => 0b01
+        }
+    }
+
+    /// Decodes a trit from the two lowest bits of `bits`, ignoring any higher
+    /// bits. Returns `None` for the unused pattern `11`.
+    pub const fn from_2bit(bits: u8) -> Option<Self> {
+        match bits & 0b11 {
+            0b00 => Some(Trit::ZERO),
+            0b01 => Some(Trit::POS),
+            0b10 => Some(Trit::NEG),
+            _ => None
+        }
+    }
+
+    /// Equality comparison usable in const contexts, where `PartialEq` is not.
+    const fn same_as(self, other: Trit) -> bool {
+        self as u8 == other as u8
+    }
+
+    pub const fn negate(self) -> Self {
         match self {
             Trit::NEG => Trit::POS,
             Trit::ZERO => Trit::ZERO,
@@ -50,26 +161,26 @@ impl Trit {
         }
     }
 
-    pub fn add(&self, rhs: &Trit) -> SumResult {
+    pub const fn add(&self, rhs: &Trit) -> SumResult {
         match (self, rhs) {
             (l, Trit::ZERO) => SumResult {result: *l, carry: Trit::ZERO},
             (Trit::ZERO, r) => SumResult {result: *r, carry: Trit::ZERO},
-            (l, r) if *l==*r => SumResult {result: l.negate(), carry: *l},
-            (l, r) if *l==r.negate() => SumResult { result: Trit::ZERO, carry: Trit::ZERO },
+            (l, r) if l.same_as(*r) => SumResult {result: l.negate(), carry: *l},
+            (l, r) if l.same_as(r.negate()) => SumResult { result: Trit::ZERO, carry: Trit::ZERO },
             _ => unreachable!()
         }
     }
 
-    pub fn add_with_carry(&self, rhs: &Trit, carry: &Trit) -> SumResult {
+    pub const fn add_with_carry(&self, rhs: &Trit, carry: &Trit) -> SumResult {
         match (self, rhs, carry) {
             // If any trit is zero we can reduce to the binary sum
             (Trit::ZERO, r, c) => r.add(c),
             (l, Trit::ZERO, c) => l.add(c),
             (l, r, Trit::ZERO) => l.add(r),
             // If any two trits negate each other the remaining trit is the result
-            (l, r, c) if l.negate()==*r => SumResult { result: *c, carry: Trit::ZERO },
-            (l, r, c) if l.negate()==*c => SumResult { result: *r, carry: Trit::ZERO },
-            (l, r, c) if r.negate()==*c => SumResult { result: *l, carry: Trit::ZERO },
+            (l, r, c) if l.negate().same_as(*r) => SumResult { result: *c, carry: Trit::ZERO },
+            (l, r, c) if l.negate().same_as(*c) => SumResult { result: *r, carry: Trit::ZERO },
+            (l, r, c) if r.negate().same_as(*c) => SumResult { result: *l, carry: Trit::ZERO },
             // Else all three trits are the same, so the result is zero with a
             // carry trit
             (l, _, _) => SumResult { result: Trit::ZERO, carry: *l }
@@ -77,6 +188,59 @@ impl Trit {
     }
 }
 
+/// Samples each of the three trits with equal probability.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Trit> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Trit {
+        match rng.gen_range(0..3) {
+            0 => Trit::NEG,
+            1 => Trit::ZERO,
+            _ => Trit::POS
+        }
+    }
+}
+
+/// Logs the trit as its `-`, `0` or `+` symbol. Each symbol is an interned
+/// string, so only its index is sent to the host.
+#[cfg(feature = "defmt")]
+impl defmt::Format for Trit {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Trit::NEG => defmt::write!(f, "-"),
+            Trit::ZERO => defmt::write!(f, "0"),
+            Trit::POS => defmt::write!(f, "+")
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Trit {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0_u8..=2)? {
+            0 => Trit::NEG,
+            1 => Trit::ZERO,
+            _ => Trit::POS
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u8 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// A proptest strategy producing each of the three trits with equal
+/// probability, shrinking towards [`Trit::ZERO`].
+#[cfg(feature = "proptest")]
+pub fn strategy() -> impl proptest::strategy::Strategy<Value = Trit> {
+    use proptest::strategy::Just;
+
+    proptest::prop_oneof![Just(Trit::ZERO), Just(Trit::NEG), Just(Trit::POS)]
+}
+
+/// Zeroizing a trit sets it to [`Trit::ZERO`], its default.
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for Trit {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +277,54 @@ mod tests {
         assert_eq!(Trit::ZERO.negate(), Trit::ZERO);
     }
 
+    #[test]
+    fn conversion_from_characters() {
+        assert_eq!(Trit::try_from('+'), Ok(Trit::POS));
+        assert_eq!(Trit::try_from('1'), Ok(Trit::POS));
+        assert_eq!(Trit::try_from('0'), Ok(Trit::ZERO));
+        assert_eq!(Trit::try_from('-'), Ok(Trit::NEG));
+        assert_eq!(Trit::try_from('T'), Ok(Trit::NEG));
+        assert_eq!(Trit::try_from('\u{2212}'), Ok(Trit::NEG));
+        assert_eq!(Trit::try_from('2'), Err(ParseTritError { character: '2' }));
+        assert_eq!(ParseTritError { character: '2' }.to_string(), "invalid trit '2'");
+    }
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(i8::from(Trit::NEG), -1);
+        assert_eq!(i8::from(Trit::ZERO), 0);
+        assert_eq!(i8::from(Trit::POS), 1);
+
+        for trit in [Trit::NEG, Trit::ZERO, Trit::POS] {
+            assert_eq!(Trit::try_from(i8::from(trit)), Ok(trit));
+        }
+        assert!(Trit::try_from(2_i8).is_err());
+        assert!(Trit::try_from(-2_i8).is_err());
+    }
+
+    #[test]
+    fn two_bit_encoding() {
+        for trit in [Trit::NEG, Trit::ZERO, Trit::POS] {
+            assert_eq!(Trit::from_2bit(trit.to_2bit()), Some(trit));
+        }
+        assert_eq!(Trit::from_2bit(0b1101), Some(Trit::POS));
+        assert_eq!(Trit::from_2bit(0b11), None);
+    }
+
+    #[test]
+    fn multiplication() {
+        for lhs in [Trit::NEG, Trit::ZERO, Trit::POS] {
+            assert_eq!(-lhs, lhs.negate());
+            for rhs in [Trit::NEG, Trit::ZERO, Trit::POS] {
+                assert_eq!(i8::from(lhs * rhs), i8::from(lhs) * i8::from(rhs));
+            }
+        }
+
+        let mut trit = Trit::POS;
+        trit *= Trit::NEG;
+        assert_eq!(trit, Trit::NEG);
+    }
+
     #[test]
     fn double_negation_has_no_change() {
         assert_eq!(Trit::POS.negate().negate(), Trit::POS);
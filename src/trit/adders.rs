@@ -0,0 +1,87 @@
+//! Adders and comparators on single trits, the building blocks of the
+//! arithmetic on whole numbers.
+//!
+//! A full adder is also a 3:2 compressor: it reduces three trits of the same
+//! weight to a sum trit of that weight and a carry trit of the next weight up.
+//! Applied independently at every position of three numbers it becomes a
+//! carry-save adder, [`Number::carry_save_add`](crate::number::Number::carry_save_add),
+//! which postpones all carry propagation to a single final addition.
+
+use crate::trit::{SumResult, Trit};
+
+/// Adds two trits, giving the sum trit and the carry.
+pub const fn half_adder(lhs: Trit, rhs: Trit) -> SumResult {
+    lhs.add(&rhs)
+}
+
+/// Adds three trits, giving the sum trit and the carry. The total of three
+/// trits always fits, as it lies between -3 and 3.
+pub const fn full_adder(lhs: Trit, rhs: Trit, carry: Trit) -> SumResult {
+    lhs.add_with_carry(&rhs, &carry)
+}
+
+/// Compares two trits, giving the sign of `lhs - rhs`.
+pub const fn compare(lhs: Trit, rhs: Trit) -> Trit {
+    cascade_compare(Trit::ZERO, lhs, rhs)
+}
+
+/// One stage of a comparator chain running from the most significant trit to
+/// the least. The comparison of the more significant trits decides the result
+/// unless they were equal, in which case these trits do.
+/// * `higher` - The result of comparing the more significant trits
+/// * `lhs` - The trit of the left hand operand in this position
+/// * `rhs` - The trit of the right hand operand in this position
+/// ```
+/// # use balanced_ternary::number::Number;
+/// # use balanced_ternary::trit::{adders, Trit};
+/// let lhs = Number::<4>::from(13);
+/// let rhs = Number::<4>::from(-5);
+/// let result = lhs.as_trits().iter().zip(rhs.as_trits())
+///     .fold(Trit::ZERO, |higher, (lhs, rhs)| adders::cascade_compare(higher, *lhs, *rhs));
+/// assert_eq!(result, Trit::POS);
+/// ```
+pub const fn cascade_compare(higher: Trit, lhs: Trit, rhs: Trit) -> Trit {
+    // A difference of two trits lies between -2 and 2, and its sign is that of
+    // the carry when there is one
+    let difference = lhs.add(&rhs.negate());
+    match (higher, difference.carry) {
+        (Trit::ZERO, Trit::ZERO) => difference.result,
+        (Trit::ZERO, carry) => carry,
+        (higher, _) => higher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+    #[test]
+    fn adders_match_integers() {
+        for lhs in TRITS {
+            for rhs in TRITS {
+                let SumResult { result, carry } = half_adder(lhs, rhs);
+                assert_eq!(i8::from(result) + 3 * i8::from(carry), i8::from(lhs) + i8::from(rhs));
+
+                for carry_in in TRITS {
+                    let SumResult { result, carry } = full_adder(lhs, rhs, carry_in);
+                    assert_eq!(i8::from(result) + 3 * i8::from(carry), i8::from(lhs) + i8::from(rhs) + i8::from(carry_in));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn comparators() {
+        for lhs in TRITS {
+            for rhs in TRITS {
+                let expected = Trit::try_from(i8::from(lhs).cmp(&i8::from(rhs)) as i8).unwrap();
+                assert_eq!(compare(lhs, rhs), expected);
+                assert_eq!(cascade_compare(Trit::ZERO, lhs, rhs), expected);
+                assert_eq!(cascade_compare(Trit::NEG, lhs, rhs), Trit::NEG);
+                assert_eq!(cascade_compare(Trit::POS, lhs, rhs), Trit::POS);
+            }
+        }
+    }
+}
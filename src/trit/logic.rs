@@ -0,0 +1,154 @@
+//! Three-valued logic on trits, reading [`Trit::NEG`] as false, [`Trit::ZERO`]
+//! as unknown and [`Trit::POS`] as true.
+//!
+//! Each binary operation is defined by a truth table indexed by
+//! `[lhs as usize][rhs as usize]`, so that the rows and columns run from false
+//! through unknown to true. The tables are public for use as lookup tables in
+//! their own right.
+
+use crate::trit::Trit;
+
+const F: Trit = Trit::NEG;
+const U: Trit = Trit::ZERO;
+const T: Trit = Trit::POS;
+
+/// Truth table of [`and`], the minimum of the two trits.
+pub const AND_TABLE: [[Trit; 3]; 3] = [
+    [F, F, F],
+    [F, U, U],
+    [F, U, T]
+];
+
+/// Truth table of [`or`], the maximum of the two trits.
+pub const OR_TABLE: [[Trit; 3]; 3] = [
+    [F, U, T],
+    [U, U, T],
+    [T, T, T]
+];
+
+/// Truth table of [`kleene_implies`], `or(not(lhs), rhs)`.
+pub const KLEENE_IMPLIES_TABLE: [[Trit; 3]; 3] = [
+    [T, T, T],
+    [U, U, T],
+    [F, U, T]
+];
+
+/// Truth table of [`lukasiewicz_implies`], which differs from Kleene
+/// implication only in that unknown implies unknown.
+pub const LUKASIEWICZ_IMPLIES_TABLE: [[Trit; 3]; 3] = [
+    [T, T, T],
+    [U, T, T],
+    [F, U, T]
+];
+
+/// Truth table of [`kleene_equiv`], which is the product of the two trits.
+pub const KLEENE_EQUIV_TABLE: [[Trit; 3]; 3] = [
+    [T, U, F],
+    [U, U, U],
+    [F, U, T]
+];
+
+/// Truth table of [`lukasiewicz_equiv`], which is true whenever the trits are
+/// equal.
+pub const LUKASIEWICZ_EQUIV_TABLE: [[Trit; 3]; 3] = [
+    [T, U, F],
+    [U, T, U],
+    [F, U, T]
+];
+
+/// Truth table of [`consensus`], which is the common value of the two trits or
+/// unknown if they differ.
+pub const CONSENSUS_TABLE: [[Trit; 3]; 3] = [
+    [F, U, U],
+    [U, U, U],
+    [U, U, T]
+];
+
+/// Logical negation, swapping true and false and leaving unknown unchanged.
+pub const fn not(value: Trit) -> Trit {
+    value.negate()
+}
+
+/// Logical conjunction, the minimum of the two trits. This is shared by the
+/// Kleene and Łukasiewicz logics.
+pub const fn and(lhs: Trit, rhs: Trit) -> Trit {
+    AND_TABLE[lhs as usize][rhs as usize]
+}
+
+/// Logical disjunction, the maximum of the two trits. This is shared by the
+/// Kleene and Łukasiewicz logics.
+pub const fn or(lhs: Trit, rhs: Trit) -> Trit {
+    OR_TABLE[lhs as usize][rhs as usize]
+}
+
+/// Kleene's strong implication, `or(not(lhs), rhs)`.
+pub const fn kleene_implies(lhs: Trit, rhs: Trit) -> Trit {
+    KLEENE_IMPLIES_TABLE[lhs as usize][rhs as usize]
+}
+
+/// Łukasiewicz implication, `min(1, 1 - lhs + rhs)` in terms of the trit
+/// values. Unlike Kleene implication, unknown implies unknown is true.
+pub const fn lukasiewicz_implies(lhs: Trit, rhs: Trit) -> Trit {
+    LUKASIEWICZ_IMPLIES_TABLE[lhs as usize][rhs as usize]
+}
+
+/// Kleene equivalence, the conjunction of implication in both directions.
+pub const fn kleene_equiv(lhs: Trit, rhs: Trit) -> Trit {
+    KLEENE_EQUIV_TABLE[lhs as usize][rhs as usize]
+}
+
+/// Łukasiewicz equivalence, the conjunction of implication in both directions,
+/// which is `1 - |lhs - rhs|` in terms of the trit values.
+pub const fn lukasiewicz_equiv(lhs: Trit, rhs: Trit) -> Trit {
+    LUKASIEWICZ_EQUIV_TABLE[lhs as usize][rhs as usize]
+}
+
+/// The consensus of two trits, which is their common value when they agree
+/// and unknown otherwise.
+pub const fn consensus(lhs: Trit, rhs: Trit) -> Trit {
+    CONSENSUS_TABLE[lhs as usize][rhs as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRITS: [Trit; 3] = [F, U, T];
+
+    fn value(trit: Trit) -> i8 {
+        trit.to_i8()
+    }
+
+    fn from_value(value: i8) -> Trit {
+        TRITS[(value + 1) as usize]
+    }
+
+    #[test]
+    fn tables_match_definitions() {
+        for lhs in TRITS {
+            assert_eq!(not(not(lhs)), lhs);
+            for rhs in TRITS {
+                assert_eq!(and(lhs, rhs), lhs.min(rhs));
+                assert_eq!(or(lhs, rhs), lhs.max(rhs));
+                assert_eq!(kleene_implies(lhs, rhs), or(not(lhs), rhs));
+                assert_eq!(lukasiewicz_implies(lhs, rhs), from_value((1 - value(lhs) + value(rhs)).min(1)));
+                assert_eq!(kleene_equiv(lhs, rhs), and(kleene_implies(lhs, rhs), kleene_implies(rhs, lhs)));
+                assert_eq!(kleene_equiv(lhs, rhs), from_value(value(lhs) * value(rhs)));
+                assert_eq!(lukasiewicz_equiv(lhs, rhs),
+                    and(lukasiewicz_implies(lhs, rhs), lukasiewicz_implies(rhs, lhs)));
+                assert_eq!(lukasiewicz_equiv(lhs, rhs), from_value(1 - (value(lhs) - value(rhs)).abs()));
+                assert_eq!(consensus(lhs, rhs), if lhs == rhs {lhs} else {U});
+            }
+        }
+    }
+
+    #[test]
+    fn de_morgan() {
+        for lhs in TRITS {
+            for rhs in TRITS {
+                assert_eq!(not(and(lhs, rhs)), or(not(lhs), not(rhs)));
+                assert_eq!(not(or(lhs, rhs)), and(not(lhs), not(rhs)));
+            }
+        }
+    }
+}
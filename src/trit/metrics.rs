@@ -0,0 +1,69 @@
+//! Similarity metrics between equal-length sequences of trits, as used when
+//! comparing ternary weight vectors or codewords.
+
+use crate::trit::Trit;
+
+/// Returns the number of positions at which two trit slices differ.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn hamming_distance(lhs: &[Trit], rhs: &[Trit]) -> usize {
+    pairs(lhs, rhs).filter(|(lhs, rhs)| lhs != rhs).count()
+}
+
+/// Returns the number of positions at which two trit slices hold the same
+/// trit. This is the length of the slices less their Hamming distance.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn matching_trits(lhs: &[Trit], rhs: &[Trit]) -> usize {
+    pairs(lhs, rhs).filter(|(lhs, rhs)| lhs == rhs).count()
+}
+
+/// Returns the signed agreement between two trit slices, the sum of the
+/// products of the trits in each position. A position counts +1 where both
+/// trits are non-zero with the same sign, -1 where they have opposite signs and
+/// 0 where either is zero, so the score ranges from minus to plus the length.
+/// ```
+/// # use balanced_ternary::trit::{metrics, Trit};
+/// let lhs = [Trit::POS, Trit::NEG, Trit::ZERO, Trit::POS];
+/// let rhs = [Trit::POS, Trit::POS, Trit::NEG, Trit::POS];
+/// assert_eq!(metrics::agreement(&lhs, &rhs), 1);
+/// ```
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn agreement(lhs: &[Trit], rhs: &[Trit]) -> isize {
+    pairs(lhs, rhs).map(|(lhs, rhs)| isize::from(i8::from(*lhs * *rhs))).sum()
+}
+
+fn pairs<'a>(lhs: &'a [Trit], rhs: &'a [Trit]) -> impl Iterator<Item = (&'a Trit, &'a Trit)> {
+    assert_eq!(lhs.len(), rhs.len(), "Trit slices must have the same length");
+    lhs.iter().zip(rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_metrics() {
+        let lhs = [Trit::POS, Trit::NEG, Trit::ZERO, Trit::POS, Trit::ZERO];
+        let rhs = [Trit::NEG, Trit::NEG, Trit::POS, Trit::POS, Trit::ZERO];
+
+        assert_eq!(hamming_distance(&lhs, &rhs), 2);
+        assert_eq!(matching_trits(&lhs, &rhs), 3);
+        assert_eq!(agreement(&lhs, &rhs), 1);
+
+        assert_eq!(hamming_distance(&lhs, &lhs), 0);
+        assert_eq!(agreement(&lhs, &lhs), 3);
+        assert_eq!(agreement(&lhs, &lhs.map(|trit| -trit)), -3);
+        assert_eq!(agreement(&[], &[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trit slices must have the same length")]
+    fn mismatched_lengths() {
+        let _ = hamming_distance(&[Trit::POS], &[Trit::POS, Trit::ZERO]);
+    }
+}
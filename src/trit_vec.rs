@@ -0,0 +1,642 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Copied;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Shl,
+    ShlAssign, Shr, ShrAssign, Sub, SubAssign
+};
+use core::slice::{self, SliceIndex};
+use core::str::FromStr;
+
+use crate::error::Error;
+use crate::number::{toom, Number, ParseTernaryError, TryFromIntError};
+use crate::trit::Trit;
+
+/// A balanced ternary number whose width is chosen at runtime, backed by a
+/// growable vector of trits.
+///
+/// The trits are stored from the least significant, so that index i holds the
+/// trit with a weight of 3^i, in the same order used for indexing and iterating
+/// over a [`Number`]. Pushing and popping trits therefore grows and shrinks the
+/// number at its most significant end.
+///
+/// Arithmetic between two trit vectors produces a result as wide as the wider
+/// operand, and wraps at that width in the same way as the arithmetic of
+/// [`Number`]. Comparisons are by value, so vectors of different widths are
+/// equal when they only differ by leading zeros.
+#[derive(Clone, Default)]
+pub struct TritVec {
+    trits: Vec<Trit>
+}
+
+impl TritVec {
+    /// Creates an empty trit vector, which has a width of zero and a value of
+    /// zero.
+    pub const fn new() -> Self {
+        TritVec { trits: Vec::new() }
+    }
+
+    /// Creates an empty trit vector with space for at least `capacity` trits.
+    pub fn with_capacity(capacity: usize) -> Self {
+        TritVec { trits: Vec::with_capacity(capacity) }
+    }
+
+    /// Creates a trit vector of the given width with every trit set to zero.
+    pub fn zeros(width: usize) -> Self {
+        TritVec { trits: alloc::vec![Trit::ZERO; width] }
+    }
+
+    /// Returns the width of the vector in trits.
+    pub fn len(&self) -> usize {
+        self.trits.len()
+    }
+
+    /// Returns `true` if the vector holds no trits.
+    pub fn is_empty(&self) -> bool {
+        self.trits.is_empty()
+    }
+
+    /// Appends a new most significant trit.
+    pub fn push(&mut self, trit: Trit) {
+        self.trits.push(trit);
+    }
+
+    /// Removes and returns the most significant trit, or `None` if the vector
+    /// is empty.
+    pub fn pop(&mut self) -> Option<Trit> {
+        self.trits.pop()
+    }
+
+    /// Changes the width of the vector, padding with zeros in the new most
+    /// significant positions or discarding the most significant trits.
+    pub fn resize(&mut self, width: usize) {
+        self.trits.resize(width, Trit::ZERO);
+    }
+
+    /// Removes any leading zero trits, leaving the narrowest vector that holds
+    /// the same value.
+    pub fn trim(&mut self) {
+        let significant = self.trits.iter().rposition(|trit| *trit != Trit::ZERO).map_or(0, |idx| idx + 1);
+        self.trits.truncate(significant);
+    }
+
+    /// Views the trits as a slice ordered from the least significant.
+    pub fn as_slice(&self) -> &[Trit] {
+        &self.trits
+    }
+
+    /// Views the trits as a mutable slice ordered from the least significant.
+    pub fn as_mut_slice(&mut self) -> &mut [Trit] {
+        &mut self.trits
+    }
+
+    /// Returns an iterator over the trits, starting from the least significant.
+    pub fn iter(&self) -> Copied<slice::Iter<'_, Trit>> {
+        self.trits.iter().copied()
+    }
+
+    /// Returns the sign of the number as a trit, which is its most significant
+    /// non-zero trit.
+    pub fn signum(&self) -> Trit {
+        self.trits.iter().rev().copied().find(|trit| *trit != Trit::ZERO).unwrap_or(Trit::ZERO)
+    }
+
+    /// Returns `true` if every trit is zero.
+    pub fn is_zero(&self) -> bool {
+        self.signum() == Trit::ZERO
+    }
+
+    /// Returns `true` if the number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.signum() == Trit::NEG
+    }
+
+    /// Returns the absolute value of the number.
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {-self} else {self.clone()}
+    }
+
+    /// Performs integer division, returning both the quotient and the
+    /// remainder at the width of the wider operand. As with [`Number::div_rem`]
+    /// the quotient is rounded towards zero and the remainder takes the sign of
+    /// the numerator.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if divisor.is_zero() {
+            panic!("Attempt to divide by zero")
+        }
+
+        // The same long division on magnitudes as `Number::div_rem`, carried out
+        // at the width of the wider operand
+        let width = self.len().max(divisor.len());
+        let mut abs_remainder = self.abs().resized(width);
+        let abs_divisor = divisor.abs().resized(width);
+
+        let max_shift = width - abs_divisor.trits.iter().rposition(|trit| *trit != Trit::ZERO).map_or(0, |idx| idx + 1);
+
+        let mut quotient = TritVec::zeros(width);
+        for shift in (0..=max_shift).rev() {
+            let shifted_divisor = abs_divisor.clone() << shift;
+
+            quotient <<= 1;
+            while abs_remainder >= shifted_divisor {
+                abs_remainder -= &shifted_divisor;
+                quotient.add_trit(Trit::POS);
+            }
+        }
+
+        let quotient = if self.is_negative() != divisor.is_negative() {-quotient} else {quotient};
+        let remainder = if self.is_negative() {-abs_remainder} else {abs_remainder};
+        (quotient, remainder)
+    }
+
+    /// Fallible [`TritVec::div_rem`]. Returns [`Error::DivisionByZero`] if
+    /// `divisor` is zero.
+    pub fn try_div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.div_rem(divisor))
+    }
+
+    fn resized(mut self, width: usize) -> Self {
+        self.resize(width);
+        self
+    }
+
+    /// Adds a single trit to the least significant position, wrapping at the
+    /// width of the vector.
+    fn add_trit(&mut self, trit: Trit) {
+        let mut carry = trit;
+        for current in self.trits.iter_mut() {
+            if carry == Trit::ZERO {
+                break;
+            }
+            let sum = current.add(&carry);
+            *current = sum.result;
+            carry = sum.carry;
+        }
+    }
+}
+
+impl <const N: usize> From<Number<N>> for TritVec {
+    fn from(number: Number<N>) -> Self {
+        number.iter().collect()
+    }
+}
+
+impl <const N: usize> TryFrom<&TritVec> for Number<N> {
+    type Error = TryFromIntError;
+
+    /// Converts to a number of N trits, failing if any non-zero trits lie
+    /// beyond its width.
+    fn try_from(trits: &TritVec) -> Result<Self, Self::Error> {
+        if trits.iter().skip(N).all(|trit| trit == Trit::ZERO) {
+            Ok(Number::<N>::from_rev_iter(trits.iter()))
+        } else {
+            Err(TryFromIntError(()))
+        }
+    }
+}
+
+impl <const N: usize> TryFrom<TritVec> for Number<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(trits: TritVec) -> Result<Self, Self::Error> {
+        Number::<N>::try_from(&trits)
+    }
+}
+
+/// Collects trits, supplied from the least significant, into a vector as wide
+/// as the number of trits.
+impl FromIterator<Trit> for TritVec {
+    fn from_iter<I: IntoIterator<Item = Trit>>(source: I) -> Self {
+        TritVec { trits: source.into_iter().collect() }
+    }
+}
+
+/// Appends trits as new most significant trits, in the order supplied.
+impl Extend<Trit> for TritVec {
+    fn extend<I: IntoIterator<Item = Trit>>(&mut self, source: I) {
+        self.trits.extend(source);
+    }
+}
+
+impl IntoIterator for TritVec {
+    type Item = Trit;
+    type IntoIter = alloc::vec::IntoIter<Trit>;
+
+    /// Consumes the vector, yielding its trits from the least significant.
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Taken rather than moved out, as with the `zeroize` feature the vector
+        // wipes its trits when dropped
+        core::mem::take(&mut self.trits).into_iter()
+    }
+}
+
+impl <'a> IntoIterator for &'a TritVec {
+    type Item = &'a Trit;
+    type IntoIter = slice::Iter<'a, Trit>;
+
+    /// Yields references to the trits from the least significant.
+    fn into_iter(self) -> Self::IntoIter {
+        self.trits.iter()
+    }
+}
+
+/// Indexes or slices the trits, with index i being the trit with a weight of 3^i.
+impl <I: SliceIndex<[Trit]>> Index<I> for TritVec {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.trits[index]
+    }
+}
+
+impl <I: SliceIndex<[Trit]>> IndexMut<I> for TritVec {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.trits[index]
+    }
+}
+
+/// Trit vectors are compared by value, padding the narrower vector with zeros.
+impl Ord for TritVec {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // As with `Number` the first differing trit from the most significant
+        // decides the ordering
+        let width = self.len().max(other.len());
+        (0..width).rev()
+            .map(|idx| {
+                let lhs = self.trits.get(idx).copied().unwrap_or(Trit::ZERO);
+                let rhs = other.trits.get(idx).copied().unwrap_or(Trit::ZERO);
+                lhs.cmp(&rhs)
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for TritVec {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TritVec {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for TritVec {}
+
+impl Neg for &TritVec {
+    type Output = TritVec;
+
+    fn neg(self) -> Self::Output {
+        self.iter().map(Trit::negate).collect()
+    }
+}
+
+impl Neg for TritVec {
+    type Output = TritVec;
+
+    fn neg(mut self) -> Self::Output {
+        self.trits.iter_mut().for_each(|trit| *trit = trit.negate());
+        self
+    }
+}
+
+impl Add for &TritVec {
+    type Output = TritVec;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let width = self.len().max(rhs.len());
+        let mut carry = Trit::ZERO;
+        (0..width)
+            .map(|idx| {
+                let lhs = self.trits.get(idx).unwrap_or(&Trit::ZERO);
+                let rhs = rhs.trits.get(idx).unwrap_or(&Trit::ZERO);
+                let sum = lhs.add_with_carry(rhs, &carry);
+                carry = sum.carry;
+                sum.result
+            })
+            .collect()
+    }
+}
+
+impl Sub for &TritVec {
+    type Output = TritVec;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul for &TritVec {
+    type Output = TritVec;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Multiply the trits as polynomials and resolve the carries, keeping
+        // only as many trits as the wider operand
+        let width = self.len().max(rhs.len());
+        let lhs_coefficients: Vec<i64> = self.iter().map(|trit| i64::from(trit.to_i8())).collect();
+        let rhs_coefficients: Vec<i64> = rhs.iter().map(|trit| i64::from(trit.to_i8())).collect();
+        let product = toom::convolve(&lhs_coefficients, &rhs_coefficients);
+
+        let mut output: TritVec = toom::normalise(&product).take(width).collect();
+        output.resize(width);
+        output
+    }
+}
+
+impl Div for &TritVec {
+    type Output = TritVec;
+
+    fn div(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).0
+    }
+}
+
+impl Rem for &TritVec {
+    type Output = TritVec;
+
+    fn rem(self, divisor: Self) -> Self::Output {
+        self.div_rem(divisor).1
+    }
+}
+
+// Forwards the operators on owned vectors and mixed operands to the
+// implementations on references
+macro_rules! forward_binary_op {
+    ($op_trait:ident, $op_fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl $op_trait for TritVec {
+            type Output = TritVec;
+
+            fn $op_fn(self, rhs: TritVec) -> Self::Output {
+                $op_trait::$op_fn(&self, &rhs)
+            }
+        }
+
+        impl $op_trait<&TritVec> for TritVec {
+            type Output = TritVec;
+
+            fn $op_fn(self, rhs: &TritVec) -> Self::Output {
+                $op_trait::$op_fn(&self, rhs)
+            }
+        }
+
+        impl $op_trait<TritVec> for &TritVec {
+            type Output = TritVec;
+
+            fn $op_fn(self, rhs: TritVec) -> Self::Output {
+                $op_trait::$op_fn(self, &rhs)
+            }
+        }
+
+        impl $assign_trait<&TritVec> for TritVec {
+            fn $assign_fn(&mut self, rhs: &TritVec) {
+                *self = $op_trait::$op_fn(&*self, rhs);
+            }
+        }
+
+        impl $assign_trait for TritVec {
+            fn $assign_fn(&mut self, rhs: TritVec) {
+                *self = $op_trait::$op_fn(&*self, &rhs);
+            }
+        }
+    };
+}
+
+forward_binary_op!(Add, add, AddAssign, add_assign);
+forward_binary_op!(Sub, sub, SubAssign, sub_assign);
+forward_binary_op!(Mul, mul, MulAssign, mul_assign);
+forward_binary_op!(Div, div, DivAssign, div_assign);
+forward_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+/// Multiplies by 3^positions, discarding trits shifted beyond the width of
+/// the vector.
+impl Shl<usize> for TritVec {
+    type Output = Self;
+
+    fn shl(mut self, positions: usize) -> Self::Output {
+        self <<= positions;
+        self
+    }
+}
+
+impl ShlAssign<usize> for TritVec {
+    fn shl_assign(&mut self, positions: usize) {
+        let positions = positions.min(self.len());
+        self.trits.rotate_right(positions);
+        self.trits[..positions].fill(Trit::ZERO);
+    }
+}
+
+/// Divides by 3^positions, rounding to the nearest integer in the same way as
+/// a right shift of a [`Number`].
+impl Shr<usize> for TritVec {
+    type Output = Self;
+
+    fn shr(mut self, positions: usize) -> Self::Output {
+        self >>= positions;
+        self
+    }
+}
+
+impl ShrAssign<usize> for TritVec {
+    fn shr_assign(&mut self, positions: usize) {
+        let positions = positions.min(self.len());
+        self.trits.rotate_left(positions);
+        let kept = self.len().saturating_sub(positions);
+        self.trits[kept..].fill(Trit::ZERO);
+    }
+}
+
+impl FromStr for TritVec {
+    type Err = ParseTernaryError;
+
+    /// Parses the `-`/`0`/`+` notation, most significant trit first, into a
+    /// vector as wide as the string.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        if encoded.is_empty() {
+            return Err(ParseTernaryError::Empty);
+        }
+
+        let mut trits = encoded.chars()
+            .enumerate()
+            .map(|(position, character)| Trit::from_char(character)
+                .ok_or(ParseTernaryError::InvalidTrit { character, position }))
+            .collect::<Result<Vec<Trit>, _>>()?;
+        trits.reverse();
+        Ok(TritVec { trits })
+    }
+}
+
+/// Writes the trits from the most significant, in the same notation used by
+/// [`Number`].
+impl fmt::Display for TritVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for trit in self.trits.iter().rev() {
+            write!(f, "{}", trit)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TritVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TritVec({})", self)
+    }
+}
+
+/// Overwrites the trits, along with any spare capacity, and empties the vector,
+/// leaving a width and value of zero. Trit vectors are also zeroized when
+/// dropped.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for TritVec {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.trits);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for TritVec {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for TritVec {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trit_vec(encoded: &str) -> TritVec {
+        encoded.parse().unwrap()
+    }
+
+    #[test]
+    fn growing_and_shrinking() {
+        let mut trits = TritVec::new();
+        assert!(trits.is_empty() && trits.is_zero());
+
+        trits.push(Trit::NEG);
+        trits.push(Trit::POS);
+        trits.push(Trit::ZERO);
+        assert_eq!(trits.len(), 3);
+        assert_eq!(trits.to_string(), "0+-");
+        assert_eq!(trits, trit_vec("+-")); // Leading zeros don't affect the value
+
+        assert_eq!(trits.pop(), Some(Trit::ZERO));
+        trits.extend([Trit::POS, Trit::NEG]);
+        assert_eq!(trits.to_string(), "-++-");
+
+        trits.resize(6);
+        assert_eq!(trits.to_string(), "00-++-");
+        trits.trim();
+        assert_eq!(trits.len(), 4);
+        assert_eq!(format!("{:?}", trits), "TritVec(-++-)");
+    }
+
+    #[test]
+    fn indexing_and_slicing() {
+        let mut trits = trit_vec("+-0--");
+
+        assert_eq!(trits[0], Trit::NEG);
+        assert_eq!(trits[4], Trit::POS);
+        assert_eq!(&trits[2..4], &[Trit::ZERO, Trit::NEG]);
+
+        trits[2] = Trit::POS;
+        assert_eq!(trits, trit_vec("+-+--"));
+    }
+
+    #[test]
+    fn number_conversions() {
+        let num_50 = Number::<8>::from("+-0--");
+        let trits = TritVec::from(num_50);
+
+        assert_eq!(trits.len(), 8);
+        assert_eq!(Number::<8>::try_from(&trits), Ok(num_50));
+        assert_eq!(Number::<5>::try_from(&trits), Ok(Number::<5>::from("+-0--")));
+        assert!(Number::<4>::try_from(trits).is_err());
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!("".parse::<TritVec>(), Err(ParseTernaryError::Empty));
+        assert_eq!("+x".parse::<TritVec>(), Err(ParseTernaryError::InvalidTrit { character: 'x', position: 1 }));
+        assert_eq!(trit_vec("00+").len(), 3);
+    }
+
+    #[test]
+    fn arithmetic_matches_number() {
+        for lhs in -40..=40_i32 {
+            for rhs in -40..=40_i32 {
+                let (num_lhs, num_rhs) = (Number::<4>::from(lhs), Number::<4>::from(rhs));
+                let (vec_lhs, vec_rhs) = (TritVec::from(num_lhs), TritVec::from(num_rhs));
+
+                assert_eq!(&vec_lhs + &vec_rhs, TritVec::from(num_lhs + num_rhs));
+                assert_eq!(&vec_lhs - &vec_rhs, TritVec::from(num_lhs - num_rhs));
+                assert_eq!(&vec_lhs * &vec_rhs, TritVec::from(num_lhs * num_rhs));
+                assert_eq!(vec_lhs.cmp(&vec_rhs), lhs.cmp(&rhs));
+                if rhs != 0 {
+                    assert_eq!(&vec_lhs / &vec_rhs, TritVec::from(num_lhs / num_rhs));
+                    assert_eq!(&vec_lhs % &vec_rhs, TritVec::from(num_lhs % num_rhs));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_widths() {
+        let num_50 = trit_vec("+-0--");
+        let num_neg_1 = trit_vec("-");
+
+        // The result is as wide as the wider operand
+        assert_eq!((&num_50 + &num_neg_1).len(), 5);
+        assert_eq!(num_neg_1.clone() * num_50.clone(), trit_vec("-+0++"));
+
+        // Arithmetic wraps at that width
+        assert_eq!(trit_vec("++") + trit_vec("+"), trit_vec("--"));
+
+        let mut accumulator = num_neg_1;
+        accumulator += &num_50;
+        accumulator -= trit_vec("+0");
+        assert_eq!(accumulator, trit_vec("+--0+")); // 50 - 1 - 3 = 46
+    }
+
+    #[test]
+    fn shifts() {
+        let num_50 = trit_vec("000+-0--");
+
+        assert_eq!((num_50.clone() << 2).to_string(), "0+-0--00");
+        assert_eq!((num_50.clone() << 8).to_string(), "00000000");
+        assert_eq!((num_50.clone() >> 2).to_string(), "00000+-0");
+        assert_eq!((num_50 >> 9).to_string(), "00000000");
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut secret = trit_vec("+-0--");
+        secret.zeroize();
+        assert!(secret.is_empty());
+        assert_eq!(secret, TritVec::new());
+    }
+
+    #[test]
+    fn fallible_division() {
+        let (num_50, num_neg_7) = (trit_vec("+-0--"), trit_vec("-+-"));
+        assert_eq!(num_50.try_div_rem(&TritVec::new()), Err(Error::DivisionByZero));
+        assert_eq!(num_50.try_div_rem(&trit_vec("000")), Err(Error::DivisionByZero));
+        assert_eq!(num_50.try_div_rem(&num_neg_7), Ok(num_50.div_rem(&num_neg_7)));
+    }
+}
@@ -0,0 +1,420 @@
+//! Truth tables of ternary functions, and their minimisation into sums of
+//! products.
+//!
+//! A function of n trits has 3^n rows, ordered with the first input as the
+//! most significant and each input running from [`Trit::NEG`] to
+//! [`Trit::POS`], the same order as the two-input tables of
+//! [`logic`](crate::trit::logic). There are 3^(3^n) such functions, each
+//! identified by the index whose trits, as unsigned digits, are its outputs.
+//!
+//! A [`SumOfProducts`] is the ternary analogue of a disjunctive normal form:
+//! the maximum of a list of terms, each of which is the minimum of a constant
+//! and one literal per input. A literal `x[S]` is [`Trit::POS`] when the input
+//! lies in the set S and [`Trit::NEG`] otherwise, so a term takes the value of
+//! its constant on a product of sets of inputs and is negative elsewhere.
+//! ```
+//! # use balanced_ternary::trit::Trit;
+//! # use balanced_ternary::truth_table::TruthTable;
+//! let table = TruthTable::from_fn(2, |inputs| inputs[0].min(inputs[1]));
+//! let minimal = table.minimize();
+//! assert_eq!(minimal.to_string(), "0 & x0[0+] & x1[0+] | x0[+] & x1[+]");
+//! assert_eq!(minimal.to_truth_table(), table);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::trit::Trit;
+
+const TRITS: [Trit; 3] = [Trit::NEG, Trit::ZERO, Trit::POS];
+
+/// The truth table of a function of a fixed number of trits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TruthTable {
+    inputs: usize,
+    outputs: Vec<Trit>
+}
+
+impl TruthTable {
+    /// The largest number of inputs for which every function can be indexed
+    /// by a `u64`, as 3^27 < 2^64.
+    pub const MAX_INDEXED_INPUTS: usize = 3;
+
+    /// Builds a truth table from its outputs, one per row.
+    ///
+    /// # Panics
+    /// Panics if there aren't exactly 3^inputs outputs.
+    pub fn new(inputs: usize, outputs: Vec<Trit>) -> Self {
+        assert_eq!(outputs.len(), rows(inputs), "Truth table of {} inputs must have {} rows", inputs, rows(inputs));
+        TruthTable { inputs, outputs }
+    }
+
+    /// Builds a truth table by evaluating a function on every row.
+    pub fn from_fn(inputs: usize, mut function: impl FnMut(&[Trit]) -> Trit) -> Self {
+        let outputs = (0..rows(inputs)).map(|row| function(&assignment(inputs, row))).collect();
+        TruthTable { inputs, outputs }
+    }
+
+    /// Builds the truth table of a two-input function from a lookup table
+    /// indexed by `[lhs as usize][rhs as usize]`, such as those of
+    /// [`logic`](crate::trit::logic).
+    pub fn from_table(table: &[[Trit; 3]; 3]) -> Self {
+        TruthTable::new(2, table.iter().flatten().copied().collect())
+    }
+
+    /// Builds the truth table of the function with the given index.
+    ///
+    /// # Panics
+    /// Panics if there are more than [`MAX_INDEXED_INPUTS`](Self::MAX_INDEXED_INPUTS)
+    /// inputs or the index is at least 3^(3^inputs).
+    pub fn from_index(inputs: usize, index: u64) -> Self {
+        assert!(index < function_count(inputs), "Index of a function of {} inputs must be less than 3^{}", inputs, rows(inputs));
+
+        let mut remaining = index;
+        let outputs = (0..rows(inputs)).map(|_| {
+            let trit = TRITS[(remaining % 3) as usize];
+            remaining /= 3;
+            trit
+        }).collect();
+        TruthTable { inputs, outputs }
+    }
+
+    /// Iterates over the truth tables of all 3^(3^inputs) functions, in the
+    /// order of their indices.
+    ///
+    /// # Panics
+    /// Panics if there are more than [`MAX_INDEXED_INPUTS`](Self::MAX_INDEXED_INPUTS)
+    /// inputs.
+    pub fn all(inputs: usize) -> impl Iterator<Item = TruthTable> {
+        (0..function_count(inputs)).map(move |index| TruthTable::from_index(inputs, index))
+    }
+
+    /// Returns the index of the function, the inverse of [`from_index`](Self::from_index).
+    ///
+    /// # Panics
+    /// Panics if there are more than [`MAX_INDEXED_INPUTS`](Self::MAX_INDEXED_INPUTS)
+    /// inputs.
+    pub fn index(&self) -> u64 {
+        let _ = function_count(self.inputs);
+        self.outputs.iter().rev().fold(0, |index, trit| index * 3 + *trit as u64)
+    }
+
+    /// Returns the number of inputs.
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Returns the output of every row.
+    pub fn outputs(&self) -> &[Trit] {
+        &self.outputs
+    }
+
+    /// Returns the output for a set of inputs.
+    ///
+    /// # Panics
+    /// Panics if the number of trits doesn't match the number of inputs.
+    pub fn evaluate(&self, inputs: &[Trit]) -> Trit {
+        assert_eq!(inputs.len(), self.inputs, "Truth table expected {} inputs but {} were supplied", self.inputs, inputs.len());
+        self.outputs[inputs.iter().fold(0, |row, trit| row * 3 + *trit as usize)]
+    }
+
+    /// Iterates over the rows of the table, giving the inputs and output of
+    /// each.
+    pub fn rows(&self) -> impl Iterator<Item = (Vec<Trit>, Trit)> + '_ {
+        self.outputs.iter().enumerate().map(|(row, output)| (assignment(self.inputs, row), *output))
+    }
+
+    /// Finds a small sum of products computing this function.
+    ///
+    /// Like the Quine–McCluskey method, this first finds the prime implicants
+    /// for each non-negative output value: the maximal products of input sets
+    /// on which the function is at least that value. It then covers the rows
+    /// taking each value with essential prime implicants, followed greedily by
+    /// those covering the most remaining rows. The search takes time
+    /// exponential in the number of inputs, and the greedy cover isn't always
+    /// the smallest.
+    pub fn minimize(&self) -> SumOfProducts {
+        let mut terms = Vec::new();
+        for constant in [Trit::ZERO, Trit::POS] {
+            let primes = self.prime_implicants(constant);
+            let mut uncovered: Vec<usize> = (0..self.outputs.len()).filter(|row| self.outputs[*row] == constant).collect();
+
+            // A prime is essential if it's the only one covering some row
+            let essential: Vec<usize> = uncovered.iter().filter_map(|row| {
+                let mut covering = primes.iter().enumerate().filter(|(_, prime)| prime.covers(self.inputs, *row));
+                match (covering.next(), covering.next()) {
+                    (Some((idx, _)), None) => Some(idx),
+                    _ => None
+                }
+            }).collect();
+
+            let mut chosen: Vec<usize> = Vec::new();
+            for idx in essential {
+                if !chosen.contains(&idx) {
+                    chosen.push(idx);
+                    uncovered.retain(|row| !primes[idx].covers(self.inputs, *row));
+                }
+            }
+            while !uncovered.is_empty() {
+                let best = (0..primes.len())
+                    .max_by_key(|idx| (uncovered.iter().filter(|row| primes[*idx].covers(self.inputs, **row)).count(), core::cmp::Reverse(*idx)))
+                    .unwrap();
+                chosen.push(best);
+                uncovered.retain(|row| !primes[best].covers(self.inputs, *row));
+            }
+
+            chosen.sort_unstable();
+            terms.extend(chosen.into_iter().map(|idx| Term { constant, literals: primes[idx].clone() }));
+        }
+        SumOfProducts { inputs: self.inputs, terms }
+    }
+
+    /// Returns the products of input sets on which the function is at least
+    /// `constant` that aren't contained in any larger such product.
+    fn prime_implicants(&self, constant: Trit) -> Vec<Vec<TritSet>> {
+        let implicants: Vec<Vec<TritSet>> = (0..7_usize.pow(self.inputs as u32))
+            .map(|index| (0..self.inputs).rev().map(|input| TritSet(((index / 7_usize.pow(input as u32)) % 7) as u8 + 1)).collect())
+            .filter(|cube: &Vec<TritSet>| (0..self.outputs.len()).all(|row| !cube.covers(self.inputs, row) || self.outputs[row] >= constant))
+            .collect();
+
+        implicants.iter().filter(|cube| {
+            !implicants.iter().any(|other| other != *cube && cube.iter().zip(other).all(|(set, other)| set.is_subset(*other)))
+        }).cloned().collect()
+    }
+}
+
+/// A set of trits, the condition of a literal.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct TritSet(u8);
+
+impl TritSet {
+    /// The set holding no trits.
+    pub const EMPTY: TritSet = TritSet(0);
+
+    /// The set holding every trit.
+    pub const ALL: TritSet = TritSet(0b111);
+
+    /// Builds the set of the given trits.
+    pub fn from_trits(trits: &[Trit]) -> Self {
+        TritSet(trits.iter().fold(0, |bits, trit| bits | 1 << *trit as u8))
+    }
+
+    /// Returns true if the set holds a trit.
+    pub fn contains(self, trit: Trit) -> bool {
+        self.0 & 1 << trit as u8 != 0
+    }
+
+    /// Returns true if every trit of this set is in the other.
+    pub fn is_subset(self, other: TritSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Iterates over the trits in the set, from negative to positive.
+    pub fn iter(self) -> impl Iterator<Item = Trit> {
+        TRITS.into_iter().filter(move |trit| self.contains(*trit))
+    }
+}
+
+impl fmt::Display for TritSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for trit in self.iter() {
+            write!(f, "{}", trit)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for TritSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TritSet({})", self)
+    }
+}
+
+/// A term of a sum of products, the minimum of a constant and one literal per
+/// input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Term {
+    constant: Trit,
+    literals: Vec<TritSet>
+}
+
+impl Term {
+    /// Builds a term from its constant and the set of each input's literal.
+    pub fn new(constant: Trit, literals: Vec<TritSet>) -> Self {
+        Term { constant, literals }
+    }
+
+    /// Returns the value the term takes when all of its literals hold.
+    pub fn constant(&self) -> Trit {
+        self.constant
+    }
+
+    /// Returns the set of each input's literal.
+    pub fn literals(&self) -> &[TritSet] {
+        &self.literals
+    }
+
+    /// Evaluates the term, which is its constant if every input lies in its
+    /// literal's set and negative otherwise.
+    pub fn evaluate(&self, inputs: &[Trit]) -> Trit {
+        if self.literals.iter().zip(inputs).all(|(set, input)| set.contains(*input)) {self.constant} else {Trit::NEG}
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Constants of positive and literals of every trit don't change the
+        // minimum, so they're left out unless nothing else remains
+        let mut separator = "";
+        if self.constant != Trit::POS {
+            write!(f, "{}", self.constant)?;
+            separator = " & ";
+        }
+        for (input, set) in self.literals.iter().enumerate().filter(|(_, set)| **set != TritSet::ALL) {
+            write!(f, "{}x{}[{}]", separator, input, set)?;
+            separator = " & ";
+        }
+        if separator.is_empty() {
+            write!(f, "{}", self.constant)?;
+        }
+        Ok(())
+    }
+}
+
+/// A ternary function written as the maximum of a list of terms.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SumOfProducts {
+    inputs: usize,
+    terms: Vec<Term>
+}
+
+impl SumOfProducts {
+    /// Builds a sum of products from its terms.
+    ///
+    /// # Panics
+    /// Panics if a term doesn't have one literal per input.
+    pub fn new(inputs: usize, terms: Vec<Term>) -> Self {
+        assert!(terms.iter().all(|term| term.literals.len() == inputs), "Each term must have one literal per input");
+        SumOfProducts { inputs, terms }
+    }
+
+    /// Returns the number of inputs.
+    pub fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Returns the terms.
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// Evaluates the function, the maximum of its terms or negative if it has
+    /// none.
+    pub fn evaluate(&self, inputs: &[Trit]) -> Trit {
+        self.terms.iter().map(|term| term.evaluate(inputs)).max().unwrap_or(Trit::NEG)
+    }
+
+    /// Returns the truth table of the function.
+    pub fn to_truth_table(&self) -> TruthTable {
+        TruthTable::from_fn(self.inputs, |inputs| self.evaluate(inputs))
+    }
+}
+
+impl fmt::Display for SumOfProducts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "{}", Trit::NEG);
+        }
+        for (idx, term) in self.terms.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " | ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks whether a product of input sets covers a row of a truth table.
+trait Cube {
+    fn covers(&self, inputs: usize, row: usize) -> bool;
+}
+
+impl Cube for Vec<TritSet> {
+    fn covers(&self, inputs: usize, row: usize) -> bool {
+        assignment(inputs, row).iter().zip(self).all(|(trit, set)| set.contains(*trit))
+    }
+}
+
+fn rows(inputs: usize) -> usize {
+    3_usize.pow(inputs as u32)
+}
+
+fn function_count(inputs: usize) -> u64 {
+    assert!(inputs <= TruthTable::MAX_INDEXED_INPUTS, "Functions can only be indexed for up to {} inputs", TruthTable::MAX_INDEXED_INPUTS);
+    3_u64.pow(rows(inputs) as u32)
+}
+
+/// Returns the inputs of a row, the first input being the most significant.
+fn assignment(inputs: usize, row: usize) -> Vec<Trit> {
+    let mut trits = vec![Trit::ZERO; inputs];
+    let mut remaining = row;
+    for trit in trits.iter_mut().rev() {
+        *trit = TRITS[remaining % 3];
+        remaining /= 3;
+    }
+    trits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trit::logic;
+
+    #[test]
+    fn tables_and_indices() {
+        let table = TruthTable::from_table(&logic::CONSENSUS_TABLE);
+        assert_eq!(table, TruthTable::from_fn(2, |inputs| logic::consensus(inputs[0], inputs[1])));
+        assert_eq!(table.evaluate(&[Trit::POS, Trit::POS]), Trit::POS);
+        assert_eq!(table.evaluate(&[Trit::NEG, Trit::POS]), Trit::ZERO);
+        assert_eq!(TruthTable::from_index(2, table.index()), table);
+
+        let rows: Vec<_> = TruthTable::from_fn(1, |inputs| -inputs[0]).rows().collect();
+        assert_eq!(rows, [(vec![Trit::NEG], Trit::POS), (vec![Trit::ZERO], Trit::ZERO), (vec![Trit::POS], Trit::NEG)]);
+
+        assert_eq!(TruthTable::all(1).count(), 27);
+        assert_eq!(TruthTable::all(0).map(|table| table.outputs()[0]).collect::<Vec<_>>(), TRITS);
+        assert_eq!(TruthTable::from_index(3, 3_u64.pow(27) - 1).outputs(), &[Trit::POS; 27]);
+    }
+
+    #[test]
+    fn minimizes_every_two_input_function() {
+        for table in TruthTable::all(2) {
+            let minimal = table.minimize();
+            assert_eq!(minimal.to_truth_table(), table, "{}", minimal);
+            assert!(minimal.terms().len() <= 9, "{}", minimal);
+        }
+    }
+
+    #[test]
+    fn minimal_forms() {
+        let minimize = |function: fn(&[Trit]) -> Trit, inputs| TruthTable::from_fn(inputs, function).minimize().to_string();
+
+        assert_eq!(minimize(|inputs| inputs[0].max(inputs[1]), 2), "0 & x0[0+] | 0 & x1[0+] | x0[+] | x1[+]");
+        assert_eq!(minimize(|inputs| -inputs[0], 1), "0 & x0[-0] | x0[-]");
+        assert_eq!(minimize(|inputs| logic::consensus(inputs[0], inputs[1]), 2), "0 & x0[0+] | 0 & x1[0+] | x0[+] & x1[+]");
+        assert_eq!(minimize(|_| Trit::ZERO, 3), "0");
+        assert_eq!(minimize(|_| Trit::NEG, 2), "-");
+
+        // A function of three inputs that only depends on two of them
+        let table = TruthTable::from_fn(3, |inputs| logic::and(inputs[0], inputs[2]));
+        assert_eq!(table.minimize().to_string(), "0 & x0[0+] & x2[0+] | x0[+] & x2[+]");
+    }
+
+    #[test]
+    #[should_panic(expected = "Functions can only be indexed for up to 3 inputs")]
+    fn too_many_inputs_to_index() {
+        let _ = TruthTable::all(4);
+    }
+}
@@ -0,0 +1,191 @@
+use core::fmt;
+
+use crate::number::{Number, TryFromIntError};
+use crate::trit::Trit;
+
+/// A tryte is a word of 9 trits, the balanced ternary analogue of a byte. It
+/// holds the values from -9841 to 9841.
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Tryte(Number<9>);
+
+impl Tryte {
+    /// The tryte with every trit set to zero.
+    pub const ZERO: Tryte = Tryte(Number::<9>::ZERO);
+
+    /// The largest tryte, 9841.
+    pub const MAX: Tryte = Tryte(Number::<9>::MAX);
+
+    /// The smallest tryte, -9841.
+    pub const MIN: Tryte = Tryte(Number::<9>::MIN);
+
+    /// The value added to a tryte to make it non-negative for packing into two
+    /// bytes.
+    const BYTES_OFFSET: i16 = 9841;
+
+    /// Packs the tryte into two little-endian bytes, holding its value offset by
+    /// 9841 so that every tryte maps to an unsigned value from 0 to 19682.
+    pub fn to_bytes(self) -> [u8; 2] {
+        let offset = i16::from(self) + Tryte::BYTES_OFFSET;
+        (offset as u16).to_le_bytes()
+    }
+
+    /// Unpacks a tryte from the two bytes produced by [`Tryte::to_bytes`],
+    /// failing if the bytes hold a value greater than 19682.
+    pub fn from_bytes(bytes: [u8; 2]) -> Result<Self, TryFromIntError> {
+        let offset = u16::from_le_bytes(bytes);
+        let value = i16::try_from(offset).map_err(|_| TryFromIntError(()))?;
+        Tryte::try_from(value - Tryte::BYTES_OFFSET)
+    }
+}
+
+impl From<Number<9>> for Tryte {
+    fn from(number: Number<9>) -> Self {
+        Tryte(number)
+    }
+}
+
+impl From<Tryte> for Number<9> {
+    fn from(tryte: Tryte) -> Self {
+        tryte.0
+    }
+}
+
+impl From<Tryte> for i16 {
+    fn from(tryte: Tryte) -> Self {
+        // Every 9 trit number fits within an i16
+        tryte.0.to_i128() as i16
+    }
+}
+
+impl TryFrom<i16> for Tryte {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        Number::<9>::try_from(i64::from(value)).map(Tryte)
+    }
+}
+
+/// Sets every trit to zero.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Tryte {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+impl fmt::Display for Tryte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for Tryte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Tryte(")?;
+        for trit in self.0.as_trits() {
+            write!(f, "{}", trit)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Compile-time check that a number of N trits is made up of exactly M trytes.
+struct TryteCount<const N: usize, const M: usize>;
+
+impl <const N: usize, const M: usize> TryteCount<N, M> {
+    const CHECK: () = assert!(N == 9 * M, "Number width must be nine times the number of trytes");
+}
+
+impl <const N: usize> Number<N> {
+    /// Builds a number from an array of trytes ordered from the most significant
+    /// tryte to the least. The width of the number must be exactly nine times
+    /// the number of trytes, otherwise it is a compile-time error.
+    /// ```
+    /// # use balanced_ternary::number::Number;
+    /// # use balanced_ternary::tryte::Tryte;
+    /// let trytes = [Tryte::try_from(1).unwrap(), Tryte::try_from(-1).unwrap()];
+    /// assert_eq!(Number::<18>::from_trytes(trytes), Number::<18>::from(19682));
+    /// ```
+    pub fn from_trytes<const M: usize>(trytes: [Tryte; M]) -> Self {
+        let () = TryteCount::<N, M>::CHECK;
+
+        let mut trits = [Trit::ZERO; N];
+        for (chunk, tryte) in trits.chunks_exact_mut(9).zip(trytes) {
+            chunk.copy_from_slice(tryte.0.as_trits());
+        }
+        Number::<N>::from_trits(trits)
+    }
+
+    /// Splits the number into an array of trytes ordered from the most
+    /// significant tryte to the least. The width of the number must be exactly
+    /// nine times the number of trytes, otherwise it is a compile-time error.
+    pub fn to_trytes<const M: usize>(self) -> [Tryte; M] {
+        let () = TryteCount::<N, M>::CHECK;
+
+        let mut trytes = [Tryte::ZERO; M];
+        for (tryte, chunk) in trytes.iter_mut().zip(self.as_trits().chunks_exact(9)) {
+            *tryte = Tryte(Number::<9>::from_trits_be(chunk));
+        }
+        trytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(i16::from(Tryte::MAX), 9841);
+        assert_eq!(i16::from(Tryte::MIN), -9841);
+        assert_eq!(Tryte::try_from(50_i16), Ok(Tryte::from(Number::<9>::from("+-0--"))));
+        assert!(Tryte::try_from(9842_i16).is_err());
+        assert!(Tryte::try_from(i16::MIN).is_err());
+
+        for value in -9841..=9841_i16 {
+            assert_eq!(i16::from(Tryte::try_from(value).unwrap()), value);
+        }
+    }
+
+    #[test]
+    fn byte_packing() {
+        assert_eq!(Tryte::MIN.to_bytes(), [0, 0]);
+        assert_eq!(Tryte::ZERO.to_bytes(), 9841_u16.to_le_bytes());
+        assert_eq!(Tryte::MAX.to_bytes(), 19682_u16.to_le_bytes());
+
+        for value in -9841..=9841_i16 {
+            let tryte = Tryte::try_from(value).unwrap();
+            assert_eq!(Tryte::from_bytes(tryte.to_bytes()), Ok(tryte));
+        }
+        assert!(Tryte::from_bytes(19683_u16.to_le_bytes()).is_err());
+        assert!(Tryte::from_bytes([0xFF, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn tryte_arrays() {
+        let number = Number::<27>::from_trits_be(&[Trit::POS; 20]);
+        let trytes: [Tryte; 3] = number.to_trytes();
+
+        assert_eq!(trytes[0], Tryte::from(Number::<9>::from("0000000++")));
+        assert_eq!(trytes[1], Tryte::MAX);
+        assert_eq!(trytes[2], Tryte::MAX);
+        assert_eq!(Number::<27>::from_trytes(trytes), number);
+    }
+
+    #[test]
+    fn representation() {
+        let tryte = Tryte::try_from(50_i16).unwrap();
+        assert_eq!(format!("{:?}", tryte), "Tryte(0000+-0--)");
+        assert_eq!(format!("{}", tryte), "0000+-0-- (50)");
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroizing() {
+        use zeroize::Zeroize;
+
+        let mut tryte = Tryte::MAX;
+        tryte.zeroize();
+        assert_eq!(tryte, Tryte::ZERO);
+    }
+}
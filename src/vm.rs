@@ -0,0 +1,318 @@
+//! A small ternary virtual machine in the spirit of the Setun computers, built
+//! on the crate's own arithmetic.
+//!
+//! The machine has three registers each holding a [`Tryte`], and a memory of
+//! 243 trytes addressed by 5 trit balanced ternary numbers from -121 to 121.
+//! Programs live in the same memory as their data, one [`Instruction`] per
+//! tryte, and execution starts from address zero.
+//! ```
+//! # use balanced_ternary::number::Number;
+//! # use balanced_ternary::tryte::Tryte;
+//! # use balanced_ternary::vm::{Instruction, Machine, Register};
+//! // Multiply the tryte at address 100 by 3 and store it back
+//! let address = Number::<5>::from(100);
+//! let program = [
+//!     Instruction::Load { register: Register::A, address },
+//!     Instruction::Shift { register: Register::A, positions: Number::<5>::ONE },
+//!     Instruction::Store { register: Register::A, address },
+//!     Instruction::Halt
+//! ];
+//!
+//! let mut machine = Machine::new();
+//! machine.load(Number::<5>::ZERO, &program.map(Instruction::encode));
+//! machine.write(address, Tryte::try_from(-14).unwrap());
+//! assert_eq!(machine.run(100), Ok(4));
+//! assert_eq!(i16::from(machine.read(address)), -42);
+//! ```
+
+#[cfg(feature = "alloc")]
+pub mod asm;
+mod instruction;
+
+use core::error::Error;
+use core::fmt;
+
+use crate::number::Number;
+use crate::trit::Trit;
+use crate::tryte::Tryte;
+
+pub use instruction::{Instruction, Register};
+
+/// Reasons that the machine stopped other than halting.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VmError {
+    /// The machine has already halted.
+    Halted,
+    /// The tryte at the program counter isn't a valid instruction.
+    InvalidInstruction { address: Number<5>, word: Tryte },
+    /// An arithmetic instruction produced a result that doesn't fit a tryte.
+    Overflow { address: Number<5> },
+    /// The machine didn't halt within the number of steps it was allowed.
+    StepLimit { steps: usize }
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::Halted =>
+                write!(f, "machine has halted"),
+            VmError::InvalidInstruction { address, word } =>
+                write!(f, "invalid instruction {} at address {}", word, address.to_i128()),
+            VmError::Overflow { address } =>
+                write!(f, "arithmetic overflow at address {}", address.to_i128()),
+            VmError::StepLimit { steps } =>
+                write!(f, "machine did not halt within {} steps", steps)
+        }
+    }
+}
+
+impl Error for VmError {}
+
+/// The state of a ternary virtual machine: its registers, program counter and
+/// memory.
+///
+/// A faulting instruction leaves the machine unchanged, with the program
+/// counter still pointing at it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Machine {
+    registers: [Tryte; 3],
+    pc: Number<5>,
+    memory: [Tryte; Machine::MEMORY_SIZE],
+    halted: bool
+}
+
+impl Machine {
+    /// The number of trytes of memory, one for each 5 trit address.
+    pub const MEMORY_SIZE: usize = 243;
+
+    /// Builds a machine with every register and memory tryte set to zero.
+    pub fn new() -> Self {
+        Machine {
+            registers: [Tryte::ZERO; 3],
+            pc: Number::<5>::ZERO,
+            memory: [Tryte::ZERO; Machine::MEMORY_SIZE],
+            halted: false
+        }
+    }
+
+    /// Copies words into consecutive memory addresses from `start`, wrapping
+    /// around from the top of memory to the bottom.
+    ///
+    /// # Panics
+    /// Panics if there are more words than trytes of memory.
+    pub fn load(&mut self, start: Number<5>, words: &[Tryte]) {
+        assert!(words.len() <= Machine::MEMORY_SIZE, "Program is larger than the memory of the machine");
+
+        let mut address = start;
+        for word in words {
+            self.write(address, *word);
+            address.inc();
+        }
+    }
+
+    /// Returns the value of a register.
+    pub fn register(&self, register: Register) -> Tryte {
+        self.registers[register.index()]
+    }
+
+    /// Sets the value of a register.
+    pub fn set_register(&mut self, register: Register, value: Tryte) {
+        self.registers[register.index()] = value;
+    }
+
+    /// Returns the address of the next instruction to execute.
+    pub fn pc(&self) -> Number<5> {
+        self.pc
+    }
+
+    /// Moves the program counter, which also resumes a halted machine.
+    pub fn set_pc(&mut self, address: Number<5>) {
+        self.pc = address;
+        self.halted = false;
+    }
+
+    /// Returns true if the machine has executed a halt instruction.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Reads the tryte at a memory address.
+    pub fn read(&self, address: Number<5>) -> Tryte {
+        self.memory[Machine::index(address)]
+    }
+
+    /// Writes a tryte to a memory address.
+    pub fn write(&mut self, address: Number<5>, value: Tryte) {
+        self.memory[Machine::index(address)] = value;
+    }
+
+    /// Executes the instruction at the program counter. A halt instruction
+    /// leaves the program counter pointing at itself.
+    pub fn step(&mut self) -> Result<(), VmError> {
+        if self.halted {
+            return Err(VmError::Halted);
+        }
+
+        let address = self.pc;
+        let word = self.read(address);
+        let instruction = Instruction::decode(word).ok_or(VmError::InvalidInstruction { address, word })?;
+        let overflow = VmError::Overflow { address };
+
+        let mut next = address;
+        next.inc();
+        match instruction {
+            Instruction::Halt => {
+                self.halted = true;
+                next = address;
+            },
+            Instruction::Load { register, address } => self.set_register(register, self.read(address)),
+            Instruction::Store { register, address } => self.write(address, self.register(register)),
+            Instruction::Add { register, address } => {
+                let value = self.value(register).checked_add(self.read(address).into()).ok_or(overflow)?;
+                self.set_register(register, value.into());
+            },
+            Instruction::Sub { register, address } => {
+                let value = self.value(register).checked_sub(self.read(address).into()).ok_or(overflow)?;
+                self.set_register(register, value.into());
+            },
+            Instruction::Mul { register, address } => {
+                let value = self.value(register).checked_mul(self.read(address).into()).ok_or(overflow)?;
+                self.set_register(register, value.into());
+            },
+            Instruction::LoadImmediate { register, value } => self.set_register(register, value.resize::<9>().into()),
+            Instruction::AddImmediate { register, value } => {
+                let value = self.value(register).checked_add(value.resize()).ok_or(overflow)?;
+                self.set_register(register, value.into());
+            },
+            Instruction::Shift { register, positions } => {
+                let value = self.value(register);
+                let shifted = match positions.signum() {
+                    Trit::NEG => value >> positions.abs().to_i128() as usize,
+                    _ => value << positions.to_i128() as usize
+                };
+                self.set_register(register, shifted.into());
+            },
+            Instruction::Jump { address } => next = address,
+            Instruction::Branch { register, sign, address } => {
+                if self.value(register).signum() == sign {
+                    next = address;
+                }
+            }
+        }
+        self.pc = next;
+        Ok(())
+    }
+
+    /// Executes instructions until the machine halts, returning the number of
+    /// steps taken including the halt.
+    /// * `max_steps` - The number of steps after which to give up
+    pub fn run(&mut self, max_steps: usize) -> Result<usize, VmError> {
+        for steps in 1..=max_steps {
+            self.step()?;
+            if self.halted {
+                return Ok(steps);
+            }
+        }
+        Err(VmError::StepLimit { steps: max_steps })
+    }
+
+    fn value(&self, register: Register) -> Number<9> {
+        self.register(register).into()
+    }
+
+    fn index(address: Number<5>) -> usize {
+        (address.to_i128() + Machine::MEMORY_SIZE as i128 / 2) as usize
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tryte(value: i16) -> Tryte {
+        Tryte::try_from(value).unwrap()
+    }
+
+    fn address(value: i32) -> Number<5> {
+        Number::<5>::from(value)
+    }
+
+    #[test]
+    fn factorial_loop() {
+        // Computes 7! with A as the product and B as the counter, keeping the
+        // counter in memory for the multiplication
+        let program = [
+            Instruction::LoadImmediate { register: Register::A, value: address(1) },
+            Instruction::LoadImmediate { register: Register::B, value: address(7) },
+            Instruction::Store { register: Register::B, address: address(-1) },
+            Instruction::Mul { register: Register::A, address: address(-1) },
+            Instruction::AddImmediate { register: Register::B, value: address(-1) },
+            Instruction::Branch { register: Register::B, sign: Trit::POS, address: address(2) },
+            Instruction::Store { register: Register::A, address: address(-2) },
+            Instruction::Halt
+        ];
+
+        let mut machine = Machine::new();
+        machine.load(Number::<5>::ZERO, &program.map(Instruction::encode));
+        assert_eq!(machine.run(1000), Ok(3 + 4 * 7 + 1));
+        assert_eq!(machine.read(address(-2)), tryte(5040));
+        assert_eq!(machine.register(Register::B), Tryte::ZERO);
+        assert_eq!(machine.pc(), address(7));
+        assert!(machine.is_halted());
+        assert_eq!(machine.step(), Err(VmError::Halted));
+
+        // Moving the program counter resumes the machine
+        machine.set_pc(address(7));
+        assert_eq!(machine.run(1), Ok(1));
+    }
+
+    #[test]
+    fn arithmetic_and_shifts() {
+        let mut machine = Machine::new();
+        machine.write(address(50), tryte(-200));
+        machine.load(address(-121), &[
+            Instruction::LoadImmediate { register: Register::C, value: address(-100) },
+            Instruction::Sub { register: Register::C, address: address(50) },
+            Instruction::Shift { register: Register::C, positions: address(2) },
+            Instruction::Add { register: Register::C, address: address(50) },
+            Instruction::Shift { register: Register::C, positions: address(-1) },
+            Instruction::Jump { address: address(121) }
+        ].map(Instruction::encode));
+        machine.write(address(121), Instruction::Halt.encode());
+
+        machine.set_pc(address(-121));
+        assert_eq!(machine.run(10), Ok(7));
+        // (100 * 9 - 200) / 3 rounds 233.3 to 233
+        assert_eq!(machine.register(Register::C), tryte(233));
+        assert_eq!(machine.register(Register::A), Tryte::ZERO);
+    }
+
+    #[test]
+    fn faults() {
+        let mut machine = Machine::new();
+        machine.write(address(10), Tryte::MAX);
+        machine.load(Number::<5>::ZERO, &[
+            Instruction::LoadImmediate { register: Register::A, value: address(2) },
+            Instruction::Mul { register: Register::A, address: address(10) }
+        ].map(Instruction::encode));
+
+        assert_eq!(machine.run(10), Err(VmError::Overflow { address: address(1) }));
+        assert_eq!(machine.pc(), address(1));
+        assert_eq!(machine.register(Register::A), tryte(2));
+
+        machine.set_pc(address(10));
+        assert_eq!(machine.step(), Err(VmError::InvalidInstruction { address: address(10), word: Tryte::MAX }));
+        assert_eq!(VmError::Overflow { address: address(1) }.to_string(), "arithmetic overflow at address 1");
+
+        // A jump to itself never halts
+        machine.write(address(20), Instruction::Jump { address: address(20) }.encode());
+        machine.set_pc(address(20));
+        assert_eq!(machine.run(50), Err(VmError::StepLimit { steps: 50 }));
+    }
+}
@@ -0,0 +1,424 @@
+//! An assembler and disassembler for the ternary virtual machine.
+//!
+//! Each line of assembly holds an optional label, an optional instruction or
+//! directive, and an optional comment starting with `;`:
+//! ```text
+//! loop:   addi b, -1      ; count down
+//!         brp b, loop
+//! ```
+//! Instructions are written as their mnemonic followed by comma-separated
+//! operands, as listed on [`Instruction`]. Registers are `a`, `b` and `c`, and
+//! every other operand is a label, a decimal integer or a balanced ternary
+//! integer prefixed with `0t`, such as `0t+-0`. Labels start with a letter or
+//! underscore and can be used before they are defined. Mnemonics, directives
+//! and registers are case-insensitive but labels are not.
+//!
+//! Words are assembled at consecutive addresses from zero, and two directives
+//! control the layout:
+//! * `.org address` - Continues assembling at an address
+//! * `.word value` - Emits a tryte of data, from -9841 to 9841
+//!
+//! ```
+//! # use balanced_ternary::vm::{asm, Machine, Register};
+//! let program = asm::assemble("
+//!         ldi a, 0
+//!         ldi b, 5
+//! loop:   add a, step         ; a += 10 five times
+//!         addi b, -1
+//!         brp b, loop
+//!         hlt
+//! step:   .word 10
+//! ").unwrap();
+//!
+//! let mut machine = Machine::new();
+//! program.load_into(&mut machine);
+//! machine.run(100).unwrap();
+//! assert_eq!(i16::from(machine.register(Register::A)), 50);
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{self, Write};
+
+use crate::number::Number;
+use crate::trit::Trit;
+use crate::tryte::Tryte;
+use crate::vm::{Instruction, Machine, Register};
+
+/// Errors found while assembling, each holding the line number, counted from
+/// one, at which it occurred.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AsmError {
+    /// The mnemonic isn't an instruction or directive.
+    UnknownMnemonic { line: usize, mnemonic: String },
+    /// A label isn't a valid identifier.
+    InvalidLabel { line: usize, label: String },
+    /// A label was defined more than once.
+    DuplicateLabel { line: usize, label: String },
+    /// An operand refers to a label that is never defined.
+    UndefinedLabel { line: usize, label: String },
+    /// An operand isn't a register, label or integer as required.
+    InvalidOperand { line: usize, operand: String },
+    /// An instruction or directive has the wrong number of operands.
+    WrongOperandCount { line: usize, found: usize, expected: usize },
+    /// A value doesn't fit in its operand or data word.
+    OutOfRange { line: usize, value: i128 },
+    /// A word or label would be placed outside of memory.
+    AddressOutOfRange { line: usize }
+}
+
+impl AsmError {
+    /// Returns the line number, counted from one, at which the error occurred.
+    pub fn line(&self) -> usize {
+        match self {
+            AsmError::UnknownMnemonic { line, .. } |
+            AsmError::InvalidLabel { line, .. } |
+            AsmError::DuplicateLabel { line, .. } |
+            AsmError::UndefinedLabel { line, .. } |
+            AsmError::InvalidOperand { line, .. } |
+            AsmError::WrongOperandCount { line, .. } |
+            AsmError::OutOfRange { line, .. } |
+            AsmError::AddressOutOfRange { line } => *line
+        }
+    }
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line())?;
+        match self {
+            AsmError::UnknownMnemonic { mnemonic, .. } =>
+                write!(f, "unknown mnemonic `{}`", mnemonic),
+            AsmError::InvalidLabel { label, .. } =>
+                write!(f, "invalid label `{}`", label),
+            AsmError::DuplicateLabel { label, .. } =>
+                write!(f, "label `{}` is already defined", label),
+            AsmError::UndefinedLabel { label, .. } =>
+                write!(f, "label `{}` is not defined", label),
+            AsmError::InvalidOperand { operand, .. } =>
+                write!(f, "invalid operand `{}`", operand),
+            AsmError::WrongOperandCount { found, expected, .. } =>
+                write!(f, "found {} operands but expected {}", found, expected),
+            AsmError::OutOfRange { value, .. } =>
+                write!(f, "value {} is out of range", value),
+            AsmError::AddressOutOfRange { .. } =>
+                write!(f, "address is outside of memory")
+        }
+    }
+}
+
+impl Error for AsmError {}
+
+/// An assembled program: the trytes to place in memory and the addresses of
+/// its labels.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Program {
+    words: Vec<(Number<5>, Tryte)>,
+    labels: Vec<(String, Number<5>)>
+}
+
+impl Program {
+    /// Returns each assembled tryte with its address, in the order they
+    /// appear in the source.
+    pub fn words(&self) -> &[(Number<5>, Tryte)] {
+        &self.words
+    }
+
+    /// Returns the address of a label, or `None` if it isn't defined.
+    pub fn label(&self, name: &str) -> Option<Number<5>> {
+        self.labels.iter().find(|(label, _)| label == name).map(|(_, address)| *address)
+    }
+
+    /// Writes the program into the memory of a machine, leaving every other
+    /// address untouched.
+    pub fn load_into(&self, machine: &mut Machine) {
+        for (address, word) in &self.words {
+            machine.write(*address, *word);
+        }
+    }
+}
+
+/// Assembles source text into a program, reporting the first error found.
+pub fn assemble(source: &str) -> Result<Program, AsmError> {
+    let lines = source.lines().enumerate()
+        .map(|(idx, text)| Line::parse(idx + 1, text))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The first pass only places the labels, so that they can be referred to
+    // before they are defined
+    let mut labels: Vec<(String, Number<5>)> = Vec::new();
+    let mut location = 0;
+    for line in &lines {
+        if let Some(label) = line.label {
+            if labels.iter().any(|(name, _)| name == label) {
+                return Err(AsmError::DuplicateLabel { line: line.number, label: label.to_string() });
+            }
+            labels.push((label.to_string(), line.address(location)?));
+        }
+        match line.mnemonic {
+            Some(mnemonic) if mnemonic.eq_ignore_ascii_case(".org") => location = line.origin(&labels)?,
+            Some(_) => location += 1,
+            None => {}
+        }
+    }
+
+    let mut words = Vec::new();
+    location = 0;
+    for line in &lines {
+        let Some(mnemonic) = line.mnemonic else {
+            continue;
+        };
+        if mnemonic.eq_ignore_ascii_case(".org") {
+            location = line.origin(&labels)?;
+            continue;
+        }
+
+        let word = if mnemonic.eq_ignore_ascii_case(".word") {
+            let [value] = line.operands()?;
+            let value = line.value(value, &labels)?;
+            Number::<9>::try_from(value).map(Tryte::from).map_err(|_| AsmError::OutOfRange { line: line.number, value })?
+        } else {
+            line.instruction(mnemonic, &labels)?.encode()
+        };
+        words.push((line.address(location)?, word));
+        location += 1;
+    }
+    Ok(Program { words, labels })
+}
+
+/// Renders trytes as a listing, one per line, giving the address in decimal,
+/// the trits of the tryte and the instruction it decodes to. Trytes that
+/// aren't valid instructions are shown as `.word` directives, so the last
+/// column of each line can be assembled back into the same tryte.
+/// ```
+/// # use balanced_ternary::number::Number;
+/// # use balanced_ternary::vm::{asm, Machine};
+/// let program = asm::assemble("ldi c, 13\njmp -1\n.word 9841").unwrap();
+/// let mut machine = Machine::new();
+/// program.load_into(&mut machine);
+///
+/// let words: [_; 3] = core::array::from_fn(|idx| machine.read(Number::<5>::from(idx as i32)));
+/// assert_eq!(asm::disassemble(Number::<5>::ZERO, &words), concat!(
+///     "   0  +-0+00+++  ldi c, 13\n",
+///     "   1  +0000000-  jmp -1\n",
+///     "   2  +++++++++  .word 9841\n"
+/// ));
+/// ```
+pub fn disassemble(start: Number<5>, words: &[Tryte]) -> String {
+    let mut listing = String::new();
+    let mut address = start;
+    for word in words {
+        write!(listing, "{:>4}  ", address.to_i128()).unwrap();
+        for trit in Number::<9>::from(*word).as_trits() {
+            write!(listing, "{}", trit).unwrap();
+        }
+        match Instruction::decode(*word) {
+            Some(instruction) => writeln!(listing, "  {}", instruction),
+            None => writeln!(listing, "  .word {}", i16::from(*word))
+        }.unwrap();
+        address.inc();
+    }
+    listing
+}
+
+/// A line of source split into its parts, without its comment.
+struct Line<'a> {
+    number: usize,
+    label: Option<&'a str>,
+    mnemonic: Option<&'a str>,
+    operands: Vec<&'a str>
+}
+
+impl <'a> Line<'a> {
+    fn parse(number: usize, text: &'a str) -> Result<Self, AsmError> {
+        let text = text.split(';').next().unwrap_or_default().trim();
+        let (label, text) = match text.split_once(':') {
+            Some((label, text)) => {
+                let label = label.trim();
+                if !is_identifier(label) {
+                    return Err(AsmError::InvalidLabel { line: number, label: label.to_string() });
+                }
+                (Some(label), text.trim())
+            },
+            None => (None, text)
+        };
+
+        let (mnemonic, operands) = match text.split_once(char::is_whitespace) {
+            Some((mnemonic, operands)) => (Some(mnemonic), operands.split(',').map(str::trim).collect()),
+            None if text.is_empty() => (None, Vec::new()),
+            None => (Some(text), Vec::new())
+        };
+        Ok(Line { number, label, mnemonic, operands })
+    }
+
+    fn operands<const C: usize>(&self) -> Result<[&'a str; C], AsmError> {
+        <[&str; C]>::try_from(self.operands.as_slice())
+            .map_err(|_| AsmError::WrongOperandCount { line: self.number, found: self.operands.len(), expected: C })
+    }
+
+    fn instruction(&self, mnemonic: &str, labels: &[(String, Number<5>)]) -> Result<Instruction, AsmError> {
+        let mnemonic = mnemonic.to_ascii_lowercase();
+        if mnemonic == "hlt" {
+            let [] = self.operands()?;
+            return Ok(Instruction::Halt);
+        }
+        if mnemonic == "jmp" {
+            let [address] = self.operands()?;
+            return Ok(Instruction::Jump { address: self.operand(address, labels)? });
+        }
+
+        let build = match mnemonic.as_str() {
+            "ld" => |register, address| Instruction::Load { register, address },
+            "st" => |register, address| Instruction::Store { register, address },
+            "add" => |register, address| Instruction::Add { register, address },
+            "sub" => |register, address| Instruction::Sub { register, address },
+            "mul" => |register, address| Instruction::Mul { register, address },
+            "ldi" => |register, value| Instruction::LoadImmediate { register, value },
+            "addi" => |register, value| Instruction::AddImmediate { register, value },
+            "sh" => |register, positions| Instruction::Shift { register, positions },
+            "brn" => |register, address| Instruction::Branch { register, sign: Trit::NEG, address },
+            "brz" => |register, address| Instruction::Branch { register, sign: Trit::ZERO, address },
+            "brp" => |register, address| Instruction::Branch { register, sign: Trit::POS, address },
+            _ => return Err(AsmError::UnknownMnemonic { line: self.number, mnemonic: mnemonic.to_string() })
+        };
+        let [register, operand] = self.operands()?;
+        Ok(build(self.register(register)?, self.operand(operand, labels)?))
+    }
+
+    fn register(&self, operand: &str) -> Result<Register, AsmError> {
+        match operand.to_ascii_lowercase().as_str() {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            "c" => Ok(Register::C),
+            _ => Err(AsmError::InvalidOperand { line: self.number, operand: operand.to_string() })
+        }
+    }
+
+    /// Resolves an address or immediate operand of 5 trits.
+    fn operand(&self, operand: &str, labels: &[(String, Number<5>)]) -> Result<Number<5>, AsmError> {
+        let value = self.value(operand, labels)?;
+        Number::<5>::try_from(value).map_err(|_| AsmError::OutOfRange { line: self.number, value })
+    }
+
+    fn value(&self, operand: &str, labels: &[(String, Number<5>)]) -> Result<i128, AsmError> {
+        if let Some((_, address)) = labels.iter().find(|(label, _)| label == operand) {
+            return Ok(address.to_i128());
+        }
+
+        let value = match operand.strip_prefix("0t") {
+            Some(trits) => trits.parse::<Number<40>>().ok().map(Number::to_i128),
+            None => operand.parse::<i128>().ok()
+        };
+        match value {
+            Some(value) => Ok(value),
+            None if is_identifier(operand) => Err(AsmError::UndefinedLabel { line: self.number, label: operand.to_string() }),
+            None => Err(AsmError::InvalidOperand { line: self.number, operand: operand.to_string() })
+        }
+    }
+
+    /// Resolves the operand of an `.org` directive, which can only refer to
+    /// labels defined before it.
+    fn origin(&self, labels: &[(String, Number<5>)]) -> Result<i128, AsmError> {
+        let [origin] = self.operands()?;
+        Ok(self.operand(origin, labels)?.to_i128())
+    }
+
+    fn address(&self, location: i128) -> Result<Number<5>, AsmError> {
+        Number::<5>::try_from(location).map_err(|_| AsmError::AddressOutOfRange { line: self.number })
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next().is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assembles_programs() {
+        let program = assemble("
+            ; 7 factorial
+            start:  LDI a, 1
+                    ldi b, 0t+-+        ; 7
+            loop:   st b, counter
+                    mul a, counter
+                    addi b, -1
+                    brp b, loop
+                    st a, result
+                    hlt
+
+                    .org -2
+            result: .word 0
+            counter:
+        ").unwrap();
+
+        assert_eq!(program.label("start"), Some(Number::<5>::ZERO));
+        assert_eq!(program.label("loop"), Some(Number::<5>::from(2)));
+        assert_eq!(program.label("counter"), Some(Number::<5>::from(-1)));
+        assert_eq!(program.label("Loop"), None);
+        assert_eq!(program.words().len(), 9);
+
+        let mut machine = Machine::new();
+        program.load_into(&mut machine);
+        assert_eq!(machine.run(1000), Ok(3 + 4 * 7 + 1));
+        assert_eq!(i16::from(machine.read(program.label("result").unwrap())), 5040);
+    }
+
+    #[test]
+    fn instructions_round_trip_through_text() {
+        let mut word = Tryte::MIN;
+        loop {
+            if let Some(instruction) = Instruction::decode(word) {
+                let program = assemble(&instruction.to_string()).unwrap();
+                assert_eq!(program.words(), &[(Number::<5>::ZERO, word)]);
+            }
+            if word == Tryte::MAX {
+                break;
+            }
+            let mut number = Number::<9>::from(word);
+            number.inc();
+            word = Tryte::from(number);
+        }
+    }
+
+    #[test]
+    fn disassembles_listings() {
+        let program = assemble("ldi c, 13\njmp -1\n.word 9841\n.word -9841").unwrap();
+        let words: Vec<Tryte> = program.words().iter().map(|(_, word)| *word).collect();
+        let listing = disassemble(Number::<5>::from(-1), &words);
+        assert_eq!(listing, concat!(
+            "  -1  +-0+00+++  ldi c, 13\n",
+            "   0  +0000000-  jmp -1\n",
+            "   1  +++++++++  .word 9841\n",
+            "   2  ---------  .word -9841\n"
+        ));
+
+        // The listing assembles back to the same words
+        let source: String = listing.lines().map(|line| line[17..].to_string() + "\n").collect();
+        assert_eq!(assemble(&source).unwrap().words(), program.words());
+    }
+
+    #[test]
+    fn reports_errors() {
+        let error = |source: &str| assemble(source).unwrap_err();
+
+        assert_eq!(error("nop"), AsmError::UnknownMnemonic { line: 1, mnemonic: "nop".to_string() });
+        assert_eq!(error("\n1x: hlt"), AsmError::InvalidLabel { line: 2, label: "1x".to_string() });
+        assert_eq!(error("a: hlt\na: hlt"), AsmError::DuplicateLabel { line: 2, label: "a".to_string() });
+        assert_eq!(error("jmp nowhere"), AsmError::UndefinedLabel { line: 1, label: "nowhere".to_string() });
+        assert_eq!(error("ld d, 0"), AsmError::InvalidOperand { line: 1, operand: "d".to_string() });
+        assert_eq!(error("ld a, 0t+x"), AsmError::InvalidOperand { line: 1, operand: "0t+x".to_string() });
+        assert_eq!(error("add a"), AsmError::WrongOperandCount { line: 1, found: 1, expected: 2 });
+        assert_eq!(error("hlt 1"), AsmError::WrongOperandCount { line: 1, found: 1, expected: 0 });
+        assert_eq!(error("ldi a, 122"), AsmError::OutOfRange { line: 1, value: 122 });
+        assert_eq!(error(".word -9842"), AsmError::OutOfRange { line: 1, value: -9842 });
+        assert_eq!(error(".org 121\nhlt\nhlt"), AsmError::AddressOutOfRange { line: 3 });
+
+        assert_eq!(error("\n\nld a,").to_string(), "line 3: invalid operand ``");
+    }
+}
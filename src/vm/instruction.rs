@@ -0,0 +1,224 @@
+use core::fmt;
+
+use crate::number::Number;
+use crate::trit::Trit;
+use crate::tryte::Tryte;
+
+/// One of the three registers of the machine, selected by a single trit of an
+/// instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Register {
+    /// The register selected by [`Trit::NEG`].
+    A,
+    /// The register selected by [`Trit::ZERO`].
+    B,
+    /// The register selected by [`Trit::POS`].
+    C
+}
+
+impl Register {
+    /// Every register, in the order of the trits selecting them.
+    pub const ALL: [Register; 3] = [Register::A, Register::B, Register::C];
+
+    /// Returns the register selected by a trit.
+    pub const fn from_trit(trit: Trit) -> Self {
+        match trit {
+            Trit::NEG => Register::A,
+            Trit::ZERO => Register::B,
+            Trit::POS => Register::C
+        }
+    }
+
+    /// Returns the trit selecting this register.
+    pub const fn to_trit(self) -> Trit {
+        match self {
+            Register::A => Trit::NEG,
+            Register::B => Trit::ZERO,
+            Register::C => Trit::POS
+        }
+    }
+
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match self {
+            Register::A => 'a',
+            Register::B => 'b',
+            Register::C => 'c'
+        })
+    }
+}
+
+/// A single instruction, encoded in one tryte.
+///
+/// From the most significant trit, a tryte holds a 3 trit opcode, a trit
+/// selecting the [`Register`] and a 5 trit operand. The operand is either a
+/// memory address or an immediate value, both from -121 to 121. Arithmetic on
+/// registers faults rather than wrapping when the result doesn't fit a tryte.
+///
+/// | Opcode | Instruction | Effect |
+/// |-------:|-------------|--------|
+/// | 0 | [`Halt`](Self::Halt) | Stops the machine |
+/// | 1 | [`Load`](Self::Load) | `r = memory[address]` |
+/// | 2 | [`Store`](Self::Store) | `memory[address] = r` |
+/// | 3 | [`Add`](Self::Add) | `r += memory[address]` |
+/// | 4 | [`Sub`](Self::Sub) | `r -= memory[address]` |
+/// | 5 | [`Mul`](Self::Mul) | `r *= memory[address]` |
+/// | 6 | [`LoadImmediate`](Self::LoadImmediate) | `r = value` |
+/// | 7 | [`AddImmediate`](Self::AddImmediate) | `r += value` |
+/// | 8 | [`Shift`](Self::Shift) | `r = r << positions`, shifting right for negative positions |
+/// | 9 | [`Jump`](Self::Jump) | `pc = address` |
+/// | 10, 11, 12 | [`Branch`](Self::Branch) | `pc = address` if the sign of `r` is -, 0 or + |
+///
+/// A tryte of zero decodes as `Halt`, so running into cleared memory stops the
+/// machine. Instructions are displayed in the assembly syntax of
+/// [`asm`](crate::vm::asm), with the mnemonics `hlt`, `ld`, `st`, `add`, `sub`,
+/// `mul`, `ldi`, `addi`, `sh`, `jmp` and `brn`/`brz`/`brp` in the order of the
+/// table above.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    /// Stops the machine.
+    Halt,
+    /// Loads a tryte from memory into a register.
+    Load { register: Register, address: Number<5> },
+    /// Stores a register into memory.
+    Store { register: Register, address: Number<5> },
+    /// Adds a tryte from memory to a register.
+    Add { register: Register, address: Number<5> },
+    /// Subtracts a tryte from memory from a register.
+    Sub { register: Register, address: Number<5> },
+    /// Multiplies a register by a tryte from memory.
+    Mul { register: Register, address: Number<5> },
+    /// Sets a register to an immediate value.
+    LoadImmediate { register: Register, value: Number<5> },
+    /// Adds an immediate value to a register.
+    AddImmediate { register: Register, value: Number<5> },
+    /// Shifts a register left by a number of trits, or right if the number is
+    /// negative. Trits shifted out of the top are lost and shifting right
+    /// rounds to the nearest integer.
+    Shift { register: Register, positions: Number<5> },
+    /// Continues execution at an address.
+    Jump { address: Number<5> },
+    /// Continues execution at an address if the sign of a register matches.
+    Branch { register: Register, sign: Trit, address: Number<5> }
+}
+
+impl Instruction {
+    /// Decodes an instruction from a tryte, or returns `None` if the opcode
+    /// isn't assigned. Operands that an instruction doesn't use must be zero.
+    pub fn decode(word: Tryte) -> Option<Self> {
+        let (opcode, fields) = Number::<9>::from(word).split::<3, 6>();
+        let (register, operand) = fields.split::<1, 5>();
+        let register = Register::from_trit(register[0]);
+
+        let instruction = match opcode.to_i128() {
+            0 => Instruction::Halt,
+            1 => Instruction::Load { register, address: operand },
+            2 => Instruction::Store { register, address: operand },
+            3 => Instruction::Add { register, address: operand },
+            4 => Instruction::Sub { register, address: operand },
+            5 => Instruction::Mul { register, address: operand },
+            6 => Instruction::LoadImmediate { register, value: operand },
+            7 => Instruction::AddImmediate { register, value: operand },
+            8 => Instruction::Shift { register, positions: operand },
+            9 => Instruction::Jump { address: operand },
+            code @ 10..=12 => Instruction::Branch { register, sign: Trit::try_from(code as i8 - 11).ok()?, address: operand },
+            _ => return None
+        };
+
+        // Rejecting unused operands keeps the encoding of each instruction
+        // unique, so decoding and encoding round trip
+        if instruction.encode() == word {Some(instruction)} else {None}
+    }
+
+    /// Encodes the instruction as a tryte.
+    pub fn encode(self) -> Tryte {
+        let (opcode, register, operand) = match self {
+            Instruction::Halt => (0, Register::B, Number::<5>::ZERO),
+            Instruction::Load { register, address } => (1, register, address),
+            Instruction::Store { register, address } => (2, register, address),
+            Instruction::Add { register, address } => (3, register, address),
+            Instruction::Sub { register, address } => (4, register, address),
+            Instruction::Mul { register, address } => (5, register, address),
+            Instruction::LoadImmediate { register, value } => (6, register, value),
+            Instruction::AddImmediate { register, value } => (7, register, value),
+            Instruction::Shift { register, positions } => (8, register, positions),
+            Instruction::Jump { address } => (9, Register::B, address),
+            Instruction::Branch { register, sign, address } => (11 + i8::from(sign), register, address)
+        };
+
+        let fields: Number<6> = Number::<1>::from_trits([register.to_trit()]).concat(operand);
+        Tryte::from(Number::<3>::from(opcode).concat::<6, 9>(fields))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Halt => write!(f, "hlt"),
+            Instruction::Load { register, address } => write!(f, "ld {}, {}", register, address.to_i128()),
+            Instruction::Store { register, address } => write!(f, "st {}, {}", register, address.to_i128()),
+            Instruction::Add { register, address } => write!(f, "add {}, {}", register, address.to_i128()),
+            Instruction::Sub { register, address } => write!(f, "sub {}, {}", register, address.to_i128()),
+            Instruction::Mul { register, address } => write!(f, "mul {}, {}", register, address.to_i128()),
+            Instruction::LoadImmediate { register, value } => write!(f, "ldi {}, {}", register, value.to_i128()),
+            Instruction::AddImmediate { register, value } => write!(f, "addi {}, {}", register, value.to_i128()),
+            Instruction::Shift { register, positions } => write!(f, "sh {}, {}", register, positions.to_i128()),
+            Instruction::Jump { address } => write!(f, "jmp {}", address.to_i128()),
+            Instruction::Branch { register, sign, address } => {
+                let condition = match sign {
+                    Trit::NEG => 'n',
+                    Trit::ZERO => 'z',
+                    Trit::POS => 'p'
+                };
+                write!(f, "br{} {}, {}", condition, register, address.to_i128())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encoding_round_trips() {
+        let address = Number::<5>::from(-40);
+        let instructions = [
+            Instruction::Halt,
+            Instruction::Load { register: Register::A, address },
+            Instruction::Store { register: Register::B, address },
+            Instruction::Add { register: Register::C, address },
+            Instruction::Sub { register: Register::A, address },
+            Instruction::Mul { register: Register::B, address },
+            Instruction::LoadImmediate { register: Register::C, value: Number::<5>::MAX },
+            Instruction::AddImmediate { register: Register::A, value: Number::<5>::MIN },
+            Instruction::Shift { register: Register::B, positions: Number::<5>::from(-2) },
+            Instruction::Jump { address },
+            Instruction::Branch { register: Register::C, sign: Trit::NEG, address },
+            Instruction::Branch { register: Register::C, sign: Trit::ZERO, address },
+            Instruction::Branch { register: Register::C, sign: Trit::POS, address }
+        ];
+        for instruction in instructions {
+            assert_eq!(Instruction::decode(instruction.encode()), Some(instruction));
+        }
+
+        assert_eq!(Instruction::Halt.encode(), Tryte::ZERO);
+        assert_eq!(Instruction::Load { register: Register::C, address: Number::<5>::from(1) }.encode(), Tryte::from(Number::<9>::from("++0000+")));
+    }
+
+    #[test]
+    fn invalid_words() {
+        // Opcodes outside the table
+        assert_eq!(Instruction::decode(Tryte::from(Number::<9>::from("-000000"))), None);
+        assert_eq!(Instruction::decode(Tryte::MAX), None);
+
+        // Operands of a halt or register of a jump must be zero
+        assert_eq!(Instruction::decode(Tryte::from(Number::<9>::from("+"))), None);
+        assert_eq!(Instruction::decode(Tryte::from(Number::<9>::from("+00+00000"))), None);
+    }
+}
@@ -0,0 +1,212 @@
+//! WebAssembly bindings, exposing [`BigTernary`] to JavaScript through
+//! wasm-bindgen.
+//!
+//! The class is exported as `BigTernary`, with methods named in the usual
+//! JavaScript style. Integers are created from trit strings, decimal strings
+//! or JavaScript numbers, and never overflow. Operations that can fail, such as
+//! division by zero or parsing an invalid string, throw an `Error`.
+//!
+//! The module is built for the `wasm32-unknown-unknown` target as a dynamic
+//! library, and then processed by the `wasm-bindgen` tool to generate the
+//! JavaScript glue:
+//! ```text
+//! cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/balanced_ternary.wasm
+//! ```
+//! ```text
+//! import init, { BigTernary } from "./pkg/balanced_ternary.js";
+//! await init();
+//! const x = new BigTernary("+0--");
+//! x.mul(BigTernary.fromNumber(3)).toDecimalString();  // "69"
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::big_ternary::BigTernary;
+use crate::error::Error;
+
+/// The largest integer that a JavaScript number holds exactly, 2^53 - 1.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+fn to_js_error(error: impl Into<Error>) -> JsError {
+    JsError::new(&error.into().to_string())
+}
+
+/// A balanced ternary integer of unbounded precision.
+#[wasm_bindgen(js_name = BigTernary)]
+#[derive(Clone)]
+pub struct WasmBigTernary(BigTernary);
+
+// The arithmetic methods are named as JavaScript callers expect, rather than
+// implementing the operator traits, which wasm-bindgen can't export
+#[allow(clippy::should_implement_trait)]
+#[wasm_bindgen(js_class = BigTernary)]
+impl WasmBigTernary {
+    /// Parses a string of `-`, `0` and `+` trits, most significant first.
+    #[wasm_bindgen(constructor)]
+    pub fn new(trits: &str) -> Result<WasmBigTernary, JsError> {
+        trits.parse().map(WasmBigTernary).map_err(to_js_error)
+    }
+
+    /// Parses a decimal integer of any length, with an optional sign.
+    #[wasm_bindgen(js_name = fromDecimal)]
+    pub fn from_decimal(decimal: &str) -> Result<WasmBigTernary, JsError> {
+        BigTernary::from_decimal_str(decimal).map(WasmBigTernary).map_err(to_js_error)
+    }
+
+    /// Converts a JavaScript number, which must be a safe integer so that it
+    /// is exact.
+    #[wasm_bindgen(js_name = fromNumber)]
+    pub fn from_number(value: f64) -> Result<WasmBigTernary, JsError> {
+        if value.fract() != 0.0 || !(-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&value) {
+            return Err(JsError::new("expected a safe integer"));
+        }
+        Ok(WasmBigTernary(BigTernary::from(value as i64)))
+    }
+
+    /// Writes the integer as trits from the most significant, without leading
+    /// zeros, or `0` for zero.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_trit_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Writes the integer in decimal.
+    #[wasm_bindgen(js_name = toDecimalString)]
+    pub fn to_decimal_string(&self) -> String {
+        self.0.to_decimal_string()
+    }
+
+    /// Converts the integer to the nearest JavaScript number, which is only
+    /// exact for safe integers.
+    #[wasm_bindgen(js_name = toNumber)]
+    pub fn to_number(&self) -> f64 {
+        // Parsing the decimal form rounds correctly at any size
+        self.0.to_decimal_string().parse().unwrap_or(f64::NAN)
+    }
+
+    /// Returns the trits as -1, 0 and 1 from the most significant, for
+    /// drawing the integer trit by trit. Zero has no trits.
+    pub fn trits(&self) -> Vec<i8> {
+        self.0.as_trits().iter().map(|&trit| i8::from(trit)).collect()
+    }
+
+    /// The number of trits needed to represent the integer.
+    #[wasm_bindgen(getter, js_name = significantTrits)]
+    pub fn significant_trits(&self) -> usize {
+        self.0.significant_trits()
+    }
+
+    /// Returns -1, 0 or 1 for a negative, zero or positive integer.
+    pub fn signum(&self) -> i8 {
+        self.0.signum().into()
+    }
+
+    /// Adds two integers.
+    pub fn add(&self, other: &WasmBigTernary) -> WasmBigTernary {
+        WasmBigTernary(&self.0 + &other.0)
+    }
+
+    /// Subtracts `other` from this integer.
+    pub fn sub(&self, other: &WasmBigTernary) -> WasmBigTernary {
+        WasmBigTernary(&self.0 - &other.0)
+    }
+
+    /// Multiplies two integers.
+    pub fn mul(&self, other: &WasmBigTernary) -> WasmBigTernary {
+        WasmBigTernary(&self.0 * &other.0)
+    }
+
+    /// Divides, rounding towards zero. Throws if `other` is zero.
+    pub fn div(&self, other: &WasmBigTernary) -> Result<WasmBigTernary, JsError> {
+        self.0.try_div_rem(&other.0).map(|(quotient, _)| WasmBigTernary(quotient)).map_err(to_js_error)
+    }
+
+    /// Returns the remainder of [`div`](Self::div), which takes the sign of
+    /// this integer. Throws if `other` is zero.
+    pub fn rem(&self, other: &WasmBigTernary) -> Result<WasmBigTernary, JsError> {
+        self.0.try_div_rem(&other.0).map(|(_, remainder)| WasmBigTernary(remainder)).map_err(to_js_error)
+    }
+
+    /// Negates the integer.
+    pub fn neg(&self) -> WasmBigTernary {
+        WasmBigTernary(-&self.0)
+    }
+
+    /// Returns the absolute value of the integer.
+    pub fn abs(&self) -> WasmBigTernary {
+        WasmBigTernary(self.0.abs())
+    }
+
+    /// Raises the integer to the power of `exponent`.
+    pub fn pow(&self, exponent: u32) -> WasmBigTernary {
+        WasmBigTernary(self.0.pow(exponent))
+    }
+
+    /// Multiplies by 3^`positions` by shifting the trits up.
+    #[wasm_bindgen(js_name = shiftLeft)]
+    pub fn shift_left(&self, positions: usize) -> WasmBigTernary {
+        WasmBigTernary(&self.0 << positions)
+    }
+
+    /// Divides by 3^`positions`, rounding to the nearest, by shifting the
+    /// trits down.
+    #[wasm_bindgen(js_name = shiftRight)]
+    pub fn shift_right(&self, positions: usize) -> WasmBigTernary {
+        WasmBigTernary(&self.0 >> positions)
+    }
+
+    /// Returns -1, 0 or 1 if this integer is less than, equal to or greater
+    /// than `other`, for use with `Array.prototype.sort`.
+    pub fn compare(&self, other: &WasmBigTernary) -> i32 {
+        self.0.cmp(&other.0) as i32
+    }
+
+    /// Returns `true` if the integers are equal.
+    pub fn equals(&self, other: &WasmBigTernary) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unwraps a result, as `JsError` can't be inspected outside of
+    /// WebAssembly.
+    fn ok<T>(result: Result<T, JsError>) -> T {
+        match result {
+            Ok(value) => value,
+            Err(_) => panic!("operation failed")
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        let big = ok(WasmBigTernary::from_number(-7.0));
+        assert_eq!(big.to_trit_string(), "-+-");
+        assert_eq!(big.trits(), [-1, 1, -1]);
+        assert_eq!(big.signum(), -1);
+        assert_eq!(ok(WasmBigTernary::from_number(MAX_SAFE_INTEGER)).to_number(), MAX_SAFE_INTEGER);
+
+        let huge = ok(WasmBigTernary::from_decimal("1000000000000000000000000000000"));
+        assert_eq!(huge.to_number(), 1e30);
+        assert_eq!(huge.to_decimal_string(), "1000000000000000000000000000000");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let x = ok(WasmBigTernary::new("+0--"));
+        let three = ok(WasmBigTernary::from_number(3.0));
+        assert_eq!(x.mul(&three).to_decimal_string(), "69");
+        assert_eq!(x.sub(&three).add(&x.neg()).to_decimal_string(), "-3");
+        assert_eq!(ok(x.div(&three)).to_decimal_string(), "7");
+        assert_eq!(ok(x.rem(&three)).to_decimal_string(), "2");
+        assert_eq!(three.pow(3).shift_left(1).to_trit_string(), "+0000");
+        assert_eq!(x.compare(&three), 1);
+        assert!(x.abs().equals(&x));
+    }
+}